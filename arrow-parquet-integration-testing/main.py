@@ -10,7 +10,12 @@ def get_file_path(file: str):
 
 
 def _prepare(
-    file: str, version: str, compression: str, encoding_utf8: str, projection=None
+    file: str,
+    version: str,
+    compression: str,
+    encoding_utf8: str,
+    projection=None,
+    encoding_int: str = "plain",
 ):
     write = f"{file}.parquet"
 
@@ -26,6 +31,8 @@ def _prepare(
         version,
         "--encoding-utf8",
         encoding_utf8,
+        "--encoding-int",
+        encoding_int,
         "--compression",
         compression,
     ]
@@ -81,13 +88,17 @@ def variations():
             # for encoding in ["plain", "delta"]:
             for encoding in ["plain"]:
                 for compression in ["uncompressed", "zstd", "snappy"]:
-                    yield (version, file, compression, encoding)
+                    yield (version, file, compression, encoding, "plain")
+            # integers encoded with `DELTA_BINARY_PACKED` are read back by pyarrow
+            yield (version, file, "uncompressed", "plain", "delta")
 
 
 if __name__ == "__main__":
-    for (version, file, compression, encoding_utf8) in variations():
+    for (version, file, compression, encoding_utf8, encoding_int) in variations():
         expected = _expected(file)
-        path = _prepare(file, version, compression, encoding_utf8)
+        path = _prepare(
+            file, version, compression, encoding_utf8, encoding_int=encoding_int
+        )
 
         table = pq.read_table(path)
         os.remove(path)
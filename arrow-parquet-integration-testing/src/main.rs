@@ -8,7 +8,7 @@ use arrow2::{
         json_integration::read,
         json_integration::ArrowJson,
         parquet::write::{
-            Compression as ParquetCompression, Encoding, FileWriter, RowGroupIterator,
+            can_encode, Compression as ParquetCompression, Encoding, FileWriter, RowGroupIterator,
             Version as ParquetVersion, WriteOptions,
         },
     },
@@ -109,6 +109,8 @@ struct Args {
     projection: Option<String>,
     #[clap(short, long, arg_enum, help = "encoding scheme for utf8", default_value_t = EncodingScheme::Plain)]
     encoding_utf8: EncodingScheme,
+    #[clap(long, arg_enum, help = "encoding scheme for integers", default_value_t = EncodingScheme::Plain)]
+    encoding_int: EncodingScheme,
     #[clap(short, long, arg_enum)]
     compression: Compression,
 }
@@ -169,6 +171,8 @@ fn main() -> Result<()> {
         write_statistics: true,
         compression: args.compression.into(),
         version: args.version.into(),
+        write_crc: false,
+        bloom_filters: None,
     };
 
     let encodings = schema
@@ -183,12 +187,22 @@ fn main() -> Result<()> {
                     Encoding::Plain
                 }
             }
+            data_type
+                if args.encoding_int == EncodingScheme::Delta
+                    && can_encode(data_type, Encoding::DeltaBinaryPacked) =>
+            {
+                Encoding::DeltaBinaryPacked
+            }
             _ => Encoding::Plain,
         })
         .collect();
 
-    let row_groups =
-        RowGroupIterator::try_new(batches.into_iter().map(Ok), &schema, options, encodings)?;
+    let row_groups = RowGroupIterator::try_new(
+        batches.into_iter().map(Ok),
+        &schema,
+        options.clone(),
+        encodings,
+    )?;
 
     let writer = File::create(args.write_path)?;
 
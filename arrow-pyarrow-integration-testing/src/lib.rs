@@ -78,10 +78,17 @@ fn to_py_array(array: Arc<dyn Array>, py: Python) -> PyResult<PyObject> {
     let array_ptr = Box::into_raw(array_ptr);
     let schema_ptr = Box::into_raw(schema_ptr);
 
-    unsafe {
-        ffi::export_field_to_c(&Field::new("", array.data_type().clone(), true), schema_ptr);
-        ffi::export_array_to_c(array, array_ptr);
+    let exported = unsafe {
+        ffi::export_field_to_c(&Field::new("", array.data_type().clone(), true), schema_ptr)
+            .and_then(|_| ffi::export_array_to_c(array, array_ptr))
     };
+    if let Err(error) = exported {
+        unsafe {
+            Box::from_raw(array_ptr);
+            Box::from_raw(schema_ptr);
+        };
+        return Err(PyO3ArrowError::from(error).into());
+    }
 
     let pa = py.import("pyarrow")?;
 
@@ -117,9 +124,10 @@ fn to_py_field(field: &Field, py: Python) -> PyResult<PyObject> {
     let schema_ptr = Box::new(ffi::Ffi_ArrowSchema::empty());
     let schema_ptr = Box::into_raw(schema_ptr);
 
-    unsafe {
-        ffi::export_field_to_c(field, schema_ptr);
-    };
+    if let Err(error) = unsafe { ffi::export_field_to_c(field, schema_ptr) } {
+        unsafe { Box::from_raw(schema_ptr) };
+        return Err(PyO3ArrowError::from(error).into());
+    }
 
     let pa = py.import("pyarrow")?;
 
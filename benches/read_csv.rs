@@ -0,0 +1,94 @@
+use std::io::Cursor;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::error::Result;
+use arrow2::io::csv::read;
+
+/// Returns a CSV with a header and `num_columns` columns alternating integers, floats and
+/// (quoted) strings.
+fn make_csv(num_rows: usize, num_columns: usize) -> Vec<u8> {
+    let mut data = (0..num_columns)
+        .map(|i| format!("c{}", i))
+        .collect::<Vec<_>>()
+        .join(",");
+    data.push('\n');
+    for row in 0..num_rows {
+        let line = (0..num_columns)
+            .map(|column| match column % 3 {
+                0 => format!("{}", row * column),
+                1 => format!("{}.5", row),
+                _ => format!("\"value, {}\"", row),
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        data.push_str(&line);
+        data.push('\n');
+    }
+    data.into_bytes()
+}
+
+fn read_full(data: &[u8], num_rows: usize, projection: &[usize]) -> Result<()> {
+    let mut reader = read::ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = read::infer_schema(&mut reader, Some(10), true, &read::infer)?;
+
+    let mut rows = vec![read::ByteRecord::default(); num_rows];
+    let rows_read = read::read_rows(&mut reader, 0, &mut rows)?;
+    let chunk = read::deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        Some(projection),
+        0,
+        read::deserialize_column,
+    )?;
+    assert_eq!(chunk.len(), num_rows);
+    Ok(())
+}
+
+fn read_projected(data: &[u8], num_rows: usize, projection: &[usize]) -> Result<()> {
+    let mut reader = read::ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = read::infer_schema(&mut reader, Some(10), true, &read::infer)?;
+    let fields = projection
+        .iter()
+        .map(|i| fields[*i].clone())
+        .collect::<Vec<_>>();
+
+    let mut rows = vec![read::ByteRecord::default(); num_rows];
+    let rows_read = read::read_rows_projected(&mut reader, 0, &mut rows, projection)?;
+    let chunk = read::deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        read::deserialize_column,
+    )?;
+    assert_eq!(chunk.len(), num_rows);
+    Ok(())
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let num_columns = 50;
+    let all = (0..num_columns).collect::<Vec<_>>();
+    let projection = [1, 20, 42];
+
+    (10..=14).step_by(2).for_each(|log2_size| {
+        let size = 2usize.pow(log2_size);
+        let data = make_csv(size, num_columns);
+
+        c.bench_function(&format!("csv read 50 columns 2^{}", log2_size), |b| {
+            b.iter(|| read_full(&data, size, &all))
+        });
+
+        c.bench_function(&format!("csv read 3 of 50 columns 2^{}", log2_size), |b| {
+            b.iter(|| read_full(&data, size, &projection))
+        });
+
+        c.bench_function(
+            &format!("csv read projected 3 of 50 columns 2^{}", log2_size),
+            |b| b.iter(|| read_projected(&data, size, &projection)),
+        );
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
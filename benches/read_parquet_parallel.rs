@@ -0,0 +1,93 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use arrow2::array::Array;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::parquet::{read, write::*};
+use arrow2::util::bench_util::create_primitive_array;
+
+const NUM_COLUMNS: usize = 16;
+
+fn to_buffer(size: usize) -> Result<Vec<u8>> {
+    let columns = (0..NUM_COLUMNS)
+        .map(|_| Arc::new(create_primitive_array::<i64>(size, 0.1)) as Arc<dyn Array>)
+        .collect::<Vec<_>>();
+    let schema = Schema::from(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(Chunk::new(columns))].into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain; NUM_COLUMNS],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+fn read_serial(buffer: &[u8]) -> Result<Vec<Arc<dyn Array>>> {
+    let mut reader = Cursor::new(buffer);
+    let metadata = read::read_metadata(&mut reader)?;
+    let schema = read::infer_schema(&metadata)?;
+
+    read::read_columns_many(&mut reader, &metadata.row_groups[0], schema.fields, None)?
+        .into_iter()
+        .map(|mut iter| iter.next().unwrap())
+        .collect()
+}
+
+fn read_parallel(buffer: &[u8]) -> Result<Vec<Arc<dyn Array>>> {
+    let mut reader = Cursor::new(buffer);
+    let metadata = read::read_metadata(&mut reader)?;
+    let schema = read::infer_schema(&metadata)?;
+
+    read::read_columns_many_parallel(
+        &mut reader,
+        &metadata.row_groups[0],
+        schema.fields,
+        |task| {
+            std::thread::spawn(task);
+        },
+    )
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    (10..=20).step_by(2).for_each(|i| {
+        let size = 2usize.pow(i);
+        let buffer = to_buffer(size).unwrap();
+
+        let a = format!("read 16 i64 serial 2^{}", i);
+        c.bench_function(&a, |b| b.iter(|| read_serial(&buffer).unwrap()));
+
+        let a = format!("read 16 i64 parallel 2^{}", i);
+        c.bench_function(&a, |b| b.iter(|| read_parallel(&buffer).unwrap()));
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
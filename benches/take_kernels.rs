@@ -20,6 +20,19 @@ fn create_random_index(size: usize, null_density: f32) -> PrimitiveArray<i32> {
         .collect::<PrimitiveArray<i32>>()
 }
 
+// ascending indices with runs of 63 consecutive indices, e.g. resulting from a range selection
+fn create_sorted_index(size: usize) -> PrimitiveArray<i32> {
+    (0..size as i32)
+        .map(|i| i + i / 63)
+        .filter(|i| (*i as usize) < size)
+        .map(Some)
+        .collect::<PrimitiveArray<i32>>()
+}
+
+fn bench_take_sorted(values: &dyn Array, indices: &PrimitiveArray<i32>) {
+    criterion::black_box(take::take_sorted(values, indices).unwrap());
+}
+
 fn bench_take(values: &dyn Array, indices: &PrimitiveArray<i32>) {
     criterion::black_box(take::take(values, indices).unwrap());
 }
@@ -36,6 +49,11 @@ fn add_benchmark(c: &mut Criterion) {
             b.iter(|| bench_take(&values, &indices))
         });
 
+        let sorted_indices = create_sorted_index(size);
+        c.bench_function(&format!("take i32 sorted 2^{}", log2_size), |b| {
+            b.iter(|| bench_take_sorted(&values, &sorted_indices))
+        });
+
         c.bench_function(&format!("take i32 nulls 2^{}", log2_size), |b| {
             b.iter(|| bench_take(&values, &indices_nulls))
         });
@@ -20,12 +20,14 @@ fn write(array: &dyn Array, encoding: Encoding) -> Result<()> {
         write_statistics: false,
         compression: Compression::Uncompressed,
         version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
     };
 
     let row_groups = RowGroupIterator::try_new(
         vec![Ok(columns)].into_iter(),
         &schema,
-        options,
+        options.clone(),
         vec![encoding],
     )?;
 
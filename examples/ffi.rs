@@ -8,10 +8,10 @@ unsafe fn export(
     array: Arc<dyn Array>,
     array_ptr: *mut ffi::Ffi_ArrowArray,
     schema_ptr: *mut ffi::Ffi_ArrowSchema,
-) {
+) -> Result<()> {
     let field = Field::new("a", array.data_type().clone(), true);
-    ffi::export_array_to_c(array, array_ptr);
-    ffi::export_field_to_c(&field, schema_ptr);
+    ffi::export_array_to_c(array, array_ptr)?;
+    ffi::export_field_to_c(&field, schema_ptr)
 }
 
 unsafe fn import(
@@ -39,7 +39,7 @@ fn main() -> Result<()> {
     // this is where a producer (in this case also us ^_^) writes to the pointers' location.
     // `array` here could be anything or not even be available, if this was e.g. from Python.
     // Safety: we just allocated the pointers correctly.
-    unsafe { export(array.clone(), array_ptr, schema_ptr) };
+    unsafe { export(array.clone(), array_ptr, schema_ptr)? };
 
     // we can now take ownership back, since we are responsible for deallocating this memory.
     // Safety: we just into_raw them.
@@ -16,12 +16,18 @@ fn write_batch(path: &str, schema: Schema, columns: Chunk<Arc<dyn Array>>) -> Re
         write_statistics: true,
         compression: Compression::Uncompressed,
         version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
     };
 
     let iter = vec![Ok(columns)];
 
-    let row_groups =
-        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
 
     // Create a new empty file
     let file = File::create(path)?;
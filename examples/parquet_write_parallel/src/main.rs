@@ -48,6 +48,8 @@ fn parallel_write(path: &str, schema: &Schema, batches: &[Chunk]) -> Result<()>
         write_statistics: true,
         compression: Compression::Snappy,
         version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
     };
 
     // declare encodings
@@ -76,7 +78,8 @@ fn parallel_write(path: &str, schema: &Schema, batches: &[Chunk]) -> Result<()>
             .zip(encodings.clone())
             .map(|((array, descriptor), encoding)| {
                 // create encoded and compressed pages this column
-                let encoded_pages = array_to_pages(array.as_ref(), descriptor, options, encoding)?;
+                let encoded_pages =
+                    array_to_pages(array.as_ref(), descriptor, options.clone(), encoding)?;
                 encoded_pages
                     .map(|page| compress(page?, vec![], options.compression).map_err(|x| x.into()))
                     .collect::<Result<VecDeque<_>>>()
@@ -96,7 +96,7 @@ async fn upload_data(
 
     let options = write::WriteOptions { compression: None };
 
-    let mut schema = flight::serialize_schema(schema, Some(fields));
+    let mut schema = flight::serialize_schema(schema, Some(fields))?;
     schema.flight_descriptor = Some(descriptor.clone());
     upload_tx.send(schema).await?;
 
@@ -149,7 +149,7 @@ async fn send_batch(
     fields: &[IpcField],
     options: &write::WriteOptions,
 ) -> Result {
-    let (dictionary_flight_data, mut batch_flight_data) = serialize_batch(batch, fields, options);
+    let (dictionary_flight_data, mut batch_flight_data) = serialize_batch(batch, fields, options)?;
 
     upload_tx
         .send_all(&mut stream::iter(dictionary_flight_data).map(Ok))
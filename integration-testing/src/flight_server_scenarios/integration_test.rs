@@ -111,30 +111,34 @@ impl FlightService for FlightServiceImpl {
 
         let options = ipc::write::WriteOptions { compression: None };
 
-        let schema = std::iter::once(Ok(serialize_schema(
-            &flight.schema,
-            Some(&flight.ipc_schema.fields),
-        )));
+        let schema = serialize_schema(&flight.schema, Some(&flight.ipc_schema.fields))
+            .map_err(|e| Status::internal(e.to_string()))?;
 
         let batches = flight
             .chunks
             .iter()
             .enumerate()
-            .flat_map(|(counter, batch)| {
+            .map(|(counter, batch)| {
                 let (dictionary_flight_data, mut batch_flight_data) =
-                    serialize_batch(batch, &flight.ipc_schema.fields, &options);
+                    serialize_batch(batch, &flight.ipc_schema.fields, &options)
+                        .map_err(|e| Status::internal(e.to_string()))?;
 
                 // Only the record batch's FlightData gets app_metadata
                 let metadata = counter.to_string().into_bytes();
                 batch_flight_data.app_metadata = metadata;
 
-                dictionary_flight_data
+                Ok(dictionary_flight_data
                     .into_iter()
-                    .chain(std::iter::once(batch_flight_data))
-                    .map(Ok)
-            });
-
-        let output = futures::stream::iter(schema.chain(batches).collect::<Vec<_>>());
+                    .chain(std::iter::once(batch_flight_data)))
+            })
+            .collect::<Result<Vec<_>, Status>>()?;
+
+        let output = futures::stream::iter(
+            std::iter::once(schema)
+                .chain(batches.into_iter().flatten())
+                .map(Ok)
+                .collect::<Vec<_>>(),
+        );
 
         Ok(Response::new(Box::pin(output) as Self::DoGetStream))
     }
@@ -241,6 +241,8 @@ def write_pyarrow(
     use_dictionary: bool,
     multiple_pages: bool,
     compression: str,
+    use_byte_stream_split: bool = False,
+    use_delta_binary_packed: bool = False,
 ):
     data, schema, path = case(size)
 
@@ -248,6 +250,18 @@ def write_pyarrow(
     if use_dictionary:
         base_path = f"{base_path}/dict"
 
+    if use_byte_stream_split:
+        base_path = f"{base_path}/byte_stream_split"
+        # only floating point columns support `BYTE_STREAM_SPLIT`
+        use_byte_stream_split = ["float64"]
+
+    # `column_encoding` requires pyarrow >= 7, so it is only passed when used
+    extra_options = {}
+    if use_delta_binary_packed:
+        base_path = f"{base_path}/delta_binary_packed"
+        # the int64 column is encoded with `DELTA_BINARY_PACKED`
+        extra_options["column_encoding"] = {"int64": "DELTA_BINARY_PACKED"}
+
     if multiple_pages:
         base_path = f"{base_path}/multi"
 
@@ -270,6 +284,8 @@ def write_pyarrow(
         write_statistics=True,
         data_page_size=data_page_size,
         data_page_version=f"{page_version}.0",
+        use_byte_stream_split=use_byte_stream_split,
+        **extra_options,
     )
 
 
@@ -279,6 +295,11 @@ for case in [case_basic_nullable, case_basic_required, case_nested, case_struct]
             for compression in ["lz4", None, "snappy"]:
                 write_pyarrow(case, 1, version, use_dict, False, compression)
 
+for case in [case_basic_nullable, case_basic_required]:
+    for version in [1, 2]:
+        write_pyarrow(case, 1, version, False, False, None, True)
+        write_pyarrow(case, 1, version, False, False, None, False, True)
+
 
 def case_benches(size):
     assert size % 8 == 0
@@ -11,9 +11,11 @@ mod list;
 mod map;
 mod null;
 mod primitive;
+mod run_end;
 mod struct_;
 mod union;
 mod utf8;
+mod utf8_view;
 
 impl PartialEq for dyn Array + '_ {
     fn eq(&self, that: &dyn Array) -> bool {
@@ -81,6 +83,18 @@ impl<O: Offset> PartialEq<&dyn Array> for Utf8Array<O> {
     }
 }
 
+impl PartialEq<Utf8ViewArray> for Utf8ViewArray {
+    fn eq(&self, other: &Self) -> bool {
+        utf8_view::equal(self, other)
+    }
+}
+
+impl PartialEq<&dyn Array> for Utf8ViewArray {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 impl<O: Offset> PartialEq<BinaryArray<O>> for BinaryArray<O> {
     fn eq(&self, other: &Self) -> bool {
         binary::equal(self, other)
@@ -153,6 +167,18 @@ impl<K: DictionaryKey> PartialEq<&dyn Array> for DictionaryArray<K> {
     }
 }
 
+impl<R: RunEndIndex> PartialEq<RunEndEncodedArray<R>> for RunEndEncodedArray<R> {
+    fn eq(&self, other: &Self) -> bool {
+        run_end::equal(self, other)
+    }
+}
+
+impl<R: RunEndIndex> PartialEq<&dyn Array> for RunEndEncodedArray<R> {
+    fn eq(&self, other: &&dyn Array) -> bool {
+        equal(self, *other)
+    }
+}
+
 impl PartialEq<UnionArray> for UnionArray {
     fn eq(&self, other: &Self) -> bool {
         union::equal(self, other)
@@ -201,6 +227,11 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             utf8::equal::<i64>(lhs, rhs)
         }
+        Utf8View => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let rhs = rhs.as_any().downcast_ref().unwrap();
+            utf8_view::equal(lhs, rhs)
+        }
         Binary => {
             let lhs = lhs.as_any().downcast_ref().unwrap();
             let rhs = rhs.as_any().downcast_ref().unwrap();
@@ -253,5 +284,12 @@ pub fn equal(lhs: &dyn Array, rhs: &dyn Array) -> bool {
             let rhs = rhs.as_any().downcast_ref().unwrap();
             map::equal(lhs, rhs)
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                run_end::equal::<$T>(lhs, rhs)
+            })
+        }
     }
 }
@@ -0,0 +1,12 @@
+use crate::array::{Array, RunEndEncodedArray, RunEndIndex};
+
+pub(super) fn equal<R: RunEndIndex>(
+    lhs: &RunEndEncodedArray<R>,
+    rhs: &RunEndEncodedArray<R>,
+) -> bool {
+    if !(lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len()) {
+        return false;
+    };
+
+    lhs.iter().zip(rhs.iter()).all(|(x, y)| x == y)
+}
@@ -0,0 +1,5 @@
+use crate::array::{Array, Utf8ViewArray};
+
+pub(super) fn equal(lhs: &Utf8ViewArray, rhs: &Utf8ViewArray) -> bool {
+    lhs.data_type() == rhs.data_type() && lhs.len() == rhs.len() && lhs.iter().eq(rhs.iter())
+}
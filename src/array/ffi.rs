@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::datatypes::PhysicalType;
 use crate::{array::*, ffi};
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 
 /// Trait describing how a struct presents itself to the
 /// [C data interface](https://arrow.apache.org/docs/format/CDataInterface.html) (FFI).
@@ -54,9 +54,9 @@ type BuffersChildren = (
     Option<Arc<dyn Array>>,
 );
 
-pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren {
+pub fn offset_buffers_children_dictionary(array: &dyn Array) -> Result<BuffersChildren> {
     use PhysicalType::*;
-    match array.data_type().to_physical_type() {
+    Ok(match array.data_type().to_physical_type() {
         Null => ffi_dyn!(array, NullArray),
         Boolean => ffi_dyn!(array, BooleanArray),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
@@ -84,5 +84,15 @@ pub fn offset_buffers_children_dictionary(array: &dyn Array) -> BuffersChildren
                 )
             })
         }
-    }
+        Utf8View => {
+            return Err(ArrowError::NotYetImplemented(
+                "Exporting Utf8View arrays to the C data interface".to_string(),
+            ))
+        }
+        RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Exporting run-end encoded arrays to the C data interface".to_string(),
+            ))
+        }
+    })
 }
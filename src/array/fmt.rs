@@ -58,6 +58,9 @@ pub fn get_value_display<'a, F: Write + 'a>(
                 f,
             )
         }),
+        Utf8View => Box::new(|f, index| {
+            super::utf8_view::fmt::write_value(array.as_any().downcast_ref().unwrap(), index, f)
+        }),
         List => Box::new(move |f, index| {
             super::list::fmt::write_value::<i32, _>(
                 array.as_any().downcast_ref().unwrap(),
@@ -94,6 +97,11 @@ pub fn get_value_display<'a, F: Write + 'a>(
                 super::dictionary::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
             })
         }),
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            Box::new(move |f, index| {
+                super::run_end::fmt::write_value::<$T,_>(array.as_any().downcast_ref().unwrap(), index, null, f)
+            })
+        }),
     }
 }
 
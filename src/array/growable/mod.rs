@@ -22,8 +22,12 @@ mod fixed_size_list;
 pub use fixed_size_list::GrowableFixedSizeList;
 mod utf8;
 pub use utf8::GrowableUtf8;
+mod utf8_view;
+pub use utf8_view::GrowableUtf8View;
 mod dictionary;
 pub use dictionary::GrowableDictionary;
+mod run_end;
+pub use run_end::GrowableRunEndEncoded;
 
 mod utils;
 
@@ -84,6 +88,12 @@ pub fn make_growable<'a>(
         }),
         Utf8 => dyn_growable!(utf8::GrowableUtf8::<i32>, arrays, use_validity, capacity),
         LargeUtf8 => dyn_growable!(utf8::GrowableUtf8::<i64>, arrays, use_validity, capacity),
+        Utf8View => dyn_growable!(
+            utf8_view::GrowableUtf8View,
+            arrays,
+            use_validity,
+            capacity
+        ),
         Binary => dyn_growable!(
             binary::GrowableBinary::<i32>,
             arrays,
@@ -130,5 +140,23 @@ pub fn make_growable<'a>(
                 ))
             })
         }
+        RunEndEncoded(run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                let arrays = arrays
+                    .iter()
+                    .map(|array| {
+                        array
+                            .as_any()
+                            .downcast_ref::<RunEndEncodedArray<$T>>()
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+                Box::new(run_end::GrowableRunEndEncoded::<$T>::new(
+                    &arrays,
+                    use_validity,
+                    capacity,
+                ))
+            })
+        }
     }
 }
@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, PrimitiveArray, RunEndEncodedArray, RunEndIndex},
+    datatypes::DataType,
+};
+
+use super::{make_growable, Growable};
+
+/// Concrete [`Growable`] for the [`RunEndEncodedArray`].
+/// # Implementation
+/// Every extension appends one run per run of the slice that it covers, so that the result
+/// has at most as many runs as the slices used to build it (adjacent equal runs are not merged).
+pub struct GrowableRunEndEncoded<'a, R: RunEndIndex> {
+    data_type: DataType,
+    arrays: Vec<&'a RunEndEncodedArray<R>>,
+    run_ends: Vec<R>,
+    values: Box<dyn Growable<'a> + 'a>,
+    length: usize,
+}
+
+impl<'a, R: RunEndIndex> GrowableRunEndEncoded<'a, R> {
+    /// Creates a new [`GrowableRunEndEncoded`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: &[&'a RunEndEncodedArray<R>], use_validity: bool, capacity: usize) -> Self {
+        let data_type = arrays[0].data_type().clone();
+
+        let values = arrays
+            .iter()
+            .map(|array| array.values().as_ref())
+            .collect::<Vec<_>>();
+        let values = make_growable(&values, use_validity, capacity);
+
+        Self {
+            data_type,
+            arrays: arrays.to_vec(),
+            run_ends: Vec::with_capacity(capacity),
+            values,
+            length: 0,
+        }
+    }
+
+    #[inline]
+    fn push_run(&mut self, length: usize) {
+        self.length += length;
+        self.run_ends.push(R::from_usize(self.length).unwrap());
+    }
+
+    fn to(&mut self) -> RunEndEncodedArray<R> {
+        let run_ends = std::mem::take(&mut self.run_ends);
+        let values = self.values.as_arc();
+        self.length = 0;
+
+        let run_ends = PrimitiveArray::<R>::from_data(R::PRIMITIVE.into(), run_ends.into(), None);
+        let array = RunEndEncodedArray::<R>::from_data(run_ends, values);
+        debug_assert_eq!(array.data_type(), &self.data_type);
+        array
+    }
+}
+
+impl<'a, R: RunEndIndex> Growable<'a> for GrowableRunEndEncoded<'a, R> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let array = self.arrays[index];
+        let run_ends = array.run_ends().values();
+
+        let mut position = array.offset() + start;
+        let end = position + len;
+        let mut run = array.physical_index(start);
+        while position < end {
+            let run_end = run_ends[run].to_usize().unwrap().min(end);
+            self.values.extend(index, run, 1);
+            self.push_run(run_end - position);
+            position = run_end;
+            run += 1;
+        }
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        if additional == 0 {
+            return;
+        }
+        self.values.extend_validity(1);
+        self.push_run(additional);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a, R: RunEndIndex> From<GrowableRunEndEncoded<'a, R>> for RunEndEncodedArray<R> {
+    #[inline]
+    fn from(mut val: GrowableRunEndEncoded<'a, R>) -> Self {
+        val.to()
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, Utf8ViewArray, View},
+    bitmap::MutableBitmap,
+};
+
+use super::{
+    utils::{build_extend_null_bits, ExtendNullBits},
+    Growable,
+};
+
+/// Concrete [`Growable`] for the [`Utf8ViewArray`].
+/// # Implementation
+/// The data buffers of all arrays are shared by the result, so that extending it copies
+/// the views but not the strings.
+pub struct GrowableUtf8View<'a> {
+    arrays: Vec<&'a Utf8ViewArray>,
+    validity: MutableBitmap,
+    views: Vec<i128>,
+    // the index of the first data buffer of each array in the data buffers of the result
+    buffer_offsets: Vec<u32>,
+    extend_null_bits: Vec<ExtendNullBits<'a>>,
+}
+
+impl<'a> GrowableUtf8View<'a> {
+    /// Creates a new [`GrowableUtf8View`] bound to `arrays` with a pre-allocated `capacity`.
+    /// # Panics
+    /// If `arrays` is empty.
+    pub fn new(arrays: Vec<&'a Utf8ViewArray>, mut use_validity: bool, capacity: usize) -> Self {
+        // if any of the arrays has nulls, insertions from any array requires setting bits
+        // as there is at least one array with nulls.
+        if arrays.iter().any(|array| array.null_count() > 0) {
+            use_validity = true;
+        };
+
+        let extend_null_bits = arrays
+            .iter()
+            .map(|array| build_extend_null_bits(*array, use_validity))
+            .collect();
+
+        let buffer_offsets = arrays
+            .iter()
+            .scan(0u32, |offset, array| {
+                let current = *offset;
+                *offset += array.buffers().len() as u32;
+                Some(current)
+            })
+            .collect();
+
+        Self {
+            arrays,
+            views: Vec::with_capacity(capacity),
+            buffer_offsets,
+            validity: MutableBitmap::with_capacity(capacity),
+            extend_null_bits,
+        }
+    }
+
+    fn to(&mut self) -> Utf8ViewArray {
+        let validity = std::mem::take(&mut self.validity);
+        let views = std::mem::take(&mut self.views);
+        let buffers = self
+            .arrays
+            .iter()
+            .flat_map(|array| array.buffers().iter().cloned())
+            .collect();
+
+        // Safety: every view references the same bytes as the view it was copied from
+        unsafe {
+            Utf8ViewArray::from_data_unchecked(
+                self.arrays[0].data_type().clone(),
+                views.into(),
+                buffers,
+                validity.into(),
+            )
+        }
+    }
+}
+
+impl<'a> Growable<'a> for GrowableUtf8View<'a> {
+    fn extend(&mut self, index: usize, start: usize, len: usize) {
+        (self.extend_null_bits[index])(&mut self.validity, start, len);
+
+        let array = self.arrays[index];
+        let buffer_offset = self.buffer_offsets[index];
+        self.views
+            .extend(array.views()[start..start + len].iter().map(|raw| {
+                let view = View::from_i128(*raw);
+                if view.is_inline() || buffer_offset == 0 {
+                    *raw
+                } else {
+                    view.with_buffer_index(view.buffer_index() + buffer_offset)
+                        .to_i128()
+                }
+            }));
+    }
+
+    fn extend_validity(&mut self, additional: usize) {
+        self.views.resize(self.views.len() + additional, 0);
+        self.validity.extend_constant(additional, false);
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+}
+
+impl<'a> From<GrowableUtf8View<'a>> for Utf8ViewArray {
+    fn from(mut val: GrowableUtf8View<'a>) -> Self {
+        val.to()
+    }
+}
@@ -10,7 +10,9 @@ use super::{new_empty_array, specification::check_offsets, Array};
 
 mod ffi;
 mod iterator;
+mod mutable;
 pub use iterator::*;
+pub use mutable::*;
 
 /// An array representing a (key, value), both of arbitrary logical types.
 #[derive(Debug, Clone)]
@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, StructArray, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::{DataType, Field},
+    error::{ArrowError, Result},
+};
+
+use super::MapArray;
+
+/// The mutable version of [`MapArray`].
+///
+/// The entries of a map are appended via [`MutableMapArray::push_keys_values`], and the map is
+/// then closed via [`MutableMapArray::try_push_valid`]. When the [`DataType::Map`] declares its
+/// keys sorted, the keys of every map must be strictly increasing.
+#[derive(Debug)]
+pub struct MutableMapArray<K: MutableArray, V: MutableArray> {
+    data_type: DataType,
+    offsets: Vec<i32>,
+    keys: K,
+    values: V,
+    validity: Option<MutableBitmap>,
+}
+
+impl<K: MutableArray, V: MutableArray> MutableMapArray<K, V> {
+    /// Creates a new empty [`MutableMapArray`] whose keys are not sorted, with fields named
+    /// `"entries"`, `"key"` and `"value"`.
+    /// # Panics
+    /// This function panics iff `keys` or `values` is not empty.
+    pub fn new(keys: K, values: V) -> Self {
+        let data_type = Self::default_datatype(keys.data_type(), values.data_type(), false);
+        Self::try_new(data_type, keys, values).unwrap()
+    }
+
+    /// Creates a new empty [`MutableMapArray`].
+    /// # Errors
+    /// This function errors iff:
+    /// * the logical type of `data_type` is not [`DataType::Map`]
+    /// * the field of `data_type` is not a [`DataType::Struct`] with two fields whose data types
+    ///   are the data types of `keys` and `values`
+    /// * `keys` or `values` is not empty
+    pub fn try_new(data_type: DataType, keys: K, values: V) -> Result<Self> {
+        let field = match data_type.to_logical_type() {
+            DataType::Map(field, _) => field,
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "A MutableMapArray must be created with a DataType::Map".to_string(),
+                ))
+            }
+        };
+        match field.data_type().to_logical_type() {
+            DataType::Struct(fields)
+                if fields.len() == 2
+                    && fields[0].data_type() == keys.data_type()
+                    && fields[1].data_type() == values.data_type() => {}
+            other => return Err(ArrowError::InvalidArgumentError(format!(
+                "The field of a MutableMapArray must be a struct of {:?} and {:?}, but it is {:?}",
+                keys.data_type(),
+                values.data_type(),
+                other
+            ))),
+        };
+        if !keys.is_empty() || !values.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "The keys and values of a new MutableMapArray must be empty".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            data_type,
+            offsets: vec![0],
+            keys,
+            values,
+            validity: None,
+        })
+    }
+
+    /// Returns the [`DataType::Map`] of entries named `"entries"` with non-nullable keys named
+    /// `"key"` and nullable values named `"value"`.
+    pub fn default_datatype(keys: &DataType, values: &DataType, keys_sorted: bool) -> DataType {
+        let entries = DataType::Struct(vec![
+            Field::new("key", keys.clone(), false),
+            Field::new("value", values.clone(), true),
+        ]);
+        DataType::Map(Box::new(Field::new("entries", entries, false)), keys_sorted)
+    }
+
+    fn keys_sorted(&self) -> bool {
+        matches!(self.data_type.to_logical_type(), DataType::Map(_, true))
+    }
+
+    /// Appends entries to the current map, one per pair of `keys` and `values`.
+    /// # Errors
+    /// This function errors iff:
+    /// * `keys` and `values` have different lengths
+    /// * the keys are declared sorted and `keys` is not strictly increasing, or entries were
+    ///   already appended to the current map (the entries of a map with sorted keys must be
+    ///   appended at once)
+    /// * pushing to the keys or values errors
+    pub fn push_keys_values<KT, VT, KI, VI>(&mut self, keys: KI, values: VI) -> Result<()>
+    where
+        KT: PartialOrd,
+        K: TryPush<Option<KT>>,
+        V: TryPush<Option<VT>>,
+        KI: IntoIterator<Item = KT>,
+        VI: IntoIterator<Item = Option<VT>>,
+    {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        let values = values.into_iter().collect::<Vec<_>>();
+        if keys.len() != values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A map entry requires one key per value, but {} keys and {} values were passed",
+                keys.len(),
+                values.len()
+            )));
+        }
+        if self.keys_sorted() && !keys.is_empty() {
+            if self.keys.len() != self.last_offset() as usize {
+                return Err(ArrowError::InvalidArgumentError(
+                    "The entries of a map with sorted keys must be pushed at once".to_string(),
+                ));
+            }
+            if keys.windows(2).any(|pair| pair[0] >= pair[1]) {
+                return Err(ArrowError::InvalidArgumentError(
+                    "The keys of a map with sorted keys must be strictly increasing".to_string(),
+                ));
+            }
+        }
+
+        for (key, value) in keys.into_iter().zip(values) {
+            self.keys.try_push(Some(key))?;
+            self.values.try_push(value)?;
+        }
+        Ok(())
+    }
+
+    /// Needs to be called when the entries of a valid map were appended to this array.
+    /// # Errors
+    /// This function errors iff the number of entries overflows an `i32`.
+    #[inline]
+    pub fn try_push_valid(&mut self) -> Result<()> {
+        let size = i32::try_from(self.keys.len()).map_err(|_| ArrowError::Overflow)?;
+        self.offsets.push(size);
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    /// Pushes a null map. Entries appended to the current map, if any, are kept as the
+    /// (masked) entries of the null map.
+    /// # Errors
+    /// This function errors iff the number of entries overflows an `i32`.
+    #[inline]
+    pub fn try_push_null(&mut self) -> Result<()> {
+        let size = i32::try_from(self.keys.len()).map_err(|_| ArrowError::Overflow)?;
+        self.offsets.push(size);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+        Ok(())
+    }
+
+    /// Pushes a null map. Entries appended to the current map, if any, are kept as the
+    /// (masked) entries of the null map.
+    /// # Panics
+    /// This function panics iff the number of entries overflows an `i32`.
+    #[inline]
+    pub fn push_null(&mut self) {
+        self.try_push_null().unwrap()
+    }
+
+    #[inline]
+    fn last_offset(&self) -> i32 {
+        *self.offsets.last().unwrap()
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len() - 1;
+
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    /// The offsets
+    pub fn offsets(&self) -> &[i32] {
+        &self.offsets
+    }
+
+    /// The keys
+    pub fn keys(&self) -> &K {
+        &self.keys
+    }
+
+    /// The values
+    pub fn values(&self) -> &V {
+        &self.values
+    }
+
+    fn to(&mut self) -> MapArray {
+        let field = MapArray::get_field(&self.data_type).data_type().clone();
+        let field =
+            StructArray::from_data(field, vec![self.keys.as_arc(), self.values.as_arc()], None);
+        MapArray::from_data(
+            self.data_type.clone(),
+            std::mem::replace(&mut self.offsets, vec![0]).into(),
+            Arc::new(field),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+
+    /// Shrinks the capacity of the [`MutableMapArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+}
+
+impl<K: MutableArray, V: MutableArray> From<MutableMapArray<K, V>> for MapArray {
+    fn from(mut other: MutableMapArray<K, V>) -> Self {
+        other.to()
+    }
+}
+
+impl<K: MutableArray + 'static, V: MutableArray + 'static> MutableArray for MutableMapArray<K, V> {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push_null()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
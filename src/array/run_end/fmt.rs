@@ -0,0 +1,23 @@
+use std::fmt::{Debug, Formatter, Result, Write};
+
+use super::super::fmt::{get_display, write_vec};
+use super::{RunEndEncodedArray, RunEndIndex};
+
+pub fn write_value<R: RunEndIndex, W: Write>(
+    array: &RunEndEncodedArray<R>,
+    index: usize,
+    null: &'static str,
+    f: &mut W,
+) -> Result {
+    let index = array.physical_index(index);
+    get_display(array.values().as_ref(), null)(f, index)
+}
+
+impl<R: RunEndIndex> Debug for RunEndEncodedArray<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let writer = |f: &mut Formatter, index| write_value(self, index, "None", f);
+
+        write!(f, "RunEndEncodedArray")?;
+        write_vec(f, writer, None, self.len(), "None", false)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::scalar::{new_scalar, Scalar};
+use crate::trusted_len::TrustedLen;
+
+use super::{RunEndEncodedArray, RunEndIndex};
+
+/// Iterator of values of a [`RunEndEncodedArray`].
+/// # Implementation
+/// This iterator walks the runs, so that iterating over the array is `O(n)`.
+pub struct RunEndEncodedIter<'a, R: RunEndIndex> {
+    array: &'a RunEndEncodedArray<R>,
+    // logical index, in the coordinates of the run ends
+    index: usize,
+    end: usize,
+    // index of the run of `index`
+    run: usize,
+}
+
+impl<'a, R: RunEndIndex> RunEndEncodedIter<'a, R> {
+    /// Returns a new [`RunEndEncodedIter`] over the values of `array`.
+    #[inline]
+    pub fn new(array: &'a RunEndEncodedArray<R>) -> Self {
+        let run = if array.is_empty() {
+            0
+        } else {
+            array.physical_index(0)
+        };
+        Self {
+            array,
+            index: array.offset(),
+            end: array.offset() + array.len(),
+            run,
+        }
+    }
+}
+
+impl<'a, R: RunEndIndex> Iterator for RunEndEncodedIter<'a, R> {
+    type Item = Box<dyn Scalar>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let run_ends = self.array.run_ends().values();
+        while run_ends[self.run].to_usize().unwrap() <= self.index {
+            self.run += 1;
+        }
+        self.index += 1;
+        Some(new_scalar(self.array.values().as_ref(), self.run))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+unsafe impl<'a, R: RunEndIndex> TrustedLen for RunEndEncodedIter<'a, R> {}
+
+impl<'a, R: RunEndIndex> std::iter::ExactSizeIterator for RunEndEncodedIter<'a, R> {}
+
+impl<'a, R: RunEndIndex> IntoIterator for &'a RunEndEncodedArray<R> {
+    type Item = Box<dyn Scalar>;
+    type IntoIter = RunEndEncodedIter<'a, R>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, R: RunEndIndex> RunEndEncodedArray<R> {
+    /// Returns an iterator over the values of this [`RunEndEncodedArray`].
+    #[inline]
+    pub fn iter(&'a self) -> RunEndEncodedIter<'a, R> {
+        RunEndEncodedIter::new(self)
+    }
+}
@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use crate::{
+    bitmap::Bitmap,
+    datatypes::{DataType, IntegerType},
+    error::{ArrowError, Result},
+    scalar::{new_scalar, Scalar},
+    types::NativeType,
+};
+
+pub(super) mod fmt;
+mod iterator;
+pub use iterator::*;
+
+use super::{new_empty_array, new_null_array, primitive::PrimitiveArray, Array};
+
+/// Trait denoting [`NativeType`]s that can be used as run ends of a [`RunEndEncodedArray`].
+pub trait RunEndIndex:
+    NativeType + PartialOrd + num_traits::NumCast + num_traits::FromPrimitive
+{
+    /// The corresponding [`IntegerType`] of this run end
+    const RUN_END_TYPE: IntegerType;
+}
+
+impl RunEndIndex for i16 {
+    const RUN_END_TYPE: IntegerType = IntegerType::Int16;
+}
+
+impl RunEndIndex for i32 {
+    const RUN_END_TYPE: IntegerType = IntegerType::Int32;
+}
+
+impl RunEndIndex for i64 {
+    const RUN_END_TYPE: IntegerType = IntegerType::Int64;
+}
+
+/// Returns whether `run_end_type` can be used as the run ends of a [`RunEndEncodedArray`],
+/// i.e. whether it is `Int16`, `Int32` or `Int64`.
+pub(crate) fn is_run_end_type(run_end_type: IntegerType) -> bool {
+    matches!(
+        run_end_type,
+        IntegerType::Int16 | IntegerType::Int32 | IntegerType::Int64
+    )
+}
+
+/// An [`Array`] whose consecutive equal values (runs) are stored once, together with the
+/// logical index where each run ends. This [`Array`] is useful when the values have long runs
+/// of equal values, since its size depends on the number of runs rather than on its length.
+///
+/// The slot `i` has the value of the first run whose (exclusive) end is larger than `i`.
+/// This array has no validity: a slot is null iff the value of its run is null.
+#[derive(Clone)]
+pub struct RunEndEncodedArray<R: RunEndIndex> {
+    data_type: DataType,
+    run_ends: PrimitiveArray<R>,
+    values: Arc<dyn Array>,
+    offset: usize,
+    length: usize,
+}
+
+impl<R: RunEndIndex> RunEndEncodedArray<R> {
+    /// Returns a new empty [`RunEndEncodedArray`].
+    pub fn new_empty(data_type: DataType) -> Self {
+        let values = Self::get_child(&data_type);
+        let values = new_empty_array(values.clone()).into();
+        let run_ends = PrimitiveArray::<R>::new_empty(R::PRIMITIVE.into());
+        Self::from_data(run_ends, values)
+    }
+
+    /// Returns a [`RunEndEncodedArray`] whose all elements are null, as a single run.
+    #[inline]
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        if length == 0 {
+            return Self::new_empty(data_type);
+        }
+        let values = Self::get_child(&data_type);
+        let values = new_null_array(values.clone(), 1).into();
+        let run_ends = PrimitiveArray::<R>::from_slice([R::from_usize(length).unwrap()]);
+        Self::from_data(run_ends, values)
+    }
+
+    /// The canonical method to create a new [`RunEndEncodedArray`].
+    /// # Panics
+    /// This function panics iff the arguments are not a valid [`RunEndEncodedArray`]
+    /// (see [`RunEndEncodedArray::try_new`]).
+    pub fn from_data(run_ends: PrimitiveArray<R>, values: Arc<dyn Array>) -> Self {
+        Self::try_new(run_ends, values).unwrap()
+    }
+
+    /// The canonical method to create a new [`RunEndEncodedArray`].
+    ///
+    /// This function returns an error iff:
+    /// * `run_ends` has nulls
+    /// * `run_ends` and `values` do not have the same length
+    /// * `run_ends` is not strictly increasing and positive
+    pub fn try_new(run_ends: PrimitiveArray<R>, values: Arc<dyn Array>) -> Result<Self> {
+        if run_ends.null_count() != 0 {
+            return Err(ArrowError::oos(
+                "The run ends of a RunEndEncodedArray cannot be null",
+            ));
+        }
+        if run_ends.len() != values.len() {
+            return Err(ArrowError::oos(
+                "A RunEndEncodedArray must have as many run ends as values",
+            ));
+        }
+        let mut previous = R::default();
+        for run_end in run_ends.values().iter() {
+            if *run_end <= previous {
+                return Err(ArrowError::oos(
+                    "The run ends of a RunEndEncodedArray must be strictly increasing and positive",
+                ));
+            }
+            previous = *run_end;
+        }
+        let length = previous.to_usize().unwrap();
+
+        let data_type =
+            DataType::RunEndEncoded(R::RUN_END_TYPE, Box::new(values.data_type().clone()));
+
+        Ok(Self {
+            data_type,
+            run_ends,
+            values,
+            offset: 0,
+            length,
+        })
+    }
+
+    /// Creates a new [`RunEndEncodedArray`] by slicing the existing [`RunEndEncodedArray`].
+    /// This is `O(1)`: the run ends and values are not changed.
+    /// # Panics
+    /// iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new array cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Creates a new [`RunEndEncodedArray`] by slicing the existing [`RunEndEncodedArray`].
+    /// # Safety
+    /// Safe iff `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        Self {
+            data_type: self.data_type.clone(),
+            run_ends: self.run_ends.clone(),
+            values: self.values.clone(),
+            offset: self.offset + offset,
+            length,
+        }
+    }
+}
+
+// accessors
+impl<R: RunEndIndex> RunEndEncodedArray<R> {
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns whether the array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the logical offset of this array into its run ends, which is
+    /// different from 0 when the array was sliced.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the (exclusive) logical end of each run, in the coordinates of the array
+    /// before it was sliced (see [`RunEndEncodedArray::offset`]).
+    #[inline]
+    pub fn run_ends(&self) -> &PrimitiveArray<R> {
+        &self.run_ends
+    }
+
+    /// Returns the values of each run of the [`RunEndEncodedArray`].
+    #[inline]
+    pub fn values(&self) -> &Arc<dyn Array> {
+        &self.values
+    }
+
+    /// Returns the index in [`RunEndEncodedArray::values`] of the slot `index`.
+    /// This is `O(log(r))` where `r` is the number of runs.
+    /// # Panics
+    /// iff `index >= self.len()`.
+    #[inline]
+    pub fn physical_index(&self, index: usize) -> usize {
+        assert!(index < self.len());
+        let index = self.offset + index;
+        self.run_ends
+            .values()
+            .partition_point(|run_end| run_end.to_usize().unwrap() <= index)
+    }
+
+    /// Returns the value of the [`RunEndEncodedArray`] at position `index`.
+    /// # Panics
+    /// iff `index >= self.len()`.
+    #[inline]
+    pub fn value(&self, index: usize) -> Box<dyn Scalar> {
+        new_scalar(self.values.as_ref(), self.physical_index(index))
+    }
+}
+
+impl<R: RunEndIndex> RunEndEncodedArray<R> {
+    pub(crate) fn get_child(data_type: &DataType) -> &DataType {
+        match data_type {
+            DataType::RunEndEncoded(_, values) => values.as_ref(),
+            DataType::Extension(_, inner, _) => Self::get_child(inner),
+            _ => panic!("RunEndEncodedArray must be initialized with DataType::RunEndEncoded"),
+        }
+    }
+}
+
+impl<R: RunEndIndex> Array for RunEndEncodedArray<R> {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        None
+    }
+
+    /// The number of slots whose run's value is null.
+    /// # Implementation
+    /// This is `O(r)` where `r` is the number of runs, and `O(1)` when the values have no nulls.
+    fn null_count(&self) -> usize {
+        if self.is_empty() || self.values.null_count() == 0 {
+            return 0;
+        }
+        let (start, end) = (self.offset, self.offset + self.length);
+        let mut run_start = 0;
+        self.run_ends
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(index, run_end)| {
+                let run_end = run_end.to_usize().unwrap();
+                let overlap = run_end.min(end).saturating_sub(run_start.max(start));
+                run_start = run_end;
+                if self.values.is_null(index) {
+                    overlap
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    #[inline]
+    fn is_null(&self, i: usize) -> bool {
+        self.values.is_null(self.physical_index(i))
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+    fn with_validity(&self, _: Option<Bitmap>) -> Box<dyn Array> {
+        panic!("cannot set validity of a run-end encoded array")
+    }
+}
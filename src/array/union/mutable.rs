@@ -0,0 +1,243 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, MutableArray, TryPush},
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::UnionArray;
+
+/// The mutable version of [`UnionArray`].
+///
+/// Every slot is pushed in two steps: [`MutableUnionArray::push_type`] declares the type id of
+/// the slot, and a value is then pushed to the field of that type id, e.g. via
+/// [`MutableUnionArray::field_mut`]. [`MutableUnionArray::try_push`] does both at once.
+/// Whether the union is dense (with offsets) or sparse is declared by its [`DataType::Union`].
+#[derive(Debug)]
+pub struct MutableUnionArray {
+    data_type: DataType,
+    types: Vec<i8>,
+    fields: Vec<Box<dyn MutableArray>>,
+    offsets: Option<Vec<i32>>,
+}
+
+impl MutableUnionArray {
+    /// Creates a new [`MutableUnionArray`] from a [`DataType::Union`] and one empty
+    /// [`MutableArray`] per field of the union.
+    /// # Panics
+    /// This function panics iff the arguments are not valid (see [`MutableUnionArray::try_new`]).
+    pub fn new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Self {
+        Self::try_new(data_type, fields).unwrap()
+    }
+
+    /// Creates a new [`MutableUnionArray`] from a [`DataType::Union`] and one empty
+    /// [`MutableArray`] per field of the union.
+    /// # Errors
+    /// This function errors iff:
+    /// * the logical type of `data_type` is not [`DataType::Union`]
+    /// * `fields` do not have the same number and data types as the fields of the union
+    /// * any of `fields` is not empty
+    pub fn try_new(data_type: DataType, fields: Vec<Box<dyn MutableArray>>) -> Result<Self> {
+        let (union_fields, _, mode) = match data_type.to_logical_type() {
+            DataType::Union(fields, ids, mode) => (fields, ids, mode),
+            _ => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "A MutableUnionArray must be created with a DataType::Union".to_string(),
+                ))
+            }
+        };
+        if union_fields.len() != fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The union has {} fields but {} arrays were passed",
+                union_fields.len(),
+                fields.len()
+            )));
+        }
+        if let Some((field, array)) = union_fields
+            .iter()
+            .zip(fields.iter())
+            .find(|(field, array)| field.data_type() != array.data_type())
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" has data type {:?} but its array has data type {:?}",
+                field.name,
+                field.data_type(),
+                array.data_type()
+            )));
+        }
+        if fields.iter().any(|array| !array.is_empty()) {
+            return Err(ArrowError::InvalidArgumentError(
+                "The fields of a new MutableUnionArray must be empty".to_string(),
+            ));
+        }
+        let offsets = if mode.is_sparse() { None } else { Some(vec![]) };
+
+        Ok(Self {
+            data_type,
+            types: vec![],
+            fields,
+            offsets,
+        })
+    }
+
+    /// Returns the index in the fields of the type id `type_id`.
+    fn field_index(&self, type_id: i8) -> Result<usize> {
+        let ids = UnionArray::get_all(&self.data_type).1;
+        let index = match ids {
+            Some(ids) => ids.iter().position(|id| *id == type_id as i32),
+            None => usize::try_from(type_id)
+                .ok()
+                .filter(|index| *index < self.fields.len()),
+        };
+        index.ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The type id {} is not a type id of the union {:?}",
+                type_id, self.data_type
+            ))
+        })
+    }
+
+    /// Declares that the next slot is of type id `type_id`. A value must then be pushed to the
+    /// field of `type_id`, e.g. via [`MutableUnionArray::field_mut`].
+    ///
+    /// On dense unions, the offset of the slot is the current length of the field. On sparse
+    /// unions, a null is pushed to every other field.
+    /// # Errors
+    /// This function errors iff `type_id` is not a type id of the union.
+    pub fn push_type(&mut self, type_id: i8) -> Result<()> {
+        let index = self.field_index(type_id)?;
+        self.push_type_unchecked(type_id, index);
+        Ok(())
+    }
+
+    fn push_type_unchecked(&mut self, type_id: i8, index: usize) {
+        self.types.push(type_id);
+        match &mut self.offsets {
+            Some(offsets) => offsets.push(self.fields[index].len() as i32),
+            None => self
+                .fields
+                .iter_mut()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .for_each(|(_, field)| field.push_null()),
+        }
+    }
+
+    /// Returns the field of type id `type_id` downcasted to `M`, or `None` if `type_id` is not a
+    /// type id of the union or its field is not an `M`.
+    pub fn field_mut<M: MutableArray + 'static>(&mut self, type_id: i8) -> Option<&mut M> {
+        let index = self.field_index(type_id).ok()?;
+        self.fields[index].as_mut_any().downcast_mut()
+    }
+
+    /// Pushes `value` to the field of type id `type_id`, that must be an `M`.
+    /// # Errors
+    /// This function errors iff `type_id` is not a type id of the union, its field is not an `M`,
+    /// or pushing `value` to the field errors.
+    pub fn try_push<M, T>(&mut self, type_id: i8, value: T) -> Result<()>
+    where
+        M: MutableArray + TryPush<T> + 'static,
+    {
+        let index = self.field_index(type_id)?;
+        if !self.fields[index].as_any().is::<M>() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The field of type id {} is not a {}",
+                type_id,
+                std::any::type_name::<M>()
+            )));
+        }
+        self.push_type_unchecked(type_id, index);
+        self.fields[index]
+            .as_mut_any()
+            .downcast_mut::<M>()
+            .unwrap()
+            .try_push(value)
+    }
+
+    /// The types of each slot.
+    pub fn types(&self) -> &[i8] {
+        &self.types
+    }
+
+    /// The fields, one per field of the union.
+    pub fn fields(&self) -> &[Box<dyn MutableArray>] {
+        &self.fields
+    }
+
+    /// The offsets of each slot into its field, iff the union is dense.
+    pub fn offsets(&self) -> Option<&[i32]> {
+        self.offsets.as_deref()
+    }
+
+    fn to(&mut self) -> UnionArray {
+        let types = std::mem::take(&mut self.types);
+        let offsets = self.offsets.as_mut().map(std::mem::take);
+        let fields = self.fields.iter_mut().map(|field| field.as_arc()).collect();
+
+        UnionArray::from_data(
+            self.data_type.clone(),
+            types.into(),
+            fields,
+            offsets.map(|x| x.into()),
+        )
+    }
+}
+
+impl From<MutableUnionArray> for UnionArray {
+    fn from(mut other: MutableUnionArray) -> Self {
+        other.to()
+    }
+}
+
+impl MutableArray for MutableUnionArray {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    /// Pushes a null to the first field of the union, since unions have no validity.
+    #[inline]
+    fn push_null(&mut self) {
+        let type_id = UnionArray::get_all(&self.data_type)
+            .1
+            .map(|ids| ids[0] as i8)
+            .unwrap_or(0);
+        self.push_type_unchecked(type_id, 0);
+        self.fields[0].push_null();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        if let Some(offsets) = &mut self.offsets {
+            offsets.shrink_to_fit();
+        }
+        self.fields
+            .iter_mut()
+            .for_each(|field| field.shrink_to_fit());
+    }
+}
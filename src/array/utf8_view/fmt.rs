@@ -0,0 +1,17 @@
+use std::fmt::{Debug, Formatter, Result, Write};
+
+use super::super::fmt::write_vec;
+use super::Utf8ViewArray;
+
+pub fn write_value<W: Write>(array: &Utf8ViewArray, index: usize, f: &mut W) -> Result {
+    write!(f, "{}", array.value(index))
+}
+
+impl Debug for Utf8ViewArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let writer = |f: &mut Formatter, index| write_value(self, index, f);
+
+        write!(f, "Utf8ViewArray")?;
+        write_vec(f, writer, self.validity(), self.len(), "None", false)
+    }
+}
@@ -0,0 +1,29 @@
+use std::iter::FromIterator;
+
+use super::{MutableUtf8ViewArray, Utf8ViewArray};
+
+impl Utf8ViewArray {
+    /// Creates a new [`Utf8ViewArray`] from a slice of `&str`.
+    #[inline]
+    pub fn from_slice<T: AsRef<str>, P: AsRef<[T]>>(slice: P) -> Self {
+        Self::from_iter_values(slice.as_ref().iter())
+    }
+
+    /// Creates a new [`Utf8ViewArray`] from a slice of `&str`.
+    // Note: this can't be `impl From` because Rust does not allow double `AsRef` on it.
+    pub fn from<T: AsRef<str>, P: AsRef<[Option<T>]>>(slice: P) -> Self {
+        Self::from_iter(slice.as_ref().iter().map(|x| x.as_ref()))
+    }
+
+    /// Creates a new [`Utf8ViewArray`] from a [`Iterator`] of `&str`.
+    pub fn from_iter_values<T: AsRef<str>, I: Iterator<Item = T>>(iterator: I) -> Self {
+        MutableUtf8ViewArray::from_iter_values(iterator).into()
+    }
+}
+
+impl<P: AsRef<str>> FromIterator<Option<P>> for Utf8ViewArray {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Option<P>>>(iter: I) -> Self {
+        MutableUtf8ViewArray::from_iter(iter).into()
+    }
+}
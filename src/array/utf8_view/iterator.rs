@@ -0,0 +1,80 @@
+use crate::bitmap::utils::{zip_validity, ZipValidity};
+use crate::trusted_len::TrustedLen;
+
+use super::Utf8ViewArray;
+
+/// Iterator of values of an `Utf8ViewArray`.
+#[derive(Debug, Clone)]
+pub struct Utf8ViewValuesIter<'a> {
+    array: &'a Utf8ViewArray,
+    index: usize,
+    end: usize,
+}
+
+impl<'a> Utf8ViewValuesIter<'a> {
+    /// Creates a new [`Utf8ViewValuesIter`]
+    pub fn new(array: &'a Utf8ViewArray) -> Self {
+        Self {
+            array,
+            index: 0,
+            end: array.len(),
+        }
+    }
+}
+
+impl<'a> Iterator for Utf8ViewValuesIter<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            return None;
+        }
+        let old = self.index;
+        self.index += 1;
+        Some(unsafe { self.array.value_unchecked(old) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.index, Some(self.end - self.index))
+    }
+}
+
+impl<'a> DoubleEndedIterator for Utf8ViewValuesIter<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            Some(unsafe { self.array.value_unchecked(self.end) })
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Utf8ViewArray {
+    type Item = Option<&'a str>;
+    type IntoIter = ZipValidity<'a, &'a str, Utf8ViewValuesIter<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> Utf8ViewArray {
+    /// Returns an iterator of `Option<&str>`
+    pub fn iter(&'a self) -> ZipValidity<'a, &'a str, Utf8ViewValuesIter<'a>> {
+        zip_validity(
+            Utf8ViewValuesIter::new(self),
+            self.validity.as_ref().map(|x| x.iter()),
+        )
+    }
+
+    /// Returns an iterator of `&str`
+    pub fn values_iter(&'a self) -> Utf8ViewValuesIter<'a> {
+        Utf8ViewValuesIter::new(self)
+    }
+}
+
+unsafe impl TrustedLen for Utf8ViewValuesIter<'_> {}
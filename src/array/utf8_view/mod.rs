@@ -0,0 +1,317 @@
+use std::sync::Arc;
+
+use crate::{
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::Array;
+
+pub(super) mod fmt;
+mod from;
+mod iterator;
+mod mutable;
+mod view;
+pub use iterator::*;
+pub use mutable::*;
+pub use view::View;
+
+/// A [`Utf8ViewArray`] is arrow's equivalent of an immutable `Vec<Option<String>>` whose strings
+/// are referenced by 16-byte [`View`]s: strings with at most [`View::MAX_INLINE_SIZE`] bytes are
+/// stored in the view itself, and longer strings are stored in one of a set of data buffers,
+/// with their first 4 bytes in the view.
+///
+/// Cloning and slicing this struct is `O(1)`.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8ViewArray;
+/// # fn main() {
+/// let array = Utf8ViewArray::from([Some("hi"), None, Some("a string longer than 12 bytes")]);
+/// assert_eq!(array.value(0), "hi");
+/// assert_eq!(array.value(2), "a string longer than 12 bytes");
+/// assert!(array.view(0).is_inline());
+/// assert!(!array.view(2).is_inline());
+/// # }
+/// ```
+/// # Safety
+/// The following invariants hold:
+/// * every view that is not inlined references a valid slice of one of the `buffers`.
+/// * the bytes referenced by every view are valid `utf8`.
+/// * `len` is equal to `validity.len()`, when defined.
+#[derive(Clone)]
+pub struct Utf8ViewArray {
+    data_type: DataType,
+    views: Buffer<i128>,
+    buffers: Arc<[Buffer<u8>]>,
+    validity: Option<Bitmap>,
+}
+
+impl Utf8ViewArray {
+    /// Returns a new empty [`Utf8ViewArray`].
+    #[inline]
+    pub fn new_empty(data_type: DataType) -> Self {
+        Self::from_data(data_type, Buffer::new(), vec![], None)
+    }
+
+    /// Returns a new [`Utf8ViewArray`] whose all slots are null / `None`.
+    #[inline]
+    pub fn new_null(data_type: DataType, length: usize) -> Self {
+        Self::from_data(
+            data_type,
+            Buffer::new_zeroed(length),
+            vec![],
+            Some(Bitmap::new_zeroed(length)),
+        )
+    }
+
+    /// The canonical method to create a [`Utf8ViewArray`] out of low-end APIs.
+    /// # Panics
+    /// This function panics iff the arguments are not a valid [`Utf8ViewArray`]
+    /// (see [`Utf8ViewArray::try_new`]).
+    pub fn from_data(
+        data_type: DataType,
+        views: Buffer<i128>,
+        buffers: Vec<Buffer<u8>>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        Self::try_new(data_type, views, buffers, validity).unwrap()
+    }
+
+    /// The canonical method to create a [`Utf8ViewArray`] out of low-end APIs.
+    ///
+    /// This function returns an error iff:
+    /// * The `data_type`'s physical type is not [`DataType::Utf8View`].
+    /// * A view that is not inlined does not reference a slice of `buffers` or its prefix
+    ///   is not equal to the first 4 bytes of the slice
+    /// * The bytes referenced by a view are not utf8 encoded
+    /// * The validity is not `None` and its length is different from `views.len()`.
+    pub fn try_new(
+        data_type: DataType,
+        views: Buffer<i128>,
+        buffers: Vec<Buffer<u8>>,
+        validity: Option<Bitmap>,
+    ) -> Result<Self> {
+        if data_type.to_physical_type() != DataType::Utf8View.to_physical_type() {
+            return Err(ArrowError::oos(
+                "Utf8ViewArray can only be initialized with DataType::Utf8View",
+            ));
+        }
+        if matches!(&validity, Some(validity) if validity.len() != views.len()) {
+            return Err(ArrowError::oos(
+                "validity mask length must match the number of values",
+            ));
+        }
+
+        for view in views.iter() {
+            let view = View::from_i128(*view);
+            if view.is_inline() {
+                simdutf8::basic::from_utf8(view.inline_bytes())?;
+                continue;
+            }
+            let buffer = buffers
+                .get(view.buffer_index() as usize)
+                .ok_or_else(|| ArrowError::oos("A view references a buffer that does not exist"))?;
+            let start = view.offset() as usize;
+            let end = start + view.length() as usize;
+            let bytes = buffer.get(start..end).ok_or_else(|| {
+                ArrowError::oos("A view references a slice out of bounds of its buffer")
+            })?;
+            if bytes[..4] != view.prefix() {
+                return Err(ArrowError::oos(
+                    "The prefix of a view must equal the first 4 bytes of its string",
+                ));
+            }
+            simdutf8::basic::from_utf8(bytes)?;
+        }
+
+        Ok(Self {
+            data_type,
+            views,
+            buffers: buffers.into(),
+            validity,
+        })
+    }
+
+    /// The same as [`Utf8ViewArray::from_data`] but does not check the views nor utf8 validity.
+    /// # Safety
+    /// * every view that is not inlined MUST reference a slice of one of the `buffers`; and
+    /// * the bytes referenced by every view MUST be valid utf8
+    /// # Panics
+    /// This function panics iff:
+    /// * The `data_type`'s physical type is not [`DataType::Utf8View`].
+    /// * The validity is not `None` and its length is different from `views.len()`.
+    pub unsafe fn from_data_unchecked(
+        data_type: DataType,
+        views: Buffer<i128>,
+        buffers: Vec<Buffer<u8>>,
+        validity: Option<Bitmap>,
+    ) -> Self {
+        if let Some(ref validity) = validity {
+            assert_eq!(views.len(), validity.len());
+        }
+        if data_type.to_physical_type() != DataType::Utf8View.to_physical_type() {
+            panic!("Utf8ViewArray can only be initialized with DataType::Utf8View")
+        }
+
+        Self {
+            data_type,
+            views,
+            buffers: buffers.into(),
+            validity,
+        }
+    }
+
+    /// Returns a slice of this [`Utf8ViewArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len(),
+            "the offset of the new Buffer cannot exceed the existing length"
+        );
+        unsafe { self.slice_unchecked(offset, length) }
+    }
+
+    /// Returns a slice of this [`Utf8ViewArray`].
+    /// # Implementation
+    /// This operation is `O(1)` as it amounts to essentially increase two ref counts.
+    /// # Safety
+    /// The caller must ensure that `offset + length <= self.len()`.
+    pub unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Self {
+        let validity = self
+            .validity
+            .clone()
+            .map(|x| x.slice_unchecked(offset, length));
+        Self {
+            data_type: self.data_type.clone(),
+            views: self.views.clone().slice_unchecked(offset, length),
+            buffers: self.buffers.clone(),
+            validity,
+        }
+    }
+
+    /// Sets the validity bitmap on this [`Utf8ViewArray`].
+    /// # Panic
+    /// This function panics iff `validity.len() != self.len()`.
+    pub fn with_validity(&self, validity: Option<Bitmap>) -> Self {
+        if matches!(&validity, Some(bitmap) if bitmap.len() != self.len()) {
+            panic!("validity should be as least as large as the array")
+        }
+        let mut arr = self.clone();
+        arr.validity = validity;
+        arr
+    }
+}
+
+// Accessors
+impl Utf8ViewArray {
+    /// Returns the length of this array
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    /// Returns whether the array is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the [`View`] of the element at index `i`
+    /// # Panics
+    /// iff `i >= self.len()`.
+    #[inline]
+    pub fn view(&self, i: usize) -> View {
+        View::from_i128(self.views[i])
+    }
+
+    /// Returns the element at index `i` as &str
+    /// # Safety
+    /// This function is safe iff `i < self.len`.
+    pub unsafe fn value_unchecked(&self, i: usize) -> &str {
+        // soundness: the invariant of the function
+        let raw = self.views.get_unchecked(i);
+        let view = View::from_i128(*raw);
+        let length = view.length() as usize;
+
+        let slice = if length <= View::MAX_INLINE_SIZE {
+            // the in-memory representation of a view is its bytes
+            let bytes: &[u8; 16] = bytemuck::cast_ref(raw);
+            bytes.get_unchecked(4..4 + length)
+        } else {
+            let start = view.offset() as usize;
+            // soundness: the invariant of the struct
+            self.buffers
+                .get_unchecked(view.buffer_index() as usize)
+                .get_unchecked(start..start + length)
+        };
+
+        // soundness: the invariant of the struct
+        std::str::from_utf8_unchecked(slice)
+    }
+
+    /// Returns the element at index `i`
+    /// # Panics
+    /// iff `i >= self.len()`.
+    #[inline]
+    pub fn value(&self, i: usize) -> &str {
+        assert!(i < self.len());
+        // soundness: `i` is in bounds
+        unsafe { self.value_unchecked(i) }
+    }
+
+    /// The optional validity.
+    #[inline]
+    pub fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    /// Returns the views of this [`Utf8ViewArray`], each the in-memory representation of a
+    /// [`View`] (see [`View::from_i128`]).
+    #[inline]
+    pub fn views(&self) -> &Buffer<i128> {
+        &self.views
+    }
+
+    /// Returns the data buffers of this [`Utf8ViewArray`], referenced by its views that are
+    /// not inlined.
+    #[inline]
+    pub fn buffers(&self) -> &[Buffer<u8>] {
+        &self.buffers
+    }
+}
+
+impl Array for Utf8ViewArray {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn validity(&self) -> Option<&Bitmap> {
+        self.validity.as_ref()
+    }
+
+    fn slice(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice(offset, length))
+    }
+    unsafe fn slice_unchecked(&self, offset: usize, length: usize) -> Box<dyn Array> {
+        Box::new(self.slice_unchecked(offset, length))
+    }
+    fn with_validity(&self, validity: Option<Bitmap>) -> Box<dyn Array> {
+        Box::new(self.with_validity(validity))
+    }
+}
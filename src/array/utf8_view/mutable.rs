@@ -0,0 +1,240 @@
+use std::{iter::FromIterator, sync::Arc};
+
+use crate::{
+    array::{Array, MutableArray, TryExtend, TryPush},
+    bitmap::MutableBitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::{Utf8ViewArray, View};
+
+/// The maximum size of a data buffer of a [`MutableUtf8ViewArray`] before a new one is started.
+const BLOCK_SIZE: usize = 2 * 1024 * 1024;
+
+/// The mutable version of [`Utf8ViewArray`]. See [`MutableArray`] for more details.
+///
+/// Strings with at most [`View::MAX_INLINE_SIZE`] bytes are stored in their view. Longer
+/// strings are appended to a data buffer, that is completed once it reaches 2MiB.
+#[derive(Debug)]
+pub struct MutableUtf8ViewArray {
+    views: Vec<i128>,
+    completed_buffers: Vec<Buffer<u8>>,
+    in_progress_buffer: Vec<u8>,
+    validity: Option<MutableBitmap>,
+}
+
+impl From<MutableUtf8ViewArray> for Utf8ViewArray {
+    fn from(mut other: MutableUtf8ViewArray) -> Self {
+        other.to()
+    }
+}
+
+impl Default for MutableUtf8ViewArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MutableUtf8ViewArray {
+    /// Initializes a new empty [`MutableUtf8ViewArray`].
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Initializes a new [`MutableUtf8ViewArray`] with a pre-allocated capacity of slots.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            views: Vec::with_capacity(capacity),
+            completed_buffers: vec![],
+            in_progress_buffer: vec![],
+            validity: None,
+        }
+    }
+
+    /// Reserves `additional` slots.
+    pub fn reserve(&mut self, additional: usize) {
+        self.views.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    /// Pushes a new element to the array.
+    /// # Panic
+    /// This operation panics iff the length of the string (in bytes) exceeds `u32::MAX`.
+    #[inline]
+    pub fn push<T: AsRef<str>>(&mut self, value: Option<T>) {
+        self.try_push(value).unwrap()
+    }
+
+    /// Pushes a new non-null element to the array.
+    /// # Panic
+    /// This operation panics iff the length of the string (in bytes) exceeds `u32::MAX`.
+    #[inline]
+    pub fn push_value<T: AsRef<str>>(&mut self, value: T) {
+        self.try_push_value(value.as_ref()).unwrap()
+    }
+
+    fn try_push_value(&mut self, value: &str) -> Result<()> {
+        let bytes = value.as_bytes();
+        if bytes.len() > u32::MAX as usize {
+            return Err(ArrowError::Overflow);
+        }
+        let view = if bytes.len() <= View::MAX_INLINE_SIZE {
+            View::new(bytes, 0, 0)
+        } else {
+            if self.in_progress_buffer.len() + bytes.len() > BLOCK_SIZE
+                && !self.in_progress_buffer.is_empty()
+            {
+                let buffer = std::mem::take(&mut self.in_progress_buffer);
+                self.completed_buffers.push(buffer.into());
+            }
+            // the buffer is at most `BLOCK_SIZE` long before a string is appended to it
+            let offset = self.in_progress_buffer.len() as u32;
+            let buffer_index =
+                u32::try_from(self.completed_buffers.len()).map_err(|_| ArrowError::Overflow)?;
+            self.in_progress_buffer.extend_from_slice(bytes);
+            View::new(bytes, buffer_index, offset)
+        };
+        self.views.push(view.to_i128());
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    fn init_validity(&mut self) {
+        let mut validity = MutableBitmap::with_capacity(self.views.capacity());
+        validity.extend_constant(self.len(), true);
+        validity.set(self.len() - 1, false);
+        self.validity = Some(validity);
+    }
+
+    /// Returns the views of this [`MutableUtf8ViewArray`].
+    pub fn views(&self) -> &[i128] {
+        &self.views
+    }
+
+    /// Converts itself into an [`Array`].
+    pub fn into_arc(self) -> Arc<dyn Array> {
+        let a: Utf8ViewArray = self.into();
+        Arc::new(a)
+    }
+
+    /// Shrinks the capacity of the [`MutableUtf8ViewArray`] to fit its current length.
+    pub fn shrink_to_fit(&mut self) {
+        self.views.shrink_to_fit();
+        self.in_progress_buffer.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit()
+        }
+    }
+
+    fn to(&mut self) -> Utf8ViewArray {
+        let mut buffers = std::mem::take(&mut self.completed_buffers);
+        let in_progress = std::mem::take(&mut self.in_progress_buffer);
+        if !in_progress.is_empty() {
+            buffers.push(in_progress.into());
+        }
+        // Safety:
+        // `MutableUtf8ViewArray` has the same invariants as `Utf8ViewArray` and thus
+        // `Utf8ViewArray` can be safely created from `MutableUtf8ViewArray` without checks.
+        unsafe {
+            Utf8ViewArray::from_data_unchecked(
+                DataType::Utf8View,
+                std::mem::take(&mut self.views).into(),
+                buffers,
+                std::mem::take(&mut self.validity).map(|x| x.into()),
+            )
+        }
+    }
+}
+
+impl MutableArray for MutableUtf8ViewArray {
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        Box::new(self.to())
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        Arc::new(self.to())
+    }
+
+    fn data_type(&self) -> &DataType {
+        &DataType::Utf8View
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn push_null(&mut self) {
+        self.push::<&str>(None)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+}
+
+impl MutableUtf8ViewArray {
+    /// Creates a new [`MutableUtf8ViewArray`] from a [`Iterator`] of `&str`.
+    pub fn from_iter_values<T: AsRef<str>, I: Iterator<Item = T>>(iterator: I) -> Self {
+        let mut array = Self::with_capacity(iterator.size_hint().0);
+        iterator.for_each(|x| array.push_value(x));
+        array
+    }
+}
+
+impl<P: AsRef<str>> FromIterator<Option<P>> for MutableUtf8ViewArray {
+    fn from_iter<I: IntoIterator<Item = Option<P>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: AsRef<str>> Extend<Option<T>> for MutableUtf8ViewArray {
+    fn extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) {
+        self.try_extend(iter).unwrap();
+    }
+}
+
+impl<T: AsRef<str>> TryExtend<Option<T>> for MutableUtf8ViewArray {
+    fn try_extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) -> Result<()> {
+        let mut iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.try_for_each(|x| self.try_push(x))
+    }
+}
+
+impl<T: AsRef<str>> TryPush<Option<T>> for MutableUtf8ViewArray {
+    #[inline]
+    fn try_push(&mut self, value: Option<T>) -> Result<()> {
+        match value {
+            Some(value) => self.try_push_value(value.as_ref())?,
+            None => {
+                self.views.push(0);
+                match &mut self.validity {
+                    Some(validity) => validity.push(false),
+                    None => self.init_validity(),
+                }
+            }
+        }
+        Ok(())
+    }
+}
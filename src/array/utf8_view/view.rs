@@ -0,0 +1,97 @@
+/// The 16-byte view of a string of a [`Utf8ViewArray`](super::Utf8ViewArray).
+///
+/// Its first 4 bytes are the length of the string (little endian). Strings with at most
+/// [`View::MAX_INLINE_SIZE`] bytes are stored in the remaining 12 bytes (zero-padded). Otherwise,
+/// the remaining bytes are the first 4 bytes of the string (its prefix), followed by the index of
+/// the data buffer and the offset in that buffer where the string is stored (little endian).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct View([u8; 16]);
+
+impl View {
+    /// The maximum number of bytes of a string stored in its view.
+    pub const MAX_INLINE_SIZE: usize = 12;
+
+    /// Returns the [`View`] of `bytes`, that is stored in the view iff
+    /// `bytes.len() <= View::MAX_INLINE_SIZE`, or else in the data buffer `buffer_index`
+    /// from `offset`.
+    /// # Panics
+    /// iff `bytes.len() > u32::MAX`.
+    pub fn new(bytes: &[u8], buffer_index: u32, offset: u32) -> Self {
+        let length: u32 = bytes.len().try_into().unwrap();
+        let mut view = [0u8; 16];
+        view[..4].copy_from_slice(&length.to_le_bytes());
+        if bytes.len() <= Self::MAX_INLINE_SIZE {
+            view[4..4 + bytes.len()].copy_from_slice(bytes);
+        } else {
+            view[4..8].copy_from_slice(&bytes[..4]);
+            view[8..12].copy_from_slice(&buffer_index.to_le_bytes());
+            view[12..].copy_from_slice(&offset.to_le_bytes());
+        }
+        Self(view)
+    }
+
+    /// Returns the [`View`] whose in-memory representation is `value`.
+    #[inline]
+    pub fn from_i128(value: i128) -> Self {
+        Self(value.to_ne_bytes())
+    }
+
+    /// Returns the in-memory representation of this [`View`].
+    #[inline]
+    pub fn to_i128(self) -> i128 {
+        i128::from_ne_bytes(self.0)
+    }
+
+    #[inline]
+    fn u32_at(&self, start: usize) -> u32 {
+        u32::from_le_bytes(self.0[start..start + 4].try_into().unwrap())
+    }
+
+    /// The length of the string, in bytes.
+    #[inline]
+    pub fn length(&self) -> u32 {
+        self.u32_at(0)
+    }
+
+    /// Whether the string is stored in the view.
+    #[inline]
+    pub fn is_inline(&self) -> bool {
+        self.length() as usize <= Self::MAX_INLINE_SIZE
+    }
+
+    /// The first 4 bytes of the string (zero-padded when shorter).
+    #[inline]
+    pub fn prefix(&self) -> [u8; 4] {
+        self.0[4..8].try_into().unwrap()
+    }
+
+    /// The bytes of the string when it is stored in the view.
+    /// # Panics
+    /// iff the string is not inlined.
+    #[inline]
+    pub fn inline_bytes(&self) -> &[u8] {
+        assert!(self.is_inline());
+        &self.0[4..4 + self.length() as usize]
+    }
+
+    /// The index of the data buffer of the string. Only meaningful when the string is not inlined.
+    #[inline]
+    pub fn buffer_index(&self) -> u32 {
+        self.u32_at(8)
+    }
+
+    /// Returns this [`View`] with its buffer index replaced by `buffer_index`. Only meaningful
+    /// when the string is not inlined.
+    #[inline]
+    pub fn with_buffer_index(mut self, buffer_index: u32) -> Self {
+        self.0[8..12].copy_from_slice(&buffer_index.to_le_bytes());
+        self
+    }
+
+    /// The offset of the string in its data buffer. Only meaningful when the string is not
+    /// inlined.
+    #[inline]
+    pub fn offset(&self) -> u32 {
+        self.u32_at(12)
+    }
+}
@@ -1,6 +1,9 @@
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
-use crate::trusted_len::TrustedLen;
+use crate::{
+    error::{ArrowError, Result},
+    trusted_len::TrustedLen,
+};
 
 use super::{
     utils::{BitChunk, BitChunkIterExact, BitChunksExact},
@@ -176,6 +179,51 @@ fn xor(lhs: &Bitmap, rhs: &Bitmap) -> Bitmap {
     binary(lhs, rhs, |x, y| x ^ y)
 }
 
+fn check_same_len(lhs: &Bitmap, rhs: &Bitmap) -> Result<()> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Bitwise operations require bitmaps of the same length (got {} and {})",
+            lhs.len(),
+            rhs.len()
+        )));
+    }
+    Ok(())
+}
+
+impl Bitmap {
+    /// Returns the bitwise `and` of `self` and `other`, computed 64 bits at a time.
+    /// The operands may have different offsets (e.g. be slices of other bitmaps).
+    /// # Errors
+    /// This function errors iff `self.len() != other.len()`.
+    pub fn and(&self, other: &Bitmap) -> Result<Bitmap> {
+        check_same_len(self, other)?;
+        Ok(and(self, other))
+    }
+
+    /// Returns the bitwise `or` of `self` and `other`, computed 64 bits at a time.
+    /// The operands may have different offsets (e.g. be slices of other bitmaps).
+    /// # Errors
+    /// This function errors iff `self.len() != other.len()`.
+    pub fn or(&self, other: &Bitmap) -> Result<Bitmap> {
+        check_same_len(self, other)?;
+        Ok(or(self, other))
+    }
+
+    /// Returns the bitwise `xor` of `self` and `other`, computed 64 bits at a time.
+    /// The operands may have different offsets (e.g. be slices of other bitmaps).
+    /// # Errors
+    /// This function errors iff `self.len() != other.len()`.
+    pub fn xor(&self, other: &Bitmap) -> Result<Bitmap> {
+        check_same_len(self, other)?;
+        Ok(xor(self, other))
+    }
+
+    /// Returns a new [`Bitmap`] with every bit of `self` flipped, computed 64 bits at a time.
+    pub fn not(&self) -> Bitmap {
+        unary(self, |a| !a)
+    }
+}
+
 fn eq(lhs: &Bitmap, rhs: &Bitmap) -> bool {
     if lhs.len() != rhs.len() {
         return false;
@@ -231,6 +279,6 @@ impl Not for &Bitmap {
     type Output = Bitmap;
 
     fn not(self) -> Bitmap {
-        unary(self, |a| !a)
+        Bitmap::not(self)
     }
 }
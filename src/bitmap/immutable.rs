@@ -5,7 +5,9 @@ use std::sync::Arc;
 use crate::{buffer::bytes::Bytes, trusted_len::TrustedLen};
 
 use super::{
-    utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter},
+    utils::{
+        count_ones, count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter,
+    },
     MutableBitmap,
 };
 
@@ -97,9 +99,23 @@ impl Bitmap {
     }
 
     /// Counts the nulls (unset bits) starting from `offset` bits and for `length` bits.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
     #[inline]
     pub fn null_count_range(&self, offset: usize, length: usize) -> usize {
-        count_zeros(&self.bytes, self.offset + offset, length)
+        length - self.count_set_bits_range(offset, length)
+    }
+
+    /// Counts the set bits starting from `offset` bits and for `length` bits.
+    /// # Implementation
+    /// This operation uses a population count over whole words, masking only the
+    /// first and last bytes of the range.
+    /// # Panic
+    /// This function panics iff `offset + length > self.len()`.
+    #[inline]
+    pub fn count_set_bits_range(&self, offset: usize, length: usize) -> usize {
+        assert!(offset + length <= self.length);
+        count_ones(&self.bytes, self.offset + offset, length)
     }
 
     /// Returns the number of unset bits on this [`Bitmap`].
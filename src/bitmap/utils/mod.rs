@@ -85,6 +85,17 @@ pub fn bytes_for(bits: usize) -> usize {
 /// This function panics iff `(offset + len).saturating_add(7) / 8 >= slice.len()`
 /// because it corresponds to the situation where `len` is beyond bounds.
 pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
+    len - count_ones(slice, offset, len)
+}
+
+/// Returns the number of set bits in the slice offsetted by `offset` and a length of `length`.
+///
+/// Bytes fully within the range are counted in groups of 8 (as `u64`) via a population count;
+/// only the (partial) first and last bytes are masked.
+/// # Panics
+/// This function panics iff `(offset + len).saturating_add(7) / 8 >= slice.len()`
+/// because it corresponds to the situation where `len` is beyond bounds.
+pub fn count_ones(slice: &[u8], offset: usize, len: usize) -> usize {
     if len == 0 {
         return 0;
     };
@@ -95,7 +106,7 @@ pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
     if (offset + len) / 8 == 0 {
         // all within a single byte
         let byte = (slice[0] >> offset) << (8 - len);
-        return len - byte.count_ones() as usize;
+        return byte.count_ones() as usize;
     }
 
     // slice: [a1,a2,a3,a4], [a5,a6,a7,a8]
@@ -135,5 +146,5 @@ pub fn count_zeros(slice: &[u8], offset: usize, len: usize) -> usize {
         .map(|byte| byte.count_ones() as usize)
         .sum::<usize>();
 
-    len - set_count
+    set_count
 }
@@ -1,7 +1,7 @@
 //! This module contains an implementation of a contiguous immutable memory region that knows
 //! how to de-allocate itself, [`Bytes`].
 
-use std::{fmt::Debug, fmt::Formatter};
+use std::{any::Any, fmt::Debug, fmt::Formatter};
 use std::{ptr::NonNull, sync::Arc};
 
 use super::foreign::MaybeForeign;
@@ -14,6 +14,12 @@ pub enum Deallocation {
     Native,
     // Foreign interface, via a callback
     Foreign(Arc<ffi::ArrowArray>),
+    /// Memory region owned by another object (e.g. a memory-mapped file), that is kept alive for
+    /// as long as the region is in use and is deallocated by it.
+    Owner(
+        // never read: it is only held to keep the owner alive
+        #[allow(dead_code)] Arc<dyn Any + Send + Sync>,
+    ),
 }
 
 impl Debug for Deallocation {
@@ -25,6 +31,9 @@ impl Debug for Deallocation {
             Deallocation::Foreign(_) => {
                 write!(f, "Deallocation::Foreign {{ capacity: unknown }}")
             }
+            Deallocation::Owner(_) => {
+                write!(f, "Deallocation::Owner {{ capacity: unknown }}")
+            }
         }
     }
 }
@@ -58,14 +67,14 @@ impl<T: NativeType> Bytes<T> {
     ///
     /// # Panics
     ///
-    /// This function panics if the give deallocation not is `Deallocation::Foreign`
+    /// This function panics if the give deallocation is `Deallocation::Native`
     #[inline]
     pub unsafe fn from_ffi(
         ptr: std::ptr::NonNull<T>,
         len: usize,
         deallocation: Deallocation,
     ) -> Self {
-        assert!(matches!(deallocation, Deallocation::Foreign(_)));
+        assert!(!matches!(deallocation, Deallocation::Native));
         // This line is technically outside the assumptions of `Vec::from_raw_parts`, since
         // `ptr` was not allocated by `Vec`. However, one of the invariants of this struct
         // is that we do not expose this region as a `Vec`; we only use `Vec` on it to provide
@@ -76,6 +85,29 @@ impl<T: NativeType> Bytes<T> {
         Self { data, deallocation }
     }
 
+    /// Returns [`Bytes`] of `length` slots sharing the memory of `owner` from byte `offset`,
+    /// keeping `owner` alive for as long as they are in use.
+    ///
+    /// This assumes that `owner.as_ref()` is the same (immutable) memory region for as long as
+    /// `owner` is alive, as is the case of e.g. `Vec<u8>` or memory-mapped files.
+    /// Returns `None` if the region is out of the bounds of `owner` or is not aligned to `T`.
+    pub fn from_owner<O: AsRef<[u8]> + Send + Sync + 'static>(
+        owner: Arc<O>,
+        offset: usize,
+        length: usize,
+    ) -> Option<Self> {
+        let bytes = (*owner).as_ref();
+        let end = offset.checked_add(length.checked_mul(std::mem::size_of::<T>())?)?;
+        let slice = bytemuck::try_cast_slice::<u8, T>(bytes.get(offset..end)?).ok()?;
+        if slice.is_empty() {
+            return Some(Vec::new().into());
+        }
+        let ptr = NonNull::new(slice.as_ptr() as *mut T)?;
+        // Safety: `ptr` is aligned and valid for `length` slots for as long as `owner` is alive,
+        // which `Deallocation::Owner` guarantees.
+        Some(unsafe { Self::from_ffi(ptr, length, Deallocation::Owner(owner)) })
+    }
+
     #[inline]
     fn as_slice(&self) -> &[T] {
         self
@@ -96,7 +128,7 @@ impl<T: NativeType> Bytes<T> {
     /// Returns `None` if allocated by a foreign interface.
     pub fn get_vec(&mut self) -> Option<&mut Vec<T>> {
         match &self.deallocation {
-            Deallocation::Foreign(_) => None,
+            Deallocation::Foreign(_) | Deallocation::Owner(_) => None,
             // Safety:
             // The allocation is native so we can share the vec
             Deallocation::Native => Some(unsafe { self.data.mut_vec() }),
@@ -107,8 +139,8 @@ impl<T: NativeType> Bytes<T> {
 impl<T: NativeType> Drop for Bytes<T> {
     fn drop(&mut self) {
         match self.deallocation {
-            // a foreign interface knows how to deallocate itself
-            Deallocation::Foreign(_) => {}
+            // a foreign interface or owner knows how to deallocate itself
+            Deallocation::Foreign(_) | Deallocation::Owner(_) => {}
             Deallocation::Native => {
                 // Safety:
                 // the allocation is native, so we can safely drop
@@ -138,13 +138,30 @@ impl<T: NativeType> Buffer<T> {
     /// * is not an offsetted slice of another [`Buffer`]
     /// * has not been cloned (i.e. [`Arc`]`::get_mut` yields [`Some`])
     /// * has not been imported from the c data interface (FFI)
+    ///
+    /// The returned [`Vec`] has the length of this [`Buffer`] (i.e. it is truncated when this
+    /// [`Buffer`] is a slice of the start of another [`Buffer`]).
+    /// # Example
+    /// ```
+    /// # use arrow2::buffer::Buffer;
+    /// let buffer = Buffer::from(vec![1u32, 2, 3]);
+    /// let shared = buffer.clone();
+    /// // the memory region is shared, so it can't be mutated
+    /// let buffer = buffer.into_mut().unwrap_left();
+    /// drop(shared);
+    /// // the memory region is no longer shared
+    /// let mut vec = buffer.into_mut().unwrap_right();
+    /// vec[0] = 10;
+    /// assert_eq!(vec, vec![10, 2, 3]);
+    /// ```
     pub fn into_mut(mut self) -> Either<Self, Vec<T>> {
         if self.offset != 0 {
             Either::Left(self)
         } else {
             match Arc::get_mut(&mut self.data).and_then(|b| b.get_vec()) {
                 Some(v) => {
-                    let data = std::mem::take(v);
+                    let mut data = std::mem::take(v);
+                    data.truncate(self.length);
                     Either::Right(data)
                 }
                 None => Either::Left(self),
@@ -166,7 +183,8 @@ impl<T: NativeType> Buffer<T> {
     /// ```
     #[inline]
     pub fn from_trusted_len_iter<I: TrustedLen<Item = T>>(iterator: I) -> Self {
-        iterator.collect::<Vec<_>>().into()
+        // Safety: the iterator is `TrustedLen`
+        unsafe { Self::from_trusted_len_iter_unchecked(iterator) }
     }
 
     /// # Safety
@@ -185,7 +203,7 @@ impl<T: NativeType> Buffer<T> {
     /// to use it on an iterator that reports an incorrect length.
     #[inline]
     pub unsafe fn from_trusted_len_iter_unchecked<I: Iterator<Item = T>>(iterator: I) -> Self {
-        iterator.collect::<Vec<_>>().into()
+        trusted_len_collect(iterator).into()
     }
 
     /// # Safety
@@ -202,6 +220,26 @@ impl<T: NativeType> Buffer<T> {
     }
 }
 
+/// Collects `iterator` into a [`Vec`] allocated once, from the upper bound of its `size_hint`.
+/// # Safety
+/// The iterator must not yield more items than the upper bound of its `size_hint`.
+unsafe fn trusted_len_collect<T, I: Iterator<Item = T>>(iterator: I) -> Vec<T> {
+    let (_, upper) = iterator.size_hint();
+    let upper = upper.expect("from_trusted_len_iter requires an upper limit");
+
+    let mut buffer = Vec::with_capacity(upper);
+
+    let mut dst = buffer.as_mut_ptr();
+    for item in iterator {
+        std::ptr::write(dst, item);
+        dst = dst.add(1);
+    }
+    let len = dst.offset_from(buffer.as_ptr()) as usize;
+    debug_assert!(len <= upper);
+    buffer.set_len(len);
+    buffer
+}
+
 impl<T: NativeType> From<Vec<T>> for Buffer<T> {
     #[inline]
     fn from(p: Vec<T>) -> Self {
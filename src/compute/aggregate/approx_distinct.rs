@@ -0,0 +1,84 @@
+use crate::array::Array;
+use crate::compute::hash::hash;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// The minimum precision of [`approx_count_distinct`]
+pub const MIN_PRECISION: u8 = 4;
+/// The maximum precision of [`approx_count_distinct`]
+pub const MAX_PRECISION: u8 = 18;
+
+/// Returns the rank (position of the first set bit) of the bits of `hash` after its
+/// first `precision` bits, that are used to select its register.
+#[inline]
+fn rank(hash: u64, precision: u8) -> u8 {
+    // the guard bit bounds the rank to `64 - precision + 1`
+    let bits = (hash << precision) | (1 << (precision - 1));
+    bits.leading_zeros() as u8 + 1
+}
+
+fn estimate(registers: &[u8]) -> u64 {
+    let m = registers.len() as f64;
+    let alpha = match registers.len() {
+        16 => 0.673,
+        32 => 0.697,
+        64 => 0.709,
+        _ => 0.7213 / (1.0 + 1.079 / m),
+    };
+
+    let sum = registers
+        .iter()
+        .map(|register| 2f64.powi(-(*register as i32)))
+        .sum::<f64>();
+    let estimate = alpha * m * m / sum;
+
+    // small cardinalities are better estimated by linear counting
+    let zeros = registers.iter().filter(|register| **register == 0).count();
+    let estimate = if estimate <= 2.5 * m && zeros > 0 {
+        m * (m / zeros as f64).ln()
+    } else {
+        estimate
+    };
+    estimate.round() as u64
+}
+
+/// Returns an estimate of the number of distinct non-null values of `array` using
+/// [HyperLogLog](https://en.wikipedia.org/wiki/HyperLogLog).
+///
+/// Every non-null value is hashed with [`hash`] and the estimate is computed from
+/// `2^precision` registers of one byte each. The relative standard error of the estimate
+/// is about `1.04 / sqrt(2^precision)`, e.g. 1.6% for a precision of 12 (4KiB of registers)
+/// and 0.8% for a precision of 14 (16KiB of registers).
+/// # Errors
+/// This function errors iff `precision` is not between [`MIN_PRECISION`] and [`MAX_PRECISION`],
+/// or the data type of `array` is not supported by [`hash`].
+/// # Examples
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::aggregate::approx_count_distinct;
+///
+/// let array = Utf8Array::<i32>::from(&[Some("a"), None, Some("b"), Some("a")]);
+/// assert_eq!(approx_count_distinct(&array, 12).unwrap(), 2);
+/// ```
+pub fn approx_count_distinct(array: &dyn Array, precision: u8) -> Result<u64> {
+    if !(MIN_PRECISION..=MAX_PRECISION).contains(&precision) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The precision of approx_count_distinct must be between {} and {} but it is {}",
+            MIN_PRECISION, MAX_PRECISION, precision
+        )));
+    }
+    if array.data_type() == &DataType::Null {
+        return Ok(0);
+    }
+
+    let hashes = hash(array)?;
+
+    let mut registers = vec![0u8; 1 << precision];
+    hashes.iter().flatten().for_each(|hash| {
+        let index = (hash >> (64 - precision)) as usize;
+        let rank = rank(*hash, precision);
+        registers[index] = registers[index].max(rank);
+    });
+
+    Ok(estimate(&registers))
+}
@@ -0,0 +1,53 @@
+use crate::array::{Array, BooleanArray};
+
+/// Returns whether any of the non-null values of `array` is `true`,
+/// or `None` if all values are null (or the array is empty).
+///
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::any,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(false), None, Some(true)]);
+/// assert_eq!(any(&a), Some(true))
+/// ```
+pub fn any(array: &BooleanArray) -> Option<bool> {
+    // short circuit if all nulls / zero length array
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    // values under a null slot are undefined and must be ignored
+    let unset_bits = match array.validity() {
+        Some(validity) => (array.values() & validity).null_count(),
+        None => array.values().null_count(),
+    };
+    Some(unset_bits != array.len())
+}
+
+/// Returns whether all of the non-null values of `array` are `true`,
+/// or `None` if all values are null (or the array is empty).
+///
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::all,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(true), None, Some(true)]);
+/// assert_eq!(all(&a), Some(true))
+/// ```
+pub fn all(array: &BooleanArray) -> Option<bool> {
+    // short circuit if all nulls / zero length array
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    // a slot is unset iff it is null or `false`
+    let unset_bits = match array.validity() {
+        Some(validity) => (array.values() & validity).null_count(),
+        None => array.values().null_count(),
+    };
+    Some(unset_bits == array.null_count())
+}
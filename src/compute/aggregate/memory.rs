@@ -108,5 +108,18 @@ pub fn estimated_bytes_size(array: &dyn Array) -> usize {
             let offsets = array.offsets().len() * std::mem::size_of::<i32>();
             offsets + estimated_bytes_size(array.field().as_ref()) + validity_size(array.validity())
         }
+        Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            array.views().len() * std::mem::size_of::<i128>()
+                + array.buffers().iter().map(|x| x.len()).sum::<usize>()
+                + validity_size(array.validity())
+        }
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<RunEndEncodedArray<$T>>()
+                .unwrap();
+            estimated_bytes_size(array.run_ends()) + estimated_bytes_size(array.values().as_ref())
+        }),
     }
 }
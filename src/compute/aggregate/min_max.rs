@@ -31,7 +31,7 @@ pub trait SimdOrd<T> {
 }
 
 /// Helper to compute min/max of [`BinaryArray`]
-fn min_max_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
+fn reduce_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
     array: &BinaryArray<O>,
     cmp: F,
 ) -> Option<&[u8]> {
@@ -71,7 +71,7 @@ fn min_max_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
 }
 
 /// Helper to compute min/max of [`Utf8Array`]
-fn min_max_string<O: Offset, F: Fn(&str, &str) -> bool>(
+fn reduce_string<O: Offset, F: Fn(&str, &str) -> bool>(
     array: &Utf8Array<O>,
     cmp: F,
 ) -> Option<&str> {
@@ -278,24 +278,82 @@ where
     })
 }
 
+/// Folds `value` into the running `(min, max)`, ignoring values that are not comparable with
+/// themselves (i.e. `NaN`).
+#[inline]
+fn min_max_fold<T: PartialOrd + Copy>(acc: Option<(T, T)>, value: T) -> Option<(T, T)> {
+    if value.partial_cmp(&value).is_none() {
+        return acc;
+    }
+    Some(match acc {
+        None => (value, value),
+        Some((min, max)) => (
+            if value < min { value } else { min },
+            if value > max { value } else { max },
+        ),
+    })
+}
+
+/// Returns the minimum and maximum values in the array, according to the natural order,
+/// computed in a single pass.
+///
+/// Nulls are ignored, and so are `NaN`s of floating point arrays. Returns `None` when the array
+/// has no such values (e.g. when it is empty or all its values are null).
+/// # Example
+/// ```
+/// use arrow2::{array::Float64Array, compute::aggregate::min_max};
+///
+/// let array = Float64Array::from(&[Some(2.0), None, Some(f64::NAN), Some(-1.0)]);
+/// assert_eq!(min_max(&array), Some((-1.0, 2.0)));
+/// ```
+pub fn min_max<T>(array: &PrimitiveArray<T>) -> Option<(T, T)>
+where
+    T: NativeType + PartialOrd,
+{
+    // Includes case array.len() == 0
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    if array.validity().is_some() {
+        array.iter().flatten().copied().fold(None, min_max_fold)
+    } else {
+        array.values().iter().copied().fold(None, min_max_fold)
+    }
+}
+
 /// Returns the maximum value in the binary array, according to the natural order.
 pub fn max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary(array, |a, b| a < b)
+    reduce_binary(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the binary array, according to the natural order.
 pub fn min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary(array, |a, b| a > b)
+    reduce_binary(array, |a, b| a > b)
 }
 
 /// Returns the maximum value in the string array, according to the natural order.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_string(array, |a, b| a < b)
+    reduce_string(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the string array, according to the natural order.
 pub fn min_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_string(array, |a, b| a > b)
+    reduce_string(array, |a, b| a > b)
+}
+
+/// Returns the minimum and maximum values in the string array, according to the natural order,
+/// computed in a single pass. Returns `None` when the array is empty or all its values are null.
+pub fn min_max_string<O: Offset>(array: &Utf8Array<O>) -> Option<(&str, &str)> {
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    if array.validity().is_some() {
+        array.iter().flatten().fold(None, min_max_fold)
+    } else {
+        array.values_iter().fold(None, min_max_fold)
+    }
 }
 
 /// Returns the minimum value in the boolean array.
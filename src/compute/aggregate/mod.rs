@@ -5,6 +5,18 @@ pub use sum::*;
 mod min_max;
 pub use min_max::*;
 
+mod quantile;
+pub use quantile::*;
+
+mod boolean;
+pub use boolean::*;
+
+#[cfg(feature = "compute_hash")]
+mod approx_distinct;
+#[cfg(feature = "compute_hash")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
+pub use approx_distinct::*;
+
 mod memory;
 pub use memory::*;
 mod simd;
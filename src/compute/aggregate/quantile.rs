@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::types::NativeType;
+
+/// The strategy to compute a quantile whose position lies between two values `i < j`
+/// of the sorted values, the same as numpy's `method` of `quantile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// `i + (j - i) * fraction`, where `fraction` is the fractional part of the position
+    Linear,
+    /// `i`
+    Lower,
+    /// `j`
+    Higher,
+    /// `i` or `j`, whichever is nearest; the one with an even position when both are
+    Nearest,
+    /// `(i + j) / 2`
+    Midpoint,
+}
+
+fn total_cmp(a: &f64, b: &f64) -> Ordering {
+    a.total_cmp(b)
+}
+
+/// Returns the `q`-th quantile of the non-null values of `array`, or `None` if
+/// all of them are null (or the array is empty).
+///
+/// The non-null values are copied, so `array` is not modified. NaNs are considered
+/// larger than any other value.
+/// # Panics
+/// iff `q` is not in `[0, 1]`.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::{quantile, Interpolation};
+///
+/// let array = Int32Array::from(&[Some(4), None, Some(1), Some(2), Some(3)]);
+/// assert_eq!(quantile(&array, 0.5, Interpolation::Linear), Some(2.5));
+/// assert_eq!(quantile(&array, 0.5, Interpolation::Lower), Some(2.0));
+/// ```
+pub fn quantile<T>(array: &PrimitiveArray<T>, q: f64, interpolation: Interpolation) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    assert!(
+        (0.0..=1.0).contains(&q),
+        "The quantile must be in [0, 1] but it is {}",
+        q
+    );
+
+    let mut values = if array.null_count() == 0 {
+        array.values().iter().map(|x| x.as_()).collect::<Vec<f64>>()
+    } else {
+        array
+            .iter()
+            .flatten()
+            .map(|x| x.as_())
+            .collect::<Vec<f64>>()
+    };
+    if values.is_empty() {
+        return None;
+    }
+
+    let position = q * (values.len() - 1) as f64;
+    let lower_index = position.floor() as usize;
+    let fraction = position - position.floor();
+
+    let (_, lower, higher) = values.select_nth_unstable_by(lower_index, total_cmp);
+    let lower = *lower;
+    // the value after `lower` in sorted order is the smallest of the higher values
+    let higher = if fraction > 0.0 {
+        higher.iter().copied().min_by(total_cmp).unwrap_or(lower)
+    } else {
+        lower
+    };
+
+    Some(match interpolation {
+        Interpolation::Linear => lower + (higher - lower) * fraction,
+        Interpolation::Lower => lower,
+        Interpolation::Higher => higher,
+        Interpolation::Nearest => match fraction.partial_cmp(&0.5) {
+            Some(Ordering::Less) => lower,
+            Some(Ordering::Greater) => higher,
+            _ if lower_index & 1 == 0 => lower,
+            _ => higher,
+        },
+        Interpolation::Midpoint => (lower + higher) / 2.0,
+    })
+}
+
+/// Returns the median of the non-null values of `array`, or `None` if all of them are null
+/// (or the array is empty). This is the same as [`quantile`] at `0.5` with
+/// [`Interpolation::Linear`].
+/// # Examples
+/// ```
+/// use arrow2::array::Float64Array;
+/// use arrow2::compute::aggregate::median;
+///
+/// let array = Float64Array::from(&[Some(3.0), None, Some(1.0), Some(2.0)]);
+/// assert_eq!(median(&array), Some(2.0));
+/// ```
+pub fn median<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: NativeType + AsPrimitive<f64>,
+{
+    quantile(array, 0.5, Interpolation::Linear)
+}
@@ -0,0 +1,58 @@
+//! Definition of fused multiply-add operations with primitive arrays
+use num_traits::Float;
+
+use crate::{
+    array::PrimitiveArray,
+    buffer::Buffer,
+    compute::utils::{check_same_len, combine_validities},
+    error::Result,
+};
+
+use super::NativeArithmetics;
+
+/// Fused multiply-add of three primitive arrays, `a * b + c`, computed with a single rounding
+/// error via [`Float::mul_add`], which is more accurate (and faster on hardware with FMA
+/// instructions) than [`mul`](super::mul) followed by [`add`](super::add).
+///
+/// Slots where either `a`, `b` or `c` is null are null. The result has the data type of `a`.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::fma;
+/// use arrow2::array::Float64Array;
+///
+/// let a = Float64Array::from([Some(2.0), None, Some(3.0)]);
+/// let b = Float64Array::from([Some(3.0), Some(1.0), Some(0.5)]);
+/// let c = Float64Array::from([Some(1.0), Some(1.0), None]);
+/// let result = fma(&a, &b, &c).unwrap();
+/// assert_eq!(result, Float64Array::from([Some(7.0), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff the arrays have different lengths.
+pub fn fma<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+    c: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeArithmetics + Float,
+{
+    check_same_len(a, b)?;
+    check_same_len(a, c)?;
+
+    let validity = combine_validities(a.validity(), b.validity());
+    let validity = combine_validities(validity.as_ref(), c.validity());
+
+    let values = a
+        .values()
+        .iter()
+        .zip(b.values().iter())
+        .zip(c.values().iter())
+        .map(|((a, b), c)| a.mul_add(*b, *c));
+    let values = Buffer::from_trusted_len_iter(values);
+
+    Ok(PrimitiveArray::<T>::from_data(
+        a.data_type().clone(),
+        values,
+        validity,
+    ))
+}
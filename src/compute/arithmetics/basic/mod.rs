@@ -9,6 +9,8 @@ mod add;
 pub use add::*;
 mod div;
 pub use div::*;
+mod fma;
+pub use fma::*;
 mod mul;
 pub use mul::*;
 mod pow;
@@ -22,7 +24,10 @@ use std::ops::Neg;
 
 use num_traits::{CheckedNeg, WrappingNeg};
 
-use crate::{array::PrimitiveArray, types::NativeType};
+use crate::{
+    array::PrimitiveArray,
+    types::{i256, NativeType},
+};
 
 use super::super::arity::{unary, unary_checked};
 
@@ -41,6 +46,7 @@ impl NativeArithmetics for i32 {}
 impl NativeArithmetics for i64 {}
 impl NativeArithmetics for f32 {}
 impl NativeArithmetics for f64 {}
+impl NativeArithmetics for i256 {}
 
 /// Negates values from array.
 ///
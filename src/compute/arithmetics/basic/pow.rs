@@ -3,11 +3,184 @@ use num_traits::{checked_pow, CheckedMul, One, Pow};
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{unary, unary_checked},
+    bitmap::Bitmap,
+    compute::arity::{unary, unary_checked, unary_with_bitmap},
 };
 
 use super::NativeArithmetics;
 
+/// Trait describing an integer [`NativeArithmetics`] that can be raised to the power of an
+/// `u32` exponent, with the semantics of Rust's integer methods of the same name.
+pub trait NativeIntegerPow: NativeArithmetics {
+    /// Checked exponentiation, returning `None` on overflow.
+    fn checked_pow(self, exponent: u32) -> Option<Self>;
+    /// Wrapping exponentiation, wrapping around at the boundary of the type on overflow.
+    fn wrapping_pow(self, exponent: u32) -> Self;
+    /// Saturating exponentiation, saturating at the boundary of the type on overflow.
+    fn saturating_pow(self, exponent: u32) -> Self;
+    /// Exponentiation returning the wrapped result and whether it overflowed.
+    fn overflowing_pow(self, exponent: u32) -> (Self, bool);
+}
+
+macro_rules! native_integer_pow {
+    ($type:ty) => {
+        impl NativeIntegerPow for $type {
+            #[inline]
+            fn checked_pow(self, exponent: u32) -> Option<Self> {
+                <$type>::checked_pow(self, exponent)
+            }
+
+            #[inline]
+            fn wrapping_pow(self, exponent: u32) -> Self {
+                <$type>::wrapping_pow(self, exponent)
+            }
+
+            #[inline]
+            fn saturating_pow(self, exponent: u32) -> Self {
+                <$type>::saturating_pow(self, exponent)
+            }
+
+            #[inline]
+            fn overflowing_pow(self, exponent: u32) -> (Self, bool) {
+                <$type>::overflowing_pow(self, exponent)
+            }
+        }
+    };
+}
+
+native_integer_pow!(u8);
+native_integer_pow!(u16);
+native_integer_pow!(u32);
+native_integer_pow!(u64);
+native_integer_pow!(i8);
+native_integer_pow!(i16);
+native_integer_pow!(i32);
+native_integer_pow!(i64);
+
+/// Raises an array of primitives to the power of exponent, e.g. integers to an `u32`
+/// and floats to an `i32`. Panics if one of the values overflows.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::pow_scalar;
+/// use arrow2::array::{Float64Array, Int32Array};
+///
+/// let a = Int32Array::from(&[Some(-2), None, Some(3)]);
+/// let actual = pow_scalar(&a, 3u32);
+/// let expected = Int32Array::from(&[Some(-8), None, Some(27)]);
+/// assert_eq!(expected, actual);
+///
+/// let a = Float64Array::from(&[Some(2.0), None]);
+/// let actual = pow_scalar(&a, -1i32);
+/// let expected = Float64Array::from(&[Some(0.5), None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn pow_scalar<T, E>(array: &PrimitiveArray<T>, exponent: E) -> PrimitiveArray<T>
+where
+    T: NativeArithmetics + Pow<E, Output = T>,
+    E: Copy,
+{
+    unary(array, |x| x.pow(exponent), array.data_type().clone())
+}
+
+/// Checked operation of raising an array of integers to the power of exponent.
+/// If the result overflows, the validity for that index is changed to None.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::checked_pow_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-2i8), None, Some(7i8)]);
+/// let actual = checked_pow_scalar(&a, 3);
+/// let expected = Int8Array::from(&[Some(-8i8), None, None]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn checked_pow_scalar<T>(array: &PrimitiveArray<T>, exponent: u32) -> PrimitiveArray<T>
+where
+    T: NativeIntegerPow,
+{
+    let op = move |a: T| a.checked_pow(exponent);
+
+    unary_checked(array, op, array.data_type().clone())
+}
+
+/// Wrapping operation of raising an array of integers to the power of exponent.
+/// It wraps around at the boundary of the type if the result overflows.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::wrapping_pow_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-2i8), None, Some(7i8)]);
+/// let actual = wrapping_pow_scalar(&a, 3);
+/// let expected = Int8Array::from(&[Some(-8i8), None, Some(87i8)]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn wrapping_pow_scalar<T>(array: &PrimitiveArray<T>, exponent: u32) -> PrimitiveArray<T>
+where
+    T: NativeIntegerPow,
+{
+    unary(
+        array,
+        |a| a.wrapping_pow(exponent),
+        array.data_type().clone(),
+    )
+}
+
+/// Saturating operation of raising an array of integers to the power of exponent.
+/// If the result is larger (smaller) than the possible number for this type, the result
+/// will be the maximum (minimum) value of the type.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::saturating_pow_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-7i8), None, Some(7i8)]);
+/// let actual = saturating_pow_scalar(&a, 3);
+/// let expected = Int8Array::from(&[Some(-128i8), None, Some(127i8)]);
+/// assert_eq!(expected, actual);
+/// ```
+pub fn saturating_pow_scalar<T>(array: &PrimitiveArray<T>, exponent: u32) -> PrimitiveArray<T>
+where
+    T: NativeIntegerPow,
+{
+    unary(
+        array,
+        |a| a.saturating_pow(exponent),
+        array.data_type().clone(),
+    )
+}
+
+/// Overflowing operation of raising an array of integers to the power of exponent.
+/// The result will be an array with overflowed values and a validity array
+/// indicating the overflowing elements from the array.
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::basic::overflowing_pow_scalar;
+/// use arrow2::array::Int8Array;
+///
+/// let a = Int8Array::from(&[Some(-2i8), Some(7i8)]);
+/// let (actual, overflow) = overflowing_pow_scalar(&a, 3);
+/// let expected = Int8Array::from(&[Some(-8i8), Some(87i8)]);
+/// assert_eq!(expected, actual);
+/// assert_eq!(overflow.iter().collect::<Vec<_>>(), vec![false, true]);
+/// ```
+pub fn overflowing_pow_scalar<T>(
+    array: &PrimitiveArray<T>,
+    exponent: u32,
+) -> (PrimitiveArray<T>, Bitmap)
+where
+    T: NativeIntegerPow,
+{
+    let op = move |a: T| a.overflowing_pow(exponent);
+
+    unary_with_bitmap(array, op, array.data_type().clone())
+}
+
 /// Raises an array of primitives to the power of exponent. Panics if one of
 /// the values values overflows.
 ///
@@ -15,11 +15,13 @@
 pub mod basic;
 pub mod decimal;
 pub mod time;
+pub mod trig;
 
 use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit, TimeUnit},
+    error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
 };
 
@@ -92,8 +94,9 @@ macro_rules! arith {
                 time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
             }
             )?
-            _ => todo!(
-                "Addition of {:?} with {:?} is not supported",
+            _ => panic!(
+                "The operation \"{}\" is not supported between arrays of {:?} and {:?}",
+                stringify!($op),
                 lhs.data_type(),
                 rhs.data_type()
             ),
@@ -113,45 +116,42 @@ macro_rules! primitive_scalar {
             .downcast_ref::<PrimitiveScalar<$type>>()
             .unwrap();
 
-        let rhs = if let Some(rhs) = rhs.value() {
-            rhs
+        if let Some(rhs) = rhs.value() {
+            Box::new(basic::$op::<$type>(lhs, &rhs)) as Box<dyn Array>
         } else {
-            return Box::new(PrimitiveArray::<$type>::new_null(
+            Box::new(PrimitiveArray::<$type>::new_null(
                 lhs.data_type().clone(),
                 lhs.len(),
-            )) as Box<dyn Array>;
-        };
-
-        let result = basic::$op::<$type>(lhs, &rhs);
-        Box::new(result) as Box<dyn Array>
+            )) as Box<dyn Array>
+        }
     }};
 }
 
-// Macro to create a `match` statement with dynamic dispatch to functions based on
-// the array's logical types
+// Macro to create a fallible `match` statement with dynamic dispatch to functions based on
+// the array's and scalar's logical types
 macro_rules! arith_scalar {
     ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )? $(, duration = $op_duration:tt )? $(, interval = $op_interval:tt )? $(, timestamp = $op_timestamp:tt )?) => {{
         let lhs = $lhs;
         let rhs = $rhs;
         use DataType::*;
         match (lhs.data_type(), rhs.data_type()) {
-            (Int8, Int8) => primitive_scalar!(lhs, rhs, $op, i8),
-            (Int16, Int16) => primitive_scalar!(lhs, rhs, $op, i16),
-            (Int32, Int32) => primitive_scalar!(lhs, rhs, $op, i32),
+            (Int8, Int8) => Ok(primitive_scalar!(lhs, rhs, $op, i8)),
+            (Int16, Int16) => Ok(primitive_scalar!(lhs, rhs, $op, i16)),
+            (Int32, Int32) => Ok(primitive_scalar!(lhs, rhs, $op, i32)),
             (Int64, Int64) | (Duration(_), Duration(_)) => {
-                primitive_scalar!(lhs, rhs, $op, i64)
+                Ok(primitive_scalar!(lhs, rhs, $op, i64))
             }
-            (UInt8, UInt8) => primitive_scalar!(lhs, rhs, $op, u8),
-            (UInt16, UInt16) => primitive_scalar!(lhs, rhs, $op, u16),
-            (UInt32, UInt32) => primitive_scalar!(lhs, rhs, $op, u32),
-            (UInt64, UInt64) => primitive_scalar!(lhs, rhs, $op, u64),
-            (Float32, Float32) => primitive_scalar!(lhs, rhs, $op, f32),
-            (Float64, Float64) => primitive_scalar!(lhs, rhs, $op, f64),
+            (UInt8, UInt8) => Ok(primitive_scalar!(lhs, rhs, $op, u8)),
+            (UInt16, UInt16) => Ok(primitive_scalar!(lhs, rhs, $op, u16)),
+            (UInt32, UInt32) => Ok(primitive_scalar!(lhs, rhs, $op, u32)),
+            (UInt64, UInt64) => Ok(primitive_scalar!(lhs, rhs, $op, u64)),
+            (Float32, Float32) => Ok(primitive_scalar!(lhs, rhs, $op, f32)),
+            (Float64, Float64) => Ok(primitive_scalar!(lhs, rhs, $op, f64)),
             $ (
             (Decimal(_, _), Decimal(_, _)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
-                Box::new(decimal::$op_decimal(lhs, rhs)) as Box<dyn Array>
+                Ok(Box::new(decimal::$op_decimal(lhs, rhs)) as Box<dyn Array>)
             }
             )?
             $ (
@@ -160,7 +160,7 @@ macro_rules! arith_scalar {
             | (Date32, Duration(_)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
-                Box::new(time::$op_duration::<i32>(lhs, rhs)) as Box<dyn Array>
+                Ok(Box::new(time::$op_duration::<i32>(lhs, rhs)) as Box<dyn Array>)
             }
             (Time64(TimeUnit::Microsecond), Duration(_))
             | (Time64(TimeUnit::Nanosecond), Duration(_))
@@ -168,28 +168,29 @@ macro_rules! arith_scalar {
             | (Timestamp(_, _), Duration(_)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
-                Box::new(time::$op_duration::<i64>(lhs, rhs)) as Box<dyn Array>
+                Ok(Box::new(time::$op_duration::<i64>(lhs, rhs)) as Box<dyn Array>)
             }
             )?
             $ (
             (Timestamp(_, _), Interval(IntervalUnit::MonthDayNano)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
-                time::$op_interval(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+                time::$op_interval(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
             }
             )?
             $ (
             (Timestamp(_, None), Timestamp(_, None)) => {
                 let lhs = lhs.as_any().downcast_ref().unwrap();
                 let rhs = rhs.as_any().downcast_ref().unwrap();
-                time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>).unwrap()
+                time::$op_timestamp(lhs, rhs).map(|x| Box::new(x) as Box<dyn Array>)
             }
             )?
-            _ => todo!(
-                "Addition of {:?} with {:?} is not supported",
+            _ => Err(ArrowError::InvalidArgumentError(format!(
+                "The operation \"{}\" is not supported between an array of {:?} and a scalar of {:?}",
+                stringify!($op),
                 lhs.data_type(),
                 rhs.data_type()
-            ),
+            ))),
         }
     }};
 }
@@ -214,9 +215,19 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_add`] to check)
-/// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+///
+/// Use [`try_add_scalar`] to get an error instead.
 pub fn add_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    try_add_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Adds an [`Array`] and a [`Scalar`].
+/// # Errors
+/// This function errors iff
+/// * the operation is not supported for the logical types
+/// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+pub fn try_add_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
     arith_scalar!(
         lhs,
         rhs,
@@ -271,13 +282,23 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
     )
 }
 
-/// Adds an [`Array`] and a [`Scalar`].
+/// Subtracts a [`Scalar`] from an [`Array`].
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_sub`] to check)
-/// * the arrays have a different length
 /// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+///
+/// Use [`try_sub_scalar`] to get an error instead.
 pub fn sub_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    try_sub_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Subtracts a [`Scalar`] from an [`Array`].
+/// # Errors
+/// This function errors iff
+/// * the operation is not supported for the logical types
+/// * one of the arrays is a timestamp with timezone and the timezone is not valid.
+pub fn try_sub_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
     arith_scalar!(
         lhs,
         rhs,
@@ -328,7 +349,16 @@ pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
+///
+/// Use [`try_mul_scalar`] to get an error instead.
 pub fn mul_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    try_mul_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Multiply an [`Array`] with a [`Scalar`].
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types.
+pub fn try_mul_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
     arith_scalar!(lhs, rhs, mul_scalar, decimal = mul_scalar)
 }
 
@@ -356,6 +386,7 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
 /// * the arrays have a different length
+/// * an integer is divided by zero or overflows (use [`checked_div`] to get a null instead)
 pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
     arith!(lhs, rhs, div, decimal = div)
 }
@@ -364,7 +395,16 @@ pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
+///
+/// Use [`try_div_scalar`] to get an error instead.
 pub fn div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    try_div_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Divide an [`Array`] with a [`Scalar`].
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types.
+pub fn try_div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
     arith_scalar!(lhs, rhs, div_scalar, decimal = div_scalar)
 }
 
@@ -373,6 +413,78 @@ pub fn can_div(lhs: &DataType, rhs: &DataType) -> bool {
     can_mul(lhs, rhs)
 }
 
+// Macro to create a `match` statement with dynamic dispatch to functions based on
+// the array's logical types, for integer types only
+macro_rules! arith_integer {
+    ($lhs:expr, $rhs:expr, $op:tt, $primitive:tt) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        use DataType::*;
+        match (lhs.data_type(), rhs.data_type()) {
+            (Int8, Int8) => $primitive!(lhs, rhs, $op, i8),
+            (Int16, Int16) => $primitive!(lhs, rhs, $op, i16),
+            (Int32, Int32) => $primitive!(lhs, rhs, $op, i32),
+            (Int64, Int64) => $primitive!(lhs, rhs, $op, i64),
+            (UInt8, UInt8) => $primitive!(lhs, rhs, $op, u8),
+            (UInt16, UInt16) => $primitive!(lhs, rhs, $op, u16),
+            (UInt32, UInt32) => $primitive!(lhs, rhs, $op, u32),
+            (UInt64, UInt64) => $primitive!(lhs, rhs, $op, u64),
+            _ => panic!(
+                "The operation \"{}\" is not supported between {:?} and {:?}",
+                stringify!($op),
+                lhs.data_type(),
+                rhs.data_type()
+            ),
+        }
+    }};
+}
+
+/// Checked division of two [`Array`]s of integers: slots whose divisor is zero or whose
+/// division overflows (e.g. `i64::MIN / -1`) are null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int64Array};
+/// use arrow2::compute::arithmetics::checked_div;
+///
+/// let a = Int64Array::from_slice([10, 10, i64::MIN]);
+/// let b = Int64Array::from_slice([2, 0, -1]);
+/// let result = checked_div(&a, &b);
+/// let expected = Int64Array::from([Some(5), None, None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+/// # Panic
+/// This function panics iff
+/// * the opertion is not supported for the logical types (use [`can_checked_div`] to check)
+/// * the arrays have a different length
+pub fn checked_div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
+    arith_integer!(lhs, rhs, checked_div, primitive)
+}
+
+/// Checked division of an [`Array`] of integers with a [`Scalar`]: slots whose division
+/// overflows are null, and all slots are null when the [`Scalar`] is zero or null.
+/// # Panic
+/// This function panics iff
+/// * the opertion is not supported for the logical types (use [`can_checked_div`] to check)
+pub fn checked_div_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    arith_integer!(lhs, rhs, checked_div_scalar, primitive_scalar)
+}
+
+/// Returns whether two [`DataType`]s can be divided by [`checked_div`].
+pub fn can_checked_div(lhs: &DataType, rhs: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        (lhs, rhs),
+        (Int8, Int8)
+            | (Int16, Int16)
+            | (Int32, Int32)
+            | (Int64, Int64)
+            | (UInt8, UInt8)
+            | (UInt16, UInt16)
+            | (UInt32, UInt32)
+            | (UInt64, UInt64)
+    )
+}
+
 /// Remainder of two [`Array`]s.
 /// # Panic
 /// This function panics iff
@@ -382,6 +494,23 @@ pub fn rem(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
     arith!(lhs, rhs, rem)
 }
 
+/// Remainder of an [`Array`] with a [`Scalar`].
+/// # Panic
+/// This function panics iff
+/// * the opertion is not supported for the logical types (use [`can_rem`] to check)
+///
+/// Use [`try_rem_scalar`] to get an error instead.
+pub fn rem_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
+    try_rem_scalar(lhs, rhs).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Remainder of an [`Array`] with a [`Scalar`].
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types.
+pub fn try_rem_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Result<Box<dyn Array>> {
+    arith_scalar!(lhs, rhs, rem_scalar)
+}
+
 /// Returns whether two [`DataType`]s "can be remainder" by [`rem`].
 pub fn can_rem(lhs: &DataType, rhs: &DataType) -> bool {
     use DataType::*;
@@ -405,13 +534,14 @@ macro_rules! with_match_negatable {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 | UInt16 | UInt32 | UInt64=> todo!(),
@@ -15,7 +15,7 @@ use num_traits::AsPrimitive;
 
 use crate::{
     array::PrimitiveArray,
-    compute::arity::{binary, unary},
+    compute::arity::{binary, binary_checked, unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
@@ -312,6 +312,12 @@ pub fn sub_timestamps_scalar(
 }
 
 /// Adds an interval to a [`DataType::Timestamp`].
+///
+/// Unlike adding a duration, this is calendar-aware: the months of the interval are added first
+/// (clamping the day to the last day of the resulting month, e.g. Jan 31 + 1 month is Feb 28
+/// or 29), then its days and then its nanoseconds. Months and days are added to the local time
+/// in the timezone of the timestamps (see [`temporal_conversions::add_interval`]).
+/// Results that overflow the time unit are null.
 pub fn add_interval(
     timestamp: &PrimitiveArray<i64>,
     interval: &PrimitiveArray<months_days_ns>,
@@ -321,12 +327,12 @@ pub fn add_interval(
             let time_unit = *time_unit;
             let timezone = temporal_conversions::parse_offset(timezone_str);
             match timezone {
-                Ok(timezone) => Ok(binary(
+                Ok(timezone) => Ok(binary_checked(
                     timestamp,
                     interval,
                     timestamp.data_type().clone(),
                     |timestamp, interval| {
-                        temporal_conversions::add_interval(
+                        temporal_conversions::checked_add_interval(
                             timestamp, time_unit, interval, &timezone,
                         )
                     },
@@ -334,12 +340,12 @@ pub fn add_interval(
                 #[cfg(feature = "chrono-tz")]
                 Err(_) => {
                     let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
-                    Ok(binary(
+                    Ok(binary_checked(
                         timestamp,
                         interval,
                         timestamp.data_type().clone(),
                         |timestamp, interval| {
-                            temporal_conversions::add_interval(
+                            temporal_conversions::checked_add_interval(
                                 timestamp, time_unit, interval, &timezone,
                             )
                         },
@@ -354,12 +360,12 @@ pub fn add_interval(
         }
         DataType::Timestamp(time_unit, None) => {
             let time_unit = *time_unit;
-            Ok(binary(
+            Ok(binary_checked(
                 timestamp,
                 interval,
                 timestamp.data_type().clone(),
                 |timestamp, interval| {
-                    temporal_conversions::add_naive_interval(timestamp, time_unit, interval)
+                    temporal_conversions::checked_add_naive_interval(timestamp, time_unit, interval)
                 },
             ))
         }
@@ -369,7 +375,7 @@ pub fn add_interval(
     }
 }
 
-/// Adds an interval to a [`DataType::Timestamp`].
+/// Adds an interval to a [`DataType::Timestamp`], as in [`add_interval`].
 pub fn add_interval_scalar(
     timestamp: &PrimitiveArray<i64>,
     interval: &PrimitiveScalar<months_days_ns>,
@@ -388,10 +394,10 @@ pub fn add_interval_scalar(
             let time_unit = *time_unit;
             let timezone = temporal_conversions::parse_offset(timezone_str);
             match timezone {
-                Ok(timezone) => Ok(unary(
+                Ok(timezone) => Ok(unary_checked(
                     timestamp,
                     |timestamp| {
-                        temporal_conversions::add_interval(
+                        temporal_conversions::checked_add_interval(
                             timestamp, time_unit, interval, &timezone,
                         )
                     },
@@ -400,10 +406,10 @@ pub fn add_interval_scalar(
                 #[cfg(feature = "chrono-tz")]
                 Err(_) => {
                     let timezone = temporal_conversions::parse_offset_tz(timezone_str)?;
-                    Ok(unary(
+                    Ok(unary_checked(
                         timestamp,
                         |timestamp| {
-                            temporal_conversions::add_interval(
+                            temporal_conversions::checked_add_interval(
                                 timestamp, time_unit, interval, &timezone,
                             )
                         },
@@ -419,10 +425,10 @@ pub fn add_interval_scalar(
         }
         DataType::Timestamp(time_unit, None) => {
             let time_unit = *time_unit;
-            Ok(unary(
+            Ok(unary_checked(
                 timestamp,
                 |timestamp| {
-                    temporal_conversions::add_naive_interval(timestamp, time_unit, interval)
+                    temporal_conversions::checked_add_naive_interval(timestamp, time_unit, interval)
                 },
                 timestamp.data_type().clone(),
             ))
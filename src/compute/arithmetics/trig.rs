@@ -0,0 +1,86 @@
+//! Trigonometric, exponential and logarithmic functions of float
+//! [`PrimitiveArray`]s.
+//!
+//! Every function preserves the validity of its input(s) and follows the semantics of the
+//! function of the same name of [`f32`] and [`f64`]: values outside of the function's domain
+//! (e.g. the square root of a negative number) are `NaN`.
+use num_traits::Float;
+
+use crate::array::PrimitiveArray;
+use crate::compute::arity::{binary, unary};
+use crate::types::NativeType;
+
+macro_rules! float_unary {
+    ($name:ident, $doc:literal) => {
+        #[doc = $doc]
+        pub fn $name<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+        where
+            T: NativeType + Float,
+        {
+            unary(array, |x| x.$name(), array.data_type().clone())
+        }
+    };
+}
+
+float_unary!(
+    sin,
+    "Computes the sine (in radians) of each value of `array`."
+);
+float_unary!(
+    cos,
+    "Computes the cosine (in radians) of each value of `array`."
+);
+float_unary!(
+    tan,
+    "Computes the tangent (in radians) of each value of `array`."
+);
+float_unary!(
+    asin,
+    "Computes the arcsine (in radians) of each value of `array`; `NaN` outside of `[-1, 1]`."
+);
+float_unary!(
+    acos,
+    "Computes the arccosine (in radians) of each value of `array`; `NaN` outside of `[-1, 1]`."
+);
+float_unary!(
+    atan,
+    "Computes the arctangent (in radians) of each value of `array`."
+);
+float_unary!(exp, "Computes `e^x` of each value `x` of `array`.");
+float_unary!(
+    ln,
+    "Computes the natural logarithm of each value of `array`; `NaN` for negative values."
+);
+float_unary!(
+    log10,
+    "Computes the base 10 logarithm of each value of `array`; `NaN` for negative values."
+);
+float_unary!(
+    sqrt,
+    "Computes the square root of each value of `array`; `NaN` for negative values."
+);
+
+/// Computes the four quadrant arctangent (in radians) of each pair of values `(y, x)`.
+/// # Panic
+/// This function panics when the arrays have different lengths.
+/// # Examples
+/// ```
+/// use arrow2::compute::arithmetics::trig::atan2;
+/// use arrow2::array::Float64Array;
+///
+/// let y = Float64Array::from(&[Some(1.0), None, Some(-1.0)]);
+/// let x = Float64Array::from(&[Some(0.0), Some(1.0), Some(0.0)]);
+/// let result = atan2(&y, &x);
+/// let expected = Float64Array::from(&[
+///     Some(std::f64::consts::FRAC_PI_2),
+///     None,
+///     Some(-std::f64::consts::FRAC_PI_2),
+/// ]);
+/// assert_eq!(result, expected);
+/// ```
+pub fn atan2<T>(y: &PrimitiveArray<T>, x: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Float,
+{
+    binary(y, x, y.data_type().clone(), |y, x| y.atan2(x))
+}
@@ -1,6 +1,9 @@
-//! Contains bitwise operators: [`or`], [`and`], [`xor`] and [`not`].
+//! Contains bitwise operators: [`or`], [`and`], [`xor`], [`not`], [`shift_left`] and
+//! [`shift_right`].
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
+use num_traits::{AsPrimitive, WrappingShl, WrappingShr};
+
 use crate::array::PrimitiveArray;
 use crate::compute::arity::{binary, unary};
 use crate::types::NativeType;
@@ -73,3 +76,54 @@ where
 {
     unary(lhs, |a| a & *rhs, lhs.data_type().clone())
 }
+
+/// Performs a left shift of the values of `lhs` by the values of `rhs`.
+///
+/// The shift amount is masked to the bit width of `T` (e.g. shifting an `i32` by 33
+/// shifts it by 1), like [`i32::wrapping_shl`].
+/// # Panic
+/// This function panics when the arrays have different lengths.
+pub fn shift_left<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShl + AsPrimitive<u32>,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shl(b.as_())
+    })
+}
+
+/// Performs a right shift of the values of `lhs` by the values of `rhs`.
+/// Signed integers are shifted arithmetically (i.e. the sign is preserved).
+///
+/// The shift amount is masked to the bit width of `T` (e.g. shifting an `i32` by 33
+/// shifts it by 1), like [`i32::wrapping_shr`].
+/// # Panic
+/// This function panics when the arrays have different lengths.
+pub fn shift_right<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShr + AsPrimitive<u32>,
+{
+    binary(lhs, rhs, lhs.data_type().clone(), |a, b| {
+        a.wrapping_shr(b.as_())
+    })
+}
+
+/// Performs a left shift of the values of a [`PrimitiveArray`] by a scalar.
+/// The shift amount is masked to the bit width of `T`, like in [`shift_left`].
+pub fn shift_left_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShl + AsPrimitive<u32>,
+{
+    let rhs = rhs.as_();
+    unary(lhs, |a| a.wrapping_shl(rhs), lhs.data_type().clone())
+}
+
+/// Performs a right shift of the values of a [`PrimitiveArray`] by a scalar.
+/// The shift amount is masked to the bit width of `T`, like in [`shift_right`].
+pub fn shift_right_scalar<T>(lhs: &PrimitiveArray<T>, rhs: &T) -> PrimitiveArray<T>
+where
+    T: NativeType + WrappingShr + AsPrimitive<u32>,
+{
+    let rhs = rhs.as_();
+    unary(lhs, |a| a.wrapping_shr(rhs), lhs.data_type().clone())
+}
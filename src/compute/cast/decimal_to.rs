@@ -11,10 +11,12 @@ fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
     to_precision: usize,
     to_scale: usize,
 ) -> PrimitiveArray<i128> {
-    let min_for_precision = 9_i128
-        .saturating_pow(1 + to_precision as u32)
-        .saturating_neg();
-    let max_for_precision = 9_i128.saturating_pow(1 + to_precision as u32);
+    // the largest number representable with `to_precision` digits
+    let max_for_precision = 10_i128
+        .checked_pow(to_precision as u32)
+        .map(|x| x - 1)
+        .unwrap_or(i128::MAX);
+    let min_for_precision = -max_for_precision;
 
     let values = from.iter().map(|x| {
         x.and_then(|x| {
@@ -31,7 +33,21 @@ fn decimal_to_decimal_impl<F: Fn(i128) -> Option<i128>>(
         .to(DataType::Decimal(to_precision, to_scale))
 }
 
-/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow
+/// Divides `x` by `factor`, rounding half away from zero (e.g. `1.25 -> 1.3` and `-1.25 -> -1.3`).
+#[inline]
+fn div_round_half_up(x: i128, factor: i128) -> i128 {
+    let quotient = x / factor;
+    let remainder = x % factor;
+    if remainder.abs() >= factor - remainder.abs() {
+        quotient + x.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Returns a [`PrimitiveArray<i128>`] with the casted values. Values are `None` on overflow,
+/// i.e. when the rescaled value does not fit in `to_precision` digits.
+/// Decreasing the scale rounds values half away from zero.
 pub fn decimal_to_decimal(
     from: &PrimitiveArray<i128>,
     to_precision: usize,
@@ -52,18 +68,21 @@ pub fn decimal_to_decimal(
     // a number will never overflow (validity is preserved)
 
     if from_scale > to_scale {
-        let factor = 10_i128.pow((from_scale - to_scale) as u32);
-        decimal_to_decimal_impl(
-            from,
-            |x: i128| x.checked_div(factor),
-            to_precision,
-            to_scale,
-        )
+        match 10_i128.checked_pow((from_scale - to_scale) as u32) {
+            Some(factor) => decimal_to_decimal_impl(
+                from,
+                |x: i128| Some(div_round_half_up(x, factor)),
+                to_precision,
+                to_scale,
+            ),
+            // every value is rounded to zero
+            None => decimal_to_decimal_impl(from, |_| Some(0), to_precision, to_scale),
+        }
     } else {
-        let factor = 10_i128.pow((to_scale - from_scale) as u32);
+        let factor = 10_i128.checked_pow((to_scale - from_scale) as u32);
         decimal_to_decimal_impl(
             from,
-            |x: i128| x.checked_mul(factor),
+            |x: i128| factor.and_then(|factor| x.checked_mul(factor)),
             to_precision,
             to_scale,
         )
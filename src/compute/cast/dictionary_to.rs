@@ -1,4 +1,7 @@
-use super::{primitive_as_primitive, primitive_to_primitive, CastOptions};
+use super::{
+    binary_to_dictionary, primitive_as_primitive, primitive_to_dictionary, primitive_to_primitive,
+    utf8_to_dictionary, CastOptions,
+};
 use crate::{
     array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray},
     compute::{cast::cast, take::take},
@@ -155,3 +158,53 @@ where
     // unwrap: The dictionary guarantees that the keys are not out-of-bounds.
     take(from.values().as_ref(), &indices).unwrap()
 }
+
+/// Encodes `array` into a [`DictionaryArray`] whose values are the distinct values of `array`, in
+/// order of first appearance, and whose keys index them. Nulls of `array` are null keys.
+/// This is the inverse of [`dictionary_to_values`], also known as packing.
+/// # Errors
+/// This function errors if `array` is not a primitive (integer or decimal), binary or utf8 array,
+/// or if `K` cannot index the number of distinct values of `array` ([`ArrowError::Overflow`]).
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::cast::dictionary_encode;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+/// let encoded = dictionary_encode::<u8>(&array).unwrap();
+/// assert_eq!(encoded.values().len(), 2);
+/// assert_eq!(encoded.keys().values().as_slice()[3], 0);
+/// assert!(encoded.is_null(1));
+/// ```
+pub fn dictionary_encode<K: DictionaryKey>(array: &dyn Array) -> Result<DictionaryArray<K>> {
+    use crate::datatypes::PhysicalType::*;
+    use crate::datatypes::PrimitiveType;
+    let any = array.as_any();
+    match array.data_type().to_physical_type() {
+        Primitive(primitive) => match primitive {
+            PrimitiveType::Int8 => primitive_to_dictionary::<i8, K>(any.downcast_ref().unwrap()),
+            PrimitiveType::Int16 => primitive_to_dictionary::<i16, K>(any.downcast_ref().unwrap()),
+            PrimitiveType::Int32 => primitive_to_dictionary::<i32, K>(any.downcast_ref().unwrap()),
+            PrimitiveType::Int64 => primitive_to_dictionary::<i64, K>(any.downcast_ref().unwrap()),
+            PrimitiveType::Int128 => {
+                primitive_to_dictionary::<i128, K>(any.downcast_ref().unwrap())
+            }
+            PrimitiveType::UInt8 => primitive_to_dictionary::<u8, K>(any.downcast_ref().unwrap()),
+            PrimitiveType::UInt16 => primitive_to_dictionary::<u16, K>(any.downcast_ref().unwrap()),
+            PrimitiveType::UInt32 => primitive_to_dictionary::<u32, K>(any.downcast_ref().unwrap()),
+            PrimitiveType::UInt64 => primitive_to_dictionary::<u64, K>(any.downcast_ref().unwrap()),
+            _ => Err(ArrowError::NotYetImplemented(format!(
+                "Dictionary encoding of {:?}",
+                array.data_type()
+            ))),
+        },
+        Utf8 => utf8_to_dictionary::<i32, K>(any.downcast_ref().unwrap()),
+        LargeUtf8 => utf8_to_dictionary::<i64, K>(any.downcast_ref().unwrap()),
+        Binary => binary_to_dictionary::<i32, K>(any.downcast_ref().unwrap()),
+        LargeBinary => binary_to_dictionary::<i64, K>(any.downcast_ref().unwrap()),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Dictionary encoding of {:?}",
+            array.data_type()
+        ))),
+    }
+}
@@ -6,6 +6,7 @@ mod decimal_to;
 mod dictionary_to;
 mod primitive_to;
 mod utf8_to;
+mod utf8_view_to;
 
 pub use binary_to::*;
 pub use boolean_to::*;
@@ -13,6 +14,7 @@ pub use decimal_to::*;
 pub use dictionary_to::*;
 pub use primitive_to::*;
 pub use utf8_to::*;
+pub use utf8_view_to::*;
 
 use crate::{
     array::*,
@@ -79,16 +81,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     }
 
     match (from_type, to_type) {
-        (
-            Null,
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-        )
-        | (
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-            Null,
-        ) => true,
+        (Null, _) | (_, Null) => true,
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (List(list_from), List(list_to)) => {
@@ -105,6 +98,11 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         }
         (Dictionary(_, value_type, _), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type, _)) => can_cast_types(from_type, value_type),
+        (_, RunEndEncoded(run_end_type, value_type)) => {
+            is_run_end_type(*run_end_type)
+                && matches!(value_type.to_physical_type(), PhysicalType::Primitive(_))
+                && can_cast_types(from_type, value_type)
+        }
 
         (_, Boolean) => is_numeric(from_type),
         (Boolean, _) => {
@@ -119,11 +117,15 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Utf8, Date64) => true,
         (Utf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (Utf8, LargeUtf8) => true,
+        (Utf8, Utf8View) => true,
         (Utf8, _) => is_numeric(to_type),
         (LargeUtf8, Date32) => true,
         (LargeUtf8, Date64) => true,
         (LargeUtf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
         (LargeUtf8, Utf8) => true,
+        (LargeUtf8, Utf8View) => true,
+        (Utf8View, Utf8) => true,
+        (Utf8View, LargeUtf8) => true,
         (LargeUtf8, _) => is_numeric(to_type),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
@@ -290,6 +292,7 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Date64) => true,
         (Int64, Duration(_)) => true,
         (Duration(_), Int64) => true,
+        (Duration(_), Duration(_)) => true,
         (_, _) => false,
     }
 }
@@ -355,18 +358,23 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
-/// * Numeric to boolean: 0 returns `false`, any other value returns `true`
+/// * Numeric to boolean: 0 returns `false`, any other value returns `true`; `NaN` returns null
+///   (or `true` if `options.wrapped`)
+/// * Boolean to numeric: `true` => 1, `false` => 0
 /// * List to List: the underlying data type is cast
 /// * PrimitiveArray to List: a list array with 1 value per slot is created
+/// * Null to any type: an array of nulls is created; any type to Null: a [`NullArray`] is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Timestamp, Time{32|64} and Duration to a finer unit: overflowing values are None (or
+///   wrapped if `options.wrapped`)
 /// * Temporal to/from backing primitive: zero-copy with data type change
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// * Interval
 pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Result<Box<dyn Array>> {
     use DataType::*;
     let from_type = array.data_type();
@@ -378,16 +386,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
 
     let as_options = options.with_wrapped(true);
     match (from_type, to_type) {
-        (
-            Null,
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-        )
-        | (
-            Boolean | Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Float32 | Date32 | Time32(_)
-            | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
-            Null,
-        ) => Ok(new_null_array(to_type.clone(), array.len())),
+        (Null, _) | (_, Null) => Ok(new_null_array(to_type.clone(), array.len())),
         (Struct(_), _) => Err(ArrowError::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
@@ -431,6 +430,11 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (_, Dictionary(index_type, value_type, _)) => match_integer_type!(index_type, |$T| {
             cast_to_dictionary::<$T>(array, value_type, options)
         }),
+        (_, RunEndEncoded(run_end_type, value_type)) if is_run_end_type(*run_end_type) => {
+            match_run_end_type!(run_end_type, |$T| {
+                cast_to_run_end_encoded::<$T>(array, value_type, options)
+            })
+        }
         (_, Boolean) => match from_type {
             UInt8 => primitive_to_boolean_dyn::<u8>(array, to_type.clone()),
             UInt16 => primitive_to_boolean_dyn::<u16>(array, to_type.clone()),
@@ -440,8 +444,8 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int16 => primitive_to_boolean_dyn::<i16>(array, to_type.clone()),
             Int32 => primitive_to_boolean_dyn::<i32>(array, to_type.clone()),
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
-            Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
-            Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
+            Float32 => float_to_boolean_dyn::<f32>(array, to_type.clone(), options),
+            Float64 => float_to_boolean_dyn::<f64>(array, to_type.clone(), options),
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -484,6 +488,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
+            Utf8View => Ok(Box::new(utf8_to_utf8_view::<i32>(
+                array.as_any().downcast_ref().unwrap(),
+            ))),
             Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
@@ -508,6 +515,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Date64 => utf8_to_date64_dyn::<i64>(array),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
                 .map(|x| Box::new(x) as Box<dyn Array>),
+            Utf8View => Ok(Box::new(utf8_to_utf8_view::<i64>(
+                array.as_any().downcast_ref().unwrap(),
+            ))),
             Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i64>(array),
             Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
                 utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
@@ -518,6 +528,9 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             ))),
         },
 
+        (Utf8View, Utf8) => utf8_view_to_utf8_dyn::<i32>(array),
+        (Utf8View, LargeUtf8) => utf8_view_to_utf8_dyn::<i64>(array),
+
         (_, Utf8) => match from_type {
             UInt8 => primitive_to_utf8_dyn::<u8, i32>(array),
             UInt16 => primitive_to_utf8_dyn::<u16, i32>(array),
@@ -814,34 +827,31 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Time64(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Date32, Date64) => primitive_dyn!(array, date32_to_date64),
         (Date64, Date32) => primitive_dyn!(array, date64_to_date32),
-        (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond)) => {
-            primitive_dyn!(array, time32s_to_time32ms)
-        }
-        (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second)) => {
-            primitive_dyn!(array, time32ms_to_time32s)
+        (Time32(from_unit), Time32(to_unit)) => {
+            scale_time_unit_dyn::<i32, i32>(array, *from_unit, *to_unit, to_type, options)
         }
         (Time32(from_unit), Time64(to_unit)) => {
-            primitive_dyn!(array, time32_to_time64, *from_unit, *to_unit)
-        }
-        (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Nanosecond)) => {
-            primitive_dyn!(array, time64us_to_time64ns)
+            scale_time_unit_dyn::<i32, i64>(array, *from_unit, *to_unit, to_type, options)
         }
-        (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Microsecond)) => {
-            primitive_dyn!(array, time64ns_to_time64us)
+        (Time64(from_unit), Time64(to_unit)) => {
+            scale_time_unit_dyn::<i64, i64>(array, *from_unit, *to_unit, to_type, options)
         }
         (Time64(from_unit), Time32(to_unit)) => {
-            primitive_dyn!(array, time64_to_time32, *from_unit, *to_unit)
+            scale_time_unit_dyn::<i64, i32>(array, *from_unit, *to_unit, to_type, options)
         }
         (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
-        (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
-            primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
+        (Timestamp(from_unit, _), Timestamp(to_unit, _)) => {
+            scale_time_unit_dyn::<i64, i64>(array, *from_unit, *to_unit, to_type, options)
         }
         (Timestamp(from_unit, _), Date32) => primitive_dyn!(array, timestamp_to_date32, *from_unit),
         (Timestamp(from_unit, _), Date64) => primitive_dyn!(array, timestamp_to_date64, *from_unit),
 
         (Int64, Duration(_)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
         (Duration(_), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        (Duration(from_unit), Duration(to_unit)) => {
+            scale_time_unit_dyn::<i64, i64>(array, *from_unit, *to_unit, to_type, options)
+        }
 
         (_, _) => Err(ArrowError::NotYetImplemented(format!(
             "Casting from {:?} to {:?} not supported",
@@ -850,6 +860,26 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
     }
 }
 
+/// Attempts to encode an array into a [`RunEndEncodedArray`] with run ends of type `R`
+/// and values of type `value_type`
+fn cast_to_run_end_encoded<R: RunEndIndex>(
+    array: &dyn Array,
+    value_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let array = cast(array, value_type, options)?;
+    let array = array.as_ref();
+    match value_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            primitive_to_run_end_encoded_dyn::<$T, R>(array)
+        }),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Unsupported output type for run-end encoding: {:?}",
+            value_type
+        ))),
+    }
+}
+
 /// Attempts to encode an array into an `ArrayDictionary` with index
 /// type K and value (dictionary) type value_type
 ///
@@ -1,12 +1,13 @@
 use std::hash::Hash;
+use std::sync::Arc;
 
 use num_traits::{AsPrimitive, Float, ToPrimitive};
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{
     array::*,
     bitmap::Bitmap,
-    compute::arity::unary,
+    compute::arity::{unary, unary_checked},
     datatypes::{DataType, TimeUnit},
     temporal_conversions::*,
     types::NativeType,
@@ -69,6 +70,40 @@ pub fn primitive_to_boolean<T: NativeType>(
     BooleanArray::from_data(to_type, values, from.validity().cloned())
 }
 
+/// Returns a [`BooleanArray`] where every element is different from zero and `NaN`s are null.
+/// Validity is otherwise preserved.
+pub fn float_to_boolean<T: NativeType + Float>(
+    from: &PrimitiveArray<T>,
+    to_type: DataType,
+) -> BooleanArray {
+    let iter = from.values().iter().map(|v| !v.is_zero());
+    let values = Bitmap::from_trusted_len_iter(iter);
+
+    let not_nan = Bitmap::from_trusted_len_iter(from.values().iter().map(|v| !v.is_nan()));
+    let validity = match from.validity() {
+        Some(validity) => validity & &not_nan,
+        None => not_nan,
+    };
+
+    BooleanArray::from_data(to_type, values, Some(validity))
+}
+
+pub(super) fn float_to_boolean_dyn<T>(
+    from: &dyn Array,
+    to_type: DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
+where
+    T: NativeType + Float,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    if options.wrapped {
+        Ok(Box::new(primitive_to_boolean::<T>(from, to_type)))
+    } else {
+        Ok(Box::new(float_to_boolean::<T>(from, to_type)))
+    }
+}
+
 pub(super) fn primitive_to_boolean_dyn<T>(
     from: &dyn Array,
     to_type: DataType,
@@ -307,6 +342,44 @@ pub fn primitive_to_dictionary<T: NativeType + Eq + Hash, K: DictionaryKey>(
     Ok(array.into())
 }
 
+pub(super) fn primitive_to_run_end_encoded_dyn<T: NativeType, R: RunEndIndex>(
+    from: &dyn Array,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    primitive_to_run_end_encoded::<T, R>(from).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+/// Cast [`PrimitiveArray`] to [`RunEndEncodedArray`], where every maximal run of equal
+/// consecutive values (including nulls) is stored once.
+/// # Errors
+/// This function errors iff the length of the array does not fit in `R`.
+pub fn primitive_to_run_end_encoded<T: NativeType, R: RunEndIndex>(
+    from: &PrimitiveArray<T>,
+) -> Result<RunEndEncodedArray<R>> {
+    R::from_usize(from.len()).ok_or(ArrowError::Overflow)?;
+
+    let mut run_ends = Vec::<R>::new();
+    let mut values = MutablePrimitiveArray::<T>::from(from.data_type().clone());
+    let mut iter = from.iter().enumerate();
+    if let Some((_, first)) = iter.next() {
+        let mut current = first;
+        for (index, value) in iter {
+            if value != current {
+                // unwrap is infallible since `index < from.len()`, which fits in `R`
+                run_ends.push(R::from_usize(index).unwrap());
+                values.push(current.copied());
+                current = value;
+            }
+        }
+        run_ends.push(R::from_usize(from.len()).unwrap());
+        values.push(current.copied());
+    }
+
+    let run_ends = PrimitiveArray::<R>::from_data(R::PRIMITIVE.into(), run_ends.into(), None);
+    let values: PrimitiveArray<T> = values.into();
+    RunEndEncodedArray::try_new(run_ends, Arc::new(values))
+}
+
 /// Get the time unit as a multiple of a second
 const fn time_unit_multiple(unit: TimeUnit) -> i64 {
     match unit {
@@ -329,7 +402,14 @@ pub fn date64_to_date32(from: &PrimitiveArray<i64>) -> PrimitiveArray<i32> {
 
 /// Conversion of times
 pub fn time32s_to_time32ms(from: &PrimitiveArray<i32>) -> PrimitiveArray<i32> {
-    unary(from, |x| x * 1000, DataType::Time32(TimeUnit::Millisecond))
+    let to_type = DataType::Time32(TimeUnit::Millisecond);
+    scale_time_unit(
+        from,
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+        to_type,
+        false,
+    )
 }
 
 /// Conversion of times
@@ -339,7 +419,14 @@ pub fn time32ms_to_time32s(from: &PrimitiveArray<i32>) -> PrimitiveArray<i32> {
 
 /// Conversion of times
 pub fn time64us_to_time64ns(from: &PrimitiveArray<i64>) -> PrimitiveArray<i64> {
-    unary(from, |x| x * 1000, DataType::Time64(TimeUnit::Nanosecond))
+    let to_type = DataType::Time64(TimeUnit::Nanosecond);
+    scale_time_unit(
+        from,
+        TimeUnit::Microsecond,
+        TimeUnit::Nanosecond,
+        to_type,
+        false,
+    )
 }
 
 /// Conversion of times
@@ -376,44 +463,109 @@ pub fn time32_to_time64(
     from_unit: TimeUnit,
     to_unit: TimeUnit,
 ) -> PrimitiveArray<i64> {
-    let from_size = time_unit_multiple(from_unit);
-    let to_size = time_unit_multiple(to_unit);
-    let divisor = to_size / from_size;
-    unary(from, |x| (x as i64 * divisor), DataType::Time64(to_unit))
+    scale_time_unit(from, from_unit, to_unit, DataType::Time64(to_unit), false)
 }
 
 /// Conversion of time
+///
+/// Slots that do not fit in an `i32` are set to null.
 pub fn time64_to_time32(
     from: &PrimitiveArray<i64>,
     from_unit: TimeUnit,
     to_unit: TimeUnit,
 ) -> PrimitiveArray<i32> {
+    scale_time_unit(from, from_unit, to_unit, DataType::Time32(to_unit), false)
+}
+
+/// Conversion of duration
+///
+/// Converting to a coarser unit truncates towards zero. Converting to a finer unit sets the
+/// slots that overflow to null.
+pub fn duration_to_duration(
+    from: &PrimitiveArray<i64>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+) -> PrimitiveArray<i64> {
+    scale_time_unit(from, from_unit, to_unit, DataType::Duration(to_unit), false)
+}
+
+/// Scales `from` from `from_unit` to `to_unit`, truncating towards zero when converting to a
+/// coarser unit. Values that overflow or do not fit in `O` are set to null, or wrapped iff
+/// `wrapped`.
+fn scale_time_unit<I, O>(
+    from: &PrimitiveArray<I>,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+    to_type: DataType,
+    wrapped: bool,
+) -> PrimitiveArray<O>
+where
+    I: NativeType + AsPrimitive<i64>,
+    O: NativeType + TryFrom<i64>,
+    i64: AsPrimitive<O>,
+{
     let from_size = time_unit_multiple(from_unit);
     let to_size = time_unit_multiple(to_unit);
-    let divisor = from_size / to_size;
-    unary(
+    // we either divide or multiply, depending on size of each unit
+    if from_size >= to_size {
+        let divisor = from_size / to_size;
+        if wrapped {
+            unary(from, |x| (x.as_() / divisor).as_(), to_type)
+        } else {
+            unary_checked(from, |x| O::try_from(x.as_() / divisor).ok(), to_type)
+        }
+    } else {
+        let multiple = to_size / from_size;
+        if wrapped {
+            unary(from, |x| x.as_().wrapping_mul(multiple).as_(), to_type)
+        } else {
+            unary_checked(
+                from,
+                |x| {
+                    x.as_()
+                        .checked_mul(multiple)
+                        .and_then(|x| O::try_from(x).ok())
+                },
+                to_type,
+            )
+        }
+    }
+}
+
+pub(super) fn scale_time_unit_dyn<I, O>(
+    from: &dyn Array,
+    from_unit: TimeUnit,
+    to_unit: TimeUnit,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>>
+where
+    I: NativeType + AsPrimitive<i64>,
+    O: NativeType + TryFrom<i64>,
+    i64: AsPrimitive<O>,
+{
+    let from = from.as_any().downcast_ref::<PrimitiveArray<I>>().unwrap();
+    Ok(Box::new(scale_time_unit::<I, O>(
         from,
-        |x| (x as i64 / divisor) as i32,
-        DataType::Time32(to_unit),
-    )
+        from_unit,
+        to_unit,
+        to_type.clone(),
+        options.wrapped,
+    )))
 }
 
 /// Conversion of timestamp
+///
+/// Converting to a coarser unit truncates towards zero. Converting to a finer unit sets the
+/// slots that overflow to null.
 pub fn timestamp_to_timestamp(
     from: &PrimitiveArray<i64>,
     from_unit: TimeUnit,
     to_unit: TimeUnit,
     tz: &Option<String>,
 ) -> PrimitiveArray<i64> {
-    let from_size = time_unit_multiple(from_unit);
-    let to_size = time_unit_multiple(to_unit);
     let to_type = DataType::Timestamp(to_unit, tz.clone());
-    // we either divide or multiply, depending on size of each unit
-    if from_size >= to_size {
-        unary(from, |x| (x / (from_size / to_size)), to_type)
-    } else {
-        unary(from, |x| (x * (to_size / from_size)), to_type)
-    }
+    scale_time_unit(from, from_unit, to_unit, to_type, false)
 }
 
 fn timestamp_to_utf8_impl<O: Offset, T: chrono::TimeZone>(
@@ -1,13 +1,13 @@
 use std::convert::TryFrom;
 
-use chrono::Datelike;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, TimeZone};
 
 use crate::{
     array::*,
-    datatypes::DataType,
+    datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     temporal_conversions::{
-        utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
+        parse_offset, utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
         utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
     },
     types::NativeType,
@@ -144,6 +144,152 @@ pub fn utf8_to_timestamp_ns<O: Offset>(
     utf8_to_timestamp_ns_(from, RFC3339, timezone)
 }
 
+/// Parses every non-null value of `from` with `op`, making values that `op` cannot parse
+/// a Null or, when `strict`, erroring.
+fn parse_with_format<O: Offset, T: NativeType, F: Fn(&str) -> Option<T>>(
+    from: &Utf8Array<O>,
+    format: &str,
+    strict: bool,
+    op: F,
+) -> Result<PrimitiveArray<T>> {
+    from.iter()
+        .map(|x| match x {
+            Some(x) => match op(x) {
+                None if strict => Err(ArrowError::InvalidArgumentError(format!(
+                    "The value \"{}\" cannot be parsed with the format \"{}\"",
+                    x, format
+                ))),
+                x => Ok(x),
+            },
+            None => Ok(None),
+        })
+        .collect()
+}
+
+/// Parses `value` with `format` to a [`NaiveDateTime`], at midnight when `format` has no time.
+/// Offsets in `value` are ignored.
+fn to_naive_datetime(value: &str, format: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(value, format)
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, format)
+                .ok()
+                .and_then(|x| x.and_hms_opt(0, 0, 0))
+        })
+}
+
+/// Parses `value` with `format` to nanoseconds since the epoch. When `format` has no offset,
+/// `value` is a local time of `tz`. Returns `None` when the value cannot be parsed or does not
+/// fit in nanoseconds.
+fn to_timestamp_ns<T: TimeZone>(value: &str, format: &str, tz: &T) -> Option<i64> {
+    match chrono::DateTime::parse_from_str(value, format) {
+        Ok(x) => x.timestamp_nanos_opt(),
+        Err(_) => to_naive_datetime(value, format)
+            .and_then(|x| tz.from_local_datetime(&x).single())
+            .and_then(|x| x.timestamp_nanos_opt()),
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+fn chrono_tz_utf8_to_timestamp_ns_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    timezone: &str,
+    strict: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let tz = crate::temporal_conversions::parse_offset_tz(timezone)?;
+    parse_with_format(from, format, strict, |x| to_timestamp_ns(x, format, &tz))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_utf8_to_timestamp_ns_with_format<O: Offset>(
+    _: &Utf8Array<O>,
+    _: &str,
+    timezone: &str,
+    _: bool,
+) -> Result<PrimitiveArray<i64>> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone
+    )))
+}
+
+/// Casts a [`Utf8Array`] to a `Timestamp(Nanosecond, timezone)` primitive by parsing its values
+/// with the chrono format string `format` (e.g. `"%m/%d/%Y %H:%M"`).
+///
+/// Values with an offset (when `format` has one, e.g. `%z`) are mapped to UTC with it. Otherwise,
+/// values are local times of `timezone` or, if it is `None`, naive timestamps.
+/// Values without a time (e.g. `"%m/%d/%Y"`) are at midnight.
+/// Values that cannot be parsed, or that are out of the range of nanosecond timestamps
+/// (approximately the years 1677 to 2262), are Null or, when `strict`, an error.
+/// # Errors
+/// This function errors if `timezone` cannot be parsed or, when `strict`, if a value cannot be parsed.
+/// # Example
+/// ```
+/// use arrow2::array::{Int64Array, Utf8Array};
+/// use arrow2::compute::cast::utf8_to_timestamp_ns_with_format;
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// let array = Utf8Array::<i32>::from([Some("12/31/1999 23:00"), Some("31/12/1999"), None]);
+/// let result = utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M", None, false).unwrap();
+/// let expected = Int64Array::from([Some(946681200000000000), None, None])
+///     .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+/// assert_eq!(result, expected);
+///
+/// assert!(utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M", None, true).is_err());
+/// ```
+pub fn utf8_to_timestamp_ns_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    timezone: Option<String>,
+    strict: bool,
+) -> Result<PrimitiveArray<i64>> {
+    let array = match &timezone {
+        Some(timezone) => match parse_offset(timezone) {
+            Ok(tz) => parse_with_format(from, format, strict, |x| to_timestamp_ns(x, format, &tz))?,
+            Err(_) => chrono_tz_utf8_to_timestamp_ns_with_format(from, format, timezone, strict)?,
+        },
+        None => parse_with_format(from, format, strict, |x| {
+            to_naive_datetime(x, format).and_then(|x| x.and_utc().timestamp_nanos_opt())
+        })?,
+    };
+    Ok(array.to(DataType::Timestamp(TimeUnit::Nanosecond, timezone)))
+}
+
+/// Casts a [`Utf8Array`] to a Date32 primitive by parsing its values with the chrono
+/// format string `format` (e.g. `"%m/%d/%Y"`).
+/// Values that cannot be parsed are Null or, when `strict`, an error.
+/// # Errors
+/// This function errors when `strict` and a value cannot be parsed.
+pub fn utf8_to_date32_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    strict: bool,
+) -> Result<PrimitiveArray<i32>> {
+    parse_with_format(from, format, strict, |x| {
+        NaiveDate::parse_from_str(x, format)
+            .ok()
+            .map(|x| x.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+    })
+    .map(|x| x.to(DataType::Date32))
+}
+
+/// Casts a [`Utf8Array`] to a Date64 primitive by parsing its values with the chrono
+/// format string `format` (e.g. `"%m/%d/%Y"`). Values without a time are at midnight.
+/// Values that cannot be parsed are Null or, when `strict`, an error.
+/// # Errors
+/// This function errors when `strict` and a value cannot be parsed.
+pub fn utf8_to_date64_with_format<O: Offset>(
+    from: &Utf8Array<O>,
+    format: &str,
+    strict: bool,
+) -> Result<PrimitiveArray<i64>> {
+    parse_with_format(from, format, strict, |x| {
+        to_naive_datetime(x, format).map(|x| x.and_utc().timestamp_millis())
+    })
+    .map(|x| x.to(DataType::Date64))
+}
+
 /// Conversion of utf8
 pub fn utf8_to_large_utf8(from: &Utf8Array<i32>) -> Utf8Array<i64> {
     let data_type = Utf8Array::<i64>::default_data_type();
@@ -176,3 +322,33 @@ pub fn utf8_large_to_utf8(from: &Utf8Array<i64>) -> Result<Utf8Array<i32>> {
     // Safety: sound because `offsets` fulfills the same invariants as `from.offsets()`
     Ok(unsafe { Utf8Array::<i32>::from_data_unchecked(data_type, offsets, values, validity) })
 }
+
+/// Conversion of utf8 to utf8 view. The data of the strings longer than
+/// [`View::MAX_INLINE_SIZE`] is shared with `from` whenever its values fit in a view's offset.
+pub fn utf8_to_utf8_view<O: Offset>(from: &Utf8Array<O>) -> Utf8ViewArray {
+    let values = from.values();
+    if u32::try_from(values.len()).is_err() {
+        return from.iter().collect();
+    }
+
+    let views = from
+        .offsets()
+        .windows(2)
+        .map(|window| {
+            let start = window[0].to_usize();
+            let end = window[1].to_usize();
+            // `start` fits in a u32 because `values.len()` does
+            View::new(&values[start..end], 0, start as u32).to_i128()
+        })
+        .collect::<Vec<_>>();
+    let validity = from.validity().cloned();
+    // Safety: every view references a slice of `values`, that is utf8 since `from` is.
+    unsafe {
+        Utf8ViewArray::from_data_unchecked(
+            DataType::Utf8View,
+            views.into(),
+            vec![values.clone()],
+            validity,
+        )
+    }
+}
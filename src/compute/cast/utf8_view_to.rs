@@ -0,0 +1,20 @@
+use crate::{array::*, error::Result};
+
+/// Conversion of utf8 view to utf8
+/// # Errors
+/// This function errors iff the total number of bytes of the strings in `from` does not fit in `O`.
+pub fn utf8_view_to_utf8<O: Offset>(from: &Utf8ViewArray) -> Result<Utf8Array<O>> {
+    let values = from
+        .views()
+        .iter()
+        .map(|view| View::from_i128(*view).length() as usize)
+        .sum();
+    let mut array = MutableUtf8Array::<O>::with_capacities(from.len(), values);
+    array.try_extend(from.iter())?;
+    Ok(array.into())
+}
+
+pub(super) fn utf8_view_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    utf8_view_to_utf8::<O>(from).map(|x| Box::new(x) as Box<dyn Array>)
+}
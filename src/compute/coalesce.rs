@@ -0,0 +1,83 @@
+//! Contains the operator [`coalesce`].
+use crate::array::{growable, Array};
+use crate::error::{ArrowError, Result};
+
+/// Returns, for each row, the first non-null value of `arrays`, or null if all are null.
+/// This is SQL's `COALESCE` over `arrays`.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::coalesce::coalesce;
+/// use arrow2::array::Int32Array;
+///
+/// # fn main() -> Result<()> {
+/// let a = Int32Array::from(&[Some(1), None, None]);
+/// let b = Int32Array::from(&[Some(4), Some(5), None]);
+/// let result = coalesce(&[&a, &b])?;
+///
+/// let expected = Int32Array::from(&[Some(1), Some(5), None]);
+///
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `arrays` is empty or its arrays do not have the same data type
+/// and length.
+pub fn coalesce(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let first = arrays.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Coalesce requires at least one array".to_string())
+    })?;
+    if let Some(array) = arrays
+        .iter()
+        .find(|array| array.data_type() != first.data_type())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Coalesce requires the arguments to have the same datatypes ({:?} != {:?})",
+            first.data_type(),
+            array.data_type()
+        )));
+    }
+    if let Some(array) = arrays.iter().find(|array| array.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Coalesce requires all arguments to have the same length ({} != {})",
+            first.len(),
+            array.len()
+        )));
+    }
+    let len = first.len();
+
+    let mut growable = growable::make_growable(arrays, true, len);
+
+    // extend the growable in runs of consecutive rows taken from the same array
+    // (`None` denotes rows where all arrays are null)
+    let mut run: Option<(Option<usize>, usize)> = None;
+    for row in 0..len {
+        let source = arrays.iter().position(|array| array.is_valid(row));
+        run = match run {
+            Some((current, start)) if current == source => Some((current, start)),
+            Some((current, start)) => {
+                extend(growable.as_mut(), current, start, row - start);
+                Some((source, row))
+            }
+            None => Some((source, row)),
+        };
+    }
+    if let Some((current, start)) = run {
+        extend(growable.as_mut(), current, start, len - start);
+    }
+    Ok(growable.as_box())
+}
+
+#[inline]
+fn extend<'a>(
+    growable: &mut dyn growable::Growable<'a>,
+    source: Option<usize>,
+    start: usize,
+    len: usize,
+) {
+    match source {
+        Some(index) => growable.extend(index, start, len),
+        None => growable.extend_validity(len),
+    }
+}
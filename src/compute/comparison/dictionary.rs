@@ -0,0 +1,115 @@
+//! Comparison functions for [`DictionaryArray`]
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use crate::{
+    array::{
+        ord::build_compare, Array, BooleanArray, DictionaryArray, DictionaryKey, PrimitiveArray,
+    },
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    types::NativeType,
+};
+
+type IsEqual = Box<dyn Fn(usize, usize) -> bool + Send + Sync>;
+
+fn is_equal_primitive<T: NativeType>(lhs: &dyn Array, rhs: &dyn Array) -> IsEqual {
+    let lhs = lhs
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    let rhs = rhs
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .unwrap()
+        .clone();
+    Box::new(move |i, j| lhs.value(i) == rhs.value(j))
+}
+
+/// Returns a function that compares the (valid) slots `i` of `lhs` and `j` of `rhs` for equality,
+/// with the semantics of the comparison kernels of the values (e.g. `NaN != NaN`).
+fn build_is_equal(lhs: &dyn Array, rhs: &dyn Array) -> IsEqual {
+    match (
+        lhs.data_type().to_logical_type(),
+        rhs.data_type().to_logical_type(),
+    ) {
+        (DataType::Float32, DataType::Float32) => is_equal_primitive::<f32>(lhs, rhs),
+        (DataType::Float64, DataType::Float64) => is_equal_primitive::<f64>(lhs, rhs),
+        _ => {
+            let comparator = build_compare(lhs, rhs).unwrap();
+            Box::new(move |i, j| comparator(i, j) == Ordering::Equal)
+        }
+    }
+}
+
+/// Evaluates `op` on whether the values of `lhs` and `rhs` are equal, slot by slot.
+///
+/// Keys are compared directly and the values they point to are only compared when the keys
+/// differ or the dictionaries of `lhs` and `rhs` are not equal. In neither case are the
+/// arrays decoded.
+fn compare_op<K, F>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>, op: F) -> BooleanArray
+where
+    K: DictionaryKey,
+    F: Fn(bool) -> bool,
+{
+    assert_eq!(lhs.len(), rhs.len());
+    let lhs_values = lhs.values().as_ref();
+    let rhs_values = rhs.values().as_ref();
+
+    // equal keys of equal dictionaries point to equal values, except for floats (`NaN != NaN`)
+    let is_float = matches!(
+        lhs_values.data_type().to_logical_type(),
+        DataType::Float32 | DataType::Float64
+    );
+    let same_values =
+        !is_float && (Arc::ptr_eq(lhs.values(), rhs.values()) || lhs_values == rhs_values);
+    let is_equal = build_is_equal(lhs_values, rhs_values);
+
+    let lhs_keys = lhs.keys();
+    let rhs_keys = rhs.keys();
+    let mut validity = MutableBitmap::with_capacity(lhs.len());
+    let mut values = MutableBitmap::with_capacity(lhs.len());
+    for i in 0..lhs.len() {
+        let keys = (lhs_keys.is_valid(i) && rhs_keys.is_valid(i)).then(|| {
+            (
+                lhs_keys.value(i).to_usize().unwrap(),
+                rhs_keys.value(i).to_usize().unwrap(),
+            )
+        });
+        match keys {
+            Some((lhs_key, rhs_key))
+                if lhs_values.is_valid(lhs_key) && rhs_values.is_valid(rhs_key) =>
+            {
+                let is_equal = (same_values && lhs_key == rhs_key) || is_equal(lhs_key, rhs_key);
+                validity.push(true);
+                values.push(op(is_equal));
+            }
+            _ => {
+                validity.push(false);
+                values.push(false);
+            }
+        }
+    }
+
+    BooleanArray::from_data(DataType::Boolean, values.into(), validity.into())
+}
+
+/// Perform `lhs == rhs` operation on two [`DictionaryArray`]s, comparing their values.
+///
+/// A slot is null when either key is null or points to a null value. Values are compared
+/// like the comparison kernels of their type, so that e.g. `NaN` does not equal `NaN`.
+/// # Panic
+/// Panics iff the arrays have different lengths or the comparison of their values is not supported.
+pub fn eq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| x)
+}
+
+/// Perform `lhs != rhs` operation on two [`DictionaryArray`]s, comparing their values.
+///
+/// See [`eq`] for how slots are compared.
+/// # Panic
+/// Panics iff the arrays have different lengths or the comparison of their values is not supported.
+pub fn neq<K: DictionaryKey>(lhs: &DictionaryArray<K>, rhs: &DictionaryArray<K>) -> BooleanArray {
+    compare_op(lhs, rhs, |x| !x)
+}
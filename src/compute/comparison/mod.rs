@@ -46,10 +46,12 @@
 
 use crate::array::*;
 use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
 use crate::scalar::*;
 
 pub mod binary;
 pub mod boolean;
+pub mod dictionary;
 pub mod primitive;
 pub mod utf8;
 
@@ -69,12 +71,14 @@ macro_rules! match_eq_ord {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
+    use crate::types::i256;
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => todo!(),
         MonthDayNano => todo!(),
         UInt8 => __with_ty__! { u8 },
@@ -91,13 +95,14 @@ macro_rules! match_eq {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::{days_ms, months_days_ns};
+    use crate::types::{days_ms, i256, months_days_ns};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         MonthDayNano => __with_ty__! { months_days_ns },
         UInt8 => __with_ty__! { u8 },
@@ -158,6 +163,27 @@ macro_rules! compare {
     }};
 }
 
+/// Returns `$op` between `$lhs` and `$rhs` if they are [`DictionaryArray`]s, which
+/// compares them without decoding them.
+macro_rules! return_if_dictionaries {
+    ($lhs:expr, $rhs:expr, $op:tt) => {{
+        use crate::datatypes::PhysicalType::*;
+        let lhs = $lhs;
+        let rhs = $rhs;
+        if let Dictionary(key_type) = lhs.data_type().to_physical_type() {
+            assert_eq!(
+                lhs.data_type().to_logical_type(),
+                rhs.data_type().to_logical_type()
+            );
+            return match_integer_type!(key_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                dictionary::$op::<$T>(lhs, rhs)
+            });
+        }
+    }};
+}
+
 /// `==` between two [`Array`]s.
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
@@ -166,6 +192,7 @@ macro_rules! compare {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    return_if_dictionaries!(lhs, rhs, eq);
     compare!(lhs, rhs, eq, match_eq)
 }
 
@@ -182,7 +209,7 @@ pub fn eq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 
 /// Returns whether a [`DataType`] is comparable is supported by [`eq`].
 pub fn can_eq(data_type: &DataType) -> bool {
-    can_partial_eq(data_type)
+    can_partial_eq(data_type) || can_partial_eq_dictionary(data_type)
 }
 
 /// `!=` between two [`Array`]s.
@@ -193,6 +220,7 @@ pub fn can_eq(data_type: &DataType) -> bool {
 /// * the arrays do not have the same length
 /// * the operation is not supported for the logical type
 pub fn neq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    return_if_dictionaries!(lhs, rhs, neq);
     compare!(lhs, rhs, neq, match_eq)
 }
 
@@ -209,7 +237,7 @@ pub fn neq_and_validity(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
 
 /// Returns whether a [`DataType`] is comparable is supported by [`neq`].
 pub fn can_neq(data_type: &DataType) -> bool {
-    can_partial_eq(data_type)
+    can_partial_eq(data_type) || can_partial_eq_dictionary(data_type)
 }
 
 /// `<` between two [`Array`]s.
@@ -444,6 +472,168 @@ pub fn can_gt_eq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_and_ord_scalar(data_type)
 }
 
+/// `lo <= lhs <= hi` (SQL's `BETWEEN`) between an [`Array`] and two [`Scalar`]s, in a single
+/// pass over `lhs`. `inclusive` declares whether `lo` and `hi` respectively satisfy the range,
+/// e.g. `(true, false)` is the half-open range `lo <= lhs < hi`.
+///
+/// Slots where `lhs` is null are null. When either `lo` or `hi` is null, all slots are null.
+/// Use [`can_between`] to check whether the operation is valid.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::between;
+/// use arrow2::scalar::PrimitiveScalar;
+/// # fn main() -> arrow2::error::Result<()> {
+///
+/// let array = Int32Array::from([Some(1), None, Some(2), Some(3)]);
+/// let lo = PrimitiveScalar::from(Some(1i32));
+/// let hi = PrimitiveScalar::from(Some(3i32));
+/// let result = between(&array, &lo, &hi, (true, false))?;
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(true), Some(false)]));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff either:
+/// * `lhs`, `lo` and `hi` do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn between(
+    lhs: &dyn Array,
+    lo: &dyn Scalar,
+    hi: &dyn Scalar,
+    inclusive: (bool, bool),
+) -> Result<BooleanArray> {
+    let data_type = lhs.data_type().to_logical_type();
+    if data_type != lo.data_type().to_logical_type()
+        || data_type != hi.data_type().to_logical_type()
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "BETWEEN requires an array and scalars of the same logical type, got {:?}, {:?} and {:?}",
+            lhs.data_type(),
+            lo.data_type(),
+            hi.data_type()
+        )));
+    }
+    if !can_between(data_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "BETWEEN of {:?} is not yet supported",
+            lhs.data_type()
+        )));
+    }
+    if !lo.is_valid() || !hi.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, lhs.len()));
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    Ok(match lhs.data_type().to_physical_type() {
+        Primitive(primitive) => match_eq_ord!(primitive, |$T| {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let lo = lo.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let hi = hi.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            // validity checked above
+            primitive::between::<$T>(lhs, lo.value().unwrap(), hi.value().unwrap(), inclusive)
+        }),
+        Utf8 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let lo = lo.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            let hi = hi.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            utf8::between::<i32>(lhs, lo.value().unwrap(), hi.value().unwrap(), inclusive)
+        }
+        LargeUtf8 => {
+            let lhs = lhs.as_any().downcast_ref().unwrap();
+            let lo = lo.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            let hi = hi.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            utf8::between::<i64>(lhs, lo.value().unwrap(), hi.value().unwrap(), inclusive)
+        }
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "BETWEEN of {:?} is not yet supported",
+                lhs.data_type()
+            )))
+        }
+    })
+}
+
+/// Returns whether a [`DataType`] is supported by [`between`].
+pub fn can_between(data_type: &DataType) -> bool {
+    can_partial_eq_and_ord(data_type)
+        && !matches!(
+            data_type,
+            DataType::Boolean | DataType::Binary | DataType::LargeBinary
+        )
+}
+
+/// Comparison operators, used to dynamically select a comparison (see [`compare_scalar`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    /// `==`
+    Eq,
+    /// `!=`
+    Neq,
+    /// `<`
+    Lt,
+    /// `<=`
+    LtEq,
+    /// `>`
+    Gt,
+    /// `>=`
+    GtEq,
+}
+
+/// Compares an [`Array`] with a [`Scalar`] using the dynamically selected [`Operator`] `op`.
+/// A null [`Scalar`], including one of [`DataType::Null`], results in a [`BooleanArray`] whose
+/// all slots are null.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Int32Array};
+/// use arrow2::compute::comparison::{compare_scalar, Operator};
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from([Some(1), None, Some(2)]);
+/// let scalar = PrimitiveScalar::from(Some(2i32));
+/// let result = compare_scalar(&array, &scalar, Operator::Lt).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+/// ```
+/// # Errors
+/// This function errors iff either:
+/// * `lhs` and `rhs` do not have the same logical type
+/// * `op` is not supported for the logical type (see e.g. [`can_lt_scalar`])
+pub fn compare_scalar(lhs: &dyn Array, rhs: &dyn Scalar, op: Operator) -> Result<BooleanArray> {
+    if rhs.data_type() == &DataType::Null {
+        return Ok(BooleanArray::new_null(DataType::Boolean, lhs.len()));
+    }
+    if lhs.data_type().to_logical_type() != rhs.data_type().to_logical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Comparison requires an array and a scalar of the same logical type, got {:?} and {:?}",
+            lhs.data_type(),
+            rhs.data_type()
+        )));
+    }
+    let can_compare = match op {
+        Operator::Eq => can_eq_scalar,
+        Operator::Neq => can_neq_scalar,
+        Operator::Lt => can_lt_scalar,
+        Operator::LtEq => can_lt_eq_scalar,
+        Operator::Gt => can_gt_scalar,
+        Operator::GtEq => can_gt_eq_scalar,
+    };
+    if !can_compare(lhs.data_type()) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Comparison {:?} is not supported for {:?}",
+            op,
+            lhs.data_type()
+        )));
+    }
+    Ok(match op {
+        Operator::Eq => eq_scalar(lhs, rhs),
+        Operator::Neq => neq_scalar(lhs, rhs),
+        Operator::Lt => lt_scalar(lhs, rhs),
+        Operator::LtEq => lt_eq_scalar(lhs, rhs),
+        Operator::Gt => gt_scalar(lhs, rhs),
+        Operator::GtEq => gt_eq_scalar(lhs, rhs),
+    })
+}
+
 // The list of operations currently supported.
 fn can_partial_eq_and_ord_scalar(data_type: &DataType) -> bool {
     if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
@@ -477,6 +667,7 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Binary
             | DataType::LargeBinary
     )
@@ -492,6 +683,15 @@ fn can_partial_eq(data_type: &DataType) -> bool {
         )
 }
 
+// Dictionaries are compared with a total order of their values.
+fn can_partial_eq_dictionary(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        can_partial_eq_and_ord(values.as_ref())
+    } else {
+        false
+    }
+}
+
 // The list of operations currently supported.
 fn can_partial_eq_scalar(data_type: &DataType) -> bool {
     can_partial_eq_and_ord_scalar(data_type)
@@ -44,20 +44,24 @@ where
     F: Fn(T::Simd, T::Simd) -> u8,
 {
     let rhs = T::Simd::from_chunk(&[rhs; 8]);
+    compare_values_op_unary(lhs, |lhs| op(lhs, rhs))
+}
 
+fn compare_values_op_unary<T, F>(lhs: &[T], op: F) -> MutableBitmap
+where
+    T: NativeType + Simd8,
+    F: Fn(T::Simd) -> u8,
+{
     let lhs_chunks_iter = lhs.chunks_exact(8);
     let lhs_remainder = lhs_chunks_iter.remainder();
 
     let mut values = Vec::with_capacity((lhs.len() + 7) / 8);
-    let iterator = lhs_chunks_iter.map(|lhs| {
-        let lhs = T::Simd::from_chunk(lhs);
-        op(lhs, rhs)
-    });
+    let iterator = lhs_chunks_iter.map(|lhs| op(T::Simd::from_chunk(lhs)));
     values.extend(iterator);
 
     if !lhs_remainder.is_empty() {
         let lhs = T::Simd::from_incomplete_chunk(lhs_remainder, T::default());
-        values.push(op(lhs, rhs))
+        values.push(op(lhs))
     };
 
     MutableBitmap::from_vec(values, lhs.len())
@@ -260,6 +264,25 @@ where
     compare_op_scalar(lhs, rhs, |a, b| a.gt_eq(b))
 }
 
+/// Perform `lo <= lhs <= hi` (SQL's `BETWEEN`) operation on an array in a single pass.
+/// `inclusive` declares whether `lo` and `hi` respectively satisfy the range.
+pub fn between<T>(lhs: &PrimitiveArray<T>, lo: T, hi: T, inclusive: (bool, bool)) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialOrd,
+{
+    let lo = T::Simd::from_chunk(&[lo; 8]);
+    let hi = T::Simd::from_chunk(&[hi; 8]);
+    let values = match inclusive {
+        (true, true) => compare_values_op_unary(lhs.values(), |x| x.gt_eq(lo) & x.lt_eq(hi)),
+        (true, false) => compare_values_op_unary(lhs.values(), |x| x.gt_eq(lo) & x.lt(hi)),
+        (false, true) => compare_values_op_unary(lhs.values(), |x| x.gt(lo) & x.lt_eq(hi)),
+        (false, false) => compare_values_op_unary(lhs.values(), |x| x.gt(lo) & x.lt(hi)),
+    };
+
+    BooleanArray::from_data(DataType::Boolean, values.into(), lhs.validity().cloned())
+}
+
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
@@ -2,7 +2,7 @@ use std::convert::TryInto;
 
 use packed_simd::*;
 
-use crate::types::{days_ms, months_days_ns};
+use crate::types::{days_ms, i256, months_days_ns};
 
 use super::*;
 
@@ -71,6 +71,7 @@ simd8!(i16, i16x8);
 simd8!(i32, i32x8);
 simd8!(i64, i64x8);
 simd8_native_all!(i128);
+simd8_native_all!(i256);
 simd8!(f32, f32x8);
 simd8!(f64, f64x8);
 simd8_native!(days_ms);
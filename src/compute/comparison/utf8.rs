@@ -142,6 +142,21 @@ pub fn gt_eq_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> BooleanArray {
     compare_op_scalar(lhs, rhs, |a, b| a >= b)
 }
 
+/// Perform `lo <= lhs <= hi` (SQL's `BETWEEN`) operation on [`Utf8Array`] in a single pass.
+/// `inclusive` declares whether `lo` and `hi` respectively satisfy the range.
+pub fn between<O: Offset>(
+    lhs: &Utf8Array<O>,
+    lo: &str,
+    hi: &str,
+    inclusive: (bool, bool),
+) -> BooleanArray {
+    let (lo_inclusive, hi_inclusive) = inclusive;
+    compare_op_scalar(lhs, lo, |a, lo| {
+        (if lo_inclusive { a >= lo } else { a > lo })
+            && (if hi_inclusive { a <= hi } else { a < hi })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
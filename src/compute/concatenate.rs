@@ -13,10 +13,26 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
-use crate::array::{growable::make_growable, Array};
+use std::sync::Arc;
+
+use crate::array::{
+    growable::make_growable, new_null_array, ord::build_compare, Array, DictionaryArray,
+    DictionaryKey, PrimitiveArray,
+};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::{DataType, PhysicalType};
 use crate::error::{ArrowError, Result};
 
 /// Concatenate multiple [Array] of the same type into a single [`Array`].
+///
+/// [`DictionaryArray`]s are concatenated into a [`DictionaryArray`] whose dictionary contains
+/// each distinct value of the dictionaries once (in order of first appearance), and whose keys
+/// are remapped to this unified dictionary. Dictionaries whose values do not have a natural order
+/// (e.g. nested types) are appended one after the other instead.
+/// # Errors
+/// This function errors iff
+/// * `arrays` is empty or the arrays do not have the same data type
+/// * the unified dictionary has more values than the key type can represent
 pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
     if arrays.is_empty() {
         return Err(ArrowError::InvalidArgumentError(
@@ -24,6 +40,21 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         ));
     }
 
+    if let DataType::FixedSizeList(_, size) = arrays[0].data_type().to_logical_type() {
+        let sizes = arrays
+            .iter()
+            .map(|array| match array.data_type().to_logical_type() {
+                DataType::FixedSizeList(_, size) => Some(*size),
+                _ => None,
+            });
+        if let Some(other) = sizes.flatten().find(|other| other != size) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "It is not possible to concatenate fixed size lists of different sizes ({} and {}).",
+                size, other
+            )));
+        }
+    }
+
     if arrays
         .iter()
         .any(|array| array.data_type() != arrays[0].data_type())
@@ -33,9 +64,17 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
         ));
     }
 
+    if let PhysicalType::Dictionary(key_type) = arrays[0].data_type().to_physical_type() {
+        return match_integer_type!(key_type, |$T| concatenate_dictionaries::<$T>(arrays));
+    }
+
     let lengths = arrays.iter().map(|array| array.len()).collect::<Vec<_>>();
     let capacity = lengths.iter().sum();
 
+    if arrays[0].data_type().to_physical_type() == PhysicalType::Null {
+        return Ok(new_null_array(arrays[0].data_type().clone(), capacity));
+    }
+
     let mut mutable = make_growable(arrays, false, capacity);
 
     for (i, len) in lengths.iter().enumerate() {
@@ -44,3 +83,102 @@ pub fn concatenate(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
 
     Ok(mutable.as_box())
 }
+
+/// Returns, for each slot of `values`, the index of its value in the unified dictionary, as well
+/// as the unified dictionary.
+fn unify_values(values: Arc<dyn Array>) -> (Vec<usize>, Arc<dyn Array>) {
+    let comparator = match build_compare(values.as_ref(), values.as_ref()) {
+        Ok(comparator) => comparator,
+        // values without an order are not unified
+        Err(_) => return ((0..values.len()).collect(), values),
+    };
+
+    // sort the slots so that equal values are adjacent (nulls last).
+    // The sort is stable, so the first slot of each group is its first appearance.
+    let mut indices = (0..values.len()).collect::<Vec<_>>();
+    indices.sort_by(
+        |&lhs, &rhs| match (values.is_valid(lhs), values.is_valid(rhs)) {
+            (true, true) => comparator(lhs, rhs),
+            (lhs, rhs) => rhs.cmp(&lhs),
+        },
+    );
+
+    // the first slot of the group of each slot
+    let mut first = vec![0; values.len()];
+    let mut group_start = 0;
+    for (i, &index) in indices.iter().enumerate() {
+        let previous = indices[group_start];
+        let is_equal = match (values.is_valid(previous), values.is_valid(index)) {
+            (true, true) => comparator(previous, index).is_eq(),
+            (lhs, rhs) => lhs == rhs,
+        };
+        if !is_equal {
+            group_start = i;
+        }
+        first[index] = indices[group_start];
+    }
+
+    // assign the unified indices in order of first appearance
+    let mut remap = vec![0; values.len()];
+    let mut growable = make_growable(&[values.as_ref()], true, values.len());
+    let mut unified_len = 0;
+    for index in 0..values.len() {
+        if first[index] == index {
+            remap[index] = unified_len;
+            growable.extend(0, index, 1);
+            unified_len += 1;
+        } else {
+            remap[index] = remap[first[index]];
+        }
+    }
+    (remap, growable.as_arc())
+}
+
+fn concatenate_dictionaries<K: DictionaryKey>(arrays: &[&dyn Array]) -> Result<Box<dyn Array>> {
+    let arrays = arrays
+        .iter()
+        .map(|array| array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap())
+        .collect::<Vec<_>>();
+
+    let values = arrays
+        .iter()
+        .map(|array| array.values().as_ref())
+        .collect::<Vec<_>>();
+    let values_capacity = values.iter().map(|values| values.len()).sum();
+    let mut growable = make_growable(&values, false, values_capacity);
+    let mut offsets = Vec::with_capacity(values.len());
+    let mut offset = 0;
+    for (i, values) in values.iter().enumerate() {
+        growable.extend(i, 0, values.len());
+        offsets.push(offset);
+        offset += values.len();
+    }
+
+    let (remap, values) = unify_values(growable.as_arc());
+    if !values.is_empty() && K::from_usize(values.len() - 1).is_none() {
+        return Err(ArrowError::Overflow);
+    }
+
+    let capacity = arrays.iter().map(|array| array.len()).sum();
+    let mut keys = Vec::<K>::with_capacity(capacity);
+    let has_nulls = arrays.iter().any(|array| array.keys().null_count() > 0);
+    let mut validity = MutableBitmap::with_capacity(if has_nulls { capacity } else { 0 });
+    for (array, offset) in arrays.iter().zip(offsets) {
+        let array_keys = array.keys();
+        keys.extend(array_keys.iter().map(|key| {
+            // null keys may contain any value
+            key.and_then(|key| key.to_usize())
+                .map(|key| K::from_usize(remap[offset + key]).unwrap())
+                .unwrap_or_default()
+        }));
+        if has_nulls {
+            match array_keys.validity() {
+                Some(bitmap) => validity.extend_from_bitmap(bitmap),
+                None => validity.extend_constant(array_keys.len(), true),
+            }
+        }
+    }
+
+    let keys = PrimitiveArray::<K>::from_data(K::PRIMITIVE.into(), keys.into(), validity.into());
+    Ok(Box::new(DictionaryArray::<K>::from_data(keys, values)))
+}
@@ -1,10 +1,13 @@
-//! Declares the [`contains`] operator
+//! Declares the [`contains`] and [`is_in`] operators
+use std::collections::HashSet;
+use std::hash::Hash;
 
 use crate::{
     array::{Array, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray, Utf8Array},
     bitmap::Bitmap,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{ArrowError, Result},
+    trusted_len::TrustedLen,
     types::NativeType,
 };
 
@@ -196,3 +199,148 @@ pub fn contains(list: &dyn Array, values: &dyn Array) -> Result<BooleanArray> {
         ))),
     }
 }
+
+/// Returns whether each value of `values` is in `set`, where a null `value` is null, and a
+/// `value` not in `set` is null iff `set` contains a null.
+fn is_in_iter<T, I, S>(values: I, set: S) -> BooleanArray
+where
+    T: Hash + Eq,
+    I: TrustedLen<Item = Option<T>>,
+    S: Iterator<Item = Option<T>>,
+{
+    let mut has_null = false;
+    let set = set
+        .filter_map(|x| {
+            has_null |= x.is_none();
+            x
+        })
+        .collect::<HashSet<_>>();
+
+    let values = values.map(|value| {
+        value.and_then(|value| {
+            if set.contains(&value) {
+                Some(true)
+            } else if has_null {
+                None
+            } else {
+                Some(false)
+            }
+        })
+    });
+    BooleanArray::from_trusted_len_iter(values)
+}
+
+fn is_in_primitive<T: NativeType + Hash + Eq>(values: &dyn Array, set: &dyn Array) -> BooleanArray {
+    let values = values.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let set = set.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    is_in_iter(
+        values.iter().map(|x| x.copied()),
+        set.iter().map(|x| x.copied()),
+    )
+}
+
+macro_rules! float {
+    ($values:expr, $set:expr, $ty:ty) => {{
+        let values = $values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<$ty>>()
+            .unwrap();
+        let set = $set.as_any().downcast_ref::<PrimitiveArray<$ty>>().unwrap();
+        is_in_iter(
+            values.iter().map(|x| x.map(|x| x.to_bits())),
+            set.iter().map(|x| x.map(|x| x.to_bits())),
+        )
+    }};
+}
+
+fn is_in_utf8<O: Offset>(values: &dyn Array, set: &dyn Array) -> BooleanArray {
+    let values = values.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    let set = set.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    is_in_iter(values.iter(), set.iter())
+}
+
+fn is_in_binary<O: Offset>(values: &dyn Array, set: &dyn Array) -> BooleanArray {
+    let values = values.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    let set = set.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    is_in_iter(values.iter(), set.iter())
+}
+
+/// Returns whether each value of `values` is in `set`, following the semantics of SQL's
+/// `value IN (set)`:
+/// * a null value results in null
+/// * a value in `set` results in `true`
+/// * a value not in `set` results in null if `set` contains a null, and `false` otherwise
+///
+/// `set` is hashed once, so this operation is `O(values.len() + set.len())`.
+/// Floats are compared by their bit representation (e.g. `NaN` is in a set containing `NaN`).
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::contains::is_in;
+/// use arrow2::array::{BooleanArray, Int32Array};
+///
+/// # fn main() -> Result<()> {
+/// let values = Int32Array::from(&[Some(1), Some(2), None]);
+/// let set = Int32Array::from_slice(&[1, 3]);
+/// let result = is_in(&values, &set)?;
+///
+/// assert_eq!(result, BooleanArray::from(&[Some(true), Some(false), None]));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `values` and `set` have different data types, or their data type
+/// is not supported (see [`can_is_in`]).
+pub fn is_in(values: &dyn Array, set: &dyn Array) -> Result<BooleanArray> {
+    if values.data_type() != set.data_type() {
+        return Err(ArrowError::InvalidArgumentError(
+            "is_in requires the values and the set to be of the same logical type".to_string(),
+        ));
+    }
+
+    use PhysicalType::*;
+    Ok(match values.data_type().to_physical_type() {
+        Primitive(primitive) => match primitive {
+            PrimitiveType::Int8 => is_in_primitive::<i8>(values, set),
+            PrimitiveType::Int16 => is_in_primitive::<i16>(values, set),
+            PrimitiveType::Int32 => is_in_primitive::<i32>(values, set),
+            PrimitiveType::Int64 => is_in_primitive::<i64>(values, set),
+            PrimitiveType::Int128 => is_in_primitive::<i128>(values, set),
+            PrimitiveType::UInt8 => is_in_primitive::<u8>(values, set),
+            PrimitiveType::UInt16 => is_in_primitive::<u16>(values, set),
+            PrimitiveType::UInt32 => is_in_primitive::<u32>(values, set),
+            PrimitiveType::UInt64 => is_in_primitive::<u64>(values, set),
+            PrimitiveType::Float32 => float!(values, set, f32),
+            PrimitiveType::Float64 => float!(values, set, f64),
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "is_in is not supported for logical type \"{:?}\"",
+                    values.data_type()
+                )))
+            }
+        },
+        Utf8 => is_in_utf8::<i32>(values, set),
+        LargeUtf8 => is_in_utf8::<i64>(values, set),
+        Binary => is_in_binary::<i32>(values, set),
+        LargeBinary => is_in_binary::<i64>(values, set),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "is_in is not supported for logical type \"{:?}\"",
+                values.data_type()
+            )))
+        }
+    })
+}
+
+/// Returns whether [`is_in`] is supported for arrays of `data_type`.
+pub fn can_is_in(data_type: &DataType) -> bool {
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Primitive(primitive) => !matches!(
+            primitive,
+            PrimitiveType::Int256 | PrimitiveType::DaysMs | PrimitiveType::MonthDayNano
+        ),
+        Utf8 | LargeUtf8 | Binary | LargeBinary => true,
+        _ => false,
+    }
+}
@@ -4,7 +4,7 @@ use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::chunk::Chunk;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::types::simd::{NativeSimd, Simd};
 use crate::types::BitChunkIter;
 use crate::{array::*, types::NativeType};
@@ -177,8 +177,37 @@ fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize
 /// Returns a prepared function optimized to filter multiple arrays.
 /// Creating this function requires time, but using it is faster than [filter] when the
 /// same filter needs to be applied to multiple arrays (e.g. a multiple columns).
+///
+/// The function captures the runs of set bits of `filter` and, like [filter], the nulls of
+/// `filter` are interpreted as `false`. The arrays passed to it must have the same length as `filter`.
+///
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Array, BooleanArray, Int32Array, Utf8Array};
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::filter::build_filter;
+/// # fn main() -> Result<()> {
+/// let ints = Int32Array::from_slice([5, 6, 7, 8]);
+/// let strings = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+/// let mask = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+///
+/// let filter = build_filter(&mask)?;
+/// assert_eq!(filter(&ints).as_ref(), &Int32Array::from_slice([5, 8]) as &dyn Array);
+/// assert_eq!(
+///     filter(&strings).as_ref(),
+///     &Utf8Array::<i32>::from_slice(["a", "d"]) as &dyn Array
+/// );
+/// # Ok(())
+/// # }
+/// ```
 pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
-    let iter = SlicesIterator::new(filter.values());
+    // The validities may be masking out `true` bits, making the filter operation
+    // based on the values incorrect
+    let values = match filter.validity() {
+        Some(validity) => filter.values() & validity,
+        None => filter.values().clone(),
+    };
+    let iter = SlicesIterator::new(&values);
     let filter_count = iter.slots();
     let chunks = iter.collect::<Vec<_>>();
 
@@ -207,6 +236,20 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
                 let array: Utf8Array<i64> = growable.into();
                 Box::new(array)
             }
+            Binary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+                let mut growable = growable::GrowableBinary::new(vec![array], false, filter_count);
+                filter_growable(&mut growable, &chunks);
+                let array: BinaryArray<i32> = growable.into();
+                Box::new(array)
+            }
+            LargeBinary => {
+                let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+                let mut growable = growable::GrowableBinary::new(vec![array], false, filter_count);
+                filter_growable(&mut growable, &chunks);
+                let array: BinaryArray<i64> = growable.into();
+                Box::new(array)
+            }
             _ => {
                 let mut mutable = make_growable(&[array], false, filter_count);
                 chunks
@@ -253,6 +296,10 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<$T>(array, filter)))
         }),
+        Null => {
+            let length = filter.len() - filter.values().null_count();
+            Ok(new_null_array(array.data_type().clone(), length))
+        }
         _ => {
             let iter = SlicesIterator::new(filter.values());
             let mut mutable = make_growable(&[array], false, iter.slots());
@@ -262,6 +309,64 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
     }
 }
 
+/// Declares to which side of [`partition`] the slots whose mask is null go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullPartition {
+    /// The slots go to the elements where the mask is `true`.
+    True,
+    /// The slots go to the elements where the mask is `false`.
+    False,
+    /// The slots are dropped from both sides.
+    Drop,
+}
+
+/// Splits an [Array] into the elements where `mask` is `true` and the elements where `mask` is
+/// `false`, in this order, each preserving the order of `array`. The elements where `mask` is
+/// null go to the side declared by `nulls`.
+///
+/// # Example
+/// ```rust
+/// # use arrow2::array::{Array, BooleanArray, Int32Array};
+/// # use arrow2::error::Result;
+/// # use arrow2::compute::filter::{partition, NullPartition};
+/// # fn main() -> Result<()> {
+/// let array = Int32Array::from_slice([5, 6, 7, 8]);
+/// let mask = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+///
+/// let (trues, falses) = partition(&array, &mask, NullPartition::False)?;
+/// assert_eq!(trues.as_ref(), &Int32Array::from_slice([5, 8]) as &dyn Array);
+/// assert_eq!(falses.as_ref(), &Int32Array::from_slice([6, 7]) as &dyn Array);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff `array` and `mask` have different lengths.
+pub fn partition(
+    array: &dyn Array,
+    mask: &BooleanArray,
+    nulls: NullPartition,
+) -> Result<(Box<dyn Array>, Box<dyn Array>)> {
+    if array.len() != mask.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Partition requires the array and the mask to have the same length".to_string(),
+        ));
+    }
+
+    let values = mask.values();
+    let (trues, falses) = match mask.validity() {
+        Some(validity) => match nulls {
+            NullPartition::True => (values | &!validity, &!values & validity),
+            NullPartition::False => (values & validity, !&(values & validity)),
+            NullPartition::Drop => (values & validity, &!values & validity),
+        },
+        None => (values.clone(), !values),
+    };
+
+    let trues = BooleanArray::from_data(DataType::Boolean, trues, None);
+    let falses = BooleanArray::from_data(DataType::Boolean, falses, None);
+    Ok((filter(array, &trues)?, filter(array, &falses)?))
+}
+
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
 /// This is a convenience function: filter multiple columns is embarassingly parallel.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
@@ -0,0 +1,106 @@
+//! Contains the operator [`group_by`].
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::array::{ord::build_compare, Array, UInt32Array};
+use crate::error::{ArrowError, Result};
+
+use super::hash::{combine_hashes, hash, NULL_HASH};
+use super::take::take;
+
+/// Groups the rows of `keys` by their distinct combinations of values, returning the
+/// distinct combinations (one array per key, in the order of their first row) and, for each
+/// combination, the indices of the rows belonging to it (in ascending order).
+///
+/// Nulls are considered equal to each other, so that rows whose key is null form a group.
+/// Rows are hashed with [`hash`](super::hash::hash), thus only its supported types can be keys.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::group_by::group_by;
+/// use arrow2::array::{Int32Array, Utf8Array};
+///
+/// # fn main() -> Result<()> {
+/// let a = Utf8Array::<i32>::from(&[Some("a"), Some("b"), Some("a"), None]);
+/// let b = Int32Array::from(&[Some(1), Some(1), Some(1), Some(1)]);
+/// let (distinct, groups) = group_by(&[&a, &b])?;
+///
+/// let expected = Utf8Array::<i32>::from(&[Some("a"), Some("b"), None]);
+/// assert_eq!(expected, distinct[0].as_ref());
+/// assert_eq!(groups, vec![vec![0, 2], vec![1], vec![3]]);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff
+/// * `keys` is empty
+/// * the arrays of `keys` do not have the same length or have more than `u32::MAX` rows
+/// * a key's data type is not supported by [`hash`](super::hash::hash)
+#[allow(clippy::type_complexity)]
+pub fn group_by(keys: &[&dyn Array]) -> Result<(Vec<Box<dyn Array>>, Vec<Vec<u32>>)> {
+    let first = keys.first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("Group by requires at least one key".to_string())
+    })?;
+    let len = first.len();
+    if let Some(array) = keys.iter().find(|array| array.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Group by requires all keys to have the same length ({} != {})",
+            len,
+            array.len()
+        )));
+    }
+    if len > u32::MAX as usize {
+        return Err(ArrowError::Overflow);
+    }
+
+    let mut hashes = vec![0u64; len];
+    let comparators = keys
+        .iter()
+        .map(|array| {
+            let column = hash(*array)?;
+            hashes
+                .iter_mut()
+                .zip(column.iter())
+                .for_each(|(hash, column)| {
+                    *hash = combine_hashes(*hash, column.copied().unwrap_or(NULL_HASH))
+                });
+            build_compare(*array, *array)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let rows_equal = |lhs: usize, rhs: usize| {
+        keys.iter().zip(comparators.iter()).all(|(array, cmp)| {
+            match (array.is_valid(lhs), array.is_valid(rhs)) {
+                (true, true) => cmp(lhs, rhs) == Ordering::Equal,
+                (false, false) => true,
+                _ => false,
+            }
+        })
+    };
+
+    // hash -> indices of the groups with that hash
+    let mut map = HashMap::<u64, Vec<usize>>::new();
+    let mut groups: Vec<Vec<u32>> = vec![];
+    for (row, hash) in hashes.into_iter().enumerate() {
+        let candidates = map.entry(hash).or_default();
+        let group = candidates
+            .iter()
+            .copied()
+            .find(|group| rows_equal(groups[*group][0] as usize, row));
+        match group {
+            Some(group) => groups[group].push(row as u32),
+            None => {
+                candidates.push(groups.len());
+                groups.push(vec![row as u32]);
+            }
+        }
+    }
+
+    let indices = UInt32Array::from_trusted_len_values_iter(groups.iter().map(|group| group[0]));
+    let distinct = keys
+        .iter()
+        .map(|array| take(*array, &indices))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((distinct, groups))
+}
@@ -12,7 +12,9 @@ macro_rules! new_state {
 }
 
 use crate::{
-    array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    array::{
+        Array, BinaryArray, BooleanArray, ListArray, Offset, PrimitiveArray, StructArray, Utf8Array,
+    },
     buffer::Buffer,
     datatypes::{DataType, PhysicalType, PrimitiveType},
     error::{ArrowError, Result},
@@ -62,18 +64,87 @@ pub fn hash_binary<O: Offset>(array: &BinaryArray<O>) -> PrimitiveArray<u64> {
     PrimitiveArray::<u64>::from_data(DataType::UInt64, values, array.validity().cloned())
 }
 
+/// The hash of null slots, so that all nulls hash to the same value when combined
+/// (see [`combine_hashes`]).
+pub(crate) const NULL_HASH: u64 = 0;
+
+/// Combines `hash` into the running hash `seed`.
+#[inline]
+pub(crate) fn combine_hashes(seed: u64, hash: u64) -> u64 {
+    seed ^ hash
+        .wrapping_add(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add(seed << 6)
+        .wrapping_add(seed >> 2)
+}
+
+/// Returns the hash of the element `i` of `hashes`, or [`NULL_HASH`] if it is null.
+#[inline]
+fn element_hash(hashes: &PrimitiveArray<u64>, i: usize) -> u64 {
+    if hashes.is_valid(i) {
+        hashes.value(i)
+    } else {
+        NULL_HASH
+    }
+}
+
+/// Element-wise hash of a [`ListArray`], combining the number of elements of each list with
+/// the hashes of its elements. Null elements hash to the same value. Validity is preserved.
+/// # Errors
+/// This function errors iff the inner type of the list is not supported by [`hash`].
+pub fn hash_list<O: Offset>(array: &ListArray<O>) -> Result<PrimitiveArray<u64>> {
+    let state = new_state!();
+    let hashes = hash(array.values().as_ref())?;
+
+    let iter = array.offsets().windows(2).map(|w| {
+        let (start, end) = (w[0].to_usize(), w[1].to_usize());
+        let seed = usize::get_hash(&(end - start), &state);
+        (start..end).fold(seed, |seed, i| combine_hashes(seed, element_hash(&hashes, i)))
+    });
+    let values = Buffer::from_trusted_len_iter(iter);
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        values,
+        array.validity().cloned(),
+    ))
+}
+
+/// Element-wise hash of a [`StructArray`], combining the hashes of its fields. Null values of
+/// the fields hash to the same value. Validity is preserved.
+/// # Errors
+/// This function errors iff a field's type is not supported by [`hash`].
+pub fn hash_struct(array: &StructArray) -> Result<PrimitiveArray<u64>> {
+    let fields = array
+        .values()
+        .iter()
+        .map(|field| hash(field.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let iter = (0..array.len()).map(|i| {
+        fields.iter().fold(NULL_HASH, |seed, hashes| {
+            combine_hashes(seed, element_hash(hashes, i))
+        })
+    });
+    let values = iter.collect::<Vec<_>>();
+    Ok(PrimitiveArray::<u64>::from_data(
+        DataType::UInt64,
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
+
 macro_rules! with_match_primitive_type {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use crate::datatypes::PrimitiveType::*;
-    use crate::types::days_ms;
+    use crate::types::{days_ms, i256};
     match $key_type {
         Int8 => __with_ty__! { i8 },
         Int16 => __with_ty__! { i16 },
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Int256 => __with_ty__! { i256 },
         DaysMs => __with_ty__! { days_ms },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
@@ -91,7 +162,8 @@ macro_rules! with_match_primitive_type {(
 /// * Boolean types
 /// * All primitive types except `Float32` and `Float64`
 /// * `[Large]Utf8`;
-/// * `[Large]Binary`.
+/// * `[Large]Binary`;
+/// * `[Large]List` and `Struct` of the above.
 /// # Errors
 /// This function errors whenever it does not support the specific `DataType`.
 pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
@@ -105,6 +177,9 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
         LargeBinary => hash_binary::<i64>(array.as_any().downcast_ref().unwrap()),
         Utf8 => hash_utf8::<i32>(array.as_any().downcast_ref().unwrap()),
         LargeUtf8 => hash_utf8::<i64>(array.as_any().downcast_ref().unwrap()),
+        List => hash_list::<i32>(array.as_any().downcast_ref().unwrap())?,
+        LargeList => hash_list::<i64>(array.as_any().downcast_ref().unwrap())?,
+        Struct => hash_struct(array.as_any().downcast_ref().unwrap())?,
         t => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Hash not implemented for type {:?}",
@@ -128,6 +203,11 @@ pub fn hash(array: &dyn Array) -> Result<PrimitiveArray<u64>> {
 /// assert_eq!(can_hash(&data_type), false);
 /// ```
 pub fn can_hash(data_type: &DataType) -> bool {
+    match data_type.to_logical_type() {
+        DataType::List(field) | DataType::LargeList(field) => return can_hash(field.data_type()),
+        DataType::Struct(fields) => return fields.iter().all(|x| can_hash(x.data_type())),
+        _ => {}
+    }
     matches!(
         data_type.to_physical_type(),
         PhysicalType::Boolean
@@ -136,6 +216,7 @@ pub fn can_hash(data_type: &DataType) -> bool {
             | PhysicalType::Primitive(PrimitiveType::Int32)
             | PhysicalType::Primitive(PrimitiveType::Int64)
             | PhysicalType::Primitive(PrimitiveType::Int128)
+            | PhysicalType::Primitive(PrimitiveType::Int256)
             | PhysicalType::Primitive(PrimitiveType::DaysMs)
             | PhysicalType::Primitive(PrimitiveType::UInt8)
             | PhysicalType::Primitive(PrimitiveType::UInt16)
@@ -19,21 +19,19 @@
 
 use crate::{
     array::*,
+    bitmap::Bitmap,
     buffer::Buffer,
     datatypes::DataType,
     error::{ArrowError, Result},
     types::NativeType,
 };
 
-fn unary_offsets_string<O, F>(array: &Utf8Array<O>, op: F) -> PrimitiveArray<O>
+fn unary_offsets<O, F>(offsets: &[O], validity: Option<&Bitmap>, op: F) -> PrimitiveArray<O>
 where
     O: Offset + NativeType,
     F: Fn(O) -> O,
 {
-    let values = array
-        .offsets()
-        .windows(2)
-        .map(|offset| op(offset[1] - offset[0]));
+    let values = offsets.windows(2).map(|offset| op(offset[1] - offset[0]));
 
     let values = Buffer::from_trusted_len_iter(values);
 
@@ -43,19 +41,47 @@ where
         DataType::Int32
     };
 
-    PrimitiveArray::<O>::from_data(data_type, values, array.validity().cloned())
+    PrimitiveArray::<O>::from_data(data_type, values, validity.cloned())
 }
 
-/// Returns an array of integers with the number of bytes on each string of the array.
+/// Returns an array of integers with the number of bytes on each string or binary of the array.
+///
+/// For [`Utf8Array`]s, this is the number of bytes of each string, not its number of characters,
+/// e.g. the length of `"💖"` is 4. Use [`char_length`](crate::compute::utf8::char_length) for
+/// the number of Unicode code points.
 pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
     match array.data_type() {
         DataType::Utf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i32, _>(array, |x| x)))
+            Ok(Box::new(unary_offsets(
+                array.offsets(),
+                array.validity(),
+                |x| x,
+            )))
         }
         DataType::LargeUtf8 => {
             let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
-            Ok(Box::new(unary_offsets_string::<i64, _>(array, |x| x)))
+            Ok(Box::new(unary_offsets(
+                array.offsets(),
+                array.validity(),
+                |x| x,
+            )))
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            Ok(Box::new(unary_offsets(
+                array.offsets(),
+                array.validity(),
+                |x| x,
+            )))
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            Ok(Box::new(unary_offsets(
+                array.offsets(),
+                array.validity(),
+                |x| x,
+            )))
         }
         _ => Err(ArrowError::InvalidArgumentError(format!(
             "length not supported for {:?}",
@@ -78,5 +104,8 @@ pub fn length(array: &dyn Array) -> Result<Box<dyn Array>> {
 /// assert_eq!(can_length(&data_type), false);
 /// ```
 pub fn can_length(data_type: &DataType) -> bool {
-    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8)
+    matches!(
+        data_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary
+    )
 }
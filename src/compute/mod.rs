@@ -30,6 +30,9 @@ pub mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
 pub mod cast;
+#[cfg(feature = "compute_coalesce")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_coalesce")))]
+pub mod coalesce;
 #[cfg(feature = "compute_comparison")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_comparison")))]
 pub mod comparison;
@@ -42,6 +45,9 @@ pub mod contains;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
+#[cfg(feature = "compute_group_by")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_group_by")))]
+pub mod group_by;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
@@ -84,9 +90,15 @@ pub mod take;
 #[cfg(feature = "compute_temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal")))]
 pub mod temporal;
+#[cfg(feature = "compute_unique")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_unique")))]
+pub mod unique;
 #[cfg(feature = "compute_upper")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_upper")))]
 pub mod upper;
+#[cfg(feature = "compute_utf8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_utf8")))]
+pub mod utf8;
 mod utils;
 #[cfg(feature = "compute_window")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_window")))]
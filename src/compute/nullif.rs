@@ -1,12 +1,11 @@
 //! Contains the operator [`nullif`].
-use crate::array::PrimitiveArray;
+use crate::array::{BinaryArray, Offset, PrimitiveArray, Utf8Array};
 use crate::bitmap::Bitmap;
 use crate::compute::comparison::{
     primitive_compare_values_op, primitive_compare_values_op_scalar, Simd8, Simd8PartialEq,
 };
 use crate::datatypes::DataType;
-use crate::scalar::PrimitiveScalar;
-use crate::scalar::Scalar;
+use crate::scalar::{BinaryScalar, PrimitiveScalar, Scalar, Utf8Scalar};
 use crate::{array::Array, types::NativeType};
 
 use super::utils::combine_validities;
@@ -78,6 +77,76 @@ where
     PrimitiveArray::<T>::from_data(lhs.data_type().clone(), lhs.values().clone(), validity)
 }
 
+/// Returns a [`Utf8Array`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+/// This has the same semantics as postgres - the validity of the rhs is ignored.
+/// # Panic
+/// This function panics iff the arguments do not have the same length.
+/// # Example
+/// ```rust
+/// # use arrow2::array::Utf8Array;
+/// # use arrow2::compute::nullif::utf8_nullif;
+/// # fn main() {
+/// let lhs = Utf8Array::<i32>::from(&[None, Some("a"), Some("a"), Some("b")]);
+/// let rhs = Utf8Array::<i32>::from(&[Some("a"), None, Some("a"), Some("a")]);
+/// let result = utf8_nullif(&lhs, &rhs);
+///
+/// let expected = Utf8Array::<i32>::from(&[None, Some("a"), None, Some("b")]);
+///
+/// assert_eq!(expected, result);
+/// # }
+/// ```
+pub fn utf8_nullif<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Utf8Array<O> {
+    assert_eq!(lhs.len(), rhs.len());
+    let not_equal = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| lhs != rhs);
+    let not_equal = Bitmap::from_trusted_len_iter(not_equal);
+
+    let validity = combine_validities(lhs.validity(), Some(&not_equal));
+
+    lhs.with_validity(validity)
+}
+
+/// Returns a [`Utf8Array`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+///
+/// This has the same semantics as postgres.
+pub fn utf8_nullif_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Utf8Array<O> {
+    let not_equal = Bitmap::from_trusted_len_iter(lhs.values_iter().map(|lhs| lhs != rhs));
+
+    let validity = combine_validities(lhs.validity(), Some(&not_equal));
+
+    lhs.with_validity(validity)
+}
+
+/// Returns a [`BinaryArray`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+/// This has the same semantics as postgres - the validity of the rhs is ignored.
+/// # Panic
+/// This function panics iff the arguments do not have the same length.
+pub fn binary_nullif<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BinaryArray<O> {
+    assert_eq!(lhs.len(), rhs.len());
+    let not_equal = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| lhs != rhs);
+    let not_equal = Bitmap::from_trusted_len_iter(not_equal);
+
+    let validity = combine_validities(lhs.validity(), Some(&not_equal));
+
+    lhs.with_validity(validity)
+}
+
+/// Returns a [`BinaryArray`] whose validity is null iff `lhs == rhs` or `lhs` is null.
+///
+/// This has the same semantics as postgres.
+pub fn binary_nullif_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BinaryArray<O> {
+    let not_equal = Bitmap::from_trusted_len_iter(lhs.values_iter().map(|lhs| lhs != rhs));
+
+    let validity = combine_validities(lhs.validity(), Some(&not_equal));
+
+    lhs.with_validity(validity)
+}
+
 /// Returns an [`Array`] with the same type as `lhs` and whose validity
 /// is null iff either `lhs == rhs` or `lhs` is null.
 ///
@@ -114,6 +183,22 @@ pub fn nullif(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
                 rhs.as_any().downcast_ref().unwrap(),
             ))
         }),
+        Utf8 => Box::new(utf8_nullif::<i32>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        )),
+        LargeUtf8 => Box::new(utf8_nullif::<i64>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        )),
+        Binary => Box::new(binary_nullif::<i32>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        )),
+        LargeBinary => Box::new(binary_nullif::<i64>(
+            lhs.as_any().downcast_ref().unwrap(),
+            rhs.as_any().downcast_ref().unwrap(),
+        )),
         other => unimplemented!("Nullif is not implemented for physical type {:?}", other),
     }
 }
@@ -154,6 +239,38 @@ pub fn nullif_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> Box<dyn Array> {
                 scalar,
             ))
         }),
+        Utf8 => {
+            let scalar = rhs.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            let scalar = scalar.value().expect("Scalar to be non-null");
+            Box::new(utf8_nullif_scalar::<i32>(
+                lhs.as_any().downcast_ref().unwrap(),
+                scalar,
+            ))
+        }
+        LargeUtf8 => {
+            let scalar = rhs.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            let scalar = scalar.value().expect("Scalar to be non-null");
+            Box::new(utf8_nullif_scalar::<i64>(
+                lhs.as_any().downcast_ref().unwrap(),
+                scalar,
+            ))
+        }
+        Binary => {
+            let scalar = rhs.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            let scalar = scalar.value().expect("Scalar to be non-null");
+            Box::new(binary_nullif_scalar::<i32>(
+                lhs.as_any().downcast_ref().unwrap(),
+                scalar,
+            ))
+        }
+        LargeBinary => {
+            let scalar = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            let scalar = scalar.value().expect("Scalar to be non-null");
+            Box::new(binary_nullif_scalar::<i64>(
+                lhs.as_any().downcast_ref().unwrap(),
+                scalar,
+            ))
+        }
         other => unimplemented!("Nullif is not implemented for physical type {:?}", other),
     }
 }
@@ -164,5 +281,12 @@ pub fn can_nullif(lhs: &DataType, rhs: &DataType) -> bool {
         return false;
     };
     use crate::datatypes::PhysicalType;
-    matches!(lhs.to_physical_type(), PhysicalType::Primitive(_))
+    matches!(
+        lhs.to_physical_type(),
+        PhysicalType::Primitive(_)
+            | PhysicalType::Utf8
+            | PhysicalType::LargeUtf8
+            | PhysicalType::Binary
+            | PhysicalType::LargeBinary
+    )
 }
@@ -17,19 +17,20 @@ fn k_element_sort_inner<I: Index, T, G, F>(
     G: Fn(usize) -> T,
     F: FnMut(&T, &T) -> std::cmp::Ordering,
 {
+    // equal values are sorted by their index, which makes the sort stable
     if descending {
         let mut compare = |lhs: &I, rhs: &I| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&rhs, &lhs)
+            let l = get(lhs.to_usize());
+            let r = get(rhs.to_usize());
+            cmp(&r, &l).then_with(|| lhs.cmp(rhs))
         };
         let (before, _, _) = indices.select_nth_unstable_by(limit, &mut compare);
         before.sort_unstable_by(&mut compare);
     } else {
         let mut compare = |lhs: &I, rhs: &I| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&lhs, &rhs)
+            let l = get(lhs.to_usize());
+            let r = get(rhs.to_usize());
+            cmp(&l, &r).then_with(|| lhs.cmp(rhs))
         };
         let (before, _, _) = indices.select_nth_unstable_by(limit, &mut compare);
         before.sort_unstable_by(&mut compare);
@@ -56,17 +57,18 @@ fn sort_unstable_by<I, T, G, F>(
         return k_element_sort_inner(indices, get, descending, limit, cmp);
     }
 
+    // equal values are sorted by their index, which makes the sort stable
     if descending {
         indices.sort_unstable_by(|lhs, rhs| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&rhs, &lhs)
+            let l = get(lhs.to_usize());
+            let r = get(rhs.to_usize());
+            cmp(&r, &l).then_with(|| lhs.cmp(rhs))
         })
     } else {
         indices.sort_unstable_by(|lhs, rhs| {
-            let lhs = get(lhs.to_usize());
-            let rhs = get(rhs.to_usize());
-            cmp(&lhs, &rhs)
+            let l = get(lhs.to_usize());
+            let r = get(rhs.to_usize());
+            cmp(&l, &r).then_with(|| lhs.cmp(rhs))
         })
     }
 }
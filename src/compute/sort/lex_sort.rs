@@ -22,8 +22,9 @@ pub struct SortColumn<'a> {
 }
 
 /// Sort a list of [`Array`] using [`SortOptions`] provided for each array.
-/// # Implementaqtion
-/// The sort is stable and lexicographical on values.
+/// # Implementation
+/// The sort is stable and lexicographical on values: rows that compare equal on all columns
+/// keep their relative order.
 ///
 /// Returns an [`ArrowError`] if any of the array type is either unsupported by
 /// `lexsort_to_indices` or `take`.
@@ -124,6 +125,8 @@ pub(crate) fn build_compare(array: &dyn Array, sort_option: SortOptions) -> Resu
 
 /// Sorts a list of [`SortColumn`] into a non-nullable [`PrimitiveArray`]
 /// representing the indices that would sort the columns.
+/// The sort is stable: rows that compare equal on all columns keep their relative order,
+/// including when `limit` is set.
 pub fn lexsort_to_indices<I: Index>(
     columns: &[SortColumn],
     limit: Option<usize>,
@@ -134,7 +137,7 @@ pub fn lexsort_to_indices<I: Index>(
         ));
     }
     if columns.len() == 1 {
-        // fallback to non-lexical sort
+        // fallback to non-lexical sort, that is also stable
         let column = &columns[0];
         return sort_to_indices(column.values, &column.options.unwrap_or_default(), limit);
     }
@@ -163,8 +166,8 @@ pub fn lexsort_to_indices<I: Index>(
                 other => return other,
             }
         }
-
-        Ordering::Equal
+        // rows equal on all columns are sorted by their position, which makes the sort stable
+        a_idx.cmp(&b_idx)
     };
 
     let mut values = I::range(0, row_count).unwrap().collect::<Vec<_>>();
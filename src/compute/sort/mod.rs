@@ -15,10 +15,12 @@ mod boolean;
 mod common;
 mod lex_sort;
 mod primitive;
+mod rank;
 mod utf8;
 
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use rank::{rank, RankMethod, RankOptions};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -94,6 +96,10 @@ macro_rules! dyn_sort_indices {
 }
 
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+///
+/// When `limit` is set, only the first `limit` indices are returned. For primitive, binary and
+/// utf8 arrays these are selected via a partial sort, which is faster than a full sort.
+/// Equal values of these arrays keep their relative order, including when `limit` is set.
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
@@ -0,0 +1,147 @@
+use std::cmp::Ordering;
+
+use crate::array::{Array, PrimitiveArray};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+use super::{build_compare, SortOptions};
+
+/// How [`rank`] ranks values that are equal to each other (ties).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankMethod {
+    /// Ties get the lowest rank of the group (`1, 2, 2, 4`)
+    Min,
+    /// Ties get the highest rank of the group (`1, 3, 3, 4`)
+    Max,
+    /// Ties get the average rank of the group (`1.0, 2.5, 2.5, 4.0`)
+    Average,
+    /// Ties get the same rank and ranks have no gaps between groups (`1, 2, 2, 3`)
+    Dense,
+    /// Ties are ranked in the order they appear in the array (`1, 2, 3, 4`)
+    First,
+}
+
+/// Options of [`rank`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RankOptions {
+    /// How values are ordered and, unless `ignore_nulls`, where nulls are placed.
+    pub sort: SortOptions,
+    /// Whether nulls are not ranked, in which case their rank is null.
+    pub ignore_nulls: bool,
+}
+
+/// Returns the 1-based rank of each value of `array` in its sorted order, where equal values
+/// (and nulls, unless they are ignored) are ranked according to `method`.
+///
+/// `array` does not need to be sorted. The ranks are a [`PrimitiveArray<u32>`], or a
+/// [`PrimitiveArray<f64>`] for [`RankMethod::Average`].
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::sort::{rank, RankMethod, RankOptions};
+///
+/// # fn main() -> Result<()> {
+/// let array = Int32Array::from(&[Some(3), Some(1), None, Some(3)]);
+/// let options = RankOptions {
+///     ignore_nulls: true,
+///     ..Default::default()
+/// };
+/// let ranks = rank(&array, RankMethod::Min, &options)?;
+///
+/// assert_eq!(UInt32Array::from(&[Some(2), Some(1), None, Some(2)]), ranks.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors if the [`DataType`] of `array` cannot be sorted or it has more than `u32::MAX` values.
+pub fn rank(
+    array: &dyn Array,
+    method: RankMethod,
+    options: &RankOptions,
+) -> Result<Box<dyn Array>> {
+    if array.len() > u32::MAX as usize {
+        return Err(ArrowError::Overflow);
+    }
+    let ignore_nulls = options.ignore_nulls && array.null_count() > 0;
+
+    let mut sort = options.sort;
+    if ignore_nulls {
+        sort.nulls_first = false;
+    }
+    let compare = build_compare(array, sort)?;
+
+    // the valid indices are first when nulls are ignored
+    let ranked = if ignore_nulls {
+        array.len() - array.null_count()
+    } else {
+        array.len()
+    };
+    let mut indices = (0..array.len()).collect::<Vec<_>>();
+    // stable, so that `First` ranks ties in order of appearance
+    indices.sort_by(|lhs, rhs| compare(*lhs, *rhs));
+    indices.truncate(ranked);
+
+    let validity = if ignore_nulls {
+        array.validity().cloned()
+    } else {
+        None
+    };
+
+    if method == RankMethod::Average {
+        let mut ranks = vec![0f64; array.len()];
+        for_each_group(&indices, &compare, |start, end| {
+            let rank = (start + 1 + end) as f64 / 2.0;
+            indices[start..end]
+                .iter()
+                .for_each(|index| ranks[*index] = rank);
+        });
+        return Ok(Box::new(PrimitiveArray::<f64>::from_data(
+            DataType::Float64,
+            ranks.into(),
+            validity,
+        )));
+    }
+
+    let mut ranks = vec![0u32; array.len()];
+    let mut dense = 0;
+    for_each_group(&indices, &compare, |start, end| {
+        dense += 1;
+        indices[start..end]
+            .iter()
+            .enumerate()
+            .for_each(|(offset, index)| {
+                ranks[*index] = match method {
+                    RankMethod::Min => start + 1,
+                    RankMethod::Max => end,
+                    RankMethod::Dense => dense,
+                    RankMethod::First => start + offset + 1,
+                    RankMethod::Average => unreachable!(),
+                } as u32
+            });
+    });
+    Ok(Box::new(PrimitiveArray::<u32>::from_data(
+        DataType::UInt32,
+        ranks.into(),
+        validity,
+    )))
+}
+
+/// Calls `op` with the bounds `[start, end)` of every group of consecutive equal values of
+/// the sorted `indices`.
+fn for_each_group<C, F>(indices: &[usize], compare: C, mut op: F)
+where
+    C: Fn(usize, usize) -> Ordering,
+    F: FnMut(usize, usize),
+{
+    let mut start = 0;
+    while start < indices.len() {
+        let end = indices[start + 1..]
+            .iter()
+            .position(|index| compare(indices[start], *index) != Ordering::Equal)
+            .map(|position| start + 1 + position)
+            .unwrap_or(indices.len());
+        op(start, end);
+        start = end;
+    }
+}
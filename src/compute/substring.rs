@@ -23,21 +23,20 @@ use crate::{
     error::{ArrowError, Result},
 };
 
-fn utf8_substring<O: Offset>(array: &Utf8Array<O>, start: O, length: &Option<O>) -> Utf8Array<O> {
-    let length = length.map(|v| v.to_usize());
+fn utf8_substring<O: Offset>(
+    array: &Utf8Array<O>,
+    start: i64,
+    length: &Option<u64>,
+) -> Utf8Array<O> {
+    let length = length.map(|v| usize::try_from(v).unwrap_or(usize::MAX));
 
     let iter = array.values_iter().map(|str_val| {
-        // compute where we should start slicing this entry.
-        let start = if start >= O::zero() {
-            start.to_usize()
+        // compute where we should start slicing this entry, in characters.
+        let start = if start >= 0 {
+            usize::try_from(start).unwrap_or(usize::MAX)
         } else {
-            let start = (O::zero() - start).to_usize();
-            str_val
-                .char_indices()
-                .rev()
-                .nth(start)
-                .map(|(idx, _)| idx + 1)
-                .unwrap_or(0)
+            let from_end = usize::try_from(start.unsigned_abs()).unwrap_or(usize::MAX);
+            str_val.chars().count().saturating_sub(from_end)
         };
 
         let mut iter_chars = str_val.char_indices();
@@ -88,11 +87,10 @@ fn binary_substring<O: Offset>(
         // compute where we should start slicing this entry
         let start = windows[0]
             + if start >= O::zero() {
-                start
+                start.min(length_i)
             } else {
-                length_i + start
+                (length_i + start).max(O::zero())
             };
-        let start = start.max(windows[0]).min(windows[1]);
 
         let length: O = length
             .unwrap_or(length_i)
@@ -118,24 +116,32 @@ fn binary_substring<O: Offset>(
 
 /// Returns an ArrayRef with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
 /// `start` can be negative, in which case the start counts from the end of the string.
+/// `start` and `length` are measured in characters (Unicode code points) for \[Large\]String
+/// arrays and in bytes for \[Large\]Binary arrays. A `start` or `length` beyond the string is
+/// clamped to it, e.g. a `start` of `-10` of `"hello"` starts at `"h"`.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<Box<dyn Array>> {
+    // offsets of binary arrays are signed and can't exceed `i32::MAX` or `i64::MAX`
+    let start_i32 = start.clamp(-(i32::MAX as i64), i32::MAX as i64) as i32;
+    let length_i32 = length.map(|e| e.min(i32::MAX as u64) as i32);
+    let start_i64 = start.max(-i64::MAX);
+    let length_i64 = length.map(|e| e.min(i64::MAX as u64) as i64);
     match array.data_type() {
         DataType::Binary => Ok(Box::new(binary_substring(
             array
                 .as_any()
                 .downcast_ref::<BinaryArray<i32>>()
                 .expect("A binary is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
+            start_i32,
+            &length_i32,
         ))),
         DataType::LargeBinary => Ok(Box::new(binary_substring(
             array
                 .as_any()
                 .downcast_ref::<BinaryArray<i64>>()
                 .expect("A large binary is expected"),
-            start,
-            &length.map(|e| e as i64),
+            start_i64,
+            &length_i64,
         ))),
         DataType::LargeUtf8 => Ok(Box::new(utf8_substring(
             array
@@ -143,15 +149,15 @@ pub fn substring(array: &dyn Array, start: i64, length: &Option<u64>) -> Result<
                 .downcast_ref::<Utf8Array<i64>>()
                 .expect("A large string is expected"),
             start,
-            &length.map(|e| e as i64),
+            length,
         ))),
         DataType::Utf8 => Ok(Box::new(utf8_substring(
             array
                 .as_any()
                 .downcast_ref::<Utf8Array<i32>>()
                 .expect("A string is expected"),
-            start as i32,
-            &length.map(|e| e as i32),
+            start,
+            length,
         ))),
         _ => Err(ArrowError::InvalidArgumentError(format!(
             "substring does not support type {:?}",
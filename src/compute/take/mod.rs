@@ -19,8 +19,9 @@
 
 use crate::{
     array::{new_empty_array, Array, NullArray, PrimitiveArray},
+    bitmap::MutableBitmap,
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
     types::Index,
 };
 
@@ -30,6 +31,7 @@ mod dict;
 mod generic_binary;
 mod list;
 mod primitive;
+mod runs;
 mod structure;
 mod utf8;
 
@@ -37,6 +39,9 @@ pub(crate) use boolean::take as take_boolean;
 
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
 /// The returned array has a length equal to `indices.len()`.
+///
+/// When the (non-null) `indices` are known to be sorted in ascending order, [`take_sorted`]
+/// is faster for primitive and boolean arrays.
 pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result<Box<dyn Array>> {
     if indices.len() == 0 {
         return Ok(new_empty_array(values.data_type().clone()));
@@ -94,6 +99,133 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
     }
 }
 
+/// Same as [`take`], but optimized for non-null `indices` sorted in ascending order: runs of
+/// consecutive indices of primitive and boolean arrays are copied at once instead of gathered
+/// one by one. Other arrays are taken via [`take`].
+///
+/// Whether `indices` are sorted is not checked: indices that are not sorted lead to the same
+/// result as [`take`], albeit more slowly.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::take::take_sorted;
+///
+/// let values = Int32Array::from_slice([1, 2, 3, 4, 5]);
+/// let indices = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+/// let taken = take_sorted(&values, &indices).unwrap();
+/// assert_eq!(
+///     taken.as_ref(),
+///     &Int32Array::from([Some(2), Some(3), None, Some(5)]) as &dyn Array
+/// );
+/// ```
+pub fn take_sorted<O: Index>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<O>,
+) -> Result<Box<dyn Array>> {
+    use crate::datatypes::PhysicalType::*;
+    match values.data_type().to_physical_type() {
+        Boolean => {
+            let values = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(boolean::take_sorted::<O>(values, indices)))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let values = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(primitive::take_sorted::<$T, _>(&values, indices)))
+        }),
+        _ => take(values, indices),
+    }
+}
+
+/// Policy of [`take_with_bounds`] for indices that are out of bounds of the values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutOfBounds {
+    /// Error on the first index out of bounds or negative
+    Error,
+    /// Take a null for every index out of bounds or negative
+    Null,
+    /// Take the last value for every index out of bounds. Negative indices error
+    Clamp,
+}
+
+/// Same as [`take`], but indices out of bounds of `values` (including negative indices)
+/// are handled according to `out_of_bounds` instead of panicking.
+/// # Errors
+/// This function errors when an index is out of bounds (or negative) and `out_of_bounds` is
+/// [`OutOfBounds::Error`], when an index is negative and `out_of_bounds` is [`OutOfBounds::Clamp`],
+/// or when an index is out of bounds of an empty `values` and `out_of_bounds` is
+/// [`OutOfBounds::Clamp`].
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::take::{take_with_bounds, OutOfBounds};
+///
+/// let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+/// let indices = Int32Array::from_slice([1, 2, -1]);
+/// let taken = take_with_bounds(&values, &indices, OutOfBounds::Null).unwrap();
+/// assert_eq!(
+///     taken.as_ref(),
+///     &Utf8Array::<i32>::from([Some("b"), None, None]) as &dyn arrow2::array::Array
+/// );
+/// ```
+pub fn take_with_bounds<O: Index>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<O>,
+    out_of_bounds: OutOfBounds,
+) -> Result<Box<dyn Array>> {
+    let len = values.len();
+    let in_bounds = |index: &O| *index >= O::zero() && index.to_usize() < len;
+    if indices.iter().flatten().all(in_bounds) {
+        return take(values, indices);
+    }
+
+    let mut validity = MutableBitmap::with_capacity(indices.len());
+    let new_indices = indices
+        .iter()
+        .map(|index| match index {
+            Some(index) if in_bounds(index) => {
+                validity.push(true);
+                Ok(*index)
+            }
+            None => {
+                validity.push(false);
+                Ok(O::default())
+            }
+            Some(index) => match out_of_bounds {
+                OutOfBounds::Null => {
+                    validity.push(false);
+                    Ok(O::default())
+                }
+                OutOfBounds::Clamp if *index >= O::zero() && len > 0 => {
+                    validity.push(true);
+                    // `len - 1 < index` and thus fits in `O`
+                    Ok(O::from_usize(len - 1).unwrap())
+                }
+                OutOfBounds::Clamp if *index >= O::zero() => {
+                    Err(ArrowError::InvalidArgumentError(format!(
+                        "The index {} cannot be clamped to the bounds of an empty array",
+                        index
+                    )))
+                }
+                _ if *index < O::zero() => Err(ArrowError::InvalidArgumentError(format!(
+                    "The index {} is negative",
+                    index
+                ))),
+                _ => Err(ArrowError::InvalidArgumentError(format!(
+                    "The index {} is out of bounds of an array of length {}",
+                    index, len
+                ))),
+            },
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let indices = PrimitiveArray::<O>::from_data(
+        indices.data_type().clone(),
+        new_indices.into(),
+        validity.into(),
+    );
+    take(values, &indices)
+}
+
 /// Checks if an array of type `datatype` can perform take operation
 ///
 /// # Examples
@@ -128,6 +260,7 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::Float32
             | DataType::Float64
             | DataType::Decimal(_, _)
+            | DataType::Decimal256(_, _)
             | DataType::Utf8
             | DataType::LargeUtf8
             | DataType::Binary
@@ -5,7 +5,7 @@ use crate::{
     types::NativeType,
 };
 
-use super::Index;
+use super::{runs::Runs, Index};
 
 // take implementation when neither values nor indices contain nulls
 fn take_no_validity<T: NativeType, I: Index>(
@@ -113,3 +113,47 @@ pub fn take<T: NativeType, I: Index>(
 
     PrimitiveArray::<T>::from_data(values.data_type().clone(), buffer, validity)
 }
+
+/// `take` implementation for primitive arrays that copies runs of consecutive indices at once,
+/// which is faster than [`take`] when `indices` are sorted.
+pub fn take_sorted<T: NativeType, I: Index>(
+    values: &PrimitiveArray<T>,
+    indices: &PrimitiveArray<I>,
+) -> PrimitiveArray<T> {
+    let values_validity = values.validity().filter(|x| x.null_count() > 0);
+    let mut buffer = Vec::<T>::with_capacity(indices.len());
+    let mut validity = if values_validity.is_some() || indices.null_count() > 0 {
+        Some(MutableBitmap::with_capacity(indices.len()))
+    } else {
+        None
+    };
+
+    let values_values = values.values();
+    for (start, length) in Runs::new(indices) {
+        match start {
+            Some(start) => {
+                buffer.extend_from_slice(&values_values[start..start + length]);
+                if let Some(validity) = validity.as_mut() {
+                    match values_validity {
+                        Some(values_validity) => {
+                            let (slice, offset, _) = values_validity.as_slice();
+                            validity.extend_from_slice(slice, offset + start, length)
+                        }
+                        None => validity.extend_constant(length, true),
+                    }
+                }
+            }
+            None => {
+                buffer.resize(buffer.len() + length, T::default());
+                // `validity` is `Some` when there are null indices
+                validity.as_mut().unwrap().extend_constant(length, false);
+            }
+        }
+    }
+
+    PrimitiveArray::<T>::from_data(
+        values.data_type().clone(),
+        buffer.into(),
+        validity.map(|x| x.into()),
+    )
+}
@@ -0,0 +1,56 @@
+use crate::{array::PrimitiveArray, bitmap::Bitmap};
+
+use super::Index;
+
+/// Iterator of the runs of consecutive indices of a [`PrimitiveArray`], yielding
+/// `(Some(start), length)` for a run of the indices `start..start + length` and
+/// `(None, length)` for a run of `length` null indices.
+pub(super) struct Runs<'a, I: Index> {
+    indices: &'a [I],
+    validity: Option<&'a Bitmap>,
+    current: usize,
+}
+
+impl<'a, I: Index> Runs<'a, I> {
+    pub fn new(indices: &'a PrimitiveArray<I>) -> Self {
+        Self {
+            indices: indices.values(),
+            validity: indices.validity().filter(|x| x.null_count() > 0),
+            current: 0,
+        }
+    }
+
+    #[inline]
+    fn is_valid(&self, i: usize) -> bool {
+        self.validity.map(|x| x.get_bit(i)).unwrap_or(true)
+    }
+}
+
+impl<'a, I: Index> Iterator for Runs<'a, I> {
+    type Item = (Option<usize>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.current;
+        if first >= self.indices.len() {
+            return None;
+        }
+        let mut length = 1;
+        if !self.is_valid(first) {
+            while first + length < self.indices.len() && !self.is_valid(first + length) {
+                length += 1;
+            }
+            self.current += length;
+            return Some((None, length));
+        }
+
+        let start = self.indices[first].to_usize();
+        while first + length < self.indices.len()
+            && self.is_valid(first + length)
+            && self.indices[first + length].to_usize() == start + length
+        {
+            length += 1;
+        }
+        self.current += length;
+        Some((Some(start), length))
+    }
+}
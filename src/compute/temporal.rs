@@ -17,7 +17,7 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::{Datelike, Timelike};
+use chrono::{Datelike, Offset, Timelike};
 
 use crate::array::*;
 use crate::datatypes::*;
@@ -49,6 +49,16 @@ trait U32IsoWeek: Datelike {
 impl U32IsoWeek for chrono::NaiveDateTime {}
 impl<T: chrono::TimeZone> U32IsoWeek for chrono::DateTime<T> {}
 
+// Create and implement a trait that extracts the year of chrono's `IsoWeek`
+trait I32IsoYear: Datelike {
+    fn i32_iso_year(&self) -> i32 {
+        self.iso_week().year()
+    }
+}
+
+impl I32IsoYear for chrono::NaiveDateTime {}
+impl<T: chrono::TimeZone> I32IsoYear for chrono::DateTime<T> {}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Datelike` methods on Arrays
 macro_rules! date_like {
@@ -109,6 +119,14 @@ pub fn iso_week(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     date_like!(u32_iso_week, array, DataType::UInt32)
 }
 
+/// Extracts ISO week-numbering year of a temporal array as [`PrimitiveArray<i32>`].
+/// It differs from the year around year boundaries: e.g. 2021-01-01 is in the
+/// last week (53) of 2020, since ISO week 1 is the week with the first Thursday of the year.
+/// Use [`can_iso_year`] to check if this operation is supported for the target [`DataType`]
+pub fn iso_year(array: &dyn Array) -> Result<PrimitiveArray<i32>> {
+    date_like!(i32_iso_year, array, DataType::Int32)
+}
+
 // Macro to avoid repetition in functions, that apply
 // `chrono::Timelike` methods on Arrays
 macro_rules! time_like {
@@ -165,6 +183,95 @@ pub fn nanosecond(array: &dyn Array) -> Result<PrimitiveArray<u32>> {
     time_like!(nanosecond, array, DataType::UInt32)
 }
 
+/// The unit [`date_trunc`] truncates timestamps to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TruncUnit {
+    /// Truncates to the second
+    Second,
+    /// Truncates to the minute
+    Minute,
+    /// Truncates to the hour
+    Hour,
+    /// Truncates to midnight
+    Day,
+    /// Truncates to midnight of the Monday of the (ISO) week
+    Week,
+    /// Truncates to midnight of the first day of the month
+    Month,
+    /// Truncates to midnight of the first day of the quarter
+    Quarter,
+    /// Truncates to midnight of the first day of the year
+    Year,
+}
+
+/// Truncates the timestamps of `array` to `unit`, i.e. returns the first instant of the
+/// `unit` each timestamp belongs to.
+///
+/// Timestamps are truncated in the timezone of the array (or in naive time when it has none),
+/// so that e.g. hours start at the local hour for an offset of `+05:30` and days land on the
+/// local midnight of the first day of the period even when the offset changed in between
+/// (e.g. across a DST transition). A local start inside a DST gap is replaced by the end of the
+/// gap, and a local hour, minute or second that is repeated starts at its occurrence with the
+/// offset of the timestamp.
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::array::PrimitiveArray;
+/// use arrow2::compute::temporal::{date_trunc, TruncUnit};
+/// use arrow2::datatypes::{DataType, TimeUnit};
+///
+/// # fn main() -> Result<()> {
+/// let data_type = DataType::Timestamp(TimeUnit::Second, Some("+01:00".to_string()));
+/// // 2021-02-15T10:30:00+01:00
+/// let array = PrimitiveArray::<i64>::from_slice([1613381400]).to(data_type.clone());
+/// let result = date_trunc(&array, TruncUnit::Month)?;
+///
+/// // 2021-02-01T00:00:00+01:00
+/// let expected = PrimitiveArray::<i64>::from_slice([1612134000]).to(data_type);
+/// assert_eq!(result, expected);
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// Errors iff the array is not a timestamp or its timezone cannot be parsed.
+pub fn date_trunc(array: &PrimitiveArray<i64>, unit: TruncUnit) -> Result<PrimitiveArray<i64>> {
+    let (time_unit, timezone) = match array.data_type().to_logical_type() {
+        DataType::Timestamp(time_unit, timezone) => (*time_unit, timezone),
+        dt => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "\"date_trunc\" does not support type {:?}",
+                dt
+            )))
+        }
+    };
+    let data_type = array.data_type().clone();
+
+    match timezone {
+        None => {
+            if let Some(seconds) = unit_seconds(unit) {
+                // naive time has no offset: truncate the instant
+                let period = seconds * time_unit_multiple(time_unit);
+                return Ok(unary(array, |x| x - x.rem_euclid(period), data_type));
+            }
+            Ok(unary(
+                array,
+                |x| {
+                    let datetime = trunc_naive(timestamp_to_naive_datetime(x, time_unit), unit);
+                    datetime.and_utc().timestamp() * time_unit_multiple(time_unit)
+                },
+                data_type,
+            ))
+        }
+        Some(timezone_str) => {
+            if let Ok(timezone) = parse_offset(timezone_str) {
+                Ok(trunc_impl(array, time_unit, timezone, unit))
+            } else {
+                chrono_tz_trunc(array, time_unit, timezone_str, unit)
+            }
+        }
+    }
+}
+
 fn date_variants<F, O>(array: &dyn Array, data_type: DataType, op: F) -> Result<PrimitiveArray<O>>
 where
     O: NativeType,
@@ -319,6 +426,112 @@ where
     }
 }
 
+/// Get the time unit as a multiple of a second
+const fn time_unit_multiple(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
+}
+
+/// The length of `unit` in seconds, if it is fixed
+fn unit_seconds(unit: TruncUnit) -> Option<i64> {
+    match unit {
+        TruncUnit::Second => Some(1),
+        TruncUnit::Minute => Some(60),
+        TruncUnit::Hour => Some(3_600),
+        _ => None,
+    }
+}
+
+fn trunc_naive(datetime: chrono::NaiveDateTime, unit: TruncUnit) -> chrono::NaiveDateTime {
+    let date = datetime.date();
+    let time = datetime.time();
+    let time = match unit {
+        TruncUnit::Second => {
+            chrono::NaiveTime::from_hms_opt(time.hour(), time.minute(), time.second())
+        }
+        TruncUnit::Minute => chrono::NaiveTime::from_hms_opt(time.hour(), time.minute(), 0),
+        TruncUnit::Hour => chrono::NaiveTime::from_hms_opt(time.hour(), 0, 0),
+        _ => chrono::NaiveTime::from_hms_opt(0, 0, 0),
+    }
+    .unwrap();
+    let date = match unit {
+        TruncUnit::Second | TruncUnit::Minute | TruncUnit::Hour | TruncUnit::Day => date,
+        TruncUnit::Week => {
+            date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64)
+        }
+        TruncUnit::Month => date.with_day(1).unwrap(),
+        TruncUnit::Quarter => date
+            .with_day(1)
+            .unwrap()
+            .with_month((date.month() - 1) / 3 * 3 + 1)
+            .unwrap(),
+        TruncUnit::Year => date.with_day(1).unwrap().with_month(1).unwrap(),
+    };
+    date.and_time(time)
+}
+
+#[cfg(feature = "chrono-tz")]
+fn chrono_tz_trunc(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone_str: &str,
+    unit: TruncUnit,
+) -> Result<PrimitiveArray<i64>> {
+    let timezone = parse_offset_tz(timezone_str)?;
+    Ok(trunc_impl(array, time_unit, timezone, unit))
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn chrono_tz_trunc(
+    _: &PrimitiveArray<i64>,
+    _: TimeUnit,
+    timezone_str: &str,
+    _: TruncUnit,
+) -> Result<PrimitiveArray<i64>> {
+    Err(ArrowError::InvalidArgumentError(format!(
+        "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
+        timezone_str
+    )))
+}
+
+fn trunc_impl<T: chrono::TimeZone>(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: T,
+    unit: TruncUnit,
+) -> PrimitiveArray<i64> {
+    let op = |x| {
+        let datetime = timestamp_to_naive_datetime(x, time_unit);
+        let local = timezone.from_utc_datetime(&datetime).naive_local();
+        let local = trunc_naive(local, unit);
+
+        let utc = match timezone.from_local_datetime(&local) {
+            chrono::LocalResult::Single(datetime) => datetime.naive_utc(),
+            chrono::LocalResult::Ambiguous(earliest, latest) => {
+                // a repeated local hour (or shorter) starts at the occurrence `x` is in, while
+                // longer units start at their first instant
+                if unit_seconds(unit).is_some() && latest.naive_utc() <= datetime {
+                    latest.naive_utc()
+                } else {
+                    earliest.naive_utc()
+                }
+            }
+            chrono::LocalResult::None => {
+                // `local` is in a gap: with the offset before the gap, it maps to its end
+                let offset =
+                    timezone.offset_from_utc_datetime(&(local - chrono::Duration::days(1)));
+                local - chrono::Duration::seconds(offset.fix().local_minus_utc() as i64)
+            }
+        };
+        utc.and_utc().timestamp() * time_unit_multiple(time_unit)
+    };
+    unary(array, op, array.data_type().clone())
+}
+
 /// Checks if an array of type `datatype` can perform year operation
 ///
 /// # Examples
@@ -353,6 +566,11 @@ pub fn can_iso_week(data_type: &DataType) -> bool {
     can_date(data_type)
 }
 
+/// Checks if an array of type `data_type` can perform ISO year operation
+pub fn can_iso_year(data_type: &DataType) -> bool {
+    can_date(data_type)
+}
+
 fn can_date(data_type: &DataType) -> bool {
     matches!(
         data_type,
@@ -0,0 +1,89 @@
+//! Contains the operator [`unique`].
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::array::{ord::build_compare, Array, UInt32Array};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+use super::hash::{can_hash, hash};
+use super::take::take;
+
+/// Returns the distinct values of `array`, in the order of their first appearance.
+///
+/// Nulls are considered equal to each other, so that the result contains a single null iff
+/// `array` contains nulls.
+/// Values are hashed with [`hash`](super::hash::hash), thus only its supported types are
+/// supported (see [`can_unique`]).
+/// # Example
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::compute::unique::unique;
+/// use arrow2::array::Utf8Array;
+///
+/// # fn main() -> Result<()> {
+/// let array = Utf8Array::<i32>::from(&[Some("b"), None, Some("a"), Some("b"), None]);
+/// let result = unique(&array)?;
+///
+/// let expected = Utf8Array::<i32>::from(&[Some("b"), None, Some("a")]);
+/// assert_eq!(expected, result.as_ref());
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff
+/// * `array` has more than `u32::MAX` slots
+/// * the data type of `array` is not supported by [`hash`](super::hash::hash)
+pub fn unique(array: &dyn Array) -> Result<Box<dyn Array>> {
+    if array.len() > u32::MAX as usize {
+        return Err(ArrowError::Overflow);
+    }
+    let hashes = hash(array)?;
+    let cmp = build_compare(array, array)?;
+
+    // hash -> slots with a distinct value with that hash
+    let mut map = HashMap::<u64, Vec<u32>>::new();
+    let mut null = None;
+    let mut indices = vec![];
+    for (index, hash) in hashes.iter().enumerate() {
+        let index = index as u32;
+        let hash = match hash {
+            Some(hash) => *hash,
+            None => {
+                if null.is_none() {
+                    null = Some(index);
+                    indices.push(index);
+                }
+                continue;
+            }
+        };
+        let candidates = map.entry(hash).or_default();
+        let is_new = !candidates
+            .iter()
+            .any(|other| cmp(*other as usize, index as usize) == Ordering::Equal);
+        if is_new {
+            candidates.push(index);
+            indices.push(index);
+        }
+    }
+
+    let indices = UInt32Array::from_vec(indices);
+    take(array, &indices)
+}
+
+/// Checks if an array of type `datatype` can be used in [`unique`].
+///
+/// # Examples
+/// ```
+/// use arrow2::compute::unique::can_unique;
+/// use arrow2::datatypes::{DataType};
+///
+/// let data_type = DataType::Utf8;
+/// assert_eq!(can_unique(&data_type), true);
+///
+/// let data_type = DataType::Null;
+/// assert_eq!(can_unique(&data_type), false);
+/// ```
+pub fn can_unique(data_type: &DataType) -> bool {
+    can_hash(data_type)
+}
@@ -0,0 +1,54 @@
+use crate::{
+    array::{Offset, PrimitiveArray, Utf8Array},
+    compute::utils::check_same_len,
+    error::Result,
+};
+
+/// Returns the 1-based index, in code points, of the first occurrence of `needle` in `haystack`,
+/// or 0 if there is none.
+fn find_str<O: Offset>(haystack: &str, needle: &str) -> O {
+    let position = haystack
+        .find(needle)
+        .map(|byte| haystack[..byte].chars().count() + 1)
+        .unwrap_or(0);
+    // the number of code points is not larger than the number of bytes, that fit in `O`
+    O::from_usize(position).unwrap()
+}
+
+/// Returns a [`PrimitiveArray`] with the position of the first occurrence of `needle` in each
+/// string of `haystack`, like SQL's `STRPOS`.
+///
+/// Positions are 1-based and counted in Unicode code points ([`char`]s), not bytes, and are 0
+/// when `needle` does not occur. An empty `needle` is at position 1. Nulls are null.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::utf8::find;
+///
+/// let array = Utf8Array::<i32>::from([Some("héllo"), Some("world"), None]);
+/// let result = find(&array, "l");
+/// assert_eq!(result, Int32Array::from([Some(3), Some(4), None]));
+/// ```
+pub fn find<O: Offset>(haystack: &Utf8Array<O>, needle: &str) -> PrimitiveArray<O> {
+    let iter = haystack.iter().map(|x| x.map(|x| find_str::<O>(x, needle)));
+    PrimitiveArray::<O>::from_trusted_len_iter(iter)
+}
+
+/// Same as [`find`], but with a needle per string of `haystack`. Slots where either the
+/// haystack or the needle is null are null.
+/// # Errors
+/// This function errors iff `haystack` and `needle` have different lengths.
+pub fn find_array<O: Offset>(
+    haystack: &Utf8Array<O>,
+    needle: &Utf8Array<O>,
+) -> Result<PrimitiveArray<O>> {
+    check_same_len(haystack, needle)?;
+    let iter = haystack
+        .iter()
+        .zip(needle.iter())
+        .map(|(haystack, needle)| match (haystack, needle) {
+            (Some(haystack), Some(needle)) => Some(find_str::<O>(haystack, needle)),
+            _ => None,
+        });
+    Ok(PrimitiveArray::<O>::from_trusted_len_iter(iter))
+}
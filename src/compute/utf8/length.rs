@@ -0,0 +1,24 @@
+use crate::array::{Offset, PrimitiveArray, Utf8Array};
+
+/// Returns a [`PrimitiveArray`] with the number of Unicode code points ([`char`]s) of each
+/// string of `array`. Nulls are null.
+///
+/// This differs from [`length`](crate::compute::length::length), that returns the number of
+/// bytes of each string: both are equal only for ASCII strings.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, Utf8Array};
+/// use arrow2::compute::utf8::char_length;
+///
+/// let array = Utf8Array::<i32>::from([Some("héllo"), Some("💖"), None]);
+/// let result = char_length(&array);
+/// assert_eq!(result, Int32Array::from([Some(5), Some(1), None]));
+/// ```
+pub fn char_length<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
+    // the number of code points is not larger than the number of bytes, that fit in `O`
+    let values = array
+        .values_iter()
+        .map(|x| O::from_usize(x.chars().count()).unwrap());
+    PrimitiveArray::<O>::from_trusted_len_values_iter(values)
+        .with_validity(array.validity().cloned())
+}
@@ -0,0 +1,15 @@
+//! Contains kernels of [`Utf8Array`](crate::array::Utf8Array)s, such as [`split`],
+//! [`regex_replace`], [`lpad`], [`find`], [`char_length`] and [`trim_matches`].
+mod find;
+mod length;
+mod pad;
+mod regex_replace;
+mod split;
+mod trim;
+
+pub use find::*;
+pub use length::*;
+pub use pad::*;
+pub use regex_replace::*;
+pub use split::*;
+pub use trim::*;
@@ -0,0 +1,56 @@
+use crate::array::{Offset, Utf8Array};
+
+/// Returns `value` padded with `fill` to `width` code points, on the left if `left`.
+fn pad(value: &str, width: usize, fill: &str, left: bool) -> String {
+    let length = value.chars().count();
+    if length >= width || fill.is_empty() {
+        // truncate to `width` code points
+        return value.chars().take(width).collect();
+    }
+    let padding = fill.chars().cycle().take(width - length);
+    if left {
+        padding.chain(value.chars()).collect()
+    } else {
+        value.chars().chain(padding).collect()
+    }
+}
+
+fn pad_with<O: Offset>(array: &Utf8Array<O>, width: usize, fill: &str, left: bool) -> Utf8Array<O> {
+    let iter = array.iter().map(|x| x.map(|x| pad(x, width, fill, left)));
+    Utf8Array::<O>::from_trusted_len_iter(iter)
+}
+
+/// Returns a [`Utf8Array`] whose strings are the strings of `array` left-padded with `fill`
+/// to `width`, like SQL's `LPAD`. `fill` is repeated and truncated as needed, strings longer
+/// than `width` are truncated to their first `width` characters and nulls are null.
+///
+/// Widths are counted in Unicode code points ([`char`]s), not in bytes or grapheme clusters:
+/// e.g. `"é"` written as `e` followed by a combining accent has a width of 2.
+/// When `fill` is empty, strings shorter than `width` are not padded.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::lpad;
+///
+/// let array = Utf8Array::<i32>::from([Some("abc"), Some("abcdefg"), None]);
+/// let result = lpad(&array, 6, "xy");
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("xyxabc"), Some("abcdef"), None]));
+/// ```
+pub fn lpad<O: Offset>(array: &Utf8Array<O>, width: usize, fill: &str) -> Utf8Array<O> {
+    pad_with(array, width, fill, true)
+}
+
+/// Returns a [`Utf8Array`] whose strings are the strings of `array` right-padded with `fill`
+/// to `width`, like SQL's `RPAD`. See [`lpad`] for how strings are padded and truncated.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::rpad;
+///
+/// let array = Utf8Array::<i32>::from([Some("abc"), Some("abcdefg"), None]);
+/// let result = rpad(&array, 6, "xy");
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("abcxyx"), Some("abcdef"), None]));
+/// ```
+pub fn rpad<O: Offset>(array: &Utf8Array<O>, width: usize, fill: &str) -> Utf8Array<O> {
+    pad_with(array, width, fill, false)
+}
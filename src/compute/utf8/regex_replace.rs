@@ -0,0 +1,47 @@
+use regex::Regex;
+
+use crate::{
+    array::{Offset, Utf8Array},
+    error::{ArrowError, Result},
+};
+
+/// Returns a [`Utf8Array`] where the first match (or every match, if `all`) of the regex
+/// `pattern` in each string of `array` is replaced by `replacement`.
+///
+/// `replacement` may reference capture groups of `pattern` by index (`$1`) or by name
+/// (`${name}`), as in [`Regex::replace`]. Nulls are null.
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::regex_replace;
+///
+/// let array = Utf8Array::<i32>::from([Some("2021-12-31"), None]);
+/// let result = regex_replace(&array, r"(\d+)-(\d+)-(\d+)", "$3/$2/$1", false).unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("31/12/2021"), None]));
+/// ```
+pub fn regex_replace<O: Offset>(
+    array: &Utf8Array<O>,
+    pattern: &str,
+    replacement: &str,
+    all: bool,
+) -> Result<Utf8Array<O>> {
+    let regex = Regex::new(pattern).map_err(|e| {
+        ArrowError::InvalidArgumentError(format!(
+            "Unable to build regex from pattern \"{}\": {}",
+            pattern, e
+        ))
+    })?;
+
+    let iter = array.iter().map(|x| {
+        x.map(|x| {
+            if all {
+                regex.replace_all(x, replacement)
+            } else {
+                regex.replace(x, replacement)
+            }
+        })
+    });
+    Ok(Utf8Array::<O>::from_trusted_len_iter(iter))
+}
@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, ListArray, MutableArray, MutableUtf8Array, Offset, Utf8Array},
+    bitmap::MutableBitmap,
+};
+
+fn split_with<'a, O, F, I>(array: &'a Utf8Array<O>, op: F) -> ListArray<O>
+where
+    O: Offset,
+    F: Fn(&'a str) -> I,
+    I: Iterator<Item = &'a str>,
+{
+    let mut values = MutableUtf8Array::<O>::with_capacity(array.len());
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    let mut validity = MutableBitmap::with_capacity(array.len());
+    offsets.push(O::zero());
+    for x in array.iter() {
+        if let Some(x) = x {
+            op(x).for_each(|part| values.push(Some(part)));
+        }
+        validity.push(x.is_some());
+        let length = O::from_usize(values.len()).expect("the number of substrings overflows O");
+        offsets.push(length);
+    }
+    let values: Utf8Array<O> = values.into();
+
+    ListArray::<O>::from_data(
+        ListArray::<O>::default_datatype(array.data_type().clone()),
+        offsets.into(),
+        Arc::new(values),
+        validity.into(),
+    )
+}
+
+/// Returns a [`ListArray`] whose every slot is the list of substrings of the corresponding slot
+/// of `array` separated by `pattern`, as in [`str::split`]. Nulls are null lists and an empty
+/// string is a list with an empty string.
+/// # Panics
+/// Panics iff the total number of substrings does not fit in `O`.
+/// # Example
+/// ```
+/// use arrow2::array::{ListArray, MutableListArray, MutableUtf8Array, TryExtend, Utf8Array};
+/// use arrow2::compute::utf8::split;
+///
+/// let array = Utf8Array::<i32>::from([Some("a, b"), None, Some("")]);
+/// let result = split(&array, ", ");
+///
+/// let mut expected = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+/// expected.try_extend([Some(vec![Some("a"), Some("b")]), None, Some(vec![Some("")])]).unwrap();
+/// let expected: ListArray<i32> = expected.into();
+/// assert_eq!(result, expected);
+/// ```
+pub fn split<O: Offset>(array: &Utf8Array<O>, pattern: &str) -> ListArray<O> {
+    split_with(array, |x| x.split(pattern))
+}
+
+/// Same as [`split`], but each list has at most `n` substrings, the last of which is the
+/// remainder of the string, as in [`str::splitn`].
+/// # Panics
+/// Panics iff the total number of substrings does not fit in `O`.
+pub fn splitn<O: Offset>(array: &Utf8Array<O>, n: usize, pattern: &str) -> ListArray<O> {
+    split_with(array, |x| x.splitn(n, pattern))
+}
@@ -0,0 +1,36 @@
+use crate::array::{Offset, Utf8Array};
+
+fn trim_with<'a, O: Offset, F: Fn(&'a str) -> &'a str>(
+    array: &'a Utf8Array<O>,
+    op: F,
+) -> Utf8Array<O> {
+    let iter = array.iter().map(|x| x.map(&op));
+    Utf8Array::<O>::from_trusted_len_iter(iter)
+}
+
+/// Returns a [`Utf8Array`] whose strings are the strings of `array` without any leading or
+/// trailing character that is in `chars`, as in [`str::trim_matches`]. Nulls are null.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::utf8::trim_matches;
+///
+/// let array = Utf8Array::<i32>::from([Some("\"a 'quoted' field\""), None]);
+/// let result = trim_matches(&array, "\"'");
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("a 'quoted' field"), None]));
+/// ```
+pub fn trim_matches<O: Offset>(array: &Utf8Array<O>, chars: &str) -> Utf8Array<O> {
+    trim_with(array, |x| x.trim_matches(|c| chars.contains(c)))
+}
+
+/// Same as [`trim_matches`], but only removes leading characters,
+/// as in [`str::trim_start_matches`].
+pub fn trim_start_matches<O: Offset>(array: &Utf8Array<O>, chars: &str) -> Utf8Array<O> {
+    trim_with(array, |x| x.trim_start_matches(|c| chars.contains(c)))
+}
+
+/// Same as [`trim_matches`], but only removes trailing characters,
+/// as in [`str::trim_end_matches`].
+pub fn trim_end_matches<O: Offset>(array: &Utf8Array<O>, chars: &str) -> Utf8Array<O> {
+    trim_with(array, |x| x.trim_end_matches(|c| chars.contains(c)))
+}
@@ -15,51 +15,254 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines windowing functions, like `shift`ing
+//! Defines windowing functions, like `shift`ing, and cumulative functions, like [`cumsum`].
+
+use std::ops::{Add, Mul};
 
 use crate::compute::concatenate::concatenate;
-use num_traits::{abs, clamp};
+use num_traits::{CheckedAdd, CheckedMul};
 
 use crate::{
-    array::{new_null_array, Array},
+    array::{new_null_array, Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array},
+    bitmap::MutableBitmap,
     error::{ArrowError, Result},
+    scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar},
+    types::NativeType,
 };
 
+fn repeat_utf8<O: Offset>(scalar: &dyn Scalar, length: usize) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<O>>().unwrap();
+    let value = scalar.value().unwrap();
+    Box::new(Utf8Array::<O>::from_iter_values((0..length).map(|_| value)))
+}
+
+fn repeat_binary<O: Offset>(scalar: &dyn Scalar, length: usize) -> Box<dyn Array> {
+    let scalar = scalar.as_any().downcast_ref::<BinaryScalar<O>>().unwrap();
+    let value = scalar.value().unwrap();
+    Box::new(BinaryArray::<O>::from_iter_values(
+        (0..length).map(|_| value),
+    ))
+}
+
+/// Returns an array with `length` slots equal to `scalar`.
+fn repeat(scalar: &dyn Scalar, length: usize) -> Result<Box<dyn Array>> {
+    let data_type = scalar.data_type().clone();
+    if !scalar.is_valid() {
+        return Ok(new_null_array(data_type, length));
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            let mut values = MutableBitmap::with_capacity(length);
+            values.extend_constant(length, scalar.value().unwrap());
+            let values = values.into();
+            Box::new(BooleanArray::from_data(data_type, values, None))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = scalar.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let values = vec![scalar.value().unwrap(); length];
+            Box::new(PrimitiveArray::<$T>::from_data(data_type, values.into(), None))
+        }),
+        Utf8 => repeat_utf8::<i32>(scalar, length),
+        LargeUtf8 => repeat_utf8::<i64>(scalar, length),
+        Binary => repeat_binary::<i32>(scalar, length),
+        LargeBinary => repeat_binary::<i64>(scalar, length),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Shift with a fill value is not supported for logical type {:?}",
+                data_type
+            )))
+        }
+    })
+}
+
 /// Shifts array by defined number of items (to left or right)
-/// A positive value for `offset` shifts the array to the right
-/// a negative value shifts the array to the left.
+/// A positive value for `offset` shifts the array to the right (a lag),
+/// a negative value shifts the array to the left (a lead).
+///
+/// The slots exposed by the shift are filled with `fill`, or null when `fill` is `None`.
+/// The result has the data type and the length of `array`; offsets whose absolute value is
+/// larger than the length of `array` result in an array of `fill` only.
 /// # Examples
 /// ```
 /// use arrow2::array::Int32Array;
 /// use arrow2::compute::window::shift;
+/// use arrow2::scalar::PrimitiveScalar;
 ///
 /// let array = Int32Array::from(&[Some(1), None, Some(3)]);
-/// let result = shift(&array, -1).unwrap();
+/// let result = shift(&array, -1, None).unwrap();
 /// let expected = Int32Array::from(&[None, Some(3), None]);
 /// assert_eq!(expected, result.as_ref());
+///
+/// let fill = PrimitiveScalar::from(Some(0i32));
+/// let result = shift(&array, 1, Some(&fill)).unwrap();
+/// let expected = Int32Array::from(&[Some(0), Some(1), None]);
+/// assert_eq!(expected, result.as_ref());
 /// ```
-pub fn shift(array: &dyn Array, offset: i64) -> Result<Box<dyn Array>> {
-    if abs(offset) as usize > array.len() {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "Shift's absolute offset must be smaller or equal to the arrays length. Offset is {}, length is {}",
-            abs(offset), array.len()
-        )));
+/// # Errors
+/// This function errors iff `fill` is not of the data type of `array`, or filling arrays of its
+/// data type with a non-null value is not supported.
+pub fn shift(array: &dyn Array, offset: i64, fill: Option<&dyn Scalar>) -> Result<Box<dyn Array>> {
+    if let Some(fill) = fill {
+        if fill.data_type() != array.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Shift's fill value must be of the data type of the array. Fill is {:?}, array is {:?}",
+                fill.data_type(),
+                array.data_type()
+            )));
+        }
     }
 
+    // number of slots exposed by the shift
+    let filled = (offset.unsigned_abs() as usize).min(array.len());
+    let length = array.len() - filled;
+
     // Compute slice
-    let slice_offset = clamp(-offset, 0, array.len() as i64) as usize;
-    let length = array.len() - abs(offset) as usize;
+    let slice_offset = if offset < 0 { filled } else { 0 };
     let slice = array.slice(slice_offset, length);
 
-    // Generate array with remaining `null` items
-    let nulls = abs(offset as i64) as usize;
+    // Generate array with the remaining `fill` (or `null`) items
+    let fill = match fill {
+        Some(fill) => repeat(fill, filled)?,
+        None => new_null_array(array.data_type().clone(), filled),
+    };
 
-    let null_array = new_null_array(array.data_type().clone(), nulls);
-
-    // Concatenate both arrays, add nulls after if shift > 0 else before
+    // Concatenate both arrays, add fill after if shift > 0 else before
     if offset > 0 {
-        concatenate(&[null_array.as_ref(), slice.as_ref()])
+        concatenate(&[fill.as_ref(), slice.as_ref()])
     } else {
-        concatenate(&[slice.as_ref(), null_array.as_ref()])
+        concatenate(&[slice.as_ref(), fill.as_ref()])
     }
 }
+
+/// Applies `op` cumulatively to the non-null values of `array`. Null values are null in the
+/// result and do not change the running state.
+fn scan<T, F>(array: &PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    let mut state: Option<T> = None;
+    let values = array
+        .iter()
+        .map(|x| match x {
+            Some(x) => {
+                let value = state.map(|state| op(state, *x)).unwrap_or(*x);
+                state = Some(value);
+                value
+            }
+            None => T::default(),
+        })
+        .collect::<Vec<_>>();
+
+    PrimitiveArray::<T>::from_data(
+        array.data_type().clone(),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Same as [`scan`] but every value from the first overflow (`op` returning `None`) onwards
+/// is null.
+fn checked_scan<T, F>(array: &PrimitiveArray<T>, op: F) -> PrimitiveArray<T>
+where
+    T: NativeType,
+    F: Fn(T, T) -> Option<T>,
+{
+    let mut state: Option<T> = None;
+    let mut overflowed = false;
+    array
+        .iter()
+        .map(|x| match x {
+            Some(x) if !overflowed => {
+                let value = match state {
+                    Some(state) => op(state, *x),
+                    None => Some(*x),
+                };
+                overflowed = value.is_none();
+                state = value;
+                value
+            }
+            _ => None,
+        })
+        .collect::<PrimitiveArray<T>>()
+        .to(array.data_type().clone())
+}
+
+/// Returns the cumulative sum of `array`: each value is the sum of all non-null values
+/// up to and including it. Nulls are kept null and skipped by the sum.
+/// If the sum overflows, this function panics in debug builds and wraps around at the
+/// boundary of the type in release builds; see [`checked_cumsum`] to null overflows instead.
+/// # Examples
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::window::cumsum;
+///
+/// let array = Int32Array::from(&[None, Some(1), None, Some(3)]);
+/// let result = cumsum(&array);
+/// assert_eq!(result, Int32Array::from(&[None, Some(1), None, Some(4)]));
+/// ```
+pub fn cumsum<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Add<Output = T>,
+{
+    scan(array, |a, b| a + b)
+}
+
+/// Checked cumulative sum of `array`, like [`cumsum`]. If the sum overflows, the value
+/// that overflows and all values after it are null.
+/// # Examples
+/// ```
+/// use arrow2::array::Int8Array;
+/// use arrow2::compute::window::checked_cumsum;
+///
+/// let array = Int8Array::from(&[Some(100), Some(27), Some(1), Some(1)]);
+/// let result = checked_cumsum(&array);
+/// assert_eq!(result, Int8Array::from(&[Some(100), Some(127), None, None]));
+/// ```
+pub fn checked_cumsum<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedAdd<Output = T>,
+{
+    checked_scan(array, |a, b| a.checked_add(&b))
+}
+
+/// Returns the cumulative product of `array`: each value is the product of all non-null
+/// values up to and including it. Nulls are kept null and skipped by the product.
+/// If the product overflows, this function panics in debug builds and wraps around at the
+/// boundary of the type in release builds; see [`checked_cumprod`] to null overflows instead.
+pub fn cumprod<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + Mul<Output = T>,
+{
+    scan(array, |a, b| a * b)
+}
+
+/// Checked cumulative product of `array`, like [`cumprod`]. If the product overflows,
+/// the value that overflows and all values after it are null.
+pub fn checked_cumprod<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + CheckedMul<Output = T>,
+{
+    checked_scan(array, |a, b| a.checked_mul(&b))
+}
+
+/// Returns the cumulative minimum of `array`: each value is the minimum of all non-null
+/// values up to and including it. Nulls are kept null and skipped by the minimum.
+pub fn cummin<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    scan(array, |a, b| if b < a { b } else { a })
+}
+
+/// Returns the cumulative maximum of `array`: each value is the maximum of all non-null
+/// values up to and including it. Nulls are kept null and skipped by the maximum.
+pub fn cummax<T>(array: &PrimitiveArray<T>) -> PrimitiveArray<T>
+where
+    T: NativeType + PartialOrd,
+{
+    scan(array, |a, b| if b > a { b } else { a })
+}
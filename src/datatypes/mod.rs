@@ -95,6 +95,9 @@ pub enum DataType {
     Utf8,
     /// A variable-length UTF-8 encoded string whose offsets are represented as [`i64`].
     LargeUtf8,
+    /// A variable-length UTF-8 encoded string represented by 16-byte views, where short strings
+    /// are stored in the view and long strings in one of a set of data buffers.
+    Utf8View,
     /// A list of some logical data type whose offsets are represented as [`i32`].
     List(Box<Field>),
     /// A list of some logical data type with a fixed number of elements.
@@ -145,11 +148,23 @@ pub enum DataType {
     ///
     /// The `bool` value indicates the `Dictionary` is sorted if set to `true`.
     Dictionary(IntegerType, Box<DataType>, bool),
+    /// A run-end encoded array (`run_end_type`, `value_type`), where consecutive equal
+    /// elements (a run) are stored once in an associated array of `value_type`, together with
+    /// the (exclusive) logical index where the run ends, of `run_end_type`.
+    /// `run_end_type` must be `Int16`, `Int32` or `Int64`.
+    ///
+    /// Run-end encoded arrays are used to store columns with long runs of equal values,
+    /// since their size depends on the number of runs rather than on the number of elements.
+    RunEndEncoded(IntegerType, Box<DataType>),
     /// Decimal value with precision and scale
     /// precision is the number of digits in the number and
     /// scale is the number of decimal places.
     /// The number 999.99 has a precision of 5 and scale of 2.
     Decimal(usize, usize),
+    /// Decimal value of 256 bits with precision and scale, stored as an [`i256`](crate::types::i256).
+    /// precision is the number of digits in the number and
+    /// scale is the number of decimal places.
+    Decimal256(usize, usize),
     /// Extension type.
     Extension(String, Box<DataType>, Option<String>),
 }
@@ -226,6 +241,7 @@ impl DataType {
                 PhysicalType::Primitive(PrimitiveType::Int64)
             }
             Decimal(_, _) => PhysicalType::Primitive(PrimitiveType::Int128),
+            Decimal256(_, _) => PhysicalType::Primitive(PrimitiveType::Int256),
             UInt8 => PhysicalType::Primitive(PrimitiveType::UInt8),
             UInt16 => PhysicalType::Primitive(PrimitiveType::UInt16),
             UInt32 => PhysicalType::Primitive(PrimitiveType::UInt32),
@@ -242,6 +258,7 @@ impl DataType {
             LargeBinary => PhysicalType::LargeBinary,
             Utf8 => PhysicalType::Utf8,
             LargeUtf8 => PhysicalType::LargeUtf8,
+            Utf8View => PhysicalType::Utf8View,
             List(_) => PhysicalType::List,
             FixedSizeList(_, _) => PhysicalType::FixedSizeList,
             LargeList(_) => PhysicalType::LargeList,
@@ -249,6 +266,7 @@ impl DataType {
             Union(_, _, _) => PhysicalType::Union,
             Map(_, _) => PhysicalType::Map,
             Dictionary(key, _, _) => PhysicalType::Dictionary(*key),
+            RunEndEncoded(run_end, _) => PhysicalType::RunEndEncoded(*run_end),
             Extension(_, key, _) => key.to_physical_type(),
         }
     }
@@ -263,6 +281,54 @@ impl DataType {
             _ => self,
         }
     }
+
+    /// Returns the number of bytes of each element of arrays of this [`DataType`] if they are
+    /// fixed-width (primitive types, decimals and [`DataType::FixedSizeBinary`]), and `None`
+    /// otherwise (e.g. variable-width and nested types, and [`DataType::Boolean`], whose
+    /// elements are bits).
+    /// # Example
+    /// ```
+    /// use arrow2::datatypes::DataType;
+    ///
+    /// assert_eq!(DataType::Int32.primitive_width(), Some(4));
+    /// assert_eq!(DataType::Decimal(10, 2).primitive_width(), Some(16));
+    /// assert_eq!(DataType::FixedSizeBinary(3).primitive_width(), Some(3));
+    /// assert_eq!(DataType::Utf8.primitive_width(), None);
+    /// ```
+    pub fn primitive_width(&self) -> Option<usize> {
+        use DataType::*;
+        match self {
+            Int8 | UInt8 => Some(1),
+            Int16 | UInt16 | Float16 => Some(2),
+            Int32 | UInt32 | Float32 | Date32 | Time32(_) | Interval(IntervalUnit::YearMonth) => {
+                Some(4)
+            }
+            Int64 | UInt64 | Float64 | Date64 | Timestamp(_, _) | Time64(_) | Duration(_) => {
+                Some(8)
+            }
+            Interval(IntervalUnit::DayTime) => Some(8),
+            Interval(IntervalUnit::MonthDayNano) => Some(16),
+            Decimal(_, _) => Some(16),
+            Decimal256(_, _) => Some(32),
+            FixedSizeBinary(size) => Some(*size),
+            Extension(_, inner, _) => inner.primitive_width(),
+            Null
+            | Boolean
+            | Binary
+            | LargeBinary
+            | Utf8
+            | LargeUtf8
+            | Utf8View
+            | List(_)
+            | FixedSizeList(_, _)
+            | LargeList(_)
+            | Struct(_)
+            | Union(_, _, _)
+            | Map(_, _)
+            | Dictionary(_, _, _)
+            | RunEndEncoded(_, _) => None,
+        }
+    }
 }
 
 impl From<IntegerType> for DataType {
@@ -292,6 +358,7 @@ impl From<PrimitiveType> for DataType {
             PrimitiveType::UInt32 => DataType::UInt32,
             PrimitiveType::UInt64 => DataType::UInt64,
             PrimitiveType::Int128 => DataType::Decimal(32, 32),
+            PrimitiveType::Int256 => DataType::Decimal256(76, 76),
             PrimitiveType::Float32 => DataType::Float32,
             PrimitiveType::Float64 => DataType::Float64,
             PrimitiveType::DaysMs => DataType::Interval(IntervalUnit::DayTime),
@@ -24,6 +24,38 @@ impl Schema {
     }
 }
 
+#[cfg(feature = "io_json_integration")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_json_integration")))]
+impl Schema {
+    /// Serializes this [`Schema`] to the JSON schema format of Arrow's integration tests.
+    ///
+    /// Dictionary-encoded fields are assigned consecutive dictionary ids, starting from 0.
+    /// # Example
+    /// ```
+    /// use arrow2::datatypes::{DataType, Field, Schema};
+    ///
+    /// let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    /// let json = schema.to_json();
+    /// assert_eq!(json["fields"][0]["type"]["bitWidth"], 32);
+    /// assert_eq!(Schema::from_json(&json).unwrap(), schema);
+    /// ```
+    pub fn to_json(&self) -> serde_json::Value {
+        use crate::io::{ipc::write::default_ipc_fields, json_integration::write};
+
+        let ipc_fields = default_ipc_fields(&self.fields);
+        let schema = write::serialize_schema(self, &ipc_fields);
+        // infallible: the schema is composed of strings, numbers and booleans
+        serde_json::to_value(schema).unwrap()
+    }
+
+    /// Deserializes a [`Schema`] from the JSON schema format of Arrow's integration tests.
+    /// # Errors
+    /// This function errors iff `value` is not a valid schema in that format.
+    pub fn from_json(value: &serde_json::Value) -> crate::error::Result<Self> {
+        crate::io::json_integration::read::deserialize_schema(value).map(|(schema, _)| schema)
+    }
+}
+
 impl From<Vec<Field>> for Schema {
     fn from(fields: Vec<Field>) -> Self {
         Self {
@@ -2,7 +2,7 @@
 
 use super::ffi::ArrowArrayRef;
 use crate::array::{BooleanArray, FromFfi};
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{array::*, datatypes::PhysicalType};
 
 /// Reads a valid `ffi` interface into a `Box<dyn Array>`
@@ -33,5 +33,15 @@ pub unsafe fn try_from<A: ArrowArrayRef>(array: A) -> Result<Box<dyn Array>> {
         }
         Union => Box::new(UnionArray::try_from_ffi(array)?),
         Map => Box::new(MapArray::try_from_ffi(array)?),
+        Utf8View => {
+            return Err(ArrowError::NotYetImplemented(
+                "Importing Utf8View arrays from the C data interface".to_string(),
+            ))
+        }
+        RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Importing run-end encoded arrays from the C data interface".to_string(),
+            ))
+        }
     })
 }
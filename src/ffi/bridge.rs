@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use crate::array::*;
+use crate::error::{ArrowError, Result};
 
 macro_rules! ffi_dyn {
     ($array:expr, $ty:ty) => {{
@@ -13,9 +14,9 @@ macro_rules! ffi_dyn {
     }};
 }
 
-pub fn align_to_c_data_interface(array: Arc<dyn Array>) -> Arc<dyn Array> {
+pub fn align_to_c_data_interface(array: Arc<dyn Array>) -> Result<Arc<dyn Array>> {
     use crate::datatypes::PhysicalType::*;
-    match array.data_type().to_physical_type() {
+    Ok(match array.data_type().to_physical_type() {
         Null => ffi_dyn!(array, NullArray),
         Boolean => ffi_dyn!(array, BooleanArray),
         Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
@@ -37,5 +38,15 @@ pub fn align_to_c_data_interface(array: Arc<dyn Array>) -> Arc<dyn Array> {
                 ffi_dyn!(array, DictionaryArray<$T>)
             })
         }
-    }
+        Utf8View => {
+            return Err(ArrowError::NotYetImplemented(
+                "Exporting Utf8View arrays to the C data interface".to_string(),
+            ))
+        }
+        RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Exporting run-end encoded arrays to the C data interface".to_string(),
+            ))
+        }
+    })
 }
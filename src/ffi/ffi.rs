@@ -44,7 +44,7 @@ pub struct Ffi_ArrowArray {
     pub(crate) buffers: *mut *const ::std::os::raw::c_void,
     children: *mut *mut Ffi_ArrowArray,
     dictionary: *mut Ffi_ArrowArray,
-    release: ::std::option::Option<unsafe extern "C" fn(arg1: *mut Ffi_ArrowArray)>,
+    pub(crate) release: ::std::option::Option<unsafe extern "C" fn(arg1: *mut Ffi_ArrowArray)>,
     // When exported, this MUST contain everything that is owned by this array.
     // for example, any buffer pointed to in `buffers` must be here, as well as the `buffers` pointer
     // itself.
@@ -102,9 +102,11 @@ impl Ffi_ArrowArray {
     /// # Safety
     /// This method releases `buffers`. Consumers of this struct *must* call `release` before
     /// releasing this struct, or contents in `buffers` leak.
-    pub(crate) fn new(array: Arc<dyn Array>) -> Self {
+    /// # Errors
+    /// Errors iff `array` (or any of its children) cannot be exported
+    pub(crate) fn try_new(array: Arc<dyn Array>) -> Result<Self> {
         let (offset, buffers, children, dictionary) =
-            offset_buffers_children_dictionary(array.as_ref());
+            offset_buffers_children_dictionary(array.as_ref())?;
 
         let buffers_ptr = buffers
             .iter()
@@ -115,14 +117,20 @@ impl Ffi_ArrowArray {
             .collect::<Box<[_]>>();
         let n_buffers = buffers.len() as i64;
 
+        let children = children
+            .into_iter()
+            .map(Ffi_ArrowArray::try_new)
+            .collect::<Result<Vec<_>>>()?;
+        let dictionary = dictionary.map(Ffi_ArrowArray::try_new).transpose()?;
+
+        // note: this cannot be done along with the above because the above is fallible and this op leaks.
         let children_ptr = children
             .into_iter()
-            .map(|child| Box::into_raw(Box::new(Ffi_ArrowArray::new(child))))
+            .map(|child| Box::into_raw(Box::new(child)))
             .collect::<Box<_>>();
         let n_children = children_ptr.len() as i64;
 
-        let dictionary_ptr =
-            dictionary.map(|array| Box::into_raw(Box::new(Ffi_ArrowArray::new(array))));
+        let dictionary_ptr = dictionary.map(|array| Box::into_raw(Box::new(array)));
 
         let length = array.len() as i64;
         let null_count = array.null_count() as i64;
@@ -134,7 +142,7 @@ impl Ffi_ArrowArray {
             dictionary_ptr,
         });
 
-        Self {
+        Ok(Self {
             length,
             null_count,
             offset: offset as i64,
@@ -145,7 +153,7 @@ impl Ffi_ArrowArray {
             dictionary: private_data.dictionary_ptr.unwrap_or(std::ptr::null_mut()),
             release: Some(c_release_array),
             private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
-        }
+        })
     }
 
     /// creates an empty [`Ffi_ArrowArray`], which can be used to import data into
@@ -1,10 +1,13 @@
 //! contains FFI bindings to import and export [`Array`](crate::array::Array) via
-//! Arrow's [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+//! Arrow's [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html),
+//! and streams of [`Chunk`](crate::chunk::Chunk) via Arrow's
+//! [C Stream Interface](https://arrow.apache.org/docs/format/CStreamInterface.html)
 mod array;
 mod bridge;
 #[allow(clippy::module_inception)]
 mod ffi;
 mod schema;
+mod stream;
 
 pub(crate) use array::try_from;
 pub(crate) use ffi::{ArrowArray, ArrowArrayRef};
@@ -17,23 +20,32 @@ use crate::error::Result;
 
 pub use ffi::Ffi_ArrowArray;
 pub use schema::Ffi_ArrowSchema;
+pub use stream::{export_stream, import_stream, ArrowArrayStreamReader, Ffi_ArrowArrayStream};
 
 use self::schema::to_field;
 
 /// Exports an [`Arc<dyn Array>`] to the C data interface.
+/// # Errors
+/// Errors iff the array's [`DataType`](crate::datatypes::DataType) is not yet supported by the
+/// C data interface, in which case `ptr` is left untouched.
 /// # Safety
 /// The pointer `ptr` must be allocated and valid
-pub unsafe fn export_array_to_c(array: Arc<dyn Array>, ptr: *mut Ffi_ArrowArray) {
-    let array = bridge::align_to_c_data_interface(array);
+pub unsafe fn export_array_to_c(array: Arc<dyn Array>, ptr: *mut Ffi_ArrowArray) -> Result<()> {
+    let array = bridge::align_to_c_data_interface(array)?;
 
-    *ptr = Ffi_ArrowArray::new(array);
+    *ptr = Ffi_ArrowArray::try_new(array)?;
+    Ok(())
 }
 
 /// Exports a [`Field`] to the C data interface.
+/// # Errors
+/// Errors iff the field's [`DataType`](crate::datatypes::DataType) is not yet supported by the
+/// C data interface, in which case `ptr` is left untouched.
 /// # Safety
 /// The pointer `ptr` must be allocated and valid
-pub unsafe fn export_field_to_c(field: &Field, ptr: *mut Ffi_ArrowSchema) {
-    *ptr = Ffi_ArrowSchema::new(field)
+pub unsafe fn export_field_to_c(field: &Field, ptr: *mut Ffi_ArrowSchema) -> Result<()> {
+    *ptr = Ffi_ArrowSchema::try_new(field)?;
+    Ok(())
 }
 
 /// Imports a [`Field`] from the C data interface.
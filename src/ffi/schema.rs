@@ -54,8 +54,10 @@ unsafe extern "C" fn c_release_schema(schema: *mut Ffi_ArrowSchema) {
 
 impl Ffi_ArrowSchema {
     /// creates a new [Ffi_ArrowSchema]
-    pub(crate) fn new(field: &Field) -> Self {
-        let format = to_format(field.data_type());
+    /// # Errors
+    /// Errors iff the [`DataType`] of `field` (or of any of its children) cannot be exported
+    pub(crate) fn try_new(field: &Field) -> Result<Self> {
+        let format = to_format(field.data_type())?;
         let name = field.name.clone();
 
         let mut flags = field.is_nullable as i64 * 2;
@@ -63,44 +65,45 @@ impl Ffi_ArrowSchema {
         // allocate (and hold) the children
         let children_vec = match field.data_type() {
             DataType::List(field) => {
-                vec![Box::new(Ffi_ArrowSchema::new(field.as_ref()))]
+                vec![Box::new(Ffi_ArrowSchema::try_new(field.as_ref())?)]
             }
             DataType::FixedSizeList(field, _) => {
-                vec![Box::new(Ffi_ArrowSchema::new(field.as_ref()))]
+                vec![Box::new(Ffi_ArrowSchema::try_new(field.as_ref())?)]
             }
             DataType::LargeList(field) => {
-                vec![Box::new(Ffi_ArrowSchema::new(field.as_ref()))]
+                vec![Box::new(Ffi_ArrowSchema::try_new(field.as_ref())?)]
             }
             DataType::Map(field, is_sorted) => {
                 flags += (*is_sorted as i64) * 4;
-                vec![Box::new(Ffi_ArrowSchema::new(field.as_ref()))]
+                vec![Box::new(Ffi_ArrowSchema::try_new(field.as_ref())?)]
             }
             DataType::Struct(fields) => fields
                 .iter()
-                .map(|field| Box::new(Ffi_ArrowSchema::new(field)))
-                .collect::<Vec<_>>(),
+                .map(|field| Ffi_ArrowSchema::try_new(field).map(Box::new))
+                .collect::<Result<Vec<_>>>()?,
             DataType::Union(fields, _, _) => fields
                 .iter()
-                .map(|field| Box::new(Ffi_ArrowSchema::new(field)))
-                .collect::<Vec<_>>(),
+                .map(|field| Ffi_ArrowSchema::try_new(field).map(Box::new))
+                .collect::<Result<Vec<_>>>()?,
             _ => vec![],
         };
-        // note: this cannot be done along with the above because the above is fallible and this op leaks.
-        let children_ptr = children_vec
-            .into_iter()
-            .map(Box::into_raw)
-            .collect::<Box<_>>();
-        let n_children = children_ptr.len() as i64;
 
         let dictionary = if let DataType::Dictionary(_, values, is_ordered) = field.data_type() {
             flags += *is_ordered as i64;
             // we do not store field info in the dict values, so can't recover it all :(
             let field = Field::new("", values.as_ref().clone(), true);
-            Some(Box::new(Ffi_ArrowSchema::new(&field)))
+            Some(Box::new(Ffi_ArrowSchema::try_new(&field)?))
         } else {
             None
         };
 
+        // note: this cannot be done along with the above because the above is fallible and this op leaks.
+        let children_ptr = children_vec
+            .into_iter()
+            .map(Box::into_raw)
+            .collect::<Box<_>>();
+        let n_children = children_ptr.len() as i64;
+
         let metadata = &field.metadata;
 
         let metadata = if let DataType::Extension(name, _, extension_metadata) = field.data_type() {
@@ -136,7 +139,7 @@ impl Ffi_ArrowSchema {
         });
 
         // <https://arrow.apache.org/docs/format/CDataInterface.html#c.ArrowSchema>
-        Self {
+        Ok(Self {
             format: private.format.as_ptr(),
             name: private.name.as_ptr(),
             metadata: private
@@ -150,7 +153,7 @@ impl Ffi_ArrowSchema {
             dictionary: private.dictionary.unwrap_or(std::ptr::null_mut()),
             release: Some(c_release_schema),
             private_data: Box::into_raw(private) as *mut ::std::os::raw::c_void,
-        }
+        })
     }
 
     /// create an empty [Ffi_ArrowSchema]
@@ -282,6 +285,7 @@ unsafe fn to_data_type(schema: &Ffi_ArrowSchema) -> Result<DataType> {
         "tDn" => DataType::Duration(TimeUnit::Nanosecond),
         "tiM" => DataType::Interval(IntervalUnit::YearMonth),
         "tiD" => DataType::Interval(IntervalUnit::DayTime),
+        "tin" => DataType::Interval(IntervalUnit::MonthDayNano),
         "+l" => {
             let child = schema.child(0);
             DataType::List(Box::new(to_field(child)?))
@@ -330,25 +334,30 @@ unsafe fn to_data_type(schema: &Ffi_ArrowSchema) -> Result<DataType> {
                         "Decimal must contain 2 or 3 comma-separated values".to_string(),
                     ));
                 };
-                if parts.len() == 3 {
-                    let bit_width = parts[0].parse::<usize>().map_err(|_| {
+                let bit_width = if parts.len() == 3 {
+                    parts[2].parse::<usize>().map_err(|_| {
                         ArrowError::OutOfSpec(
                             "Decimal bit width is not a valid integer".to_string(),
                         )
-                    })?;
-                    if bit_width != 128 {
-                        return Err(ArrowError::OutOfSpec(
-                            "Decimal256 is not supported".to_string(),
-                        ));
-                    }
-                }
+                    })?
+                } else {
+                    128
+                };
                 let precision = parts[0].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal precision is not a valid integer".to_string())
                 })?;
                 let scale = parts[1].parse::<usize>().map_err(|_| {
                     ArrowError::OutOfSpec("Decimal scale is not a valid integer".to_string())
                 })?;
-                DataType::Decimal(precision, scale)
+                match bit_width {
+                    128 => DataType::Decimal(precision, scale),
+                    256 => DataType::Decimal256(precision, scale),
+                    _ => {
+                        return Err(ArrowError::OutOfSpec(
+                            "Decimal bit width must be 128 or 256".to_string(),
+                        ))
+                    }
+                }
             } else if !parts.is_empty() && ((parts[0] == "+us") || (parts[0] == "+ud")) {
                 // union
                 let mode = UnionMode::sparse(parts[0] == "+us");
@@ -377,8 +386,8 @@ unsafe fn to_data_type(schema: &Ffi_ArrowSchema) -> Result<DataType> {
 }
 
 /// the inverse of [to_field]
-fn to_format(data_type: &DataType) -> String {
-    match data_type {
+fn to_format(data_type: &DataType) -> Result<String> {
+    Ok(match data_type {
         DataType::Null => "n".to_string(),
         DataType::Boolean => "b".to_string(),
         DataType::Int8 => "c".to_string(),
@@ -414,9 +423,7 @@ fn to_format(data_type: &DataType) -> String {
         DataType::Duration(TimeUnit::Nanosecond) => "tDn".to_string(),
         DataType::Interval(IntervalUnit::YearMonth) => "tiM".to_string(),
         DataType::Interval(IntervalUnit::DayTime) => "tiD".to_string(),
-        DataType::Interval(IntervalUnit::MonthDayNano) => {
-            todo!("Spec for FFI for MonthDayNano still not defined.")
-        }
+        DataType::Interval(IntervalUnit::MonthDayNano) => "tin".to_string(),
         DataType::Timestamp(unit, tz) => {
             let unit = match unit {
                 TimeUnit::Second => "s",
@@ -431,6 +438,7 @@ fn to_format(data_type: &DataType) -> String {
             )
         }
         DataType::Decimal(precision, scale) => format!("d:{},{}", precision, scale),
+        DataType::Decimal256(precision, scale) => format!("d:{},{},256", precision, scale),
         DataType::List(_) => "+l".to_string(),
         DataType::LargeList(_) => "+L".to_string(),
         DataType::Struct(_) => "+s".to_string(),
@@ -450,9 +458,19 @@ fn to_format(data_type: &DataType) -> String {
             r
         }
         DataType::Map(_, _) => "+m".to_string(),
-        DataType::Dictionary(index, _, _) => to_format(&(*index).into()),
-        DataType::Extension(_, inner, _) => to_format(inner.as_ref()),
-    }
+        DataType::Dictionary(index, _, _) => to_format(&(*index).into())?,
+        DataType::Extension(_, inner, _) => to_format(inner.as_ref())?,
+        DataType::Utf8View => {
+            return Err(ArrowError::NotYetImplemented(
+                "Exporting Utf8View arrays to the C data interface".to_string(),
+            ))
+        }
+        DataType::RunEndEncoded(_, _) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Exporting run-end encoded arrays to the C data interface".to_string(),
+            ))
+        }
+    })
 }
 
 pub(super) fn get_field_child(field: &Field, index: usize) -> Result<Field> {
@@ -0,0 +1,298 @@
+use std::ffi::{CStr, CString};
+use std::sync::Arc;
+
+use crate::{
+    array::{Array, StructArray},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    error::{ArrowError, Result},
+};
+
+use super::{bridge, import_array_from_c, import_field_from_c, Ffi_ArrowArray, Ffi_ArrowSchema};
+
+/// The error code returned by the callbacks of an exported stream when the stream errors.
+const EINVAL: i32 = 22;
+
+/// ABI-compatible struct for `ArrowArrayStream` from C Stream Interface
+/// See <https://arrow.apache.org/docs/format/CStreamInterface.html#structure-definitions>
+/// This was created by bindgen
+#[repr(C)]
+#[derive(Debug)]
+pub struct Ffi_ArrowArrayStream {
+    get_schema: Option<
+        unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream, out: *mut Ffi_ArrowSchema) -> i32,
+    >,
+    get_next: Option<
+        unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream, out: *mut Ffi_ArrowArray) -> i32,
+    >,
+    get_last_error: Option<
+        unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream) -> *const std::os::raw::c_char,
+    >,
+    release: Option<unsafe extern "C" fn(arg1: *mut Ffi_ArrowArrayStream)>,
+    private_data: *mut std::os::raw::c_void,
+}
+
+// Sound because the arrow specification requires producers to support (non-concurrent) calls
+// from any thread, and because streams exported by this crate own a `Send` iterator
+unsafe impl Send for Ffi_ArrowArrayStream {}
+
+impl Ffi_ArrowArrayStream {
+    /// creates an empty [`Ffi_ArrowArrayStream`], which can be used to import a stream into
+    pub fn empty() -> Self {
+        Self {
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: std::ptr::null_mut(),
+        }
+    }
+}
+
+impl Drop for Ffi_ArrowArrayStream {
+    fn drop(&mut self) {
+        match self.release {
+            None => (),
+            Some(release) => unsafe { release(self) },
+        };
+    }
+}
+
+struct PrivateData {
+    iter: Box<dyn Iterator<Item = Result<Chunk<Arc<dyn Array>>>> + Send>,
+    field: Field,
+    error: Option<CString>,
+}
+
+impl PrivateData {
+    fn next(&mut self) -> Result<Option<Ffi_ArrowArray>> {
+        let chunk = match self.iter.next() {
+            Some(chunk) => chunk?,
+            None => return Ok(None),
+        };
+        let fields = StructArray::get_fields(&self.field.data_type);
+        if fields.len() != chunk.columns().len()
+            || fields
+                .iter()
+                .zip(chunk.columns())
+                .any(|(field, array)| field.data_type() != array.data_type())
+        {
+            return Err(ArrowError::InvalidArgumentError(
+                "The arrays of a chunk of a stream must have the data types of its schema"
+                    .to_string(),
+            ));
+        }
+        let array = StructArray::from_data(self.field.data_type.clone(), chunk.into_arrays(), None);
+        let array = bridge::align_to_c_data_interface(Arc::new(array))?;
+        Ffi_ArrowArray::try_new(array).map(Some)
+    }
+}
+
+unsafe fn private_data<'a>(stream: *mut Ffi_ArrowArrayStream) -> &'a mut PrivateData {
+    &mut *((*stream).private_data as *mut PrivateData)
+}
+
+unsafe extern "C" fn get_schema(
+    stream: *mut Ffi_ArrowArrayStream,
+    out: *mut Ffi_ArrowSchema,
+) -> i32 {
+    if stream.is_null() || out.is_null() {
+        return EINVAL;
+    }
+    let private = private_data(stream);
+    match Ffi_ArrowSchema::try_new(&private.field) {
+        Ok(schema) => {
+            std::ptr::write(out, schema);
+            0
+        }
+        Err(error) => {
+            private.error = Some(CString::new(error.to_string()).unwrap_or_default());
+            EINVAL
+        }
+    }
+}
+
+unsafe extern "C" fn get_next(stream: *mut Ffi_ArrowArrayStream, out: *mut Ffi_ArrowArray) -> i32 {
+    if stream.is_null() || out.is_null() {
+        return EINVAL;
+    }
+    let private = private_data(stream);
+    match private.next() {
+        Ok(Some(array)) => {
+            std::ptr::write(out, array);
+            0
+        }
+        Ok(None) => {
+            // a released array marks the end of the stream
+            std::ptr::write(out, Ffi_ArrowArray::empty());
+            0
+        }
+        Err(error) => {
+            private.error = Some(CString::new(error.to_string()).unwrap_or_default());
+            EINVAL
+        }
+    }
+}
+
+unsafe extern "C" fn get_last_error(
+    stream: *mut Ffi_ArrowArrayStream,
+) -> *const std::os::raw::c_char {
+    if stream.is_null() {
+        return std::ptr::null();
+    }
+    match &private_data(stream).error {
+        Some(error) => error.as_ptr(),
+        None => std::ptr::null(),
+    }
+}
+
+// callback used to drop [Ffi_ArrowArrayStream] when it is exported
+unsafe extern "C" fn release(stream: *mut Ffi_ArrowArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+
+    // take ownership of `private_data`, therefore dropping it
+    let _ = Box::from_raw(stream.private_data as *mut PrivateData);
+
+    stream.release = None;
+}
+
+/// Exports an iterator of [`Chunk`]s whose arrays have the data types of the fields of `schema`
+/// to the C stream interface.
+///
+/// Each [`Chunk`] is exported as a [`DataType::Struct`] whose fields are the fields of `schema`.
+/// Items of `iterator` that error, or whose arrays do not match `schema`, are reported to the
+/// consumer via `get_last_error`, as are fields of `schema` that cannot be exported.
+///
+/// `iterator` must be [`Send`] because consumers may pull from the stream on any thread.
+pub fn export_stream(
+    iterator: Box<dyn Iterator<Item = Result<Chunk<Arc<dyn Array>>>> + Send>,
+    schema: Schema,
+) -> Ffi_ArrowArrayStream {
+    let field =
+        Field::new("", DataType::Struct(schema.fields), false).with_metadata(schema.metadata);
+
+    let private_data = Box::new(PrivateData {
+        iter: iterator,
+        field,
+        error: None,
+    });
+
+    Ffi_ArrowArrayStream {
+        get_schema: Some(get_schema),
+        get_next: Some(get_next),
+        get_last_error: Some(get_last_error),
+        release: Some(release),
+        private_data: Box::into_raw(private_data) as *mut std::os::raw::c_void,
+    }
+}
+
+/// An iterator of [`Chunk`]s imported from the C stream interface (see [`import_stream`]).
+pub struct ArrowArrayStreamReader {
+    stream: Box<Ffi_ArrowArrayStream>,
+    field: Field,
+    schema: Schema,
+}
+
+impl ArrowArrayStreamReader {
+    /// The [`Schema`] of the stream.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn last_error(&mut self, status: i32) -> ArrowError {
+        let error = unsafe {
+            self.stream
+                .get_last_error
+                .map(|get_last_error| get_last_error(self.stream.as_mut()))
+                .filter(|error| !error.is_null())
+                .map(|error| CStr::from_ptr(error).to_string_lossy().into_owned())
+        };
+        ArrowError::ExternalFormat(format!(
+            "The stream errored with code {}: {}",
+            status,
+            error.unwrap_or_default()
+        ))
+    }
+
+    fn next_chunk(&mut self) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+        let get_next = self.stream.get_next.ok_or_else(|| {
+            ArrowError::ExternalFormat("The stream's get_next must not be null".to_string())
+        })?;
+        let mut array = Box::new(Ffi_ArrowArray::empty());
+        let status = unsafe { get_next(self.stream.as_mut(), array.as_mut()) };
+        if status != 0 {
+            return Err(self.last_error(status));
+        }
+        if array.release.is_none() {
+            return Ok(None);
+        }
+
+        let array = unsafe { import_array_from_c(array, &self.field) }?;
+        let array = array
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .ok_or_else(|| {
+                ArrowError::ExternalFormat(
+                    "The arrays of a stream must be struct arrays".to_string(),
+                )
+            })?;
+        Chunk::try_new(array.values().to_vec()).map(Some)
+    }
+}
+
+impl Iterator for ArrowArrayStreamReader {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+/// Imports a stream of [`Chunk`]s from the C stream interface.
+///
+/// The stream is released when the returned [`ArrowArrayStreamReader`] is dropped.
+/// # Errors
+/// This function errors iff the stream is released, or its schema can't be imported
+/// or is not a [`DataType::Struct`].
+/// # Safety
+/// This function is intrinsically `unsafe` and relies on a [`Ffi_ArrowArrayStream`]
+/// valid according to the [C stream interface](https://arrow.apache.org/docs/format/CStreamInterface.html) (FFI).
+pub unsafe fn import_stream(
+    mut stream: Box<Ffi_ArrowArrayStream>,
+) -> Result<ArrowArrayStreamReader> {
+    if stream.release.is_none() {
+        return Err(ArrowError::ExternalFormat(
+            "The stream to import must not be released".to_string(),
+        ));
+    }
+    let get_schema = stream.get_schema.ok_or_else(|| {
+        ArrowError::ExternalFormat("The stream's get_schema must not be null".to_string())
+    })?;
+
+    let mut schema = Box::new(Ffi_ArrowSchema::empty());
+    let status = get_schema(stream.as_mut(), schema.as_mut());
+    let mut reader = ArrowArrayStreamReader {
+        stream,
+        field: Field::new("", DataType::Null, false),
+        schema: Schema::from(vec![]),
+    };
+    if status != 0 {
+        return Err(reader.last_error(status));
+    }
+
+    let field = import_field_from_c(schema.as_ref())?;
+    let fields = match field.data_type() {
+        DataType::Struct(fields) => fields.clone(),
+        _ => {
+            return Err(ArrowError::ExternalFormat(
+                "The schema of a stream must be a struct".to_string(),
+            ))
+        }
+    };
+    reader.schema = Schema::from(fields).with_metadata(field.metadata.clone());
+    reader.field = field;
+    Ok(reader)
+}
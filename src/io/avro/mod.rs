@@ -92,6 +92,19 @@ macro_rules! read_metadata {
 
 pub(crate) use {avro_decode, read_header, read_metadata};
 
+/// Converts the number of rows and bytes of a block, as read from a file, to `usize`.
+/// # Error
+/// Errors iff either is negative, which only happens when the file is corrupted.
+fn block_size(rows: i64, bytes: i64) -> crate::error::Result<(usize, usize)> {
+    match (rows.try_into(), bytes.try_into()) {
+        (Ok(rows), Ok(bytes)) => Ok((rows, bytes)),
+        _ => Err(crate::error::ArrowError::ExternalFormat(format!(
+            "Avro: a block has a negative number of rows ({}) or bytes ({}); the file is corrupted",
+            rows, bytes
+        ))),
+    }
+}
+
 /// A compressed Avro block.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct CompressedBlock {
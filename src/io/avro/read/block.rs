@@ -22,7 +22,7 @@ fn read_size<R: Read>(reader: &mut R) -> Result<(usize, usize)> {
         Err(other) => return Err(other),
     };
     let bytes = util::zigzag_i64(reader)?;
-    Ok((rows as usize, bytes as usize))
+    super::super::block_size(rows, bytes)
 }
 
 /// Reads a [`CompressedBlock`] from the `reader`.
@@ -54,7 +54,15 @@ fn read_block<R: Read>(
     Ok(())
 }
 
-/// [`FallibleStreamingIterator`] of compressed avro blocks
+/// [`FallibleStreamingIterator`] of compressed avro blocks.
+///
+/// Each item is a [`CompressedBlock`], i.e. the number of rows and the (compressed) bytes of a
+/// block, read without decompressing nor deserializing it. Use it to e.g. count the rows of a
+/// file, or to decode only some blocks via [`decompress_block`](super::decompress_block) and
+/// [`deserialize`](super::deserialize).
+/// # Error
+/// Advancing errors iff the block cannot be read, its size is invalid, or its sync marker
+/// does not match `file_marker`, all of which signal a corrupted file.
 pub struct BlockStreamIterator<R: Read> {
     buf: CompressedBlock,
     reader: R,
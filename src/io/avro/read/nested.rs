@@ -216,8 +216,18 @@ impl DynMutableStructArray {
         self.values[field].as_mut()
     }
 
+    /// Marks the last record, whose values were pushed to [`Self::mut_values`], as valid
+    #[inline]
+    pub fn push_valid(&mut self) {
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+    }
+
     #[inline]
     fn push_null(&mut self) {
+        // the values of every field must have the same length as the struct
+        self.values.iter_mut().for_each(|x| x.push_null());
         match &mut self.validity {
             Some(validity) => validity.push(false),
             None => self.init_validity(),
@@ -20,7 +20,12 @@ fn external_props(schema: &AvroSchema) -> Metadata {
 }
 
 /// Infers an [`Schema`] from the root [`AvroSchema`].
-/// This
+///
+/// Avro logical types are mapped to their Arrow counterparts: `decimal` (of `bytes` or `fixed`)
+/// to [`DataType::Decimal`] with the precision and scale of the Avro schema, `date` to
+/// [`DataType::Date32`], `time-*` to [`DataType::Time32`] and [`DataType::Time64`],
+/// `timestamp-*` to [`DataType::Timestamp`] in UTC, `local-timestamp-*` to
+/// [`DataType::Timestamp`] without timezone and `duration` to [`DataType::Interval`].
 pub fn infer_schema(schema: &AvroSchema) -> Result<Schema> {
     if let AvroSchema::Record(Record { fields, .. }) = schema {
         Ok(fields
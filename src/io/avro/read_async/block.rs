@@ -24,7 +24,7 @@ async fn read_size<R: AsyncRead + Unpin + Send>(reader: &mut R) -> Result<(usize
         Err(other) => return Err(other),
     };
     let bytes = zigzag_i64(reader).await?;
-    Ok((rows as usize, bytes as usize))
+    super::super::block_size(rows, bytes)
 }
 
 /// Reads a [`CompressedBlock`] from the `reader`.
@@ -1,5 +1,5 @@
 use avro_schema::{
-    BytesLogical, Field as AvroField, Fixed, FixedLogical, IntLogical, LongLogical,
+    BytesLogical, Field as AvroField, Fixed, FixedLogical, IntLogical, LongLogical, Record,
     Schema as AvroSchema,
 };
 
@@ -7,24 +7,26 @@ use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
 /// Converts a [`Schema`] to a vector of [`AvroField`] with it.
+///
+/// Fields of type [`DataType::Struct`] are converted to Avro records named after the field.
 pub fn to_avro_schema(schema: &Schema) -> Result<Vec<AvroField>> {
     schema.fields.iter().map(field_to_field).collect()
 }
 
 fn field_to_field(field: &Field) -> Result<AvroField> {
-    let schema = type_to_schema(field.data_type(), field.is_nullable)?;
+    let schema = type_to_schema(field.data_type(), field.is_nullable, &field.name)?;
     Ok(AvroField::new(&field.name, schema))
 }
 
-fn type_to_schema(data_type: &DataType, is_nullable: bool) -> Result<AvroSchema> {
+fn type_to_schema(data_type: &DataType, is_nullable: bool, name: &str) -> Result<AvroSchema> {
     Ok(if is_nullable {
-        AvroSchema::Union(vec![AvroSchema::Null, _type_to_schema(data_type)?])
+        AvroSchema::Union(vec![AvroSchema::Null, _type_to_schema(data_type, name)?])
     } else {
-        _type_to_schema(data_type)?
+        _type_to_schema(data_type, name)?
     })
 }
 
-fn _type_to_schema(data_type: &DataType) -> Result<AvroSchema> {
+fn _type_to_schema(data_type: &DataType, name: &str) -> Result<AvroSchema> {
     Ok(match data_type.to_logical_type() {
         DataType::Null => AvroSchema::Null,
         DataType::Boolean => AvroSchema::Boolean,
@@ -39,7 +41,15 @@ fn _type_to_schema(data_type: &DataType) -> Result<AvroSchema> {
         DataType::List(inner) => AvroSchema::Array(Box::new(type_to_schema(
             &inner.data_type,
             inner.is_nullable,
+            &inner.name,
         )?)),
+        DataType::Struct(fields) => AvroSchema::Record(Record::new(
+            name,
+            fields
+                .iter()
+                .map(field_to_field)
+                .collect::<Result<Vec<_>>>()?,
+        )),
         DataType::Date32 => AvroSchema::Int(Some(IntLogical::Date)),
         DataType::Time32(TimeUnit::Millisecond) => AvroSchema::Int(Some(IntLogical::Time)),
         DataType::Time64(TimeUnit::Microsecond) => AvroSchema::Long(Some(LongLogical::Time)),
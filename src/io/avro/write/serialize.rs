@@ -1,4 +1,4 @@
-use avro_schema::Schema as AvroSchema;
+use avro_schema::{Record, Schema as AvroSchema};
 
 use crate::datatypes::{IntervalUnit, PhysicalType, PrimitiveType};
 use crate::types::months_days_ns;
@@ -61,6 +61,49 @@ fn binary_optional<O: Offset>(array: &BinaryArray<O>) -> BoxSerializer {
     ))
 }
 
+fn struct_required<'a>(array: &'a StructArray, schema: &Record) -> BoxSerializer<'a> {
+    let mut inner = array
+        .values()
+        .iter()
+        .zip(schema.fields.iter())
+        .map(|(array, field)| new_serializer(array.as_ref(), &field.schema))
+        .collect::<Vec<_>>();
+
+    Box::new(BufStreamingIterator::new(
+        0..array.len(),
+        move |_, buf| {
+            inner
+                .iter_mut()
+                .for_each(|item| buf.extend_from_slice(item.next().unwrap()))
+        },
+        vec![],
+    ))
+}
+
+fn struct_optional<'a>(array: &'a StructArray, schema: &Record) -> BoxSerializer<'a> {
+    let mut inner = array
+        .values()
+        .iter()
+        .zip(schema.fields.iter())
+        .map(|(array, field)| new_serializer(array.as_ref(), &field.schema))
+        .collect::<Vec<_>>();
+
+    Box::new(BufStreamingIterator::new(
+        (0..array.len()).map(|index| array.is_valid(index)),
+        move |is_valid, buf| {
+            util::zigzag_encode(is_valid as i64, buf).unwrap();
+            // the values of the fields are advanced regardless of the validity of the record
+            inner.iter_mut().for_each(|item| {
+                let item = item.next().unwrap();
+                if is_valid {
+                    buf.extend_from_slice(item)
+                }
+            })
+        },
+        vec![],
+    ))
+}
+
 /// Creates a [`StreamingIterator`] trait object that presents items from `array`
 /// encoded according to `schema`.
 /// # Panic
@@ -297,6 +340,15 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
                 vec![],
             ))
         }
+        (PhysicalType::Struct, AvroSchema::Record(inner)) => {
+            struct_required(array.as_any().downcast_ref().unwrap(), inner)
+        }
+        (PhysicalType::Struct, AvroSchema::Union(inner)) => match inner.as_slice() {
+            [AvroSchema::Null, AvroSchema::Record(inner)] => {
+                struct_optional(array.as_any().downcast_ref().unwrap(), inner)
+            }
+            _ => unreachable!(),
+        },
         (a, b) => todo!("{:?} -> {:?} not supported", a, b),
     }
 }
@@ -304,10 +356,13 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
 /// Whether [`new_serializer`] supports `data_type`.
 pub fn can_serialize(data_type: &DataType) -> bool {
     use DataType::*;
-    matches!(
-        data_type,
-        Boolean | Int32 | Int64 | Utf8 | Binary | Interval(IntervalUnit::MonthDayNano)
-    )
+    match data_type {
+        Struct(fields) => fields.iter().all(|field| can_serialize(field.data_type())),
+        other => matches!(
+            other,
+            Boolean | Int32 | Int64 | Utf8 | Binary | Interval(IntervalUnit::MonthDayNano)
+        ),
+    }
 }
 
 #[inline]
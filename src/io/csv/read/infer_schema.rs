@@ -6,7 +6,7 @@ use std::{
 use crate::datatypes::{DataType, Field};
 use crate::error::Result;
 
-use super::super::utils::merge_schema;
+use super::super::utils::{merge_schema, FormatsInference, TemporalFormats};
 use super::{ByteRecord, Reader};
 
 /// Infers the [`Field`]s of a CSV file by reading through the first n records up to `max_rows`.
@@ -18,6 +18,30 @@ pub fn infer_schema<R: Read + Seek, F: Fn(&[u8]) -> DataType>(
     has_header: bool,
     infer: &F,
 ) -> Result<(Vec<Field>, usize)> {
+    let (fields, _, records_count) = infer_schema_with_formats(
+        reader,
+        max_rows,
+        has_header,
+        infer,
+        &TemporalFormats::default(),
+    )?;
+    Ok((fields, records_count))
+}
+
+/// Infers the [`Field`]s of a CSV file like [`infer_schema`], inferring the temporal columns
+/// from the candidate `formats` (see [`TemporalFormats`]).
+///
+/// Also returns the chosen format of each field, to be used as
+/// [`DeserializeOptions::formats`](super::DeserializeOptions::formats), and the number
+/// of rows used to infer.
+/// Seeks back to the begining of the file _after_ the header
+pub fn infer_schema_with_formats<R: Read + Seek, F: Fn(&[u8]) -> DataType>(
+    reader: &mut Reader<R>,
+    max_rows: Option<usize>,
+    has_header: bool,
+    infer: &F,
+    formats: &TemporalFormats,
+) -> Result<(Vec<Field>, Vec<Option<String>>, usize)> {
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
     let headers: Vec<String> = if has_header {
@@ -35,6 +59,7 @@ pub fn infer_schema<R: Read + Seek, F: Fn(&[u8]) -> DataType>(
     let header_length = headers.len();
     // keep track of inferred field types
     let mut column_types: Vec<HashSet<DataType>> = vec![HashSet::new(); header_length];
+    let mut column_formats = FormatsInference::new(formats, header_length);
 
     let mut records_count = 0;
 
@@ -49,14 +74,16 @@ pub fn infer_schema<R: Read + Seek, F: Fn(&[u8]) -> DataType>(
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
                 column.insert(infer(string));
+                column_formats.update(i, string);
             }
         }
     }
 
-    let fields = merge_schema(&headers, &mut column_types);
+    let mut fields = merge_schema(&headers, &mut column_types);
+    let formats = column_formats.finish(&mut fields);
 
     // return the reader seek back to the start
     reader.seek(position)?;
 
-    Ok((fields, records_count))
+    Ok((fields, formats, records_count))
 }
@@ -7,7 +7,8 @@ pub use csv::{ByteRecord, Reader, ReaderBuilder};
 
 mod infer_schema;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
-pub use infer_schema::infer_schema;
+pub use super::read_utils::{DeserializeOptions, NullTokens};
+pub use super::utils::{infer, infer_with_boolean_tokens, BooleanTokens, TemporalFormats};
+pub use deserialize::{deserialize_batch, deserialize_column, deserialize_column_with_options};
+pub use infer_schema::{infer_schema, infer_schema_with_formats};
 pub use reader::*;
@@ -33,3 +33,63 @@ pub fn read_rows<R: Read>(
     }
     Ok(row_number)
 }
+
+/// Reads `len` rows from `reader` into `row`, skiping the first `skip`, keeping only the
+/// fields of the columns in `projection` (in the order of `projection`).
+///
+/// The fields of the rows are still split on delimiters and unquoted by `reader`, but fields of
+/// columns not in `projection` are not copied into `rows`. Thus, the column `projection[i]`
+/// is the column `i` of `rows`, e.g. to deserialize them with the projected fields and no
+/// projection in [`deserialize_batch`](super::deserialize_batch).
+/// # Errors
+/// Errors if a column of `projection` is missing from a row.
+pub fn read_rows_projected<R: Read>(
+    reader: &mut Reader<R>,
+    skip: usize,
+    rows: &mut [ByteRecord],
+    projection: &[usize],
+) -> Result<usize> {
+    let mut record = ByteRecord::new();
+    // skip first `start` rows.
+    for _ in 0..skip {
+        let res = reader.read_byte_record(&mut record);
+        if !res.unwrap_or(false) {
+            break;
+        }
+    }
+
+    let mut row_number = 0;
+    for row in rows.iter_mut() {
+        let has_more = reader.read_byte_record(&mut record).map_err(|e| {
+            ArrowError::External(format!(" at line {}", skip + row_number), Box::new(e))
+        })?;
+        if !has_more {
+            break;
+        }
+        project(&record, row, projection, skip + row_number)?;
+        row_number += 1;
+    }
+    Ok(row_number)
+}
+
+/// Copies the fields of `record` in `projection` into `row`.
+fn project(
+    record: &ByteRecord,
+    row: &mut ByteRecord,
+    projection: &[usize],
+    line: usize,
+) -> Result<()> {
+    row.clear();
+    for column in projection {
+        let field = record.get(*column).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The projected column {} is out of bounds of the {} columns at line {}",
+                column,
+                record.len(),
+                line
+            ))
+        })?;
+        row.push_field(field);
+    }
+    Ok(())
+}
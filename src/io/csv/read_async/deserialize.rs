@@ -11,7 +11,7 @@ use crate::{
 
 use super::super::read_utils::{
     deserialize_batch as deserialize_batch_gen, deserialize_column as deserialize_column_gen,
-    ByteRecordGeneric,
+    ByteRecordGeneric, DeserializeOptions,
 };
 
 impl ByteRecordGeneric for ByteRecord {
@@ -28,7 +28,30 @@ pub fn deserialize_column(
     datatype: DataType,
     line_number: usize,
 ) -> Result<Arc<dyn Array>> {
-    deserialize_column_gen(rows, column, datatype, line_number)
+    deserialize_column_gen(
+        rows,
+        column,
+        datatype,
+        line_number,
+        &DeserializeOptions::default(),
+    )
+}
+
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype` with `options`.
+///
+/// Use it with [`deserialize_batch`] via a closure that captures `options`.
+/// # Errors
+/// Besides the errors of [`deserialize_column`], this function errors iff
+/// `options.boolean_tokens` is strict and a non-empty value of a [`DataType::Boolean`] column
+/// matches none of its tokens.
+pub fn deserialize_column_with_options(
+    rows: &[ByteRecord],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+    options: &DeserializeOptions,
+) -> Result<Arc<dyn Array>> {
+    deserialize_column_gen(rows, column, datatype, line_number, options)
 }
 
 /// Deserializes rows [`ByteRecord`] into [`Chunk`].
@@ -4,7 +4,7 @@ use super::{AsyncReader, ByteRecord};
 
 use crate::datatypes::{DataType, Field};
 use crate::error::Result;
-use crate::io::csv::utils::merge_schema;
+use crate::io::csv::utils::{merge_schema, FormatsInference, TemporalFormats};
 
 use futures::{AsyncRead, AsyncSeek};
 
@@ -16,6 +16,30 @@ pub async fn infer_schema<R, F>(
     has_header: bool,
     infer: &F,
 ) -> Result<(Vec<Field>, usize)>
+where
+    R: AsyncRead + AsyncSeek + Unpin + Send + Sync,
+    F: Fn(&[u8]) -> DataType,
+{
+    let formats = TemporalFormats::default();
+    let (fields, _, records_count) =
+        infer_schema_with_formats(reader, max_rows, has_header, infer, &formats).await?;
+    Ok((fields, records_count))
+}
+
+/// Infers the [`Field`]s of a CSV file like [`infer_schema`], inferring the temporal columns
+/// from the candidate `formats` (see [`TemporalFormats`]).
+///
+/// Also returns the chosen format of each field, to be used as
+/// [`DeserializeOptions::formats`](super::DeserializeOptions::formats), and the number
+/// of rows used to infer.
+/// Seeks back to the begining of the file _after_ the header
+pub async fn infer_schema_with_formats<R, F>(
+    reader: &mut AsyncReader<R>,
+    max_rows: Option<usize>,
+    has_header: bool,
+    infer: &F,
+    formats: &TemporalFormats,
+) -> Result<(Vec<Field>, Vec<Option<String>>, usize)>
 where
     R: AsyncRead + AsyncSeek + Unpin + Send + Sync,
     F: Fn(&[u8]) -> DataType,
@@ -42,6 +66,7 @@ where
     let header_length = headers.len();
     // keep track of inferred field types
     let mut column_types: Vec<HashSet<DataType>> = vec![HashSet::new(); header_length];
+    let mut column_formats = FormatsInference::new(formats, header_length);
 
     let mut records_count = 0;
 
@@ -56,14 +81,16 @@ where
         for (i, column) in column_types.iter_mut().enumerate() {
             if let Some(string) = record.get(i) {
                 column.insert(infer(string));
+                column_formats.update(i, string);
             }
         }
     }
 
-    let fields = merge_schema(&headers, &mut column_types);
+    let mut fields = merge_schema(&headers, &mut column_types);
+    let formats = column_formats.finish(&mut fields);
 
     // return the reader seek back to the start
     reader.seek(position).await?;
 
-    Ok((fields, records_count))
+    Ok((fields, formats, records_count))
 }
@@ -7,9 +7,10 @@ mod deserialize;
 mod infer_schema;
 mod reader;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
-pub use infer_schema::infer_schema;
+pub use super::read_utils::{DeserializeOptions, NullTokens};
+pub use super::utils::{infer, infer_with_boolean_tokens, BooleanTokens, TemporalFormats};
+pub use deserialize::{deserialize_batch, deserialize_column, deserialize_column_with_options};
+pub use infer_schema::{infer_schema, infer_schema_with_formats};
 pub use reader::*;
 
 pub use csv_async::Error as CSVError;
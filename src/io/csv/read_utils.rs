@@ -1,3 +1,4 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use chrono::Datelike;
@@ -18,18 +19,74 @@ use crate::{
     types::NativeType,
 };
 
-use super::utils::RFC3339;
+use super::utils::{BooleanTokens, RFC3339};
+
+/// Per-column sets of values (e.g. `NA`, `null` or `\\N`) that are deserialized as nulls.
+///
+/// Null tokens are matched against the raw bytes of each value before it is parsed, and
+/// thus apply to every [`DataType`], including [`DataType::Utf8`] and [`DataType::Binary`]
+/// (where e.g. `NA` becomes a null, not the string `"NA"`).
+/// Independently of the null tokens, empty values in non-utf8, non-binary columns are nulls.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NullTokens {
+    tokens: HashMap<usize, HashSet<Vec<u8>>>,
+}
+
+impl NullTokens {
+    /// Returns a new [`NullTokens`] without tokens.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares `token` as a null value of the column at index `column`.
+    pub fn insert<T: Into<Vec<u8>>>(&mut self, column: usize, token: T) {
+        self.tokens.entry(column).or_default().insert(token.into());
+    }
+
+    /// Returns the null tokens of the column at index `column`, if any.
+    pub fn get(&self, column: usize) -> Option<&HashSet<Vec<u8>>> {
+        self.tokens.get(&column)
+    }
+}
+
+/// Options to deserialize the columns of a CSV file.
+///
+/// The default deserializes every value from its default representation, without null tokens.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeserializeOptions {
+    /// The values deserialized as nulls
+    pub null_tokens: NullTokens,
+    /// The chrono format of the temporal values of each column, e.g. as chosen by
+    /// `infer_schema_with_formats`. Columns without a format (`None` or beyond its length) use
+    /// their default representation. It is used for [`DataType::Date32`] and
+    /// [`DataType::Timestamp`] and ignored otherwise.
+    pub formats: Vec<Option<String>>,
+    /// The values deserialized as booleans
+    pub boolean_tokens: BooleanTokens,
+}
+
+impl DeserializeOptions {
+    fn format(&self, column: usize) -> Option<&str> {
+        self.formats.get(column).and_then(|x| x.as_deref())
+    }
+}
 
 #[inline]
 fn to_utf8(bytes: &[u8]) -> Option<&str> {
     simdutf8::basic::from_utf8(bytes).ok()
 }
 
+#[inline]
+fn is_null(bytes: &[u8], tokens: Option<&HashSet<Vec<u8>>>) -> bool {
+    tokens.map(|tokens| tokens.contains(bytes)).unwrap_or(false)
+}
+
 #[inline]
 fn deserialize_primitive<T, B: ByteRecordGeneric, F>(
     rows: &[B],
     column: usize,
     datatype: DataType,
+    tokens: Option<&HashSet<Vec<u8>>>,
     op: F,
 ) -> Arc<dyn Array>
 where
@@ -38,7 +95,7 @@ where
 {
     let iter = rows.iter().map(|row| match row.get(column) {
         Some(bytes) => {
-            if bytes.is_empty() {
+            if bytes.is_empty() || is_null(bytes, tokens) {
                 return None;
             }
             op(bytes)
@@ -93,14 +150,19 @@ fn deserialize_decimal(bytes: &[u8], precision: usize, scale: usize) -> Option<i
 }
 
 #[inline]
-fn deserialize_boolean<B, F>(rows: &[B], column: usize, op: F) -> Arc<dyn Array>
+fn deserialize_boolean<B, F>(
+    rows: &[B],
+    column: usize,
+    tokens: Option<&HashSet<Vec<u8>>>,
+    op: F,
+) -> Arc<dyn Array>
 where
     B: ByteRecordGeneric,
     F: Fn(&[u8]) -> Option<bool>,
 {
     let iter = rows.iter().map(|row| match row.get(column) {
         Some(bytes) => {
-            if bytes.is_empty() {
+            if bytes.is_empty() || is_null(bytes, tokens) {
                 return None;
             }
             op(bytes)
@@ -110,9 +172,41 @@ where
     Arc::new(BooleanArray::from_trusted_len_iter(iter))
 }
 
+/// Errors iff a non-null value of `column` is not a boolean.
+fn check_booleans<B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    line_number: usize,
+    tokens: Option<&HashSet<Vec<u8>>>,
+    boolean_tokens: &BooleanTokens,
+) -> Result<()> {
+    rows.iter()
+        .enumerate()
+        .try_for_each(|(index, row)| match row.get(column) {
+            Some(bytes)
+                if !bytes.is_empty()
+                    && !is_null(bytes, tokens)
+                    && boolean_tokens.parse(bytes).is_none() =>
+            {
+                Err(ArrowError::ExternalFormat(format!(
+                    "The value \"{}\" of column {} at line {} is not a boolean",
+                    String::from_utf8_lossy(bytes),
+                    column,
+                    line_number + index
+                )))
+            }
+            _ => Ok(()),
+        })
+}
+
 #[inline]
-fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(rows: &[B], column: usize) -> Arc<dyn Array> {
+fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    tokens: Option<&HashSet<Vec<u8>>>,
+) -> Arc<dyn Array> {
     let iter = rows.iter().map(|row| match row.get(column) {
+        Some(bytes) if is_null(bytes, tokens) => None,
         Some(bytes) => to_utf8(bytes),
         None => None,
     });
@@ -123,25 +217,31 @@ fn deserialize_utf8<O: Offset, B: ByteRecordGeneric>(rows: &[B], column: usize)
 fn deserialize_binary<O: Offset, B: ByteRecordGeneric>(
     rows: &[B],
     column: usize,
+    tokens: Option<&HashSet<Vec<u8>>>,
 ) -> Arc<dyn Array> {
-    let iter = rows.iter().map(|row| row.get(column));
+    let iter = rows
+        .iter()
+        .map(|row| row.get(column).filter(|bytes| !is_null(bytes, tokens)));
     Arc::new(BinaryArray::<O>::from_trusted_len_iter(iter))
 }
 
 #[inline]
-fn deserialize_datetime<T: chrono::TimeZone>(string: &str, tz: &T) -> Option<i64> {
+fn deserialize_datetime(string: &str, format: &str, time_unit: TimeUnit) -> Option<i64> {
     let mut parsed = chrono::format::Parsed::new();
-    let fmt = chrono::format::StrftimeItems::new(RFC3339);
-    if chrono::format::parse(&mut parsed, string, fmt).is_ok() {
-        parsed
-            .to_datetime()
-            .map(|x| x.naive_utc())
-            .map(|x| tz.from_utc_datetime(&x))
-            .map(|x| x.timestamp_nanos())
-            .ok()
-    } else {
-        None
-    }
+    let fmt = chrono::format::StrftimeItems::new(format);
+    chrono::format::parse(&mut parsed, string, fmt).ok()?;
+    parsed
+        .to_datetime()
+        .ok()
+        .and_then(|x| temporal_conversions::naive_datetime_to_timestamp(&x.naive_utc(), time_unit))
+}
+
+#[inline]
+fn deserialize_naive_datetime(string: &str, time_unit: TimeUnit) -> Option<i64> {
+    string
+        .parse::<chrono::NaiveDateTime>()
+        .ok()
+        .and_then(|x| temporal_conversions::naive_datetime_to_timestamp(&x, time_unit))
 }
 
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
@@ -150,104 +250,113 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
     rows: &[B],
     column: usize,
     datatype: DataType,
-    _line_number: usize,
+    line_number: usize,
+    options: &DeserializeOptions,
 ) -> Result<Arc<dyn Array>> {
     use DataType::*;
+    let tokens = options.null_tokens.get(column);
+    let format = options.format(column);
+    let boolean_tokens = &options.boolean_tokens;
+    if let Some(format) = format {
+        match datatype {
+            Date32 => {
+                return Ok(deserialize_primitive(
+                    rows,
+                    column,
+                    datatype,
+                    tokens,
+                    |bytes| {
+                        to_utf8(bytes)
+                            .and_then(|x| chrono::NaiveDate::parse_from_str(x, format).ok())
+                            .map(|x| {
+                                x.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE
+                            })
+                    },
+                ))
+            }
+            Timestamp(time_unit, None) => {
+                return Ok(deserialize_primitive(
+                    rows,
+                    column,
+                    datatype,
+                    tokens,
+                    |bytes| {
+                        to_utf8(bytes)
+                            .and_then(|x| chrono::NaiveDateTime::parse_from_str(x, format).ok())
+                            .and_then(|x| {
+                                temporal_conversions::naive_datetime_to_timestamp(&x, time_unit)
+                            })
+                    },
+                ))
+            }
+            _ => {}
+        }
+    }
     Ok(match datatype {
-        Boolean => deserialize_boolean(rows, column, |bytes| {
-            if bytes.eq_ignore_ascii_case(b"false") {
-                Some(false)
-            } else if bytes.eq_ignore_ascii_case(b"true") {
-                Some(true)
-            } else {
-                None
+        Boolean => {
+            if boolean_tokens.strict {
+                check_booleans(rows, column, line_number, tokens, boolean_tokens)?;
             }
-        }),
-        Int8 => deserialize_primitive(rows, column, datatype, |bytes| {
+            deserialize_boolean(rows, column, tokens, |bytes| boolean_tokens.parse(bytes))
+        }
+        Int8 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<i8>(bytes).ok()
         }),
-        Int16 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int16 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<i16>(bytes).ok()
         }),
-        Int32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int32 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<i32>(bytes).ok()
         }),
-        Int64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Int64 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<i64>(bytes).ok()
         }),
-        UInt8 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt8 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<u8>(bytes).ok()
         }),
-        UInt16 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt16 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<u16>(bytes).ok()
         }),
-        UInt32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt32 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<u32>(bytes).ok()
         }),
-        UInt64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        UInt64 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<u64>(bytes).ok()
         }),
-        Float32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Float32 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<f32>(bytes).ok()
         }),
-        Float64 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Float64 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             lexical_core::parse::<f64>(bytes).ok()
         }),
-        Date32 => deserialize_primitive(rows, column, datatype, |bytes| {
+        Date32 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
             to_utf8(bytes)
                 .and_then(|x| x.parse::<chrono::NaiveDate>().ok())
                 .map(|x| x.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE)
         }),
-        Date64 => deserialize_primitive(rows, column, datatype, |bytes| {
-            to_utf8(bytes)
-                .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                .map(|x| x.timestamp_millis())
+        Date64 => deserialize_primitive(rows, column, datatype, tokens, |bytes| {
+            to_utf8(bytes).and_then(|x| deserialize_naive_datetime(x, TimeUnit::Millisecond))
         }),
-        Timestamp(TimeUnit::Nanosecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                    .map(|x| x.timestamp_nanos())
+        Timestamp(time_unit, None) => {
+            deserialize_primitive(rows, column, datatype, tokens, |bytes| {
+                to_utf8(bytes).and_then(|x| deserialize_naive_datetime(x, time_unit))
             })
         }
-        Timestamp(TimeUnit::Microsecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                    .map(|x| x.timestamp_nanos() / 1000)
-            })
-        }
-        Timestamp(time_unit, None) => deserialize_primitive(rows, column, datatype, |bytes| {
-            to_utf8(bytes)
-                .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                .map(|x| x.timestamp_nanos())
-                .map(|x| match time_unit {
-                    TimeUnit::Second => x / 1_000_000_000,
-                    TimeUnit::Millisecond => x / 1_000_000,
-                    TimeUnit::Microsecond => x / 1_000,
-                    TimeUnit::Nanosecond => x,
-                })
-        }),
         Timestamp(time_unit, Some(ref tz)) => {
-            let tz = temporal_conversions::parse_offset(tz)?;
-            deserialize_primitive(rows, column, datatype, |bytes| {
+            // the offset of each value is parsed, but the timezone must still be valid
+            temporal_conversions::parse_offset(tz)?;
+            deserialize_primitive(rows, column, datatype, tokens, |bytes| {
                 to_utf8(bytes)
-                    .and_then(|x| deserialize_datetime(x, &tz))
-                    .map(|x| match time_unit {
-                        TimeUnit::Second => x / 1_000_000_000,
-                        TimeUnit::Millisecond => x / 1_000_000,
-                        TimeUnit::Microsecond => x / 1_000,
-                        TimeUnit::Nanosecond => x,
-                    })
+                    .and_then(|x| deserialize_datetime(x, format.unwrap_or(RFC3339), time_unit))
             })
         }
-        Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, |x| {
+        Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, tokens, |x| {
             deserialize_decimal(x, precision, scale)
         }),
-        Utf8 => deserialize_utf8::<i32, _>(rows, column),
-        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column),
-        Binary => deserialize_binary::<i32, _>(rows, column),
-        LargeBinary => deserialize_binary::<i64, _>(rows, column),
+        Utf8 => deserialize_utf8::<i32, _>(rows, column, tokens),
+        LargeUtf8 => deserialize_utf8::<i64, _>(rows, column, tokens),
+        Binary => deserialize_binary::<i32, _>(rows, column, tokens),
+        LargeBinary => deserialize_binary::<i64, _>(rows, column, tokens),
         other => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Deserializing type \"{:?}\" is not implemented",
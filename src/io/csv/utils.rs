@@ -4,6 +4,55 @@ use crate::datatypes::{DataType, Field, TimeUnit};
 
 pub(super) const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
+/// The values of boolean columns, used to infer and deserialize [`DataType::Boolean`].
+///
+/// By default, `true` and `false` (case-insensitive) are booleans and other values are nulls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BooleanTokens {
+    /// The values deserialized as `true`, e.g. `true`, `t`, `yes` or `1`
+    pub truthy: Vec<String>,
+    /// The values deserialized as `false`, e.g. `false`, `f`, `no` or `0`
+    pub falsy: Vec<String>,
+    /// Whether values must match the tokens' case
+    pub case_sensitive: bool,
+    /// Whether (non-empty) values that match no token are an error instead of a null
+    pub strict: bool,
+}
+
+impl Default for BooleanTokens {
+    fn default() -> Self {
+        Self {
+            truthy: vec!["true".to_string()],
+            falsy: vec!["false".to_string()],
+            case_sensitive: false,
+            strict: false,
+        }
+    }
+}
+
+impl BooleanTokens {
+    fn matches(&self, tokens: &[String], bytes: &[u8]) -> bool {
+        tokens.iter().any(|token| {
+            if self.case_sensitive {
+                token.as_bytes() == bytes
+            } else {
+                token.as_bytes().eq_ignore_ascii_case(bytes)
+            }
+        })
+    }
+
+    /// Returns the boolean that `bytes` represents, if any.
+    pub fn parse(&self, bytes: &[u8]) -> Option<bool> {
+        if self.matches(&self.truthy, bytes) {
+            Some(true)
+        } else if self.matches(&self.falsy, bytes) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
 fn is_boolean(bytes: &[u8]) -> bool {
     bytes.eq_ignore_ascii_case(b"true") | bytes.eq_ignore_ascii_case(b"false")
 }
@@ -28,20 +77,140 @@ fn is_naive_datetime(string: &str) -> bool {
     string.parse::<chrono::NaiveDateTime>().is_ok()
 }
 
+fn offset_to_string(offset: i32) -> String {
+    let hours = offset / 60 / 60;
+    let minutes = offset / 60 - hours * 60;
+    format!("{:03}:{:02}", hours, minutes)
+}
+
 fn is_datetime(string: &str) -> Option<String> {
     let mut parsed = chrono::format::Parsed::new();
     let fmt = chrono::format::StrftimeItems::new(RFC3339);
     if chrono::format::parse(&mut parsed, string, fmt).is_ok() {
-        parsed.offset.map(|x| {
-            let hours = x / 60 / 60;
-            let minutes = x / 60 - hours * 60;
-            format!("{:03}:{:02}", hours, minutes)
-        })
+        parsed.offset.map(offset_to_string)
     } else {
         None
     }
 }
 
+fn is_date_with_format(string: &str, format: &str) -> bool {
+    chrono::NaiveDate::parse_from_str(string, format).is_ok()
+}
+
+/// Returns whether `string` is a timestamp of `format` and, if so, its offset, if any.
+fn is_timestamp_with_format(string: &str, format: &str) -> Option<Option<String>> {
+    let mut parsed = chrono::format::Parsed::new();
+    let fmt = chrono::format::StrftimeItems::new(format);
+    chrono::format::parse(&mut parsed, string, fmt).ok()?;
+    if let Some(offset) = parsed.offset {
+        parsed.to_datetime().ok()?;
+        Some(Some(offset_to_string(offset)))
+    } else {
+        parsed.to_naive_datetime_with_offset(0).ok()?;
+        Some(None)
+    }
+}
+
+/// Candidate [chrono format strings](chrono::format::strftime) used to infer temporal
+/// columns, e.g. `"%d/%m/%Y"` or `"%Y-%m-%dT%H:%M:%S%:z"`.
+///
+/// A column is inferred as [`DataType::Date32`] (or [`DataType::Timestamp`] of milliseconds)
+/// when a format of `date` (or `timestamp`) parses every non-empty value sampled from it.
+/// Formats are tried in order, dates before timestamps, and the first that matches is chosen.
+/// Timestamps parsed with an offset (e.g. `%z`) are inferred with the offset of the first value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemporalFormats {
+    /// The candidate formats of [`DataType::Date32`]
+    pub date: Vec<String>,
+    /// The candidate formats of [`DataType::Timestamp`]
+    pub timestamp: Vec<String>,
+}
+
+/// The state of inferring the format of a column
+#[derive(Debug, Clone)]
+struct ColumnFormats {
+    // whether each candidate (dates followed by timestamps) parsed all values so far
+    candidates: Vec<bool>,
+    first_value: Option<String>,
+}
+
+/// Infers the [`TemporalFormats`] of the columns of a CSV file, one value at a time.
+#[derive(Debug, Clone)]
+pub(crate) struct FormatsInference<'a> {
+    formats: &'a TemporalFormats,
+    columns: Vec<ColumnFormats>,
+}
+
+impl<'a> FormatsInference<'a> {
+    pub fn new(formats: &'a TemporalFormats, num_columns: usize) -> Self {
+        let num_formats = formats.date.len() + formats.timestamp.len();
+        let column = ColumnFormats {
+            candidates: vec![true; num_formats],
+            first_value: None,
+        };
+        Self {
+            formats,
+            columns: vec![column; num_columns],
+        }
+    }
+
+    /// Updates the candidates of column `index` with `bytes`.
+    pub fn update(&mut self, index: usize, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let column = &mut self.columns[index];
+        let string = match simdutf8::basic::from_utf8(bytes) {
+            Ok(string) => string,
+            Err(_) => {
+                column.candidates.iter_mut().for_each(|x| *x = false);
+                return;
+            }
+        };
+        let dates = self
+            .formats
+            .date
+            .iter()
+            .map(|f| is_date_with_format(string, f));
+        let timestamps = self
+            .formats
+            .timestamp
+            .iter()
+            .map(|f| is_timestamp_with_format(string, f).is_some());
+        column
+            .candidates
+            .iter_mut()
+            .zip(dates.chain(timestamps))
+            .for_each(|(candidate, is_valid)| *candidate &= is_valid);
+        if column.first_value.is_none() {
+            column.first_value = Some(string.to_string());
+        }
+    }
+
+    /// Overrides the data type of `fields` by the one of their chosen format, if any, and
+    /// returns the chosen format of each field.
+    pub fn finish(self, fields: &mut [Field]) -> Vec<Option<String>> {
+        let formats = self.formats;
+        fields
+            .iter_mut()
+            .zip(self.columns)
+            .map(|(field, column)| {
+                let first_value = column.first_value?;
+                let index = column.candidates.iter().position(|x| *x)?;
+                Some(if index < formats.date.len() {
+                    field.data_type = DataType::Date32;
+                    formats.date[index].clone()
+                } else {
+                    let format = &formats.timestamp[index - formats.date.len()];
+                    let offset = is_timestamp_with_format(&first_value, format).unwrap();
+                    field.data_type = DataType::Timestamp(TimeUnit::Millisecond, offset);
+                    format.clone()
+                })
+            })
+            .collect()
+    }
+}
+
 /// Infers [`DataType`] from `bytes`
 /// # Implementation
 /// * case insensitive "true" or "false" are mapped to [`DataType::Boolean`]
@@ -54,6 +223,19 @@ fn is_datetime(string: &str) -> Option<String> {
 /// * other utf8 is mapped to [`DataType::Utf8`]
 /// * invalid utf8 is mapped to [`DataType::Binary`]
 pub fn infer(bytes: &[u8]) -> DataType {
+    infer_impl(bytes, is_boolean)
+}
+
+/// Infers [`DataType`] from `bytes` like [`infer`], where values of `tokens`
+/// (instead of `true` and `false`) are mapped to [`DataType::Boolean`].
+///
+/// Use it in `infer_schema` via a closure that captures `tokens`.
+pub fn infer_with_boolean_tokens(bytes: &[u8], tokens: &BooleanTokens) -> DataType {
+    infer_impl(bytes, |bytes| tokens.parse(bytes).is_some())
+}
+
+#[inline]
+fn infer_impl<F: Fn(&[u8]) -> bool>(bytes: &[u8], is_boolean: F) -> DataType {
     if is_boolean(bytes) {
         DataType::Boolean
     } else if is_integer(bytes) {
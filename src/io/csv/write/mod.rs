@@ -1,14 +1,16 @@
 //! APIs to write to CSV
 mod serialize;
+mod writer;
 
 use super::super::iterator::StreamingIterator;
 
 use std::io::Write;
 
 // re-export necessary public APIs from csv
-pub use csv::{ByteRecord, Writer, WriterBuilder};
+pub use csv::{ByteRecord, QuoteStyle, Writer, WriterBuilder};
 
 pub use serialize::*;
+pub use writer::{CsvWriter, CsvWriterOptions};
 
 use crate::array::Array;
 use crate::chunk::Chunk;
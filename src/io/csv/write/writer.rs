@@ -0,0 +1,99 @@
+use std::io::Write;
+
+use csv::{QuoteStyle, Writer, WriterBuilder};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::error::{ArrowError, Result};
+
+use super::{write_chunk, write_header, SerializeOptions};
+
+/// Options of a [`CsvWriter`]
+#[derive(Debug, Clone)]
+pub struct CsvWriterOptions {
+    /// The field delimiter. Defaults to `b','`.
+    pub delimiter: u8,
+    /// The quote character. Defaults to `b'"'`.
+    pub quote: u8,
+    /// When fields are quoted. Defaults to [`QuoteStyle::Necessary`].
+    pub quote_style: QuoteStyle,
+    /// How values are serialized
+    pub serialize: SerializeOptions,
+}
+
+impl Default for CsvWriterOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            quote_style: QuoteStyle::Necessary,
+            serialize: SerializeOptions::default(),
+        }
+    }
+}
+
+/// A writer of CSV that can be fed [`Chunk`]s incrementally.
+///
+/// The header (if any) is written once, before the first chunk, and every chunk is flushed to
+/// the underlying writer once serialized. Thus, memory usage is bounded by the size of a chunk
+/// regardless of the number of chunks written.
+pub struct CsvWriter<W: Write> {
+    writer: Writer<W>,
+    header: Option<Vec<String>>,
+    options: SerializeOptions,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Returns a new [`CsvWriter`] that writes to `writer`, with the column names `header`
+    /// (or no header if `None`).
+    pub fn new(writer: W, header: Option<Vec<String>>, options: CsvWriterOptions) -> Self {
+        let writer = WriterBuilder::new()
+            .delimiter(options.delimiter)
+            .quote(options.quote)
+            .quote_style(options.quote_style)
+            .from_writer(writer);
+        Self {
+            writer,
+            header,
+            options: options.serialize,
+        }
+    }
+
+    fn write_header(&mut self) -> Result<()> {
+        if let Some(header) = self.header.take() {
+            write_header(&mut self.writer, &header)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `chunk` to the underlying writer and flushes it, writing the header
+    /// before the first chunk.
+    /// # Errors
+    /// Errors iff the number of columns of `chunk` differs from the number of column names of
+    /// the header, or writing to the underlying writer fails.
+    pub fn write_chunk<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        if let Some(header) = &self.header {
+            if header.len() != chunk.arrays().len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The chunk has {} columns but the header has {} column names",
+                    chunk.arrays().len(),
+                    header.len()
+                )));
+            }
+        }
+        self.write_header()?;
+        write_chunk(&mut self.writer, chunk, &self.options)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Flushes any pending data, writing the header if no chunk was written,
+    /// and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.write_header()?;
+        self.writer.flush()?;
+        self.writer
+            .into_inner()
+            .map_err(|error| ArrowError::from(error.into_error()))
+    }
+}
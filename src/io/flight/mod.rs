@@ -1,4 +1,8 @@
 //! Serialization and deserialization to Arrow's flight protocol
+//!
+//! [`FlightData`] is a plain struct generated from Flight's protobuf definitions and does not
+//! depend on any gRPC implementation: use it to map these messages to the gRPC layer of your
+//! choice.
 use std::sync::Arc;
 
 use arrow_format::flight::data::{FlightData, SchemaResult};
@@ -20,21 +24,23 @@ use super::ipc::{IpcField, IpcSchema};
 
 /// Serializes [`Chunk`] to a vector of [`FlightData`] representing the serialized dictionaries
 /// and a [`FlightData`] representing the batch.
+/// # Errors
+/// This function errors iff the IPC format does not support a data type of `columns` or
+/// `fields` do not match them.
 pub fn serialize_batch(
     columns: &Chunk<Arc<dyn Array>>,
     fields: &[IpcField],
     options: &WriteOptions,
-) -> (Vec<FlightData>, FlightData) {
+) -> Result<(Vec<FlightData>, FlightData)> {
     let mut dictionary_tracker = DictionaryTracker::new(false);
 
     let (encoded_dictionaries, encoded_batch) =
-        encode_chunk(columns, fields, &mut dictionary_tracker, options)
-            .expect("DictionaryTracker configured above to not error on replacement");
+        encode_chunk(columns, fields, &mut dictionary_tracker, options)?;
 
     let flight_dictionaries = encoded_dictionaries.into_iter().map(Into::into).collect();
     let flight_batch = encoded_batch.into();
 
-    (flight_dictionaries, flight_batch)
+    Ok((flight_dictionaries, flight_batch))
 }
 
 impl From<EncodedData> for FlightData {
@@ -48,22 +54,26 @@ impl From<EncodedData> for FlightData {
 }
 
 /// Serializes a [`Schema`] to [`SchemaResult`].
+/// # Errors
+/// This function errors iff the IPC format does not support a data type of `schema`.
 pub fn serialize_schema_to_result(
     schema: &Schema,
     ipc_fields: Option<&[IpcField]>,
-) -> SchemaResult {
-    SchemaResult {
-        schema: schema_as_flatbuffer(schema, ipc_fields),
-    }
+) -> Result<SchemaResult> {
+    Ok(SchemaResult {
+        schema: schema_as_flatbuffer(schema, ipc_fields)?,
+    })
 }
 
 /// Serializes a [`Schema`] to [`FlightData`].
-pub fn serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> FlightData {
-    let data_header = schema_as_flatbuffer(schema, ipc_fields);
-    FlightData {
+/// # Errors
+/// This function errors iff the IPC format does not support a data type of `schema`.
+pub fn serialize_schema(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Result<FlightData> {
+    let data_header = schema_as_flatbuffer(schema, ipc_fields)?;
+    Ok(FlightData {
         data_header,
         ..Default::default()
-    }
+    })
 }
 
 /// Convert a [`Schema`] to bytes in the format expected in [`arrow_format::flight::data::FlightInfo`].
@@ -72,10 +82,10 @@ pub fn serialize_schema_to_info(
     ipc_fields: Option<&[IpcField]>,
 ) -> Result<Vec<u8>> {
     let encoded_data = if let Some(ipc_fields) = ipc_fields {
-        schema_as_encoded_data(schema, ipc_fields)
+        schema_as_encoded_data(schema, ipc_fields)?
     } else {
         let ipc_fields = default_ipc_fields(&schema.fields);
-        schema_as_encoded_data(schema, &ipc_fields)
+        schema_as_encoded_data(schema, &ipc_fields)?
     };
 
     let mut schema = vec![];
@@ -83,7 +93,7 @@ pub fn serialize_schema_to_info(
     Ok(schema)
 }
 
-fn schema_as_flatbuffer(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Vec<u8> {
+fn schema_as_flatbuffer(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Result<Vec<u8>> {
     if let Some(ipc_fields) = ipc_fields {
         write::schema_to_bytes(schema, ipc_fields)
     } else {
@@ -92,11 +102,11 @@ fn schema_as_flatbuffer(schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Vec
     }
 }
 
-fn schema_as_encoded_data(schema: &Schema, ipc_fields: &[IpcField]) -> EncodedData {
-    EncodedData {
-        ipc_message: write::schema_to_bytes(schema, ipc_fields),
+fn schema_as_encoded_data(schema: &Schema, ipc_fields: &[IpcField]) -> Result<EncodedData> {
+    Ok(EncodedData {
+        ipc_message: write::schema_to_bytes(schema, ipc_fields)?,
         arrow_data: vec![],
-    }
+    })
 }
 
 /// Deserialize an IPC message into [`Schema`], [`IpcSchema`].
@@ -105,33 +115,73 @@ pub fn deserialize_schemas(bytes: &[u8]) -> Result<(Schema, IpcSchema)> {
     read::deserialize_schema(bytes)
 }
 
-/// Deserializes [`FlightData`] to [`Chunk`].
-pub fn deserialize_batch(
+fn deserialize_message_header(
     data: &FlightData,
-    fields: &[Field],
-    ipc_schema: &IpcSchema,
-    dictionaries: &read::Dictionaries,
-) -> Result<Chunk<Arc<dyn Array>>> {
-    // check that the data_header is a record batch message
+) -> Result<(ipc::MessageRef<'_>, ipc::MessageHeaderRef<'_>)> {
+    // check that the data_header is a message
     let message =
         arrow_format::ipc::MessageRef::read_as_root(&data.data_header).map_err(|err| {
             ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
         })?;
 
+    let header = message
+        .header()?
+        .ok_or_else(|| ArrowError::oos("Unable to convert flight data header to a message"))?;
+    Ok((message, header))
+}
+
+/// Deserializes [`FlightData`] representing a dictionary batch (e.g. one of the dictionaries
+/// returned by [`serialize_batch`]) into `dictionaries`.
+/// # Errors
+/// This function errors if `data` is not a dictionary batch or is out of spec.
+pub fn deserialize_dictionary(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
+) -> Result<()> {
+    let (_, header) = deserialize_message_header(data)?;
+
+    let dictionary_batch = if let ipc::MessageHeaderRef::DictionaryBatch(batch) = header {
+        batch
+    } else {
+        return Err(ArrowError::oos(
+            "Unable to convert flight data header to a dictionary batch",
+        ));
+    };
+
     let mut reader = std::io::Cursor::new(&data.data_body);
+    read::read_dictionary(
+        dictionary_batch,
+        fields,
+        ipc_schema,
+        dictionaries,
+        &mut reader,
+        0,
+    )
+}
 
-    let version = message.version()?;
+/// Deserializes [`FlightData`] representing a record batch message to [`Chunk`].
+/// The dictionaries of the record batch must have been read into `dictionaries`
+/// (e.g. via [`deserialize_dictionary`]).
+pub fn deserialize_batch(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &read::Dictionaries,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let (message, header) = deserialize_message_header(data)?;
+
+    let mut reader = std::io::Cursor::new(&data.data_body);
 
-    match message.header()?.ok_or_else(|| {
-        ArrowError::oos("Unable to convert flight data header to a record batch".to_string())
-    })? {
+    match header {
         ipc::MessageHeaderRef::RecordBatch(batch) => read::read_record_batch(
             batch,
             fields,
             ipc_schema,
             None,
             dictionaries,
-            version,
+            message.version()?,
             &mut reader,
             0,
         ),
@@ -140,3 +190,24 @@ pub fn deserialize_batch(
         )),
     }
 }
+
+/// Deserializes [`FlightData`] of a stream of messages: dictionary batches are read into
+/// `dictionaries` and return `None`, and record batches are deserialized to [`Chunk`].
+/// # Errors
+/// This function errors if `data` is neither a dictionary batch nor a record batch message,
+/// or is out of spec.
+pub fn deserialize_message(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
+) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+    let (_, header) = deserialize_message_header(data)?;
+
+    match header {
+        ipc::MessageHeaderRef::DictionaryBatch(_) => {
+            deserialize_dictionary(data, fields, ipc_schema, dictionaries).map(|_| None)
+        }
+        _ => deserialize_batch(data, fields, ipc_schema, dictionaries).map(Some),
+    }
+}
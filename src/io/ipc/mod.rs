@@ -27,18 +27,17 @@
 //! read in certain order - the one they were written in (first in, first out).
 //!
 //! # Examples
-//! Read and write to a file:
+//! Read and write to a file (here an in-memory [`Cursor`](std::io::Cursor)):
 //! ```
 //! use arrow2::io::ipc::{{read::{FileReader, read_file_metadata}}, {write::{FileWriter, WriteOptions}}};
-//! # use std::fs::File;
+//! # use std::io::Cursor;
 //! # use std::sync::Arc;
 //! # use arrow2::datatypes::{Field, Schema, DataType};
 //! # use arrow2::array::{Int32Array, Array};
 //! # use arrow2::chunk::Chunk;
 //! # use arrow2::error::ArrowError;
 //! // Setup the writer
-//! let path = "example.arrow".to_string();
-//! let mut file = File::create(&path)?;
+//! let mut file = Cursor::new(vec![]);
 //! let x_coord = Field::new("x", DataType::Int32, false);
 //! let y_coord = Field::new("y", DataType::Int32, false);
 //! let schema = Schema::from(vec![x_coord, y_coord]);
@@ -59,7 +58,8 @@
 //! writer.finish();
 //!
 //! // Fetch some of the data and get the reader back
-//! let mut reader = File::open(&path)?;
+//! let mut reader = writer.into_inner();
+//! reader.set_position(0);
 //! let metadata = read_file_metadata(&mut reader)?;
 //! let mut filereader = FileReader::new(reader, metadata, None);
 //! let row1 = filereader.next().unwrap();  // [[-1, 1], [1, -1]]
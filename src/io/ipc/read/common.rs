@@ -201,7 +201,10 @@ fn first_dict_field<'a>(
 }
 
 /// Read the dictionary from the buffer and provided metadata,
-/// updating the `dictionaries` with the resulting dictionary
+/// updating the `dictionaries` with the resulting dictionary.
+///
+/// A delta dictionary batch (`isDelta`) appends its values to the existing dictionary of the
+/// same id, which must have been read before it.
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
     fields: &[Field],
@@ -210,12 +213,6 @@ pub fn read_dictionary<R: Read + Seek>(
     reader: &mut R,
     block_offset: u64,
 ) -> Result<()> {
-    if batch.is_delta()? {
-        return Err(ArrowError::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id()?;
     let (first_field, first_ipc_field) = first_dict_field(id, fields, &ipc_schema.fields)?;
 
@@ -251,11 +248,33 @@ pub fn read_dictionary<R: Read + Seek>(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    let dictionary_values = if batch.is_delta()? {
+        // a delta dictionary batch appends its values to the existing dictionary
+        let existing = dictionaries.get(&id).ok_or_else(|| {
+            ArrowError::OutOfSpec(format!(
+                "The delta dictionary batch of id {} is not preceded by a dictionary batch of the same id",
+                id
+            ))
+        })?;
+        concatenate_values(existing.as_ref(), dictionary_values.as_ref())
+    } else {
+        dictionary_values
+    };
+
     dictionaries.insert(id, dictionary_values);
 
     Ok(())
 }
 
+/// Returns the values of `existing` followed by the values of `delta`
+fn concatenate_values(existing: &dyn Array, delta: &dyn Array) -> ArrayRef {
+    let mut growable =
+        growable::make_growable(&[existing, delta], false, existing.len() + delta.len());
+    growable.extend(0, 0, existing.len());
+    growable.extend(1, 0, delta.len());
+    growable.as_arc()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,7 +9,7 @@ use arrow_format::ipc::MetadataVersion;
 
 use crate::array::*;
 use crate::datatypes::{DataType, Field, PhysicalType};
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::io::ipc::IpcField;
 
 use super::{array::*, Dictionaries};
@@ -208,6 +208,12 @@ pub fn read<R: Read + Seek>(
             version,
         )
         .map(|x| Arc::new(x) as Arc<dyn Array>),
+        Utf8View => Err(ArrowError::NotYetImplemented(
+            "Reading Utf8View arrays from IPC".to_string(),
+        )),
+        RunEndEncoded(_) => Err(ArrowError::NotYetImplemented(
+            "Reading run-end encoded arrays from IPC".to_string(),
+        )),
     }
 }
 
@@ -231,5 +237,11 @@ pub fn skip(
         Dictionary(_) => skip_dictionary(field_nodes, buffers),
         Union => skip_union(field_nodes, data_type, buffers),
         Map => skip_map(field_nodes, data_type, buffers),
+        Utf8View => Err(ArrowError::NotYetImplemented(
+            "Reading Utf8View arrays from IPC".to_string(),
+        )),
+        RunEndEncoded(_) => Err(ArrowError::NotYetImplemented(
+            "Reading run-end encoded arrays from IPC".to_string(),
+        )),
     }
 }
@@ -0,0 +1,416 @@
+//! APIs to read Arrow's IPC files from memory (e.g. a memory-mapped file) without copying
+//! the buffers of its arrays.
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow_format::ipc::planus::ReadAsRoot;
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::buffer::bytes::Bytes;
+use crate::buffer::Buffer;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::io::ipc::{IpcField, CONTINUATION_MARKER};
+use crate::types::NativeType;
+
+use super::super::endianess::is_native_little_endian;
+use super::reader::get_serialized_batch;
+use super::{Dictionaries, FileMetadata, IpcBuffer, Node};
+
+/// The memory of an IPC file and the region of the body of the message being read
+struct Body<'a, T> {
+    owner: &'a Arc<T>,
+    /// the offset of the body in `owner`
+    offset: usize,
+    bytes: &'a [u8],
+}
+
+impl<'a, T: AsRef<[u8]> + Send + Sync + 'static> Body<'a, T> {
+    /// Returns the offset (in the body) and the bytes of the next buffer
+    fn next(&self, buffers: &mut VecDeque<IpcBuffer>) -> Result<(usize, &'a [u8])> {
+        let buffer = buffers.pop_front().ok_or_else(|| {
+            ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted.")
+        })?;
+        let offset: usize = buffer
+            .offset()
+            .try_into()
+            .map_err(|_| ArrowError::oos("IPC: a buffer must have a non-negative offset"))?;
+        let length: usize = buffer
+            .length()
+            .try_into()
+            .map_err(|_| ArrowError::oos("IPC: a buffer must have a non-negative length"))?;
+        let bytes = offset
+            .checked_add(length)
+            .and_then(|end| self.bytes.get(offset..end))
+            .ok_or_else(|| {
+                ArrowError::oos("IPC: a buffer is out of the bounds of its message body")
+            })?;
+        Ok((offset, bytes))
+    }
+
+    /// Returns [`Bytes`] of `length` slots from the next buffer, sharing its memory when it is
+    /// aligned to `N`.
+    fn bytes<N: NativeType>(
+        &self,
+        buffers: &mut VecDeque<IpcBuffer>,
+        length: usize,
+    ) -> Result<Bytes<N>> {
+        let (offset, bytes) = self.next(buffers)?;
+        let num_bytes = length * std::mem::size_of::<N>();
+        let bytes = bytes.get(..num_bytes).ok_or_else(|| {
+            ArrowError::oos(format!(
+                "IPC: the buffer of {} bytes is smaller than the {} bytes required by its {} slots",
+                bytes.len(),
+                num_bytes,
+                length
+            ))
+        })?;
+        Ok(
+            Bytes::from_owner(self.owner.clone(), self.offset + offset, length).unwrap_or_else(
+                || {
+                    // unaligned buffers are copied
+                    let mut values = vec![N::default(); length];
+                    bytemuck::cast_slice_mut::<N, u8>(&mut values).copy_from_slice(bytes);
+                    values.into()
+                },
+            ),
+        )
+    }
+
+    /// Returns a [`Buffer`] of `length` slots from the next buffer.
+    fn buffer<N: NativeType>(
+        &self,
+        buffers: &mut VecDeque<IpcBuffer>,
+        length: usize,
+    ) -> Result<Buffer<N>> {
+        self.bytes(buffers, length).map(Buffer::from_bytes)
+    }
+
+    /// Returns a [`Bitmap`] of `length` bits from the next buffer.
+    fn bitmap(&self, buffers: &mut VecDeque<IpcBuffer>, length: usize) -> Result<Bitmap> {
+        let bytes = self.bytes::<u8>(buffers, length.div_ceil(8))?;
+        Ok(Bitmap::from_bytes(bytes, length))
+    }
+
+    fn validity(&self, buffers: &mut VecDeque<IpcBuffer>, node: &Node) -> Result<Option<Bitmap>> {
+        if node.null_count() > 0 {
+            Ok(Some(self.bitmap(buffers, node.length() as usize)?))
+        } else {
+            self.next(buffers)?;
+            Ok(None)
+        }
+    }
+}
+
+fn get_node<'a>(nodes: &mut VecDeque<Node<'a>>, data_type: &DataType) -> Result<Node<'a>> {
+    nodes.pop_front().ok_or_else(|| {
+        ArrowError::oos(format!(
+            "IPC: unable to fetch the field for {:?}. The file or stream is corrupted.",
+            data_type
+        ))
+    })
+}
+
+fn mmap_binary<O: Offset, T: AsRef<[u8]> + Send + Sync + 'static>(
+    body: &Body<T>,
+    nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    data_type: &DataType,
+) -> Result<(Buffer<O>, Buffer<u8>, Option<Bitmap>)> {
+    let node = get_node(nodes, data_type)?;
+    let validity = body.validity(buffers, &node)?;
+    let offsets = body.buffer::<O>(buffers, node.length() as usize + 1)?;
+    let offsets = if offsets.is_empty() {
+        // Older versions of the IPC format sometimes do not report an offset
+        vec![O::default()].into()
+    } else {
+        offsets
+    };
+    let length = offsets.as_slice()[offsets.len() - 1].to_usize();
+    let values = body.buffer::<u8>(buffers, length)?;
+    Ok((offsets, values, validity))
+}
+
+fn mmap_array<T: AsRef<[u8]> + Send + Sync + 'static>(
+    body: &Body<T>,
+    nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    data_type: DataType,
+    ipc_field: &IpcField,
+    dictionaries: &Dictionaries,
+) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Null => {
+            let node = get_node(nodes, &data_type)?;
+            Box::new(NullArray::from_data(data_type, node.length() as usize))
+        }
+        Boolean => {
+            let node = get_node(nodes, &data_type)?;
+            let validity = body.validity(buffers, &node)?;
+            let values = body.bitmap(buffers, node.length() as usize)?;
+            Box::new(BooleanArray::from_data(data_type, values, validity))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let node = get_node(nodes, &data_type)?;
+            let validity = body.validity(buffers, &node)?;
+            let values = body.buffer::<$T>(buffers, node.length() as usize)?;
+            Box::new(PrimitiveArray::<$T>::from_data(data_type, values, validity))
+        }),
+        Binary => {
+            let (offsets, values, validity) =
+                mmap_binary::<i32, _>(body, nodes, buffers, &data_type)?;
+            Box::new(BinaryArray::<i32>::from_data(
+                data_type, offsets, values, validity,
+            ))
+        }
+        LargeBinary => {
+            let (offsets, values, validity) =
+                mmap_binary::<i64, _>(body, nodes, buffers, &data_type)?;
+            Box::new(BinaryArray::<i64>::from_data(
+                data_type, offsets, values, validity,
+            ))
+        }
+        Utf8 => {
+            let (offsets, values, validity) =
+                mmap_binary::<i32, _>(body, nodes, buffers, &data_type)?;
+            Box::new(Utf8Array::<i32>::from_data(
+                data_type, offsets, values, validity,
+            ))
+        }
+        LargeUtf8 => {
+            let (offsets, values, validity) =
+                mmap_binary::<i64, _>(body, nodes, buffers, &data_type)?;
+            Box::new(Utf8Array::<i64>::from_data(
+                data_type, offsets, values, validity,
+            ))
+        }
+        FixedSizeBinary => {
+            let node = get_node(nodes, &data_type)?;
+            let validity = body.validity(buffers, &node)?;
+            let length = node.length() as usize * FixedSizeBinaryArray::get_size(&data_type);
+            let values = body.buffer::<u8>(buffers, length)?;
+            Box::new(FixedSizeBinaryArray::from_data(data_type, values, validity))
+        }
+        List => {
+            let (offsets, values, validity) =
+                mmap_list::<i32, _>(body, nodes, buffers, &data_type, ipc_field, dictionaries)?;
+            Box::new(ListArray::<i32>::from_data(
+                data_type, offsets, values, validity,
+            ))
+        }
+        LargeList => {
+            let (offsets, values, validity) =
+                mmap_list::<i64, _>(body, nodes, buffers, &data_type, ipc_field, dictionaries)?;
+            Box::new(ListArray::<i64>::from_data(
+                data_type, offsets, values, validity,
+            ))
+        }
+        FixedSizeList => {
+            let node = get_node(nodes, &data_type)?;
+            let validity = body.validity(buffers, &node)?;
+            let (field, _) = FixedSizeListArray::get_child_and_size(&data_type);
+            let values = mmap_array(
+                body,
+                nodes,
+                buffers,
+                field.data_type().clone(),
+                &ipc_field.fields[0],
+                dictionaries,
+            )?;
+            Box::new(FixedSizeListArray::from_data(
+                data_type,
+                values.into(),
+                validity,
+            ))
+        }
+        Struct => {
+            let node = get_node(nodes, &data_type)?;
+            let validity = body.validity(buffers, &node)?;
+            let values = StructArray::get_fields(&data_type)
+                .iter()
+                .zip(ipc_field.fields.iter())
+                .map(|(field, ipc_field)| {
+                    mmap_array(
+                        body,
+                        nodes,
+                        buffers,
+                        field.data_type().clone(),
+                        ipc_field,
+                        dictionaries,
+                    )
+                    .map(|x| x.into())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(StructArray::from_data(data_type, values, validity))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let id = ipc_field
+                .dictionary_id
+                .ok_or_else(|| ArrowError::oos("Dictionary has no id."))?;
+            let values = dictionaries
+                .get(&id)
+                .ok_or_else(|| ArrowError::oos(format!("Dictionary id {} not found.", id)))?
+                .clone();
+            let node = get_node(nodes, &data_type)?;
+            let validity = body.validity(buffers, &node)?;
+            let keys = body.buffer::<$T>(buffers, node.length() as usize)?;
+            let keys = PrimitiveArray::<$T>::from_data($T::PRIMITIVE.into(), keys, validity);
+            Box::new(DictionaryArray::<$T>::from_data(keys, values))
+        }),
+        Utf8View | Union | Map | RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Memory-mapping arrays of type {:?}",
+                data_type
+            )))
+        }
+    })
+}
+
+#[allow(clippy::type_complexity)]
+fn mmap_list<O: Offset, T: AsRef<[u8]> + Send + Sync + 'static>(
+    body: &Body<T>,
+    nodes: &mut VecDeque<Node>,
+    buffers: &mut VecDeque<IpcBuffer>,
+    data_type: &DataType,
+    ipc_field: &IpcField,
+    dictionaries: &Dictionaries,
+) -> Result<(Buffer<O>, Arc<dyn Array>, Option<Bitmap>)> {
+    let node = get_node(nodes, data_type)?;
+    let validity = body.validity(buffers, &node)?;
+    let offsets = body.buffer::<O>(buffers, node.length() as usize + 1)?;
+    let offsets = if offsets.is_empty() {
+        // Older versions of the IPC format sometimes do not report an offset
+        vec![O::default()].into()
+    } else {
+        offsets
+    };
+    let field = ListArray::<O>::get_child_field(data_type);
+    let values = mmap_array(
+        body,
+        nodes,
+        buffers,
+        field.data_type().clone(),
+        &ipc_field.fields[0],
+        dictionaries,
+    )?;
+    Ok((offsets, values.into(), validity))
+}
+
+/// Reads the record batch `chunk` of an IPC file whose bytes are `data` and whose metadata is
+/// `metadata`, returning arrays whose buffers share the memory of `data` (zero-copy).
+///
+/// The arrays hold a (reference-counted) reference to `data`, so that `data` (e.g. an
+/// `Arc<memmap2::Mmap>` of a file) is only released once both it and every array read from it
+/// are dropped: the arrays are `'static` and may outlive the caller's handle to `data`.
+/// However, the contents of `data` must not change while arrays read from it are alive; in
+/// particular, a memory-mapped file must not be modified (by this or any other process) while
+/// they are in use.
+///
+/// Buffers that are not aligned to their type are copied; the buffers of files written by this
+/// crate and by other Arrow implementations are aligned. The dictionaries of `metadata`, read
+/// by [`read_file_metadata`](super::read_file_metadata), are shared by the dictionary arrays.
+/// # Errors
+/// This function errors iff:
+/// * `chunk` is not a record batch of the file
+/// * the record batch is compressed or written in a different endianess than the native one
+/// * the record batch contains arrays of type `Union` or `Map`
+/// * the file is not a valid IPC file
+pub fn mmap<T: AsRef<[u8]> + Send + Sync + 'static>(
+    metadata: &FileMetadata,
+    data: Arc<T>,
+    chunk: usize,
+) -> Result<Chunk<Box<dyn Array>>> {
+    let block = metadata.blocks.get(chunk).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "The file has {} record batches but record batch {} was requested",
+            metadata.blocks.len(),
+            chunk
+        ))
+    })?;
+    let bytes = (*data).as_ref();
+
+    let offset: usize = block
+        .offset
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: a block must have a non-negative offset"))?;
+    let meta_data_length: usize = block
+        .meta_data_length
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: a block must have a non-negative metadata length"))?;
+    let body_length: usize = block
+        .body_length
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: a block must have a non-negative body length"))?;
+
+    let get = |start: usize, length: usize| {
+        start
+            .checked_add(length)
+            .and_then(|end| bytes.get(start..end))
+            .ok_or_else(|| ArrowError::oos("IPC: a block is out of the bounds of the file"))
+    };
+
+    let mut message_start = offset;
+    let mut message_length = get(message_start, 4)?;
+    if message_length == CONTINUATION_MARKER {
+        message_start += 4;
+        message_length = get(message_start, 4)?;
+    }
+    let message_length = i32::from_le_bytes(message_length.try_into().unwrap());
+    let message_length: usize = message_length
+        .try_into()
+        .map_err(|_| ArrowError::oos("IPC: a message must have a non-negative length"))?;
+    let message = get(message_start + 4, message_length)?;
+
+    let message = arrow_format::ipc::MessageRef::read_as_root(message)
+        .map_err(|err| ArrowError::oos(format!("Unable parse message: {:?}", err)))?;
+    let batch = get_serialized_batch(&message)?;
+
+    if batch.compression()?.is_some() {
+        return Err(ArrowError::NotYetImplemented(
+            "Memory-mapping compressed IPC record batches".to_string(),
+        ));
+    }
+    if metadata.ipc_schema.is_little_endian != is_native_little_endian() {
+        return Err(ArrowError::NotYetImplemented(
+            "Memory-mapping IPC files of a different endianess than the native one".to_string(),
+        ));
+    }
+
+    let body = Body {
+        owner: &data,
+        offset: offset + meta_data_length,
+        bytes: get(offset + meta_data_length, body_length)?,
+    };
+
+    let buffers = batch
+        .buffers()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain buffers"))?;
+    let mut buffers = buffers.iter().collect::<VecDeque<_>>();
+
+    let nodes = batch
+        .nodes()?
+        .ok_or_else(|| ArrowError::oos("IPC RecordBatch must contain field nodes"))?;
+    let mut nodes = nodes.iter().collect::<VecDeque<_>>();
+
+    let columns = metadata
+        .schema
+        .fields
+        .iter()
+        .zip(metadata.ipc_schema.fields.iter())
+        .map(|(field, ipc_field)| {
+            mmap_array(
+                &body,
+                &mut nodes,
+                &mut buffers,
+                field.data_type().clone(),
+                ipc_field,
+                &metadata.dictionaries,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Chunk::try_new(columns)
+}
@@ -12,6 +12,7 @@ use crate::array::Array;
 mod array;
 mod common;
 mod deserialize;
+pub mod mmap;
 mod read_basic;
 mod reader;
 mod schema;
@@ -43,6 +43,40 @@ fn read_swapped<T: NativeType, R: Read + Seek>(
     Ok(())
 }
 
+/// Decompresses the compressed IPC buffer `slice` into `out`.
+///
+/// As declared in the IPC specification, the first 8 bytes of a compressed buffer are the
+/// (little endian) length of the uncompressed buffer, or `-1` when the remaining bytes are
+/// not compressed.
+fn decompress(slice: &[u8], out: &mut [u8], compression: Compression) -> Result<()> {
+    if out.is_empty() {
+        return Ok(());
+    }
+    if slice.len() < 8 {
+        return Err(ArrowError::oos(
+            "A compressed IPC buffer must start with the 8 bytes of its uncompressed length",
+        ));
+    }
+    let (length, data) = slice.split_at(8);
+    let length = i64::from_le_bytes(length.try_into().unwrap());
+    if length == -1 {
+        if data.len() < out.len() {
+            return Err(ArrowError::oos(format!(
+                "The uncompressed IPC buffer has {} bytes but {} bytes are required",
+                data.len(),
+                out.len()
+            )));
+        }
+        out.copy_from_slice(&data[..out.len()]);
+        return Ok(());
+    }
+
+    match compression.codec()? {
+        arrow_format::ipc::CompressionType::Lz4Frame => compression::decompress_lz4(data, out),
+        arrow_format::ipc::CompressionType::Zstd => compression::decompress_zstd(data, out),
+    }
+}
+
 fn read_uncompressed_buffer<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     buffer_length: usize,
@@ -102,16 +136,8 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
 
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], out_slice)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], out_slice)?;
-            Ok(buffer)
-        }
-    }
+    decompress(&slice, out_slice, compression)?;
+    Ok(buffer)
 }
 
 pub fn read_buffer<T: NativeType, R: Read + Seek>(
@@ -175,16 +201,8 @@ fn read_compressed_bitmap<R: Read + Seek>(
     let mut slice = vec![0u8; bytes];
     reader.read_exact(&mut slice)?;
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-    }
+    decompress(&slice, &mut buffer, compression)?;
+    Ok(buffer)
 }
 
 pub fn read_bitmap<R: Read + Seek>(
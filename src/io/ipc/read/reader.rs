@@ -26,10 +26,10 @@ pub struct FileMetadata {
     /// The blocks in the file
     ///
     /// A block indicates the regions in the file to read to get data
-    blocks: Vec<arrow_format::ipc::Block>,
+    pub(super) blocks: Vec<arrow_format::ipc::Block>,
 
     /// Dictionaries associated to each dict_id
-    dictionaries: Dictionaries,
+    pub(super) dictionaries: Dictionaries,
 }
 
 /// Arrow File reader
@@ -166,7 +166,7 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     })
 }
 
-fn get_serialized_batch<'a>(
+pub(super) fn get_serialized_batch<'a>(
     message: &'a arrow_format::ipc::MessageRef,
 ) -> Result<arrow_format::ipc::RecordBatchRef<'a>> {
     let header = message.header()?.ok_or_else(|| {
@@ -227,8 +227,13 @@ pub fn read_batch<R: Read + Seek>(
 
 impl<R: Read + Seek> FileReader<R> {
     /// Creates a new [`FileReader`]. Use `projection` to only take certain columns.
+    ///
+    /// The schema of the reader and of its chunks only contains the projected fields, and only
+    /// the buffers of the projected columns are read and deserialized: the field nodes and
+    /// buffers of the other columns (including those of their children) are skipped.
     /// # Panic
     /// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+    /// or contains an index out of bounds of the fields of the schema.
     pub fn new(reader: R, metadata: FileMetadata, projection: Option<Vec<usize>>) -> Self {
         if let Some(projection) = projection.as_ref() {
             projection.windows(2).for_each(|x| {
@@ -237,6 +242,13 @@ impl<R: Read + Seek> FileReader<R> {
                     "The projection on IPC must be ordered and non-overlapping"
                 );
             });
+            if let Some(last) = projection.last() {
+                assert!(
+                    *last < metadata.schema.fields.len(),
+                    "The projection on IPC must be within the {} fields of the schema",
+                    metadata.schema.fields.len()
+                );
+            }
         }
         let projection = projection.map(|projection| {
             let fields = projection
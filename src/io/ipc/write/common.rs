@@ -60,7 +60,7 @@ fn encode_dictionary(
                     array.as_ref(),
                     options,
                     is_native_little_endian(),
-                ));
+                )?);
             };
             Ok(())
         }),
@@ -167,6 +167,12 @@ fn encode_dictionary(
                 encoded_dictionaries,
             )
         }
+        Utf8View => Err(ArrowError::NotYetImplemented(
+            "Writing Utf8View arrays to IPC".to_string(),
+        )),
+        RunEndEncoded(_) => Err(ArrowError::NotYetImplemented(
+            "Writing run-end encoded arrays to IPC".to_string(),
+        )),
     }
 }
 
@@ -188,7 +194,7 @@ pub fn encode_chunk(
         )?;
     }
 
-    let encoded_message = columns_to_bytes(columns, options);
+    let encoded_message = columns_to_bytes(columns, options)?;
 
     Ok((encoded_dictionaries, encoded_message))
 }
@@ -212,7 +218,10 @@ fn serialize_compression(
 
 /// Write [`Chunk`] into two sets of bytes, one for the header (ipc::Schema::Message) and the
 /// other for the batch's data
-fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) -> EncodedData {
+fn columns_to_bytes(
+    columns: &Chunk<Arc<dyn Array>>,
+    options: &WriteOptions,
+) -> Result<EncodedData> {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
@@ -226,7 +235,7 @@ fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) ->
             &mut offset,
             is_native_little_endian(),
             options.compression,
-        )
+        )?
     }
 
     let compression = serialize_compression(options.compression);
@@ -248,10 +257,10 @@ fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) ->
     let mut builder = Builder::new();
     let ipc_message = builder.finish(&message, None);
 
-    EncodedData {
+    Ok(EncodedData {
         ipc_message: ipc_message.to_vec(),
         arrow_data,
-    }
+    })
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
@@ -261,7 +270,7 @@ fn dictionary_batch_to_bytes(
     array: &dyn Array,
     options: &WriteOptions,
     is_little_endian: bool,
-) -> EncodedData {
+) -> Result<EncodedData> {
     let mut nodes: Vec<arrow_format::ipc::FieldNode> = vec![];
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
@@ -275,7 +284,7 @@ fn dictionary_batch_to_bytes(
         is_little_endian,
         options.compression,
         false,
-    );
+    )?;
 
     let compression = serialize_compression(options.compression);
 
@@ -300,10 +309,10 @@ fn dictionary_batch_to_bytes(
     let mut builder = Builder::new();
     let ipc_message = builder.finish(&message, None);
 
-    EncodedData {
+    Ok(EncodedData {
         ipc_message: ipc_message.to_vec(),
         arrow_data,
-    }
+    })
 }
 
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
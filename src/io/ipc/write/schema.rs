@@ -3,13 +3,16 @@ use arrow_format::ipc::planus::Builder;
 use crate::datatypes::{
     DataType, Field, IntegerType, IntervalUnit, Metadata, Schema, TimeUnit, UnionMode,
 };
+use crate::error::{ArrowError, Result};
 use crate::io::ipc::endianess::is_native_little_endian;
 
 use super::super::IpcField;
 
 /// Converts a [Schema] and [IpcField]s to a flatbuffers-encoded [arrow_format::ipc::Message].
-pub fn schema_to_bytes(schema: &Schema, ipc_fields: &[IpcField]) -> Vec<u8> {
-    let schema = serialize_schema(schema, ipc_fields);
+/// # Errors
+/// This function errors iff the IPC format does not support a data type of `schema`.
+pub fn schema_to_bytes(schema: &Schema, ipc_fields: &[IpcField]) -> Result<Vec<u8>> {
+    let schema = serialize_schema(schema, ipc_fields)?;
 
     let message = arrow_format::ipc::Message {
         version: arrow_format::ipc::MetadataVersion::V5,
@@ -19,10 +22,13 @@ pub fn schema_to_bytes(schema: &Schema, ipc_fields: &[IpcField]) -> Vec<u8> {
     };
     let mut builder = Builder::new();
     let footer_data = builder.finish(&message, None);
-    footer_data.to_vec()
+    Ok(footer_data.to_vec())
 }
 
-pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> arrow_format::ipc::Schema {
+pub fn serialize_schema(
+    schema: &Schema,
+    ipc_fields: &[IpcField],
+) -> Result<arrow_format::ipc::Schema> {
     let endianness = if is_native_little_endian() {
         arrow_format::ipc::Endianness::Little
     } else {
@@ -34,7 +40,7 @@ pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> arrow_forma
         .iter()
         .zip(ipc_fields.iter())
         .map(|(field, ipc_field)| serialize_field(field, ipc_field))
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
 
     let mut custom_metadata = vec![];
     for (key, value) in &schema.metadata {
@@ -49,12 +55,12 @@ pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> arrow_forma
         Some(custom_metadata)
     };
 
-    arrow_format::ipc::Schema {
+    Ok(arrow_format::ipc::Schema {
         endianness,
         fields: Some(fields),
         custom_metadata,
         features: None, // todo add this one
-    }
+    })
 }
 
 fn write_metadata(metadata: &Metadata, kv_vec: &mut Vec<arrow_format::ipc::KeyValue>) {
@@ -92,15 +98,18 @@ fn write_extension(
 }
 
 /// Create an IPC Field from an Arrow Field
-pub(crate) fn serialize_field(field: &Field, ipc_field: &IpcField) -> arrow_format::ipc::Field {
+pub(crate) fn serialize_field(
+    field: &Field,
+    ipc_field: &IpcField,
+) -> Result<arrow_format::ipc::Field> {
     // custom metadata.
     let mut kv_vec = vec![];
     if let DataType::Extension(name, _, metadata) = field.data_type() {
         write_extension(name, metadata, &mut kv_vec);
     }
 
-    let type_ = serialize_type(field.data_type());
-    let children = serialize_children(field.data_type(), ipc_field);
+    let type_ = serialize_type(field.data_type())?;
+    let children = serialize_children(field.data_type(), ipc_field)?;
 
     let dictionary = if let DataType::Dictionary(index_type, inner, is_ordered) = field.data_type()
     {
@@ -126,14 +135,14 @@ pub(crate) fn serialize_field(field: &Field, ipc_field: &IpcField) -> arrow_form
         None
     };
 
-    arrow_format::ipc::Field {
+    Ok(arrow_format::ipc::Field {
         name: Some(field.name.clone()),
         nullable: field.is_nullable,
         type_: Some(type_),
         dictionary: dictionary.map(Box::new),
         children: Some(children),
         custom_metadata,
-    }
+    })
 }
 
 fn serialize_time_unit(unit: &TimeUnit) -> arrow_format::ipc::TimeUnit {
@@ -145,10 +154,10 @@ fn serialize_time_unit(unit: &TimeUnit) -> arrow_format::ipc::TimeUnit {
     }
 }
 
-fn serialize_type(data_type: &DataType) -> arrow_format::ipc::Type {
+fn serialize_type(data_type: &DataType) -> Result<arrow_format::ipc::Type> {
     use arrow_format::ipc;
     use DataType::*;
-    match data_type {
+    Ok(match data_type {
         Null => ipc::Type::Null(Box::new(ipc::Null {})),
         Boolean => ipc::Type::Bool(Box::new(ipc::Bool {})),
         UInt8 => ipc::Type::Int(Box::new(ipc::Int {
@@ -197,6 +206,11 @@ fn serialize_type(data_type: &DataType) -> arrow_format::ipc::Type {
             scale: *scale as i32,
             bit_width: 128,
         })),
+        Decimal256(precision, scale) => ipc::Type::Decimal(Box::new(ipc::Decimal {
+            precision: *precision as i32,
+            scale: *scale as i32,
+            bit_width: 256,
+        })),
         Binary => ipc::Type::Binary(Box::new(ipc::Binary {})),
         LargeBinary => ipc::Type::LargeBinary(Box::new(ipc::LargeBinary {})),
         Utf8 => ipc::Type::Utf8(Box::new(ipc::Utf8 {})),
@@ -248,14 +262,27 @@ fn serialize_type(data_type: &DataType) -> arrow_format::ipc::Type {
             keys_sorted: *keys_sorted,
         })),
         Struct(_) => ipc::Type::Struct(Box::new(ipc::Struct {})),
-        Dictionary(_, v, _) => serialize_type(v),
-        Extension(_, v, _) => serialize_type(v),
-    }
+        Dictionary(_, v, _) => serialize_type(v)?,
+        Extension(_, v, _) => serialize_type(v)?,
+        Utf8View => {
+            return Err(ArrowError::NotYetImplemented(
+                "Writing Utf8View arrays to IPC".to_string(),
+            ))
+        }
+        RunEndEncoded(_, _) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Writing run-end encoded arrays to IPC".to_string(),
+            ))
+        }
+    })
 }
 
-fn serialize_children(data_type: &DataType, ipc_field: &IpcField) -> Vec<arrow_format::ipc::Field> {
+fn serialize_children(
+    data_type: &DataType,
+    ipc_field: &IpcField,
+) -> Result<Vec<arrow_format::ipc::Field>> {
     use DataType::*;
-    match data_type {
+    Ok(match data_type {
         Null
         | Boolean
         | Int8
@@ -281,18 +308,29 @@ fn serialize_children(data_type: &DataType, ipc_field: &IpcField) -> Vec<arrow_f
         | LargeBinary
         | Utf8
         | LargeUtf8
-        | Decimal(_, _) => vec![],
+        | Decimal(_, _)
+        | Decimal256(_, _) => vec![],
         FixedSizeList(inner, _) | LargeList(inner) | List(inner) | Map(inner, _) => {
-            vec![serialize_field(inner, &ipc_field.fields[0])]
+            vec![serialize_field(inner, &ipc_field.fields[0])?]
         }
         Union(fields, _, _) | Struct(fields) => fields
             .iter()
             .zip(ipc_field.fields.iter())
             .map(|(field, ipc)| serialize_field(field, ipc))
-            .collect(),
-        Dictionary(_, inner, _) => serialize_children(inner, ipc_field),
-        Extension(_, inner, _) => serialize_children(inner, ipc_field),
-    }
+            .collect::<Result<_>>()?,
+        Dictionary(_, inner, _) => serialize_children(inner, ipc_field)?,
+        Extension(_, inner, _) => serialize_children(inner, ipc_field)?,
+        Utf8View => {
+            return Err(ArrowError::NotYetImplemented(
+                "Writing Utf8View arrays to IPC".to_string(),
+            ))
+        }
+        RunEndEncoded(_, _) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Writing run-end encoded arrays to IPC".to_string(),
+            ))
+        }
+    })
 }
 
 /// Create an IPC dictionary encoding
@@ -4,6 +4,7 @@ use crate::{
     array::*,
     bitmap::Bitmap,
     datatypes::{DataType, PhysicalType},
+    error::{ArrowError, Result},
     trusted_len::TrustedLen,
     types::NativeType,
 };
@@ -210,7 +211,7 @@ fn write_list<O: Offset>(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
     let offsets = array.offsets();
     let validity = array.validity();
@@ -257,7 +258,7 @@ fn write_list<O: Offset>(
         offset,
         is_little_endian,
         compression,
-    );
+    )
 }
 
 pub fn write_struct(
@@ -268,7 +269,7 @@ pub fn write_struct(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let array = array.as_any().downcast_ref::<StructArray>().unwrap();
     write_bitmap(
         array.validity(),
@@ -278,7 +279,7 @@ pub fn write_struct(
         offset,
         compression,
     );
-    array.values().iter().for_each(|array| {
+    array.values().iter().try_for_each(|array| {
         write(
             array.as_ref(),
             buffers,
@@ -287,8 +288,8 @@ pub fn write_struct(
             offset,
             is_little_endian,
             compression,
-        );
-    });
+        )
+    })
 }
 
 pub fn write_union(
@@ -299,7 +300,7 @@ pub fn write_union(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
 
     write_buffer(
@@ -321,7 +322,7 @@ pub fn write_union(
             compression,
         );
     }
-    array.fields().iter().for_each(|array| {
+    array.fields().iter().try_for_each(|array| {
         write(
             array.as_ref(),
             buffers,
@@ -331,7 +332,7 @@ pub fn write_union(
             is_little_endian,
             compression,
         )
-    });
+    })
 }
 
 fn write_map(
@@ -342,7 +343,7 @@ fn write_map(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let array = array.as_any().downcast_ref::<MapArray>().unwrap();
     let offsets = array.offsets();
     let validity = array.validity();
@@ -389,7 +390,7 @@ fn write_map(
         offset,
         is_little_endian,
         compression,
-    );
+    )
 }
 
 fn write_fixed_size_list(
@@ -400,7 +401,7 @@ fn write_fixed_size_list(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
     write_bitmap(
         array.validity(),
@@ -418,7 +419,7 @@ fn write_fixed_size_list(
         offset,
         is_little_endian,
         compression,
-    );
+    )
 }
 
 // use `write_keys` to either write keys or values
@@ -432,7 +433,7 @@ pub fn _write_dictionary<K: DictionaryKey>(
     is_little_endian: bool,
     compression: Option<Compression>,
     write_keys: bool,
-) -> usize {
+) -> Result<usize> {
     let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
     if write_keys {
         _write_primitive(
@@ -443,7 +444,7 @@ pub fn _write_dictionary<K: DictionaryKey>(
             is_little_endian,
             compression,
         );
-        array.keys().len()
+        Ok(array.keys().len())
     } else {
         write(
             array.values().as_ref(),
@@ -453,12 +454,14 @@ pub fn _write_dictionary<K: DictionaryKey>(
             offset,
             is_little_endian,
             compression,
-        );
-        array.values().len()
+        )?;
+        Ok(array.values().len())
     }
 }
 
-/// Writes a dictionary array
+/// Writes a dictionary array, returning the number of keys or values written
+/// # Errors
+/// This function errors iff the IPC format does not support the type of its values.
 #[allow(clippy::too_many_arguments)]
 pub fn write_dictionary(
     array: &dyn Array,
@@ -469,7 +472,7 @@ pub fn write_dictionary(
     is_little_endian: bool,
     compression: Option<Compression>,
     write_keys: bool,
-) -> usize {
+) -> Result<usize> {
     match array.data_type() {
         DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
@@ -490,6 +493,8 @@ pub fn write_dictionary(
 }
 
 /// Writes an [`Array`] to `arrow_data`
+/// # Errors
+/// This function errors iff the IPC format does not support the type of `array`.
 pub fn write(
     array: &dyn Array,
     buffers: &mut Vec<ipc::Buffer>,
@@ -498,7 +503,7 @@ pub fn write(
     offset: &mut i64,
     is_little_endian: bool,
     compression: Option<Compression>,
-) {
+) -> Result<()> {
     nodes.push(ipc::FieldNode {
         length: array.len() as i64,
         null_count: array.null_count() as i64,
@@ -565,7 +570,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         LargeList => write_list::<i64>(
             array,
             buffers,
@@ -574,7 +579,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         FixedSizeList => write_fixed_size_list(
             array,
             buffers,
@@ -583,7 +588,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         Struct => write_struct(
             array,
             buffers,
@@ -592,7 +597,7 @@ pub fn write(
             offset,
             is_little_endian,
             compression,
-        ),
+        )?,
         Dictionary(_) => {
             write_dictionary(
                 array,
@@ -603,7 +608,7 @@ pub fn write(
                 is_little_endian,
                 compression,
                 true,
-            );
+            )?;
         }
         Union => {
             write_union(
@@ -614,7 +619,7 @@ pub fn write(
                 offset,
                 is_little_endian,
                 compression,
-            );
+            )?;
         }
         Map => {
             write_map(
@@ -625,9 +630,20 @@ pub fn write(
                 offset,
                 is_little_endian,
                 compression,
-            );
+            )?;
+        }
+        Utf8View => {
+            return Err(ArrowError::NotYetImplemented(
+                "Writing Utf8View arrays to IPC".to_string(),
+            ))
+        }
+        RunEndEncoded(_) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Writing run-end encoded arrays to IPC".to_string(),
+            ))
         }
     }
+    Ok(())
 }
 
 #[inline]
@@ -646,26 +662,53 @@ fn write_bytes(
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            }
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            }
-        }
+        compress(bytes, arrow_data, compression);
     } else {
         arrow_data.extend_from_slice(bytes);
         pad_buffer_to_8(arrow_data, arrow_data.len() - start);
     };
 
-    let total_len = (arrow_data.len() - start) as i64;
+    finish_buffer(start, buffers, arrow_data, offset);
+}
+
+/// Compresses `bytes` to `arrow_data` prefixed by their (uncompressed) length, as declared in
+/// the IPC specification. When compression does not reduce the size of `bytes`, they are
+/// written uncompressed, prefixed by `-1`.
+fn compress(bytes: &[u8], arrow_data: &mut Vec<u8>, compression: Compression) {
+    let start = arrow_data.len();
+    arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
+    match compression {
+        Compression::LZ4 => {
+            compression::compress_lz4(bytes, arrow_data).unwrap();
+        }
+        Compression::ZSTD => {
+            compression::compress_zstd(bytes, arrow_data).unwrap();
+        }
+    }
+    if arrow_data.len() - start - 8 >= bytes.len() {
+        arrow_data.truncate(start);
+        arrow_data.extend_from_slice(&(-1i64).to_le_bytes());
+        arrow_data.extend_from_slice(bytes);
+    }
+}
+
+/// Pushes the buffer written to `arrow_data` since `start` to `buffers`, padding `arrow_data`
+/// to a 8 byte boundary if needed. Padding added here is not part of the length of the buffer,
+/// since readers decompress compressed buffers in full.
+fn finish_buffer(
+    start: usize,
+    buffers: &mut Vec<ipc::Buffer>,
+    arrow_data: &mut Vec<u8>,
+    offset: &mut i64,
+) {
+    let length = arrow_data.len() - start;
+    pad_buffer_to_8(arrow_data, length);
+
     buffers.push(ipc::Buffer {
         offset: *offset,
-        length: total_len,
+        length: length as i64,
     });
-    *offset += total_len;
+    *offset += (arrow_data.len() - start) as i64;
 }
 
 fn write_bitmap(
@@ -715,12 +758,7 @@ fn write_buffer<T: NativeType>(
         pad_buffer_to_8(arrow_data, arrow_data.len() - start);
     };
 
-    let total_len = (arrow_data.len() - start) as i64;
-    buffers.push(ipc::Buffer {
-        offset: *offset,
-        length: total_len,
-    });
-    *offset += total_len;
+    finish_buffer(start, buffers, arrow_data, offset);
 }
 
 #[inline]
@@ -760,15 +798,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .map(|x| T::to_be_bytes(&x))
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
-    arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
-        Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
-        }
-        Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
-        }
-    }
+    compress(&swapped, arrow_data, compression);
 }
 
 fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_little_endian: bool) {
@@ -789,15 +819,7 @@ fn _write_compressed_buffer<T: NativeType>(
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            }
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            }
-        }
+        compress(bytes, arrow_data, compression);
     } else {
         todo!()
     }
@@ -822,10 +844,5 @@ fn write_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
         pad_buffer_to_8(arrow_data, arrow_data.len() - start);
     }
 
-    let total_len = (arrow_data.len() - start) as i64;
-    buffers.push(ipc::Buffer {
-        offset: *offset,
-        length: total_len,
-    });
-    *offset += total_len;
+    finish_buffer(start, buffers, arrow_data, offset);
 }
@@ -41,13 +41,13 @@ impl<W: AsyncWrite + Unpin + Send> StreamWriter<W> {
     pub async fn start(&mut self, schema: &Schema, ipc_fields: Option<&[IpcField]>) -> Result<()> {
         let encoded_message = if let Some(ipc_fields) = ipc_fields {
             EncodedData {
-                ipc_message: schema_to_bytes(schema, ipc_fields),
+                ipc_message: schema_to_bytes(schema, ipc_fields)?,
                 arrow_data: vec![],
             }
         } else {
             let ipc_fields = default_ipc_fields(&schema.fields);
             EncodedData {
-                ipc_message: schema_to_bytes(schema, &ipc_fields),
+                ipc_message: schema_to_bytes(schema, &ipc_fields)?,
                 arrow_data: vec![],
             }
         };
@@ -44,22 +44,22 @@ impl<W: Write> FileWriter<W> {
         ipc_fields: Option<Vec<IpcField>>,
         options: WriteOptions,
     ) -> Result<Self> {
-        // write magic to header
-        writer.write_all(&ARROW_MAGIC[..])?;
-        // create an 8-byte boundary after the header
-        writer.write_all(&[0, 0])?;
-        // write the schema, set the written bytes to the schema
-
         let ipc_fields = if let Some(ipc_fields) = ipc_fields {
             ipc_fields
         } else {
             default_ipc_fields(&schema.fields)
         };
         let encoded_message = EncodedData {
-            ipc_message: schema_to_bytes(schema, &ipc_fields),
+            ipc_message: schema_to_bytes(schema, &ipc_fields)?,
             arrow_data: vec![],
         };
 
+        // write magic to header
+        writer.write_all(&ARROW_MAGIC[..])?;
+        // create an 8-byte boundary after the header
+        writer.write_all(&[0, 0])?;
+        // write the schema, set the written bytes to the schema
+
         let (meta, data) = write_message(&mut writer, encoded_message)?;
         Ok(Self {
             writer,
@@ -135,7 +135,7 @@ impl<W: Write> FileWriter<W> {
         // write EOS
         write_continuation(&mut self.writer, 0)?;
 
-        let schema = schema::serialize_schema(&self.schema, &self.ipc_fields);
+        let schema = schema::serialize_schema(&self.schema, &self.ipc_fields)?;
 
         let root = arrow_format::ipc::Footer {
             version: arrow_format::ipc::MetadataVersion::V5,
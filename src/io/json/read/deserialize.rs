@@ -2,6 +2,7 @@ use std::borrow::Borrow;
 use std::hash::Hasher;
 use std::{collections::hash_map::DefaultHasher, sync::Arc};
 
+use chrono::{Datelike, NaiveDateTime};
 use hash_hasher::HashedMap;
 use indexmap::map::IndexMap as HashMap;
 use num_traits::NumCast;
@@ -11,8 +12,9 @@ use crate::{
     array::*,
     bitmap::MutableBitmap,
     chunk::Chunk,
-    datatypes::{DataType, Field, IntervalUnit},
-    error::ArrowError,
+    datatypes::{DataType, Field, IntervalUnit, Schema},
+    error::{ArrowError, Result},
+    temporal_conversions,
     types::NativeType,
 };
 
@@ -91,6 +93,40 @@ fn deserialize_float<T: NativeType + NumCast, A: Borrow<Value>>(
     PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
 }
 
+/// Parses a timestamp with an offset as declared in RFC3339 (e.g. `2021-01-01T00:00:00.123+05:00`
+/// or `2021-01-01T00:00:00Z`), returning the instant it represents in UTC.
+pub(super) fn parse_offset_datetime(string: &str) -> Option<NaiveDateTime> {
+    chrono::DateTime::parse_from_rfc3339(string)
+        .ok()
+        .map(|x| x.naive_utc())
+}
+
+/// Parses a timestamp without an offset (e.g. `2021-01-01T00:00:00.123`); the date and time may
+/// also be separated by a space.
+pub(super) fn parse_naive_datetime(string: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(string, "%Y-%m-%dT%H:%M:%S%.f")
+        .or_else(|_| NaiveDateTime::parse_from_str(string, "%Y-%m-%d %H:%M:%S%.f"))
+        .ok()
+}
+
+fn deserialize_timestamp<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> PrimitiveArray<i64> {
+    let (time_unit, has_tz) = match &data_type {
+        DataType::Timestamp(time_unit, tz) => (*time_unit, tz.is_some()),
+        _ => unreachable!(),
+    };
+    let iter = rows.iter().map(|row| match row.borrow() {
+        Value::Number(number) => number.as_i64(),
+        Value::String(string) => if has_tz {
+            parse_offset_datetime(string)
+        } else {
+            parse_naive_datetime(string)
+        }
+        .and_then(|x| temporal_conversions::naive_datetime_to_timestamp(&x, time_unit)),
+        _ => None,
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
+}
+
 fn deserialize_binary<O: Offset, A: Borrow<Value>>(rows: &[A]) -> BinaryArray<O> {
     let iter = rows.iter().map(|row| match row.borrow() {
         Value::String(v) => Some(v.as_bytes()),
@@ -147,17 +183,21 @@ fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Stru
         .map(|f| (&f.name, (f.data_type(), vec![])))
         .collect::<HashMap<_, _>>();
 
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+
     rows.iter().for_each(|row| {
         match row.borrow() {
             Value::Object(value) => {
                 values
                     .iter_mut()
                     .for_each(|(s, (_, inner))| inner.push(value.get(*s).unwrap_or(&Value::Null)));
+                validity.push(true);
             }
             _ => {
                 values
                     .iter_mut()
                     .for_each(|(_, (_, inner))| inner.push(&Value::Null));
+                validity.push(false);
             }
         };
     });
@@ -167,7 +207,7 @@ fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Stru
         .map(|(_, (data_type, values))| _deserialize(&values, data_type.clone()))
         .collect::<Vec<_>>();
 
-    StructArray::from_data(data_type, values, None)
+    StructArray::from_data(data_type, values, validity.into())
 }
 
 fn deserialize_dictionary<K: DictionaryKey, A: Borrow<Value>>(
@@ -218,11 +258,10 @@ fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Ar
         DataType::Interval(IntervalUnit::DayTime) => {
             unimplemented!("There is no natural representation of DayTime in JSON.")
         }
-        DataType::Int64
-        | DataType::Date64
-        | DataType::Time64(_)
-        | DataType::Timestamp(_, _)
-        | DataType::Duration(_) => Arc::new(deserialize_int::<i64, _>(rows, data_type)),
+        DataType::Int64 | DataType::Date64 | DataType::Time64(_) | DataType::Duration(_) => {
+            Arc::new(deserialize_int::<i64, _>(rows, data_type))
+        }
+        DataType::Timestamp(_, _) => Arc::new(deserialize_timestamp(rows, data_type)),
         DataType::UInt8 => Arc::new(deserialize_int::<u8, _>(rows, data_type)),
         DataType::UInt16 => Arc::new(deserialize_int::<u16, _>(rows, data_type)),
         DataType::UInt32 => Arc::new(deserialize_int::<u32, _>(rows, data_type)),
@@ -252,11 +291,13 @@ fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Ar
 }
 
 /// Deserializes `rows` into a [`Chunk`] according to `fields`.
+///
+/// Timestamps are deserialized from numbers (in their time unit) or from strings. Strings of
+/// timestamps with a timezone must have an offset (RFC3339) and are normalized to UTC, while
+/// strings of timestamps without a timezone must not have one. Fractions of a second finer than
+/// the time unit are truncated.
 /// This is CPU-bounded.
-pub fn deserialize<A: AsRef<str>>(
-    rows: &[A],
-    fields: &[Field],
-) -> Result<Chunk<Arc<dyn Array>>, ArrowError> {
+pub fn deserialize<A: AsRef<str>>(rows: &[A], fields: &[Field]) -> Result<Chunk<Arc<dyn Array>>> {
     let data_type = DataType::Struct(fields.to_vec());
 
     // convert rows to `Value`
@@ -266,7 +307,7 @@ pub fn deserialize<A: AsRef<str>>(
             let row: Value = serde_json::from_str(row.as_ref()).map_err(ArrowError::from)?;
             Ok(row)
         })
-        .collect::<Result<Vec<_>, ArrowError>>()?;
+        .collect::<Result<Vec<_>>>()?;
 
     let (_, columns, _) = deserialize_struct(&rows, data_type).into_data();
     Ok(Chunk::new(columns))
@@ -274,7 +315,309 @@ pub fn deserialize<A: AsRef<str>>(
 
 /// Deserializes a slice of [`Value`] to an Array of logical type [`DataType`].
 ///
-/// This function allows consuming deserialized JSON to Arrow.
+/// This function allows consuming deserialized JSON to Arrow. Values of a
+/// [`DataType::Struct`] that are not JSON objects (e.g. `null`) are deserialized as null
+/// structs.
 pub fn deserialize_json(rows: &[Value], data_type: DataType) -> Arc<dyn Array> {
     _deserialize(rows, data_type)
 }
+
+fn error(path: &str, value: &Value, data_type: &DataType) -> ArrowError {
+    ArrowError::ExternalFormat(format!(
+        "Cannot coerce the JSON value {} of field \"{}\" to {:?}",
+        value, path, data_type
+    ))
+}
+
+/// Coerces every non-null value of `rows` with `op`, erroring when `op` returns `None`.
+fn coerce_primitive<T, F>(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(&Value) -> Option<T>,
+{
+    rows.iter()
+        .map(|row| match row {
+            Value::Null => Ok(None),
+            value => op(value)
+                .map(Some)
+                .ok_or_else(|| error(path, value, &data_type)),
+        })
+        .collect::<Result<PrimitiveArray<T>>>()
+        .map(|array| array.to(data_type))
+}
+
+fn as_integer<T: NumCast>(value: &Value) -> Option<T> {
+    match value {
+        Value::Number(number) => number
+            .as_i64()
+            .and_then(num_traits::cast::<i64, T>)
+            .or_else(|| number.as_u64().and_then(num_traits::cast::<u64, T>)),
+        _ => None,
+    }
+}
+
+fn as_float<T: NumCast>(value: &Value) -> Option<T> {
+    match value {
+        Value::Number(number) => number.as_f64().and_then(num_traits::cast::<f64, T>),
+        _ => None,
+    }
+}
+
+fn coerce_int<T: NativeType + NumCast>(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+) -> Result<PrimitiveArray<T>> {
+    coerce_primitive(rows, data_type, path, as_integer::<T>)
+}
+
+fn coerce_float<T: NativeType + NumCast>(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+) -> Result<PrimitiveArray<T>> {
+    coerce_primitive(rows, data_type, path, as_float::<T>)
+}
+
+fn coerce_date32(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+    format: Option<&str>,
+) -> Result<PrimitiveArray<i32>> {
+    coerce_primitive(rows, data_type, path, |value| match value {
+        Value::String(string) => match format {
+            Some(format) => chrono::NaiveDate::parse_from_str(string, format).ok(),
+            None => string.parse::<chrono::NaiveDate>().ok(),
+        }
+        .map(|x| x.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE),
+        value => as_integer(value),
+    })
+}
+
+fn coerce_timestamp(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+    format: Option<&str>,
+) -> Result<PrimitiveArray<i64>> {
+    let (time_unit, has_tz) = match &data_type {
+        DataType::Timestamp(time_unit, tz) => (*time_unit, tz.is_some()),
+        _ => unreachable!(),
+    };
+    coerce_primitive(rows, data_type.clone(), path, |value| match value {
+        Value::String(string) => match (has_tz, format) {
+            (true, Some(format)) => chrono::DateTime::parse_from_str(string, format)
+                .ok()
+                .map(|x| x.naive_utc()),
+            (false, Some(format)) => NaiveDateTime::parse_from_str(string, format).ok(),
+            (true, None) => parse_offset_datetime(string),
+            (false, None) => parse_naive_datetime(string),
+        }
+        .and_then(|x| temporal_conversions::naive_datetime_to_timestamp(&x, time_unit)),
+        value => as_integer(value),
+    })
+}
+
+fn coerce_boolean(rows: &[&Value], path: &str) -> Result<BooleanArray> {
+    rows.iter()
+        .map(|row| match row {
+            Value::Null => Ok(None),
+            Value::Bool(v) => Ok(Some(*v)),
+            value => Err(error(path, value, &DataType::Boolean)),
+        })
+        .collect()
+}
+
+fn coerce_utf8<O: Offset>(
+    rows: &[&Value],
+    data_type: &DataType,
+    path: &str,
+) -> Result<Utf8Array<O>> {
+    rows.iter()
+        .map(|row| match row {
+            Value::Null => Ok(None),
+            Value::String(v) => Ok(Some(v.clone())),
+            Value::Number(v) => Ok(Some(v.to_string())),
+            Value::Bool(v) => Ok(Some(v.to_string())),
+            value => Err(error(path, value, data_type)),
+        })
+        .collect()
+}
+
+fn coerce_list<O: Offset>(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+    format: Option<&str>,
+) -> Result<ListArray<O>> {
+    let child = ListArray::<O>::get_child_type(&data_type);
+
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    let mut offsets = Vec::<O>::with_capacity(rows.len() + 1);
+    let mut inner = vec![];
+    let mut length = O::zero();
+    offsets.push(length);
+    for row in rows {
+        match row {
+            Value::Array(value) => {
+                inner.extend(value.iter());
+                length += O::from_usize(value.len()).ok_or(ArrowError::Overflow)?;
+                validity.push(true);
+            }
+            Value::Null => validity.push(false),
+            value => return Err(error(path, value, &data_type)),
+        }
+        offsets.push(length);
+    }
+
+    let values = coerce(&inner, child.clone(), path, format)?;
+
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        values,
+        validity.into(),
+    ))
+}
+
+/// Coerces the values of each of `fields` from `rows`, that must be JSON objects or null.
+/// Keys of the objects that are not in `fields` are ignored and missing keys are null.
+fn coerce_fields(
+    rows: &[&Value],
+    fields: &[Field],
+    path: &str,
+    format: Option<&str>,
+) -> Result<Vec<Arc<dyn Array>>> {
+    fields
+        .iter()
+        .map(|field| {
+            let values = rows
+                .iter()
+                .map(|row| match row {
+                    Value::Object(value) => value.get(&field.name).unwrap_or(&Value::Null),
+                    _ => &Value::Null,
+                })
+                .collect::<Vec<_>>();
+            let path = if path.is_empty() {
+                field.name.clone()
+            } else {
+                format!("{}.{}", path, field.name)
+            };
+            coerce(&values, field.data_type().clone(), &path, format)
+        })
+        .collect()
+}
+
+fn coerce_struct(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+    format: Option<&str>,
+) -> Result<StructArray> {
+    let fields = StructArray::get_fields(&data_type);
+
+    let validity = rows
+        .iter()
+        .map(|row| match row {
+            Value::Object(_) => Ok(true),
+            Value::Null => Ok(false),
+            value => Err(error(path, value, &data_type)),
+        })
+        .collect::<Result<MutableBitmap>>()?;
+
+    let values = coerce_fields(rows, fields, path, format)?;
+
+    Ok(StructArray::from_data(data_type, values, validity.into()))
+}
+
+fn coerce(
+    rows: &[&Value],
+    data_type: DataType,
+    path: &str,
+    format: Option<&str>,
+) -> Result<Arc<dyn Array>> {
+    use DataType::*;
+    Ok(match data_type.to_logical_type() {
+        Null => Arc::new(NullArray::from_data(data_type, rows.len())),
+        Boolean => Arc::new(coerce_boolean(rows, path)?),
+        Int8 => Arc::new(coerce_int::<i8>(rows, data_type, path)?),
+        Int16 => Arc::new(coerce_int::<i16>(rows, data_type, path)?),
+        Int32 | Time32(_) => Arc::new(coerce_int::<i32>(rows, data_type, path)?),
+        Int64 | Date64 | Time64(_) | Duration(_) => {
+            Arc::new(coerce_int::<i64>(rows, data_type, path)?)
+        }
+        UInt8 => Arc::new(coerce_int::<u8>(rows, data_type, path)?),
+        UInt16 => Arc::new(coerce_int::<u16>(rows, data_type, path)?),
+        UInt32 => Arc::new(coerce_int::<u32>(rows, data_type, path)?),
+        UInt64 => Arc::new(coerce_int::<u64>(rows, data_type, path)?),
+        Float32 => Arc::new(coerce_float::<f32>(rows, data_type, path)?),
+        Float64 => Arc::new(coerce_float::<f64>(rows, data_type, path)?),
+        Date32 => Arc::new(coerce_date32(rows, data_type, path, format)?),
+        Timestamp(_, _) => Arc::new(coerce_timestamp(rows, data_type, path, format)?),
+        Utf8 => Arc::new(coerce_utf8::<i32>(rows, &data_type, path)?),
+        LargeUtf8 => Arc::new(coerce_utf8::<i64>(rows, &data_type, path)?),
+        List(_) => Arc::new(coerce_list::<i32>(rows, data_type, path, format)?),
+        LargeList(_) => Arc::new(coerce_list::<i64>(rows, data_type, path, format)?),
+        Struct(_) => Arc::new(coerce_struct(rows, data_type, path, format)?),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Coercing JSON values of field \"{}\" to {:?}",
+                path, other
+            )))
+        }
+    })
+}
+
+/// Options to deserialize JSON rows with [`deserialize_to`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeserializeOptions {
+    /// The chrono format of dates and timestamps. When `None`, they are parsed from their default
+    /// representation.
+    pub format: Option<String>,
+}
+
+/// Deserializes `rows`, each a JSON object, into a [`Chunk`] whose columns are the fields of
+/// `schema`, coercing every value to the data type of its field.
+///
+/// Keys that are not fields of `schema` are ignored, and fields whose key is missing are null.
+/// Numbers are coerced to the integer or float width of their field, and strings are parsed
+/// into dates and timestamps with [`DeserializeOptions::format`] or, without one, from their
+/// default representation: timestamps with a timezone are parsed from RFC3339 with an offset
+/// (e.g. `2021-01-01T00:00:00+05:00`), and timestamps without from strings without an offset
+/// (e.g. `2021-01-01T00:00:00.123`). Fractions of a second finer than the time unit are
+/// truncated.
+/// This is CPU-bounded.
+/// # Errors
+/// This function errors iff a row is not a valid JSON object or a value cannot be coerced to the
+/// data type of its field (e.g. a string to an integer, a float to an integer or a timestamp
+/// that overflows its time unit).
+pub fn deserialize_to<A: AsRef<str>>(
+    rows: &[A],
+    schema: &Schema,
+    options: &DeserializeOptions,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let format = options.format.as_deref();
+    let rows = rows
+        .iter()
+        .map(|row| {
+            let row: Value = serde_json::from_str(row.as_ref())?;
+            if !row.is_object() {
+                return Err(ArrowError::ExternalFormat(format!(
+                    "Expected JSON record to be an object, found {:?}",
+                    row
+                )));
+            }
+            Ok(row)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let rows = rows.iter().collect::<Vec<_>>();
+
+    let columns = coerce_fields(&rows, &schema.fields, "", format)?;
+    Ok(Chunk::new(columns))
+}
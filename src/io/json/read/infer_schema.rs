@@ -8,6 +8,7 @@ use serde_json::Value;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
+use super::deserialize::{parse_naive_datetime, parse_offset_datetime};
 use super::iterator::ValueIter;
 
 type Tracker = HashMap<String, HashSet<DataType>>;
@@ -15,6 +16,10 @@ type Tracker = HashMap<String, HashSet<DataType>>;
 const ITEM_NAME: &str = "item";
 
 /// Infers the fields of a JSON file by reading the first `number_of_rows` rows.
+///
+/// Strings that are timestamps with an offset (RFC3339, e.g. `2021-01-01T00:00:00+05:00`) are
+/// inferred as `Timestamp(Microsecond, Some("UTC"))`, timestamps without an offset
+/// (e.g. `2021-01-01T00:00:00.123`) as `Timestamp(Microsecond, None)` and any other string as `Utf8`.
 /// # Examples
 /// ```
 /// use std::io::Cursor;
@@ -31,7 +36,38 @@ const ITEM_NAME: &str = "item";
 /// let fields = infer(&mut reader, None).unwrap();
 /// ```
 pub fn infer<R: BufRead>(reader: &mut R, number_of_rows: Option<usize>) -> Result<Vec<Field>> {
-    infer_iterator(ValueIter::new(reader, number_of_rows))
+    infer_iter(reader, number_of_rows).map(|(fields, _)| fields)
+}
+
+/// Infers the fields of a JSON file by reading at most `max_lines` (non-empty) lines of `reader`,
+/// or until the end of `reader`, merging the data types of each field as each line is read.
+///
+/// Returns the fields and the number of lines actually sampled. Only the current line is kept
+/// in memory, so this can be used to infer the schema of arbitrarily large files.
+/// The data types of a field are merged as in [`infer`]: e.g. `Int64` and `Float64` are
+/// `Float64`, and `null` values are ignored so that a field that is `null` and some string is
+/// `Utf8`. Fields are ordered by their first non-null value.
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// use arrow2::io::json::read::infer_iter;
+///
+/// let data = r#"{"a":1, "b":null}
+/// {"a":2.5, "b":"text"}
+/// {"a":"text", "b":"text"}
+/// "#;
+///
+/// let (fields, num_lines) = infer_iter(&mut Cursor::new(data), Some(2)).unwrap();
+/// assert_eq!(num_lines, 2);
+/// ```
+pub fn infer_iter<R: BufRead>(
+    reader: &mut R,
+    max_lines: Option<usize>,
+) -> Result<(Vec<Field>, usize)> {
+    let mut num_lines = 0;
+    let iter = ValueIter::new(reader, max_lines).inspect(|_| num_lines += 1);
+    let fields = infer_iterator(iter)?;
+    Ok((fields, num_lines))
 }
 
 /// Infer [`Field`]s from an iterator of [`Value`].
@@ -93,7 +129,7 @@ fn infer_value(value: &Value) -> Result<DataType> {
         Value::Array(array) => infer_array(array)?,
         Value::Null => DataType::Null,
         Value::Number(number) => infer_number(number),
-        Value::String(_) => DataType::Utf8,
+        Value::String(string) => infer_string(string),
         Value::Object(inner) => {
             let fields = inner
                 .iter()
@@ -111,7 +147,7 @@ pub fn infer_rows(rows: &[Value]) -> Result<DataType> {
             Value::Null => None,
             Value::Number(n) => Some(infer_number(n)),
             Value::Bool(_) => Some(DataType::Boolean),
-            Value::String(_) => Some(DataType::Utf8),
+            Value::String(string) => Some(infer_string(string)),
             Value::Array(array) => Some(infer_array(array)?),
             Value::Object(inner) => {
                 let fields = inner
@@ -157,6 +193,21 @@ fn infer_number(n: &serde_json::Number) -> DataType {
     }
 }
 
+/// Infers the [`DataType`] of a JSON string:
+/// * timestamps with an offset (RFC3339, e.g. `2021-01-01T00:00:00+05:00`) are
+///   `Timestamp(Microsecond, Some("UTC"))`
+/// * timestamps without an offset (e.g. `2021-01-01T00:00:00.123`) are `Timestamp(Microsecond, None)`
+/// * all other strings are `Utf8`
+fn infer_string(string: &str) -> DataType {
+    if parse_offset_datetime(string).is_some() {
+        DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string()))
+    } else if parse_naive_datetime(string).is_some() {
+        DataType::Timestamp(TimeUnit::Microsecond, None)
+    } else {
+        DataType::Utf8
+    }
+}
+
 fn add_or_insert(values: &mut Tracker, key: &str, data_type: DataType) {
     if data_type == DataType::Null {
         return;
@@ -116,15 +116,17 @@ fn list_serializer<'a, O: Offset>(
     // ]
     //
     let mut serializer = new_serializer(array.values().as_ref());
+    // the offsets of sliced arrays do not start at the first value
+    let start = array.offsets()[0].to_usize();
+    for _ in 0..start {
+        serializer.next();
+    }
 
     Box::new(BufStreamingIterator::new(
-        zip_validity(
-            array.offsets().windows(2),
-            array.validity().map(|x| x.iter()),
-        ),
-        move |offset, buf| {
-            if let Some(offset) = offset {
-                let length = (offset[1] - offset[0]).to_usize();
+        array.offsets().windows(2).enumerate(),
+        move |(index, offset), buf| {
+            let length = (offset[1] - offset[0]).to_usize();
+            if array.is_valid(index) {
                 buf.push(b'[');
                 let mut is_first_row = true;
                 for _ in 0..length {
@@ -136,6 +138,10 @@ fn list_serializer<'a, O: Offset>(
                 }
                 buf.push(b']');
             } else {
+                // null lists may still span values, which are not serialized
+                for _ in 0..length {
+                    serializer.next();
+                }
                 buf.extend(b"null");
             }
         },
@@ -186,7 +192,7 @@ where
     ))
 }
 
-fn new_serializer<'a>(
+pub(crate) fn new_serializer<'a>(
     array: &'a dyn Array,
 ) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
     match array.data_type().to_logical_type() {
@@ -11,7 +11,7 @@ use crate::{
     datatypes::{DataType, PhysicalType, PrimitiveType, Schema},
     error::{ArrowError, Result},
     io::ipc::IpcField,
-    types::{days_ms, months_days_ns, NativeType},
+    types::{days_ms, i256, months_days_ns, NativeType},
 };
 
 use super::super::{ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch};
@@ -126,6 +126,39 @@ fn to_decimal(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray
     PrimitiveArray::<i128>::from_data(data_type, values, validity)
 }
 
+fn to_decimal256(json_col: &ArrowJsonColumn, data_type: DataType) -> PrimitiveArray<i256> {
+    let validity = to_validity(&json_col.validity);
+    let values = json_col
+        .data
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|value| match value {
+            Value::String(x) => {
+                let (negative, digits) = match x.strip_prefix('-') {
+                    Some(digits) => (true, digits),
+                    None => (false, x.as_str()),
+                };
+                // accumulate with the sign of the number, so that `i256::MIN` does not overflow
+                digits.bytes().fold(i256::ZERO, |acc, digit| {
+                    let digit = i256::from((digit - b'0') as i128);
+                    let acc = acc * i256::from(10);
+                    if negative {
+                        acc - digit
+                    } else {
+                        acc + digit
+                    }
+                })
+            }
+            _ => {
+                panic!()
+            }
+        })
+        .collect();
+
+    PrimitiveArray::<i256>::from_data(data_type, values, validity)
+}
+
 fn to_primitive<T: NativeType + NumCast>(
     json_col: &ArrowJsonColumn,
     data_type: DataType,
@@ -287,6 +320,7 @@ pub fn to_array(
         Primitive(PrimitiveType::Int32) => Ok(Arc::new(to_primitive::<i32>(json_col, data_type))),
         Primitive(PrimitiveType::Int64) => Ok(Arc::new(to_primitive::<i64>(json_col, data_type))),
         Primitive(PrimitiveType::Int128) => Ok(Arc::new(to_decimal(json_col, data_type))),
+        Primitive(PrimitiveType::Int256) => Ok(Arc::new(to_decimal256(json_col, data_type))),
         Primitive(PrimitiveType::DaysMs) => Ok(Arc::new(to_primitive_days_ms(json_col, data_type))),
         Primitive(PrimitiveType::MonthDayNano) => {
             Ok(Arc::new(to_primitive_months_days_ns(json_col, data_type)))
@@ -407,6 +441,12 @@ pub fn to_array(
             Ok(Arc::new(array))
         }
         Map => to_map(json_col, data_type, field, dictionaries),
+        Utf8View => Err(ArrowError::NotYetImplemented(
+            "Reading Utf8View arrays from the JSON integration format".to_string(),
+        )),
+        RunEndEncoded(_) => Err(ArrowError::NotYetImplemented(
+            "Reading run-end encoded arrays from the JSON integration format".to_string(),
+        )),
     }
 }
 
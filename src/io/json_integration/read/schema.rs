@@ -187,7 +187,12 @@ fn to_data_type(item: &Value, mut children: Vec<Field>) -> Result<DataType> {
                 )),
             };
 
-            DataType::Decimal(precision?, scale?)
+            match item.get("bitWidth") {
+                Some(Value::Number(bit_width)) if bit_width.as_u64() == Some(256) => {
+                    DataType::Decimal256(precision?, scale?)
+                }
+                _ => DataType::Decimal(precision?, scale?),
+            }
         }
         "floatingpoint" => match item.get("precision") {
             Some(p) if p == "HALF" => DataType::Float16,
@@ -360,13 +365,18 @@ fn deserialize_field(value: &Value) -> Result<Field> {
         }
     };
 
-    let metadata = if let Some(metadata) = map.get("metadata") {
+    let mut metadata = if let Some(metadata) = map.get("metadata") {
         read_metadata(metadata)?
     } else {
         Metadata::default()
     };
 
     let extension = get_extension(&metadata);
+    if extension.is_some() {
+        // the extension is part of the `DataType` and is not field metadata
+        metadata.remove("ARROW:extension:name");
+        metadata.remove("ARROW:extension:metadata");
+    }
 
     let type_ = map
         .get("type")
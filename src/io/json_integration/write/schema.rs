@@ -23,6 +23,7 @@ fn serialize_data_type(data_type: &DataType) -> Value {
         DataType::Float64 => json!({"name": "floatingpoint", "precision": "DOUBLE"}),
         DataType::Utf8 => json!({"name": "utf8"}),
         DataType::LargeUtf8 => json!({"name": "largeutf8"}),
+        DataType::Utf8View => json!({"name": "utf8view"}),
         DataType::Binary => json!({"name": "binary"}),
         DataType::LargeBinary => json!({"name": "largebinary"}),
         DataType::FixedSizeBinary(byte_width) => {
@@ -85,16 +86,25 @@ fn serialize_data_type(data_type: &DataType) -> Value {
             TimeUnit::Microsecond => "MICROSECOND",
             TimeUnit::Nanosecond => "NANOSECOND",
         }}),
-        DataType::Dictionary(_, _, _) => json!({ "name": "dictionary"}),
+        // the type of a dictionary-encoded field is the type of its values
+        DataType::Dictionary(_, values, _) => serialize_data_type(values),
         DataType::Decimal(precision, scale) => {
             json!({"name": "decimal", "precision": precision, "scale": scale})
         }
+        DataType::Decimal256(precision, scale) => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 256})
+        }
         DataType::Extension(_, inner_data_type, _) => serialize_data_type(inner_data_type),
+        DataType::RunEndEncoded(_, _) => json!({"name": "runendencoded"}),
     }
 }
 
 fn serialize_field(field: &Field, ipc_field: &IpcField) -> ArrowJsonField {
-    let children = match field.data_type() {
+    let data_type = match field.data_type() {
+        DataType::Dictionary(_, values, _) => values.as_ref(),
+        data_type => data_type,
+    };
+    let children = match data_type.to_logical_type() {
         DataType::Union(fields, ..) | DataType::Struct(fields) => fields
             .iter()
             .zip(ipc_field.fields.iter())
@@ -108,7 +118,24 @@ fn serialize_field(field: &Field, ipc_field: &IpcField) -> ArrowJsonField {
         }
         _ => vec![],
     };
-    let metadata = serialize_metadata(&field.metadata);
+    let extension = match field.data_type() {
+        DataType::Dictionary(_, inner, _) => inner.as_ref(),
+        data_type => data_type,
+    };
+    let metadata = if let DataType::Extension(name, _, extension_metadata) = extension {
+        // the extension is written as field metadata
+        let mut metadata = field.metadata.clone();
+        if let Some(extension_metadata) = extension_metadata {
+            metadata.insert(
+                "ARROW:extension:metadata".to_string(),
+                extension_metadata.clone(),
+            );
+        }
+        metadata.insert("ARROW:extension:name".to_string(), name.clone());
+        serialize_metadata(&metadata)
+    } else {
+        serialize_metadata(&field.metadata)
+    };
 
     let dictionary = if let DataType::Dictionary(key_type, _, is_ordered) = field.data_type() {
         use crate::datatypes::IntegerType::*;
@@ -152,7 +179,7 @@ pub fn serialize_schema(schema: &Schema, ipc_fields: &[IpcField]) -> ArrowJsonSc
             .zip(ipc_fields.iter())
             .map(|(field, ipc_field)| serialize_field(field, ipc_field))
             .collect(),
-        metadata: Some(serde_json::to_value(&schema.metadata).unwrap()),
+        metadata: serialize_metadata(&schema.metadata),
     }
 }
 
@@ -161,7 +188,8 @@ fn serialize_metadata(metadata: &Metadata) -> Option<Value> {
         .iter()
         .map(|(k, v)| {
             let mut kv_map = Map::new();
-            kv_map.insert(k.clone(), Value::String(v.clone()));
+            kv_map.insert("key".to_string(), Value::String(k.clone()));
+            kv_map.insert("value".to_string(), Value::String(v.clone()));
             Value::Object(kv_map)
         })
         .collect::<Vec<_>>();
@@ -1,6 +1,6 @@
 #![forbid(unsafe_code)]
 //! Contains modules to interface with other formats such as [`csv`],
-//! [`parquet`], [`json`], [`ipc`], [`mod@print`] and [`avro`].
+//! [`parquet`], [`json`], [`ipc`], [`mod@print`], [`avro`] and [`orc`].
 #[cfg(any(
     feature = "io_csv_read",
     feature = "io_csv_read_async",
@@ -20,6 +20,10 @@ pub mod csv;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_json")))]
 pub mod json;
 
+#[cfg(feature = "io_json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_json")))]
+pub mod ndjson;
+
 #[cfg(feature = "io_ipc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_ipc")))]
 pub mod ipc;
@@ -40,6 +44,10 @@ pub mod parquet;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_avro")))]
 pub mod avro;
 
+#[cfg(feature = "io_orc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_orc")))]
+pub mod orc;
+
 #[cfg(feature = "io_print")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_print")))]
 pub mod print;
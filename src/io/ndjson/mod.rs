@@ -0,0 +1,2 @@
+//! APIs to write to newline-delimited JSON (NDJSON), where every line is a JSON object.
+pub mod write;
@@ -0,0 +1,96 @@
+//! APIs to write to NDJSON
+use std::io::Write;
+
+use crate::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema,
+    error::{ArrowError, Result},
+    io::json::write::new_serializer,
+};
+
+/// A writer of NDJSON that can be fed [`Chunk`]s incrementally.
+///
+/// Every row of a chunk is written to the underlying writer as a JSON object terminated by
+/// `\n`, whose keys are the field names of the schema. Null values are written as `null` and nested
+/// types (structs and lists) are written recursively.
+///
+/// Rows are serialized to a scratch buffer that is reused across rows and chunks, so memory
+/// usage does not depend on the number of rows written.
+pub struct NdJsonWriter<W: Write> {
+    writer: W,
+    schema: Schema,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> NdJsonWriter<W> {
+    /// Returns a new [`NdJsonWriter`] writing chunks of `schema` to `writer`.
+    pub fn new(writer: W, schema: Schema) -> Self {
+        Self {
+            writer,
+            schema,
+            buffer: vec![],
+        }
+    }
+
+    /// Returns the [`Schema`] of the chunks written by this writer.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Writes every row of `chunk` to the underlying writer.
+    /// # Errors
+    /// Errors iff the arrays of `chunk` do not match the fields of the schema (by number or data
+    /// type), or writing to the underlying writer fails.
+    pub fn write_chunk<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        let fields = &self.schema.fields;
+        if chunk.arrays().len() != fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunk has {} columns but the schema has {} fields",
+                chunk.arrays().len(),
+                fields.len()
+            )));
+        }
+        if let Some((field, array)) = fields
+            .iter()
+            .zip(chunk.arrays())
+            .find(|(field, array)| field.data_type() != array.as_ref().data_type())
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" has data type {:?} but its array has data type {:?}",
+                field.name,
+                field.data_type(),
+                array.as_ref().data_type()
+            )));
+        }
+
+        let mut serializers = chunk
+            .arrays()
+            .iter()
+            .map(|array| new_serializer(array.as_ref()))
+            .collect::<Vec<_>>();
+
+        for _ in 0..chunk.len() {
+            self.buffer.clear();
+            self.buffer.push(b'{');
+            for (index, (serializer, field)) in serializers.iter_mut().zip(fields).enumerate() {
+                if index > 0 {
+                    self.buffer.push(b',');
+                }
+                serde_json::to_writer(&mut self.buffer, &field.name)?;
+                self.buffer.push(b':');
+                // `unwrap` is infallible because `array.len()` equals `len` on `Chunk`
+                self.buffer.extend_from_slice(serializer.next().unwrap());
+            }
+            self.buffer.extend_from_slice(b"}\n");
+            self.writer.write_all(&self.buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the underlying writer and returns it.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
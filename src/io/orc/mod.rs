@@ -0,0 +1,12 @@
+//! APIs to read from [ORC format](https://orc.apache.org).
+pub mod read;
+
+pub use orc_format as format;
+
+use crate::error::ArrowError;
+
+impl From<format::error::Error> for ArrowError {
+    fn from(error: format::error::Error) -> Self {
+        ArrowError::ExternalFormat(format!("{:?}", error))
+    }
+}
@@ -0,0 +1,426 @@
+//! APIs to read from [ORC format](https://orc.apache.org).
+//!
+//! Reading a column of a stripe is composed by:
+//! 1. read the file's metadata ([`read_metadata`]) and infer its schema ([`infer_schema`])
+//! 2. read the footer of the stripe ([`read_stripe_footer`])
+//! 3. read the column of the stripe ([`read_stripe_column`])
+//! 4. deserialize the column into an [`Array`] ([`deserialize`])
+//!
+//! Only uncompressed and ZLIB-compressed files are supported. Integer columns are only supported
+//! with the `DIRECT_V2` encoding (run length encoding v2), and byte columns with the `DIRECT`
+//! encoding (byte run length encoding).
+use std::io::Read;
+use std::sync::Arc;
+
+use crate::array::{Array, BinaryArray, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::buffer::Buffer;
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use orc_format::error::Error as OrcError;
+use orc_format::proto::column_encoding::Kind as ColumnEncodingKind;
+use orc_format::proto::stream::Kind;
+use orc_format::proto::{Footer, Type};
+use orc_format::read::decode;
+pub use orc_format::read::{
+    read_metadata, read_stripe_column, read_stripe_footer, Column, FileMetadata,
+};
+
+/// Infers a [`Schema`] from the files' [`Footer`].
+/// # Errors
+/// This function errors if the type is not yet supported.
+pub fn infer_schema(footer: &Footer) -> Result<Schema> {
+    let types = &footer.types;
+
+    let root = types
+        .first()
+        .ok_or_else(|| ArrowError::oos("ORC: the footer has no types"))?;
+
+    match infer_dt(root, types)? {
+        DataType::Struct(fields) => Ok(fields.into()),
+        _ => Err(ArrowError::oos("ORC: the root type must be a struct")),
+    }
+}
+
+fn infer_dt(type_: &Type, types: &[Type]) -> Result<DataType> {
+    use orc_format::proto::r#type::Kind;
+    let dt = match type_.kind() {
+        Kind::Boolean => DataType::Boolean,
+        Kind::Byte => DataType::Int8,
+        Kind::Short => DataType::Int16,
+        Kind::Int => DataType::Int32,
+        Kind::Long => DataType::Int64,
+        Kind::Float => DataType::Float32,
+        Kind::Double => DataType::Float64,
+        Kind::String | Kind::Varchar | Kind::Char => DataType::Utf8,
+        Kind::Binary => DataType::Binary,
+        Kind::Struct => {
+            let fields = type_
+                .subtypes
+                .iter()
+                .zip(type_.field_names.iter())
+                .map(|(index, name)| {
+                    let type_ = types.get(*index as usize).ok_or_else(|| {
+                        ArrowError::oos(format!("ORC: the type {} does not exist", index))
+                    })?;
+                    infer_dt(type_, types).map(|dt| Field::new(name, dt, true))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            DataType::Struct(fields)
+        }
+        kind => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Reading {:?} from ORC",
+                kind
+            )))
+        }
+    };
+    Ok(dt)
+}
+
+fn deserialize_validity(column: &Column, scratch: &mut Vec<u8>) -> Result<Option<Bitmap>> {
+    let mut stream = match column.get_stream(Kind::Present, std::mem::take(scratch)) {
+        Ok(stream) => stream,
+        // columns without nulls have no present stream
+        Err(OrcError::InvalidKind(_, _)) => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+
+    let mut validity = MutableBitmap::with_capacity(column.number_of_rows());
+    for item in decode::BooleanIter::new(&mut stream, column.number_of_rows()) {
+        validity.push(item?)
+    }
+    if validity.len() != column.number_of_rows() {
+        return Err(ArrowError::oos(
+            "ORC: the present stream has fewer values than the number of rows",
+        ));
+    }
+
+    *scratch = stream.into_inner();
+
+    Ok(validity.into())
+}
+
+/// Returns the number of values of a column, i.e. its number of non-null rows
+fn number_of_values(column: &Column, validity: Option<&Bitmap>) -> usize {
+    validity
+        .map(|validity| validity.len() - validity.null_count())
+        .unwrap_or_else(|| column.number_of_rows())
+}
+
+/// Calls `op` with the next item of `values` for every valid row and with `None`
+/// for every null row.
+fn for_each_row<T, I, F>(
+    column: &Column,
+    validity: Option<&Bitmap>,
+    mut values: I,
+    mut op: F,
+) -> Result<()>
+where
+    I: Iterator<Item = std::result::Result<T, OrcError>>,
+    F: FnMut(Option<T>) -> Result<()>,
+{
+    let mut next = || {
+        values.next().transpose()?.ok_or_else(|| {
+            ArrowError::oos("ORC: a column has fewer values than its number of rows")
+        })
+    };
+    match validity {
+        Some(validity) => validity.iter().try_for_each(|is_valid| {
+            let value = if is_valid { Some(next()?) } else { None };
+            op(value)
+        }),
+        None => (0..column.number_of_rows()).try_for_each(|_| op(Some(next()?))),
+    }
+}
+
+fn check_encoding(column: &Column, kinds: &[ColumnEncodingKind]) -> Result<ColumnEncodingKind> {
+    let kind = column.encoding().kind();
+    if kinds.contains(&kind) {
+        Ok(kind)
+    } else {
+        Err(ArrowError::NotYetImplemented(format!(
+            "Reading ORC columns encoded with {:?}",
+            kind
+        )))
+    }
+}
+
+fn deserialize_bool(data_type: DataType, column: &Column) -> Result<BooleanArray> {
+    let mut scratch = vec![];
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_of_values = number_of_values(column, validity.as_ref());
+
+    let mut stream = column.get_stream(Kind::Data, scratch)?;
+    let iter = decode::BooleanIter::new(&mut stream, num_of_values);
+
+    let mut values = MutableBitmap::with_capacity(column.number_of_rows());
+    for_each_row(column, validity.as_ref(), iter, |value| {
+        values.push(value.unwrap_or_default());
+        Ok(())
+    })?;
+
+    Ok(BooleanArray::from_data(data_type, values.into(), validity))
+}
+
+fn deserialize_float<T: NativeType + decode::Float>(
+    data_type: DataType,
+    column: &Column,
+) -> Result<PrimitiveArray<T>> {
+    let mut scratch = vec![];
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_of_values = number_of_values(column, validity.as_ref());
+
+    let mut stream = column.get_stream(Kind::Data, scratch)?;
+    let iter = decode::FloatIter::<T, _>::new(&mut stream, num_of_values);
+
+    let mut values = Vec::with_capacity(column.number_of_rows());
+    for_each_row(column, validity.as_ref(), iter, |value| {
+        values.push(value.unwrap_or_default());
+        Ok(())
+    })?;
+
+    Ok(PrimitiveArray::from_data(
+        data_type,
+        values.into(),
+        validity,
+    ))
+}
+
+/// Decodes `length` values encoded with ORC's byte run length encoding from `data`
+fn decode_byte_rle(data: &[u8], length: usize) -> Result<Vec<u8>> {
+    let mut values = Vec::with_capacity(length);
+    let mut data = data;
+    while values.len() < length {
+        let (header, remaining) = data
+            .split_first()
+            .ok_or_else(|| ArrowError::oos("ORC: a byte stream has fewer values than expected"))?;
+        let header = *header as i8;
+        if header >= 0 {
+            // a run of `header + 3` repeated values
+            let (value, remaining) = remaining.split_first().ok_or_else(|| {
+                ArrowError::oos("ORC: a byte stream has fewer values than expected")
+            })?;
+            values.resize(values.len() + header as usize + 3, *value);
+            data = remaining;
+        } else {
+            // `-header` literal values
+            let run = -(header as isize) as usize;
+            if remaining.len() < run {
+                return Err(ArrowError::oos(
+                    "ORC: a byte stream has fewer values than expected",
+                ));
+            }
+            values.extend_from_slice(&remaining[..run]);
+            data = &remaining[run..];
+        }
+    }
+    values.truncate(length);
+    Ok(values)
+}
+
+fn deserialize_byte(data_type: DataType, column: &Column) -> Result<PrimitiveArray<i8>> {
+    check_encoding(column, &[ColumnEncodingKind::Direct])?;
+
+    let mut scratch = vec![];
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_of_values = number_of_values(column, validity.as_ref());
+
+    let mut stream = column.get_stream(Kind::Data, scratch)?;
+    let mut data = vec![];
+    stream.read_to_end(&mut data)?;
+    let iter = decode_byte_rle(&data, num_of_values)?
+        .into_iter()
+        .map(|x| Ok(x as i8));
+
+    let mut values = Vec::with_capacity(column.number_of_rows());
+    for_each_row(column, validity.as_ref(), iter, |value| {
+        values.push(value.unwrap_or_default());
+        Ok(())
+    })?;
+
+    Ok(PrimitiveArray::from_data(
+        data_type,
+        values.into(),
+        validity,
+    ))
+}
+
+fn deserialize_int<T: NativeType + TryFrom<i64>>(
+    data_type: DataType,
+    column: &Column,
+) -> Result<PrimitiveArray<T>> {
+    check_encoding(column, &[ColumnEncodingKind::DirectV2])?;
+
+    let mut scratch = vec![];
+    let validity = deserialize_validity(column, &mut scratch)?;
+    let num_of_values = number_of_values(column, validity.as_ref());
+
+    let mut stream = column.get_stream(Kind::Data, scratch)?;
+    let iter = decode::SignedRleV2Iter::new(&mut stream, num_of_values, vec![]);
+
+    let mut values = Vec::with_capacity(column.number_of_rows());
+    for_each_row(column, validity.as_ref(), iter, |value| {
+        let value = value
+            .map(|value| {
+                T::try_from(value).map_err(|_| {
+                    ArrowError::oos(format!(
+                        "ORC: the value {} does not fit in {:?}",
+                        value, data_type
+                    ))
+                })
+            })
+            .transpose()?;
+        values.push(value.unwrap_or_default());
+        Ok(())
+    })?;
+
+    Ok(PrimitiveArray::from_data(
+        data_type,
+        values.into(),
+        validity,
+    ))
+}
+
+/// Deserializes the lengths and values of a dictionary-encoded column's dictionary
+fn deserialize_dictionary(column: &Column) -> Result<(Vec<usize>, Vec<u8>)> {
+    let size = column.dictionary_size().unwrap_or_default();
+
+    let mut lengths = column.get_stream(Kind::Length, vec![])?;
+    let lengths = decode::UnsignedRleV2Iter::new(&mut lengths, size, vec![]);
+
+    let mut data = vec![];
+    column
+        .get_stream(Kind::DictionaryData, vec![])?
+        .read_to_end(&mut data)?;
+
+    let mut offsets = Vec::with_capacity(size + 1);
+    offsets.push(0);
+    for length in lengths {
+        let end = *offsets.last().unwrap() + length? as usize;
+        offsets.push(end);
+    }
+    if offsets.len() != size + 1 || *offsets.last().unwrap() > data.len() {
+        return Err(ArrowError::oos(
+            "ORC: the dictionary's lengths do not match its size or data",
+        ));
+    }
+    Ok((offsets, data))
+}
+
+fn deserialize_binary_generic<O: Offset>(
+    column: &Column,
+    validity: Option<&Bitmap>,
+) -> Result<(Buffer<O>, Buffer<u8>)> {
+    let encoding = check_encoding(
+        column,
+        &[
+            ColumnEncodingKind::DirectV2,
+            ColumnEncodingKind::DictionaryV2,
+        ],
+    )?;
+    let num_of_values = number_of_values(column, validity);
+
+    let mut offsets = Vec::<O>::with_capacity(column.number_of_rows() + 1);
+    offsets.push(O::zero());
+    let mut values = Vec::<u8>::new();
+
+    let mut push = |value: Option<&[u8]>| {
+        if let Some(value) = value {
+            values.extend_from_slice(value);
+        }
+        offsets.push(O::from_usize(values.len()).ok_or(ArrowError::Overflow)?);
+        Ok(())
+    };
+
+    if encoding == ColumnEncodingKind::DirectV2 {
+        let mut lengths = column.get_stream(Kind::Length, vec![])?;
+        let lengths = decode::UnsignedRleV2Iter::new(&mut lengths, num_of_values, vec![]);
+
+        let mut data = decode::Values::new(column.get_stream(Kind::Data, vec![])?, vec![]);
+        for_each_row(column, validity, lengths, |length| match length {
+            Some(length) => push(Some(data.next(length as usize)?)),
+            None => push(None),
+        })?;
+    } else {
+        let (dict_offsets, dict_values) = deserialize_dictionary(column)?;
+
+        let mut indices = column.get_stream(Kind::Data, vec![])?;
+        let indices = decode::UnsignedRleV2Iter::new(&mut indices, num_of_values, vec![]);
+        for_each_row(column, validity, indices, |index| match index {
+            Some(index) => {
+                let index = index as usize;
+                if index + 1 >= dict_offsets.len() {
+                    return Err(ArrowError::oos(format!(
+                        "ORC: the index {} is out of bounds of the dictionary",
+                        index
+                    )));
+                }
+                push(Some(
+                    &dict_values[dict_offsets[index]..dict_offsets[index + 1]],
+                ))
+            }
+            None => push(None),
+        })?;
+    }
+
+    Ok((offsets.into(), values.into()))
+}
+
+fn deserialize_utf8<O: Offset>(data_type: DataType, column: &Column) -> Result<Utf8Array<O>> {
+    let validity = deserialize_validity(column, &mut vec![])?;
+    let (offsets, values) = deserialize_binary_generic::<O>(column, validity.as_ref())?;
+    Utf8Array::try_new(data_type, offsets, values, validity)
+}
+
+fn deserialize_binary<O: Offset>(data_type: DataType, column: &Column) -> Result<BinaryArray<O>> {
+    let validity = deserialize_validity(column, &mut vec![])?;
+    let (offsets, values) = deserialize_binary_generic::<O>(column, validity.as_ref())?;
+    BinaryArray::try_new(data_type, offsets, values, validity)
+}
+
+/// Deserializes `column` of a stripe into an [`Array`] of [`DataType`] `data_type`.
+/// # Errors
+/// This function errors if the column is out of spec or if `data_type`
+/// (or the column's encoding) is not yet supported. Supported data types are
+/// boolean, `Int8`, `Int16`, `Int32`, `Int64`, floats, utf8 and binary.
+pub fn deserialize(data_type: DataType, column: &Column) -> Result<Arc<dyn Array>> {
+    match data_type {
+        DataType::Boolean => deserialize_bool(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Int8 => deserialize_byte(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Int16 => deserialize_int::<i16>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Int32 => deserialize_int::<i32>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Int64 => deserialize_int::<i64>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Float32 => deserialize_float::<f32>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Float64 => deserialize_float::<f64>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Utf8 => deserialize_utf8::<i32>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::LargeUtf8 => deserialize_utf8::<i64>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::Binary => deserialize_binary::<i32>(data_type, column).map(|x| Arc::new(x) as _),
+        DataType::LargeBinary => {
+            deserialize_binary::<i64>(data_type, column).map(|x| Arc::new(x) as _)
+        }
+        dt => Err(ArrowError::NotYetImplemented(format!(
+            "Deserializing {:?} from ORC",
+            dt
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_rle() -> Result<()> {
+        // a run of 100 zeros, followed by the literals [1, 2, 3]
+        let data = [0x61, 0x00, 0xfd, 0x01, 0x02, 0x03];
+        let mut expected = vec![0; 100];
+        expected.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(decode_byte_rle(&data, 103)?, expected);
+
+        assert!(decode_byte_rle(&data, 104).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+//! APIs to verify the checksums (CRC) of the pages of parquet column chunks.
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet_format_async_temp::{thrift::protocol::TCompactInputProtocol, PageHeader};
+
+use crate::error::{ArrowError, Result};
+
+use super::ColumnChunkMetaData;
+
+/// Verifies the checksum of every page of the column chunk `column`.
+///
+/// As declared in the parquet specification, the checksum of a page is the CRC32 of its
+/// (compressed) bytes, excluding its header. Pages without a checksum are not verified.
+/// Note that files written by [`FileWriter`](crate::io::parquet::write::FileWriter) have no
+/// checksums, since the page headers are written by `parquet2`, that does not declare them.
+///
+/// # Error
+/// Errors with [`ArrowError::OutOfSpec`] iff the checksum of a page does not match its bytes,
+/// or the column chunk is not a valid sequence of pages.
+/// # Implementation
+/// This operation is IO-bounded: it reads the column chunk from `reader`.
+pub fn verify_column_chunk_crc<R: Read + Seek>(
+    reader: &mut R,
+    column: &ColumnChunkMetaData,
+) -> Result<()> {
+    let (start, length) = column.byte_range();
+    reader.seek(SeekFrom::Start(start))?;
+    let mut reader = reader.take(length);
+
+    let mut buffer = vec![];
+    while reader.limit() > 0 {
+        let offset = start + length - reader.limit();
+        let mut protocol = TCompactInputProtocol::new(&mut reader);
+        let header = PageHeader::read_from_in_protocol(&mut protocol).map_err(|error| {
+            ArrowError::oos(format!("Failed to read the page header: {}", error))
+        })?;
+
+        if header.compressed_page_size < 0 {
+            return Err(ArrowError::oos(
+                "A page must have a non-negative compressed page size",
+            ));
+        }
+        buffer.clear();
+        buffer.resize(header.compressed_page_size as usize, 0);
+        reader.read_exact(&mut buffer)?;
+
+        if let Some(crc) = header.crc {
+            let expected = crc::crc32::checksum_ieee(&buffer) as i32;
+            if crc != expected {
+                return Err(ArrowError::oos(format!(
+                    "The checksum of the page at offset {} is {} but its bytes have checksum {}",
+                    offset, crc, expected
+                )));
+            }
+        }
+    }
+    Ok(())
+}
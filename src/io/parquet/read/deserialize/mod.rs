@@ -12,6 +12,7 @@ use crate::{
     array::{Array, BinaryArray, DictionaryKey, PrimitiveArray, Utf8Array},
     datatypes::{DataType, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
+    types::{i256, NativeType},
 };
 
 use super::binary;
@@ -149,6 +150,55 @@ pub fn page_iter_to_arrays<'a, I: 'a + DataPages>(
             _ => unreachable!(),
         },
 
+        Decimal256(_, _) => match physical_type {
+            &PhysicalType::FixedLenByteArray(n) if n > 32 => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Can't decode Decimal256 type from Fixed Size Byte Array of len {:?}",
+                    n
+                )))
+            }
+            &PhysicalType::FixedLenByteArray(n) => {
+                let n = n as usize;
+
+                let pages =
+                    fixed_size_binary::Iter::new(pages, DataType::FixedSizeBinary(n), chunk_size);
+
+                let pages = pages.map(move |maybe_array| {
+                    let array = maybe_array?;
+                    let values = array
+                        .values()
+                        .chunks_exact(n)
+                        .map(|value: &[u8]| {
+                            // Copy the fixed-size byte value to the end of a 32 byte stack
+                            // allocated buffer filled with the sign bit, which accounts for
+                            // leading 1's in negative (two's complement) values.
+                            let sign = if value[0] & 0x80 != 0 { 0xFF } else { 0 };
+                            let mut bytes = [sign; 32];
+                            bytes[32 - n..].copy_from_slice(value);
+                            i256::from_be_bytes(bytes)
+                        })
+                        .collect::<Vec<_>>();
+                    let validity = array.validity().cloned();
+
+                    Ok(PrimitiveArray::<i256>::from_data(
+                        data_type.clone(),
+                        values.into(),
+                        validity,
+                    ))
+                });
+
+                let arrays = pages.map(|x| x.map(|x| Arc::new(x) as Arc<dyn Array>));
+
+                Box::new(arrays) as _
+            }
+            other => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Can't decode Decimal256 type from {:?}",
+                    other
+                )))
+            }
+        },
+
         // INT64
         Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => dyn_iter(iden(
             primitive::Iter::new(pages, data_type, chunk_size, |x: i64| x as i64),
@@ -228,6 +228,14 @@ pub(super) fn next_dict<
                 }
                 (Dict::Complete(_), _) => {}
             };
+            if !matches!(
+                page.encoding(),
+                Encoding::PlainDictionary | Encoding::RleDictionary
+            ) {
+                return MaybeNext::Some(Err(ArrowError::nyi(
+                    "dictionary arrays from column chunks mixing dictionary-encoded and plain pages",
+                )));
+            }
 
             let maybe_array = {
                 // there is a new page => consume the page from the start
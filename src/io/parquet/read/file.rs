@@ -12,7 +12,9 @@ use crate::{
 
 use super::{infer_schema, read_metadata, FileMetaData, RowGroupDeserializer, RowGroupMetaData};
 
-type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
+/// A filter over row groups: it receives the index and the metadata of a row group and returns
+/// whether the row group is to be read.
+pub type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
 
 /// An iterator of [`Chunk`] coming from row groups of a paquet file.
 ///
@@ -113,6 +115,16 @@ impl<R: Read + Seek> FileReader<R> {
         self.row_groups.set_groups_filter(groups_filter);
     }
 
+    /// Only reads the row groups whose metadata satisfies `predicate`.
+    ///
+    /// See [`RowGroupReader::filter_row_groups`] for details.
+    pub fn filter_row_groups<F>(&mut self, predicate: F)
+    where
+        F: Fn(&RowGroupMetaData) -> bool + 'static,
+    {
+        self.row_groups.filter_row_groups(predicate);
+    }
+
     fn next_row_group(&mut self) -> Result<Option<RowGroupDeserializer>> {
         let result = self.row_groups.next().transpose()?;
 
@@ -209,6 +221,23 @@ impl<R: Read + Seek> RowGroupReader<R> {
         self.groups_filter = Some(groups_filter);
     }
 
+    /// Only reads the row groups whose metadata satisfies `predicate`, e.g. based on the
+    /// statistics of its columns (see [`super::statistics::deserialize_statistics`]).
+    ///
+    /// The predicate is evaluated before any column chunk is read, so skipped row groups are
+    /// neither read nor decompressed. It receives the metadata of all columns of the row group,
+    /// independently of the projection; the projection only restricts the columns that are read
+    /// from the row groups that satisfy it.
+    /// Skipped row groups do not count towards the limit.
+    ///
+    /// This replaces any filter previously set.
+    pub fn filter_row_groups<F>(&mut self, predicate: F)
+    where
+        F: Fn(&RowGroupMetaData) -> bool + 'static,
+    {
+        self.set_groups_filter(Arc::new(move |_, row_group| predicate(row_group)));
+    }
+
     #[inline]
     fn _next(&mut self) -> Result<Option<RowGroupDeserializer>> {
         if self.schema.fields.is_empty() {
@@ -0,0 +1,169 @@
+//! APIs to read the page index (`ColumnIndex` and `OffsetIndex`) of parquet column chunks.
+use std::io::{Read, Seek, SeekFrom};
+
+use parquet2::statistics::{deserialize_statistics, ParquetStatistics};
+use parquet_format_async_temp::{
+    thrift::protocol::TCompactInputProtocol, ColumnIndex, OffsetIndex,
+};
+
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+use super::schema::to_leaf_data_type;
+use super::statistics::{_deserialize_statistics, Statistics};
+use super::{ColumnChunkMetaData, RowGroupMetaData};
+
+/// The location of a data page in a file, as declared in the `OffsetIndex` of its column chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLocation {
+    /// The offset of the page in the file, in bytes
+    pub offset: u64,
+    /// The size of the page in the file, including its header, in bytes
+    pub length: usize,
+    /// The index, within the row group, of the first row of the page
+    pub first_row_index: u64,
+}
+
+/// The page index of a column chunk: the statistics and location of each of its data pages.
+#[derive(Debug)]
+pub struct ColumnPageStatistics {
+    /// The statistics of each page.
+    /// Pages with only null values have no minimum nor maximum.
+    pub statistics: Vec<Box<dyn Statistics>>,
+    /// The location of each page
+    pub locations: Vec<PageLocation>,
+}
+
+fn read_thrift<R: Read + Seek>(reader: &mut R, offset: i64, length: i32) -> Result<Vec<u8>> {
+    let offset = u64::try_from(offset)
+        .map_err(|_| ArrowError::oos("The offset of a page index must be non-negative"))?;
+    let length = usize::try_from(length)
+        .map_err(|_| ArrowError::oos("The length of a page index must be non-negative"))?;
+
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut buffer = vec![0; length];
+    reader.read_exact(&mut buffer)?;
+    Ok(buffer)
+}
+
+fn thrift_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ArrowError::ExternalFormat(format!("Failed to read the page index: {}", error))
+}
+
+fn deserialize_page_statistics(
+    index: ColumnIndex,
+    column: &ColumnChunkMetaData,
+    data_type: &DataType,
+) -> Result<Vec<Box<dyn Statistics>>> {
+    let ColumnIndex {
+        null_pages,
+        min_values,
+        max_values,
+        null_counts,
+        ..
+    } = index;
+    if min_values.len() != null_pages.len() || max_values.len() != null_pages.len() {
+        return Err(ArrowError::oos(
+            "The column index must contain a min and max value per page",
+        ));
+    }
+    let mut null_counts = null_counts.map(|x| x.into_iter());
+
+    null_pages
+        .into_iter()
+        .zip(min_values.into_iter().zip(max_values))
+        .map(|(is_null, (min_value, max_value))| {
+            let (min_value, max_value) = if is_null {
+                (None, None)
+            } else {
+                (Some(min_value), Some(max_value))
+            };
+            let statistics = ParquetStatistics {
+                max: None,
+                min: None,
+                null_count: null_counts.as_mut().and_then(|x| x.next()),
+                distinct_count: None,
+                max_value,
+                min_value,
+            };
+            let statistics = deserialize_statistics(&statistics, column.descriptor().clone())?;
+            _deserialize_statistics(statistics.as_ref(), data_type.clone())
+        })
+        .collect()
+}
+
+fn read_column_index<R: Read + Seek>(
+    reader: &mut R,
+    column: &ColumnChunkMetaData,
+) -> Result<Option<ColumnPageStatistics>> {
+    let chunk = column.clone().into_thrift();
+    let data_type = to_leaf_data_type(column.descriptor().type_())
+        .ok_or_else(|| ArrowError::oos("The type of a column chunk must be a primitive type"))?;
+
+    let (column_index, offset_index) = match (
+        chunk.column_index_offset.zip(chunk.column_index_length),
+        chunk.offset_index_offset.zip(chunk.offset_index_length),
+    ) {
+        (Some(column_index), Some(offset_index)) => (column_index, offset_index),
+        _ => return Ok(None),
+    };
+
+    let buffer = read_thrift(reader, column_index.0, column_index.1)?;
+    let mut protocol = TCompactInputProtocol::new(buffer.as_slice());
+    let column_index = ColumnIndex::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+
+    let buffer = read_thrift(reader, offset_index.0, offset_index.1)?;
+    let mut protocol = TCompactInputProtocol::new(buffer.as_slice());
+    let offset_index = OffsetIndex::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+
+    let statistics = deserialize_page_statistics(column_index, column, &data_type)?;
+
+    let locations = offset_index
+        .page_locations
+        .into_iter()
+        .map(|location| {
+            Ok(PageLocation {
+                offset: u64::try_from(location.offset).map_err(|_| {
+                    ArrowError::oos("The offset of a page location must be non-negative")
+                })?,
+                length: usize::try_from(location.compressed_page_size).map_err(|_| {
+                    ArrowError::oos("The size of a page location must be non-negative")
+                })?,
+                first_row_index: u64::try_from(location.first_row_index).map_err(|_| {
+                    ArrowError::oos("The first row index of a page location must be non-negative")
+                })?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if locations.len() != statistics.len() {
+        return Err(ArrowError::oos(
+            "The column index and offset index must declare the same number of pages",
+        ));
+    }
+
+    Ok(Some(ColumnPageStatistics {
+        statistics,
+        locations,
+    }))
+}
+
+/// Reads the page index of every column chunk of `row_group`.
+///
+/// This returns one entry per parquet column, in the order of the columns of `row_group`.
+/// Entries are `None` when the column chunk has no page index. The statistics of each
+/// column are deserialized to the [`DataType`] inferred from its parquet type.
+///
+/// # Implementation
+/// This operation is IO-bounded `O(C)` where C is the number of columns: it seeks to and reads
+/// both indexes of each column chunk.
+pub fn read_columns_indexes<R: Read + Seek>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+) -> Result<Vec<Option<ColumnPageStatistics>>> {
+    row_group
+        .columns()
+        .iter()
+        .map(|column| read_column_index(reader, column))
+        .collect()
+}
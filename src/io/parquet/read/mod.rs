@@ -6,17 +6,25 @@ use std::{
     sync::Arc,
 };
 
+#[cfg(feature = "io_parquet_async")]
 use futures::{AsyncRead, AsyncSeek};
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
+pub use parquet2::read::{
+    get_page_stream as _get_page_stream, read_metadata_async as _read_metadata_async,
+};
 pub use parquet2::{
     error::ParquetError,
     fallible_streaming_iterator,
     metadata::{ColumnChunkMetaData, ColumnDescriptor, RowGroupMetaData},
-    page::{CompressedDataPage, DataPage, DataPageHeader},
+    page::{
+        BinaryPageDict, CompressedDataPage, DataPage, DataPageHeader, DictPage,
+        FixedLenByteArrayPageDict, PrimitivePageDict,
+    },
     read::{
         decompress, get_column_iterator, get_page_iterator as _get_page_iterator,
-        get_page_stream as _get_page_stream, read_metadata as _read_metadata,
-        read_metadata_async as _read_metadata_async, BasicDecompressor, ColumnChunkIter,
-        Decompressor, MutStreamingIterator, PageFilter, PageIterator, ReadColumnIterator, State,
+        read_metadata as _read_metadata, BasicDecompressor, ColumnChunkIter, Decompressor,
+        MutStreamingIterator, PageFilter, PageIterator, ReadColumnIterator, State,
     },
     schema::types::{
         LogicalType, ParquetType, PhysicalType, PrimitiveConvertedType,
@@ -38,19 +46,25 @@ use crate::{
 
 mod binary;
 mod boolean;
+mod crc;
 mod deserialize;
 mod dictionary;
 mod file;
 mod fixed_size_binary;
+mod indexes;
 mod nested_utils;
 mod null;
+mod pages;
 mod primitive;
 mod row_group;
 pub mod schema;
 pub mod statistics;
 mod utils;
 
-pub use file::{FileReader, RowGroupReader};
+pub use crc::verify_column_chunk_crc;
+pub use file::{FileReader, GroupFilter, RowGroupReader};
+pub use indexes::{read_columns_indexes, ColumnPageStatistics, PageLocation};
+pub use pages::DataPageIterator;
 pub use row_group::*;
 pub(crate) use schema::is_type_nullable;
 pub use schema::{infer_schema, FileMetaData};
@@ -124,6 +138,8 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetaData> {
 }
 
 /// Reads parquets' metadata asynchronously.
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
     reader: &mut R,
 ) -> Result<FileMetaData> {
@@ -0,0 +1,60 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use parquet2::page::{DataPage, DictPage};
+use parquet2::read::{decompress, PageIterator};
+
+use crate::error::Result;
+
+/// An iterator of the decompressed (but still encoded) [`DataPage`]s of a column chunk, that
+/// reads and decompresses one page at a time.
+///
+/// The dictionary page of the column chunk, if any, is available via
+/// [`DataPageIterator::dictionary`], so that data pages can be decoded against it.
+pub struct DataPageIterator<R: Read> {
+    pages: PageIterator<R>,
+    first: Option<DataPage>,
+    dictionary: Option<Arc<dyn DictPage>>,
+}
+
+impl<R: Read> DataPageIterator<R> {
+    /// Returns a new [`DataPageIterator`] from a [`PageIterator`], e.g. created via
+    /// [`get_page_iterator`](super::get_page_iterator).
+    /// # Errors
+    /// This function errors iff the first page or the dictionary page of the column chunk
+    /// cannot be read or decompressed.
+    pub fn try_new(mut pages: PageIterator<R>) -> Result<Self> {
+        // the dictionary page is read together with the first data page
+        let first = pages
+            .next()
+            .transpose()?
+            .map(|page| decompress(page, &mut vec![]))
+            .transpose()?;
+        let dictionary = first
+            .as_ref()
+            .and_then(|page| page.dictionary_page().cloned());
+        Ok(Self {
+            pages,
+            first,
+            dictionary,
+        })
+    }
+
+    /// The dictionary page of the column chunk, if any.
+    pub fn dictionary(&self) -> Option<&Arc<dyn DictPage>> {
+        self.dictionary.as_ref()
+    }
+}
+
+impl<R: Read> Iterator for DataPageIterator<R> {
+    type Item = Result<DataPage>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(page) = self.first.take() {
+            return Some(Ok(page));
+        }
+        self.pages
+            .next()
+            .map(|page| Ok(decompress(page?, &mut vec![])?))
+    }
+}
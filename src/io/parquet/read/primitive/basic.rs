@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
 use parquet2::{
-    encoding::{hybrid_rle, Encoding},
+    encoding::{hybrid_rle, uleb128, Encoding},
     page::{DataPage, PrimitivePageDict},
     schema::Repetition,
     types::decode,
@@ -9,7 +9,10 @@ use parquet2::{
 };
 
 use crate::{
-    array::MutablePrimitiveArray, bitmap::MutableBitmap, datatypes::DataType, error::Result,
+    array::MutablePrimitiveArray,
+    bitmap::MutableBitmap,
+    datatypes::DataType,
+    error::{ArrowError, Result},
     types::NativeType,
 };
 
@@ -73,6 +76,243 @@ where
     }
 }
 
+/// Values encoded with `BYTE_STREAM_SPLIT`: the `k`-th byte of every value is stored
+/// contiguously in the `k`-th stream. Decoding gathers the bytes of each value back together.
+#[derive(Debug)]
+pub(super) struct ByteStreamSplit<'a, P>
+where
+    P: ParquetNativeType,
+{
+    values: &'a [u8],
+    // number of values in the page, i.e. the length of each stream
+    num_values: usize,
+    index: usize,
+    phantom: std::marker::PhantomData<P>,
+}
+
+impl<'a, P> ByteStreamSplit<'a, P>
+where
+    P: ParquetNativeType,
+{
+    pub fn new(page: &'a DataPage) -> Result<Self> {
+        let (_, _, values) = utils::split_buffer(page);
+        let size = std::mem::size_of::<P>();
+        if values.len() % size != 0 {
+            return Err(ArrowError::oos(format!(
+                "A BYTE_STREAM_SPLIT-encoded page must have a length multiple of the type width ({}), but it has {} bytes",
+                size,
+                values.len()
+            )));
+        }
+        Ok(Self {
+            values,
+            num_values: values.len() / size,
+            index: 0,
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.num_values - self.index
+    }
+}
+
+impl<'a, P> Iterator for ByteStreamSplit<'a, P>
+where
+    P: ParquetNativeType,
+{
+    type Item = P;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.num_values {
+            return None;
+        }
+        let size = std::mem::size_of::<P>();
+        // the largest parquet native type (int96) has 12 bytes
+        let mut bytes = [0u8; 16];
+        bytes[..size]
+            .iter_mut()
+            .enumerate()
+            .for_each(|(stream, byte)| *byte = self.values[stream * self.num_values + self.index]);
+        self.index += 1;
+        Some(decode(&bytes[..size]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+fn read_uleb128(values: &mut &[u8]) -> Result<u64> {
+    let (value, consumed) = uleb128::decode(values);
+    if consumed == 0 {
+        return Err(ArrowError::oos(
+            "A DELTA_BINARY_PACKED-encoded page ended before its last value",
+        ));
+    }
+    *values = &values[consumed..];
+    Ok(value)
+}
+
+fn read_zigzag(values: &mut &[u8]) -> Result<i64> {
+    let value = read_uleb128(values)?;
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+/// Unpacks the `num_bits`-wide (up to 64), little-endian bit-packed `values` to `unpacked`.
+fn unpack(values: &[u8], num_bits: usize, unpacked: &mut [u64]) {
+    let mask = u64::MAX >> (64 - num_bits);
+    let mut container = 0u128;
+    let mut container_bits = 0;
+    let mut bytes = values.iter();
+    unpacked.iter_mut().for_each(|value| {
+        while container_bits < num_bits {
+            container |= (*bytes.next().unwrap() as u128) << container_bits;
+            container_bits += 8;
+        }
+        *value = container as u64 & mask;
+        container >>= num_bits;
+        container_bits -= num_bits;
+    });
+}
+
+/// Decodes `DELTA_BINARY_PACKED`-encoded `values` of a page with `num_values` slots. Deltas are
+/// added with wrapping, as declared in the parquet specification.
+///
+/// The header of the encoding is not trusted: at most `num_values` values are decoded and
+/// allocated, independently of the declared block size and total count, and every block must
+/// fit in `values`.
+fn decode_delta(mut values: &[u8], num_values: usize) -> Result<Vec<i64>> {
+    let values = &mut values;
+    let block_size = read_uleb128(values)? as usize;
+    let num_mini_blocks = read_uleb128(values)? as usize;
+    let total_count = (read_uleb128(values)? as usize).min(num_values);
+    let mut value = read_zigzag(values)?;
+
+    let mini_block_size = block_size.checked_div(num_mini_blocks).unwrap_or_default();
+    // multiples of 128 and 32 are checked via their low bits
+    if mini_block_size == 0
+        || mini_block_size * num_mini_blocks != block_size
+        || block_size & 127 != 0
+        || mini_block_size & 31 != 0
+    {
+        return Err(ArrowError::oos(format!(
+            "A DELTA_BINARY_PACKED-encoded page must have a block size multiple of 128 divided in mini-blocks multiple of 32, but it has a block size of {} and {} mini-blocks",
+            block_size, num_mini_blocks
+        )));
+    }
+
+    let mut decoded = Vec::with_capacity(total_count);
+    if total_count == 0 {
+        return Ok(decoded);
+    }
+    decoded.push(value);
+
+    // only the values up to `total_count` are unpacked
+    let mut relative = vec![0u64; mini_block_size.min(total_count)];
+    while decoded.len() < total_count {
+        let min_delta = read_zigzag(values)?;
+        if values.len() < num_mini_blocks {
+            return Err(ArrowError::oos(
+                "A DELTA_BINARY_PACKED-encoded page ended before its last value",
+            ));
+        }
+        let (bit_widths, remaining) = values.split_at(num_mini_blocks);
+        *values = remaining;
+
+        for num_bits in bit_widths {
+            let remaining = total_count - decoded.len();
+            if remaining == 0 {
+                // the mini-blocks after the last value are omitted
+                break;
+            }
+            let num_bits = *num_bits as usize;
+            if num_bits > 64 {
+                return Err(ArrowError::oos(format!(
+                    "The bit width of a DELTA_BINARY_PACKED mini-block must be at most 64, but it is {}",
+                    num_bits
+                )));
+            }
+            let length = mini_block_size
+                .checked_mul(num_bits)
+                .map(|bits| bits / 8)
+                .filter(|length| *length <= values.len());
+            let length = if let Some(length) = length {
+                length
+            } else {
+                return Err(ArrowError::oos(
+                    "A DELTA_BINARY_PACKED-encoded page ended before its last value",
+                ));
+            };
+            let (mini_block, remaining_values) = values.split_at(length);
+            *values = remaining_values;
+
+            if num_bits == 0 {
+                relative.iter_mut().for_each(|x| *x = 0);
+            } else {
+                unpack(mini_block, num_bits, &mut relative);
+            }
+            relative.iter().take(remaining).for_each(|x| {
+                value = value.wrapping_add(min_delta.wrapping_add(*x as i64));
+                decoded.push(value);
+            });
+        }
+    }
+    Ok(decoded)
+}
+
+/// Values encoded with `DELTA_BINARY_PACKED`, which is only valid for `INT32` and `INT64`.
+#[derive(Debug)]
+pub(super) struct Delta<P>
+where
+    P: ParquetNativeType,
+{
+    values: std::vec::IntoIter<i64>,
+    phantom: std::marker::PhantomData<P>,
+}
+
+impl<P> Delta<P>
+where
+    P: ParquetNativeType,
+{
+    pub fn try_new(page: &DataPage) -> Result<Self> {
+        let (_, _, values) = utils::split_buffer(page);
+        Ok(Self {
+            values: decode_delta(values, page.num_values())?.into_iter(),
+            phantom: std::marker::PhantomData,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<P> Iterator for Delta<P>
+where
+    P: ParquetNativeType,
+{
+    type Item = P;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // the decoder yields `i64`; `INT32` values are its (little-endian) lower 4 bytes
+        self.values
+            .next()
+            .map(|x| decode(&x.to_le_bytes()[..std::mem::size_of::<P>()]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
 // The state of a `DataPage` of `Primitive` parquet primitive type
 #[derive(Debug)]
 enum State<'a, P>
@@ -83,6 +323,10 @@ where
     Required(Values<'a, P>),
     RequiredDictionary(ValuesDictionary<'a, P>),
     OptionalDictionary(OptionalPageValidity<'a>, ValuesDictionary<'a, P>),
+    RequiredByteStreamSplit(ByteStreamSplit<'a, P>),
+    OptionalByteStreamSplit(OptionalPageValidity<'a>, ByteStreamSplit<'a, P>),
+    RequiredDelta(Delta<P>),
+    OptionalDelta(OptionalPageValidity<'a>, Delta<P>),
 }
 
 impl<'a, P> utils::PageState<'a> for State<'a, P>
@@ -95,6 +339,10 @@ where
             State::Required(values) => values.len(),
             State::RequiredDictionary(values) => values.len(),
             State::OptionalDictionary(optional, _) => optional.len(),
+            State::RequiredByteStreamSplit(values) => values.len(),
+            State::OptionalByteStreamSplit(optional, _) => optional.len(),
+            State::RequiredDelta(values) => values.len(),
+            State::OptionalDelta(optional, _) => optional.len(),
         }
     }
 }
@@ -165,6 +413,20 @@ where
                 Ok(State::Optional(validity, values))
             }
             (Encoding::Plain, _, false) => Ok(State::Required(Values::new(page))),
+            (Encoding::ByteStreamSplit, _, true) => Ok(State::OptionalByteStreamSplit(
+                OptionalPageValidity::new(page),
+                ByteStreamSplit::new(page)?,
+            )),
+            (Encoding::ByteStreamSplit, _, false) => {
+                Ok(State::RequiredByteStreamSplit(ByteStreamSplit::new(page)?))
+            }
+            (Encoding::DeltaBinaryPacked, _, true) => Ok(State::OptionalDelta(
+                OptionalPageValidity::new(page),
+                Delta::try_new(page)?,
+            )),
+            (Encoding::DeltaBinaryPacked, _, false) => {
+                Ok(State::RequiredDelta(Delta::try_new(page)?))
+            }
             _ => Err(utils::not_implemented(
                 &page.encoding(),
                 is_optional,
@@ -217,6 +479,28 @@ where
                 let op1 = |index: u32| page.dict[index as usize];
                 values.extend(page.values.by_ref().map(op1).map(self.op).take(remaining));
             }
+            State::OptionalByteStreamSplit(page_validity, page_values) => {
+                utils::extend_from_decoder(
+                    validity,
+                    page_validity,
+                    Some(remaining),
+                    values,
+                    page_values.by_ref().map(self.op),
+                )
+            }
+            State::RequiredByteStreamSplit(page_values) => {
+                values.extend(page_values.by_ref().map(self.op).take(remaining));
+            }
+            State::OptionalDelta(page_validity, page_values) => utils::extend_from_decoder(
+                validity,
+                page_validity,
+                Some(remaining),
+                values,
+                page_values.by_ref().map(self.op),
+            ),
+            State::RequiredDelta(page_values) => {
+                values.extend(page_values.by_ref().map(self.op).take(remaining));
+            }
         }
     }
 }
@@ -297,3 +581,42 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_uleb128(mut value: u64, buffer: &mut Vec<u8>) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                buffer.push(byte);
+                break;
+            }
+            buffer.push(byte | 0x80);
+        }
+    }
+
+    #[test]
+    fn delta_untrusted_header() -> Result<()> {
+        // a block of 2^47 values in 4 mini-blocks and a total count of 2^60 values, starting
+        // at 1 (zigzag 2), followed by a block with a min delta of 1 (zigzag 2) and zero bit widths
+        let mut data = vec![];
+        encode_uleb128(1 << 47, &mut data);
+        encode_uleb128(4, &mut data);
+        encode_uleb128(1 << 60, &mut data);
+        encode_uleb128(2, &mut data);
+        encode_uleb128(2, &mut data);
+        data.extend_from_slice(&[0, 0, 0, 0]);
+
+        // only the 3 values of the page are decoded
+        assert_eq!(decode_delta(&data, 3)?, vec![1, 2, 3]);
+
+        // a mini-block of 2^45 values of 8 bits does not fit in the page
+        let length = data.len();
+        data[length - 4] = 8;
+        assert!(decode_delta(&data, 3).is_err());
+        Ok(())
+    }
+}
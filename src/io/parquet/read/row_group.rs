@@ -3,17 +3,23 @@ use std::{
     sync::Arc,
 };
 
+#[cfg(feature = "io_parquet_async")]
 use futures::{
     future::{try_join_all, BoxFuture},
-    AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt,
+    stream::{self, BoxStream},
+    AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, StreamExt,
 };
 use parquet2::{
     metadata::ColumnChunkMetaData,
     read::{BasicDecompressor, PageIterator},
+    schema::types::{ParquetType, PhysicalType},
 };
 
 use crate::{
-    array::Array, chunk::Chunk, datatypes::Field, error::Result,
+    array::{Array, DictionaryArray, PrimitiveArray, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, IntegerType},
+    error::{ArrowError, Result},
     io::parquet::read::column_iter_to_arrays,
 };
 
@@ -71,7 +77,7 @@ impl Iterator for RowGroupDeserializer {
             .map(|iter| {
                 let array = iter.next().unwrap()?;
                 Ok(if array.len() > self.remaining_rows {
-                    array.slice(0, array.len() - self.remaining_rows).into()
+                    array.slice(0, self.remaining_rows).into()
                 } else {
                     array
                 })
@@ -128,6 +134,19 @@ where
     Ok((meta, chunk))
 }
 
+#[cfg(feature = "io_parquet_async")]
+async fn _read_column_chunk_async<R>(reader: &mut R, meta: &ColumnChunkMetaData) -> Result<Vec<u8>>
+where
+    R: AsyncRead + AsyncSeek + Send + Unpin,
+{
+    let (start, len) = meta.byte_range();
+    reader.seek(std::io::SeekFrom::Start(start)).await?;
+    let mut chunk = vec![0; len as usize];
+    reader.read_exact(&mut chunk).await?;
+    Ok(chunk)
+}
+
+#[cfg(feature = "io_parquet_async")]
 async fn _read_single_column_async<'b, R, F>(
     factory: F,
     meta: &ColumnChunkMetaData,
@@ -137,10 +156,7 @@ where
     F: Fn() -> BoxFuture<'b, std::io::Result<R>>,
 {
     let mut reader = factory().await?;
-    let (start, len) = meta.byte_range();
-    reader.seek(std::io::SeekFrom::Start(start)).await?;
-    let mut chunk = vec![0; len as usize];
-    reader.read_exact(&mut chunk).await?;
+    let chunk = _read_column_chunk_async(&mut reader, meta).await?;
     Result::Ok((meta, chunk))
 }
 
@@ -151,6 +167,8 @@ where
 ///
 /// It does so asynchronously via a single `join_all` over all the necessary columns for
 /// `field_name`.
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
 pub async fn read_columns_async<
     'a,
     'b,
@@ -199,6 +217,56 @@ pub fn to_deserializer<'a>(
     column_iter_to_arrays(columns, types, field, chunk_size)
 }
 
+/// Deserializes a dictionary-encoded utf8 column chunk into a [`DictionaryArray`] whose values
+/// are the entries of the chunk's dictionary page, without materializing the (dense) utf8 values.
+///
+/// `column_chunk` is a non-nested column chunk as returned by [`read_columns`].
+/// # Errors
+/// This function errors with [`ArrowError::InvalidArgumentError`] iff the column chunk is nested
+/// or its physical type is not `BYTE_ARRAY`. It errors with [`ArrowError::NotYetImplemented`]
+/// iff any of its data pages is not dictionary-encoded, e.g. when the writer fell back to plain
+/// encoding because the dictionary grew too large; such column chunks can still be read
+/// via [`to_deserializer`].
+pub fn deserialize_as_dictionary(
+    column_chunk: (&ColumnChunkMetaData, Vec<u8>),
+) -> Result<DictionaryArray<i32>> {
+    let descriptor = column_chunk.0.descriptor();
+    if descriptor.max_rep_level() > 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "deserialize_as_dictionary can only be called with a non-nested column chunk".into(),
+        ));
+    }
+    if !matches!(
+        descriptor.type_(),
+        ParquetType::PrimitiveType {
+            physical_type: PhysicalType::ByteArray,
+            ..
+        }
+    ) {
+        return Err(ArrowError::InvalidArgumentError(
+            "deserialize_as_dictionary can only be called with a BYTE_ARRAY column chunk".into(),
+        ));
+    }
+
+    let num_rows = column_chunk.0.num_values() as usize;
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let field = Field::new(descriptor.name(), data_type, true);
+
+    let mut iter = to_deserializer(vec![column_chunk], field, num_rows, None)?;
+    // `chunk_size` is `None`, so the whole column chunk is deserialized into a single array
+    match iter.next().transpose()? {
+        Some(array) => Ok(array
+            .as_any()
+            .downcast_ref::<DictionaryArray<i32>>()
+            .unwrap()
+            .clone()),
+        None => Ok(DictionaryArray::from_data(
+            PrimitiveArray::<i32>::from_slice([]),
+            Arc::new(Utf8Array::<i32>::new_empty(DataType::Utf8)),
+        )),
+    }
+}
+
 /// Returns a vector of iterators of [`Array`] ([`ArrayIter`]) corresponding to the top
 /// level parquet fields whose name matches `fields`'s names.
 ///
@@ -231,6 +299,63 @@ pub fn read_columns_many<'a, R: Read + Seek>(
         .collect()
 }
 
+/// Reads the columns of `row_group` associated to `fields` and deserializes them in parallel,
+/// returning one [`Array`] per field, in the same order as `fields`.
+///
+/// `spawn` is used to run each of the (CPU-bounded) tasks of decompressing and deserializing
+/// a column, e.g. `|task| rayon::spawn(task)` or `|task| pool.execute(task)`.
+/// This function blocks until all tasks have finished.
+///
+/// # Implementation
+/// Reading the columns into memory is IO-bounded `O(C)` where C is the number of columns
+/// associated to `fields`, and happens sequentially on the current thread,
+/// see [`read_columns_many`].
+/// # Errors
+/// This function errors if reading any of the columns fails, if any of the tasks errors,
+/// or if any task is dropped without completing (e.g. it panicked).
+pub fn read_columns_many_parallel<R, S>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    spawn: S,
+) -> Result<Vec<Arc<dyn Array>>>
+where
+    R: Read + Seek,
+    S: Fn(Box<dyn FnOnce() + Send>),
+{
+    let columns = read_columns_many(reader, row_group, fields, None)?;
+    let num_columns = columns.len();
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    for (index, mut column) in columns.into_iter().enumerate() {
+        let sender = sender.clone();
+        spawn(Box::new(move || {
+            // `chunk_size` is `None`, so the whole column is deserialized into a single array
+            let array = column
+                .next()
+                .unwrap_or_else(|| Err(ArrowError::oos("A column chunk must contain an array")));
+            // the receiver only hangs up after an error, in which case this result is not needed
+            let _ = sender.send((index, array));
+        }));
+    }
+    drop(sender);
+
+    let mut arrays = vec![None; num_columns];
+    for (index, array) in receiver.iter() {
+        arrays[index] = Some(array?);
+    }
+    arrays
+        .into_iter()
+        .map(|array| {
+            array.ok_or_else(|| {
+                ArrowError::ExternalFormat(
+                    "A task deserializing a parquet column did not complete".to_string(),
+                )
+            })
+        })
+        .collect()
+}
+
 /// Returns a vector of iterators of [`Array`] corresponding to the top level parquet fields whose
 /// name matches `fields`'s names.
 ///
@@ -241,6 +366,8 @@ pub fn read_columns_many<'a, R: Read + Seek>(
 /// This operation is IO-bounded `O(C)` where C is the number of columns in the row group -
 /// it reads all the columns to memory from the row group associated to the requested fields.
 /// It does so asynchronously via `join_all`
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
 pub async fn read_columns_many_async<
     'a,
     'b,
@@ -266,3 +393,79 @@ pub async fn read_columns_many_async<
         })
         .collect()
 }
+
+/// Reads all the columns of `row_group` associated to `fields` from `reader` and returns
+/// a [`RowGroupDeserializer`] over them.
+///
+/// # Implementation
+/// The columns are read sequentially from `reader`, which is IO-bounded `O(C)` where C is the
+/// number of columns associated to `fields`. Deserializing them (decompressing and decoding)
+/// is CPU-bounded and only happens when the returned iterator is advanced.
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
+pub async fn read_row_group_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
+    reader: &mut R,
+    row_group: &RowGroupMetaData,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limit: Option<usize>,
+) -> Result<RowGroupDeserializer> {
+    let num_rows = row_group.num_rows() as usize;
+
+    let mut column_chunks = Vec::with_capacity(fields.len());
+    for field in fields {
+        let mut columns = vec![];
+        for meta in get_field_columns(row_group.columns(), &field.name) {
+            columns.push((meta, _read_column_chunk_async(reader, meta).await?));
+        }
+        column_chunks.push(to_deserializer(columns, field, num_rows, chunk_size)?);
+    }
+
+    Ok(RowGroupDeserializer::new(column_chunks, num_rows, limit))
+}
+
+/// Returns a [`Stream`](futures::Stream) of [`RowGroupDeserializer`], one per entry of
+/// `row_groups` until `limit` rows are reached, whose columns are read from `reader`.
+///
+/// Each item is yielded as soon as the column chunks of its row group are in memory,
+/// see [`read_row_group_async`]. The stream ends after the first error.
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
+pub fn read_row_groups_async<'a, R: AsyncRead + AsyncSeek + Send + Unpin + 'a>(
+    reader: R,
+    row_groups: Vec<RowGroupMetaData>,
+    fields: Vec<Field>,
+    chunk_size: Option<usize>,
+    limit: Option<usize>,
+) -> BoxStream<'a, Result<RowGroupDeserializer>> {
+    let state = (reader, row_groups.into_iter(), limit.unwrap_or(usize::MAX));
+
+    stream::unfold(Some(state), move |state| {
+        let fields = fields.clone();
+        async move {
+            let (mut reader, mut row_groups, remaining_rows) = state?;
+            if remaining_rows == 0 {
+                return None;
+            }
+            let row_group = row_groups.next()?;
+
+            let deserializer = read_row_group_async(
+                &mut reader,
+                &row_group,
+                fields,
+                chunk_size,
+                Some(remaining_rows),
+            )
+            .await;
+            match deserializer {
+                Ok(deserializer) => {
+                    let remaining_rows =
+                        remaining_rows.saturating_sub(row_group.num_rows() as usize);
+                    Some((Ok(deserializer), Some((reader, row_groups, remaining_rows))))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+    .boxed()
+}
@@ -143,17 +143,25 @@ fn from_byte_array(
     }
 }
 
+/// The [`DataType`] of a decimal of `precision`, which is [`DataType::Decimal256`] when its
+/// values do not fit in an `i128`.
+fn decimal_type(precision: usize, scale: usize) -> DataType {
+    if precision > 38 {
+        DataType::Decimal256(precision, scale)
+    } else {
+        DataType::Decimal(precision, scale)
+    }
+}
+
 fn from_fixed_len_byte_array(
     length: &i32,
     logical_type: &Option<LogicalType>,
     converted_type: &Option<PrimitiveConvertedType>,
 ) -> DataType {
     match (logical_type, converted_type) {
-        (Some(LogicalType::DECIMAL(t)), _) => {
-            DataType::Decimal(t.precision as usize, t.scale as usize)
-        }
+        (Some(LogicalType::DECIMAL(t)), _) => decimal_type(t.precision as usize, t.scale as usize),
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
-            DataType::Decimal(*precision as usize, *scale as usize)
+            decimal_type(*precision as usize, *scale as usize)
         }
         (None, Some(PrimitiveConvertedType::Interval)) => {
             // There is currently no reliable way of determining which IntervalUnit
@@ -380,6 +388,24 @@ pub(crate) fn to_data_type(type_: &ParquetType) -> Option<DataType> {
     }
 }
 
+/// Converts the [`ParquetType`] of a column (a primitive type) to the [`DataType`] of its values,
+/// ignoring its repetition. Returns `None` for group types.
+pub(crate) fn to_leaf_data_type(type_: &ParquetType) -> Option<DataType> {
+    match type_ {
+        ParquetType::PrimitiveType {
+            physical_type,
+            logical_type,
+            converted_type,
+            ..
+        } => Some(to_primitive_type_inner(
+            physical_type,
+            logical_type,
+            converted_type,
+        )),
+        ParquetType::GroupType { .. } => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use parquet2::metadata::SchemaDescriptor;
@@ -21,10 +21,10 @@ fn get_arrow_schema_from_metadata(encoded_meta: &str) -> Result<Schema> {
     let decoded = base64::decode(encoded_meta);
     match decoded {
         Ok(bytes) => {
-            let slice = if bytes[0..4] == [255u8; 4] {
-                &bytes[8..]
-            } else {
-                bytes.as_slice()
+            // the schema may be prefixed by the IPC continuation marker and its length
+            let slice = match bytes.get(..4) {
+                Some([255, 255, 255, 255]) => bytes.get(8..).unwrap_or_default(),
+                _ => bytes.as_slice(),
             };
             deserialize_schema(slice).map(|x| x.0)
         }
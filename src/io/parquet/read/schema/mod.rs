@@ -16,6 +16,9 @@ use self::metadata::parse_key_value_metadata;
 /// Infers a [`Schema`] from parquet's [`FileMetaData`]. This first looks for the metadata key
 /// `"ARROW:schema"`; if it does not exist, it converts the parquet types declared in the
 /// file's parquet schema to Arrow's equivalent.
+///
+/// The remaining key-value metadata of the file is the metadata of the [`Schema`]; when
+/// `"ARROW:schema"` exists, the metadata of the arrow schema takes precedence over it.
 /// # Error
 /// This function errors iff the key `"ARROW:schema"` exists but is not correctly encoded,
 /// indicating that that the file's arrow metadata was incorrectly written.
@@ -23,10 +26,17 @@ pub fn infer_schema(file_metadata: &FileMetaData) -> Result<Schema> {
     let mut metadata = parse_key_value_metadata(file_metadata.key_value_metadata());
 
     let schema = read_schema_from_metadata(&mut metadata)?;
-    Ok(schema.unwrap_or_else(|| {
-        let fields = parquet_to_arrow_schema(file_metadata.schema().fields());
-        Schema { fields, metadata }
-    }))
+    Ok(match schema {
+        Some(mut schema) => {
+            metadata.append(&mut schema.metadata);
+            schema.metadata = metadata;
+            schema
+        }
+        None => {
+            let fields = parquet_to_arrow_schema(file_metadata.schema().fields());
+            Schema { fields, metadata }
+        }
+    })
 }
 
 pub(crate) fn is_type_nullable(type_: &ParquetType) -> bool {
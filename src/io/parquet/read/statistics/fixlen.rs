@@ -1,9 +1,10 @@
 use std::any::Any;
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use super::primitive::PrimitiveStatistics;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
+use crate::types::{i256, NativeType};
 use parquet2::{
     schema::types::PhysicalType,
     statistics::{
@@ -58,6 +59,15 @@ impl From<&ParquetFixedLenStatistics> for FixedLenStatistics {
     }
 }
 
+/// Converts a big-endian two's complement integer of at most 16 bytes into an `i128`,
+/// sign-extending it.
+fn convert_i128(value: &[u8]) -> i128 {
+    let n = value.len();
+    let mut bytes = [0u8; 16];
+    bytes[..n].copy_from_slice(value);
+    i128::from_be_bytes(bytes) >> (8 * (16 - n))
+}
+
 impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i128> {
     type Error = ArrowError;
     fn try_from((stats, data_type): (&ParquetFixedLenStatistics, DataType)) -> Result<Self> {
@@ -71,22 +81,48 @@ impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i12
                 byte_lens
             )))
         } else {
-            let paddings = (0..(16 - byte_lens)).map(|_| 0u8).collect::<Vec<_>>();
-            let max_value = stats.max_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
+            let max_value = stats.max_value.as_deref().map(convert_i128);
+            let min_value = stats.min_value.as_deref().map(convert_i128);
+            Ok(Self {
+                data_type,
+                null_count: stats.null_count,
+                distinct_count: stats.distinct_count,
+                max_value,
+                min_value,
+            })
+        }
+    }
+}
 
-            let min_value = stats.min_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
+/// Converts a big-endian two's complement integer of at most 32 bytes into an `i256`,
+/// sign-extending it.
+fn convert_i256(value: &[u8]) -> i256 {
+    let n = value.len();
+    let sign = if matches!(value.first(), Some(x) if x & 0x80 != 0) {
+        0xFF
+    } else {
+        0
+    };
+    let mut bytes = [sign; 32];
+    bytes[32 - n..].copy_from_slice(value);
+    i256::from_be_bytes(bytes)
+}
+
+impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i256> {
+    type Error = ArrowError;
+    fn try_from((stats, data_type): (&ParquetFixedLenStatistics, DataType)) -> Result<Self> {
+        let byte_lens = match stats.physical_type() {
+            PhysicalType::FixedLenByteArray(size) => *size,
+            _ => unreachable!(),
+        };
+        if byte_lens > 32 {
+            Err(ArrowError::ExternalFormat(format!(
+                "Can't deserialize i256 from Fixed Len Byte array with length {:?}",
+                byte_lens
+            )))
+        } else {
+            let max_value = stats.max_value.as_deref().map(convert_i256);
+            let min_value = stats.min_value.as_deref().map(convert_i256);
             Ok(Self {
                 data_type,
                 null_count: stats.null_count,
@@ -105,6 +141,7 @@ pub(super) fn statistics_from_fix_len(
     use DataType::*;
     Ok(match data_type {
         Decimal(_, _) => Box::new(PrimitiveStatistics::<i128>::try_from((stats, data_type))?),
+        Decimal256(_, _) => Box::new(PrimitiveStatistics::<i256>::try_from((stats, data_type))?),
         FixedSizeBinary(_) => Box::new(FixedLenStatistics::from(stats)),
         other => {
             return Err(ArrowError::NotYetImplemented(format!(
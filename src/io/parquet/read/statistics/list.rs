@@ -0,0 +1,37 @@
+use crate::datatypes::DataType;
+use std::any::Any;
+
+use super::Statistics;
+
+/// Statistics of a list (or large list) parquet column, i.e. the statistics of its leaf values.
+///
+/// Parquet stores a single null count per leaf column, which aggregates nulls across all
+/// repetition levels: it counts null values and null lists (of any nesting level),
+/// but not empty lists.
+#[derive(Debug)]
+pub struct ListStatistics {
+    /// the data type of the list
+    pub data_type: DataType,
+    /// the statistics of the values of the list
+    pub values: Box<dyn Statistics>,
+}
+
+impl PartialEq for ListStatistics {
+    fn eq(&self, other: &Self) -> bool {
+        self.data_type == other.data_type && self.values.as_ref() == other.values.as_ref()
+    }
+}
+
+impl Statistics for ListStatistics {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn null_count(&self) -> Option<i64> {
+        self.values.null_count()
+    }
+}
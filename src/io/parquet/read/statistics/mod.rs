@@ -19,6 +19,8 @@ mod boolean;
 pub use boolean::*;
 mod fixlen;
 pub use fixlen::*;
+mod list;
+pub use list::*;
 
 use super::get_field_columns;
 
@@ -48,7 +50,7 @@ impl PartialEq for Box<dyn Statistics> {
 
 /// Deserializes [`ParquetStatistics`] into [`Statistics`] based on `data_type`.
 /// This takes into account the Arrow schema declared in Parquet's schema
-fn _deserialize_statistics(
+pub(super) fn _deserialize_statistics(
     stats: &dyn ParquetStatistics,
     data_type: DataType,
 ) -> Result<Box<dyn Statistics>> {
@@ -97,35 +99,59 @@ fn _deserialize_statistics(
     }
 }
 
-fn get_fields(field: &Field) -> Vec<&Field> {
-    match field.data_type.to_logical_type() {
-        DataType::List(inner) => get_fields(inner),
-        DataType::LargeList(inner) => get_fields(inner),
-        DataType::Struct(fields) => fields.iter().map(get_fields).flatten().collect(),
-        _ => vec![field],
-    }
+/// Deserializes the statistics of the (leaf) columns of `data_type`, wrapping them in
+/// [`ListStatistics`] for each list they are nested in.
+fn deserialize_nested<'a, I: Iterator<Item = &'a ColumnChunkMetaData>>(
+    data_type: &DataType,
+    columns: &mut I,
+    statistics: &mut Vec<Option<Box<dyn Statistics>>>,
+) -> Result<()> {
+    match data_type.to_logical_type() {
+        DataType::List(inner) | DataType::LargeList(inner) => {
+            let start = statistics.len();
+            deserialize_nested(&inner.data_type, columns, statistics)?;
+            statistics[start..].iter_mut().for_each(|x| {
+                *x = x.take().map(|values| {
+                    Box::new(ListStatistics {
+                        data_type: data_type.clone(),
+                        values,
+                    }) as Box<dyn Statistics>
+                })
+            });
+        }
+        DataType::Struct(fields) => {
+            for field in fields {
+                deserialize_nested(&field.data_type, columns, statistics)?;
+            }
+        }
+        _ => {
+            let column = columns.next().ok_or_else(|| {
+                ArrowError::oos("The field has more leaf columns than the row group")
+            })?;
+            statistics.push(
+                column
+                    .statistics()
+                    .map(|x| _deserialize_statistics(x?.as_ref(), data_type.clone()))
+                    .transpose()?,
+            );
+        }
+    };
+    Ok(())
 }
 
 /// Deserializes [`ParquetStatistics`] into [`Statistics`] associated to `field`
 ///
 /// For non-nested types, it returns a single column.
-/// For nested types, it returns one column per parquet primitive column.
+/// For nested types, it returns one column per parquet primitive column:
+/// the statistics of columns nested in (large) lists are wrapped in [`ListStatistics`],
+/// once per list level.
 pub fn deserialize_statistics(
     field: &Field,
     columns: &[ColumnChunkMetaData],
 ) -> Result<Vec<Option<Box<dyn Statistics>>>> {
     let columns = get_field_columns(columns, field.name.as_ref());
 
-    let fields = get_fields(field);
-
-    columns
-        .into_iter()
-        .zip(fields.into_iter())
-        .map(|(column, field)| {
-            column
-                .statistics()
-                .map(|x| _deserialize_statistics(x?.as_ref(), field.data_type.clone()))
-                .transpose()
-        })
-        .collect()
+    let mut statistics = vec![];
+    deserialize_nested(&field.data_type, &mut columns.into_iter(), &mut statistics)?;
+    Ok(statistics)
 }
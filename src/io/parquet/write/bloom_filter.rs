@@ -0,0 +1,381 @@
+//! APIs to build and serialize parquet's split-block bloom filters (SBBF).
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use parquet2::compression::Compression;
+use parquet2::encoding::{delta_length_byte_array, Encoding};
+use parquet2::metadata::ColumnDescriptor;
+use parquet2::page::{split_buffer, DataPageHeader, DataPageHeaderExt};
+use parquet2::read::{BasicDecompressor, PageFilter, PageIterator};
+use parquet2::FallibleStreamingIterator;
+
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+use xxhash_rust::xxh64::xxh64;
+
+use crate::array::{Array, BinaryArray, DictionaryArray, Offset, Utf8Array};
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+use crate::io::parquet::read::column_iter_to_arrays;
+
+/// The salts of the split-block bloom filter, as declared in the parquet specification.
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+/// The number of bytes of a block (a power of two)
+const BLOCK_SIZE: usize = 32;
+/// The minimum size of a bloom filter, in bytes
+const MIN_SIZE: usize = BLOCK_SIZE;
+/// The maximum size of a bloom filter, in bytes (128 MiB, as used by parquet-mr)
+const MAX_SIZE: usize = 128 * 1024 * 1024;
+
+/// Options declaring which columns are written with a bloom filter, see
+/// [`WriteOptions::bloom_filters`](super::WriteOptions::bloom_filters).
+///
+/// The filter of each column chunk is built from its values by
+/// [`FileWriter`](super::FileWriter) as the chunk is written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The names of the (top-level) fields whose column chunks have a bloom filter
+    pub columns: Vec<String>,
+    /// The target false-positive probability of each filter, in `]0, 1[`
+    pub false_positive_probability: f64,
+}
+
+impl BloomFilterOptions {
+    /// Returns whether a bloom filter is to be written for the field `name`
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.columns.iter().any(|column| column == name)
+    }
+
+    /// Returns the field of `fields` of each of the columns of these options.
+    /// # Error
+    /// Errors iff a column is not a field of `fields`, its field is not utf8 or binary (or a
+    /// dictionary of them), or the false-positive probability is not in `]0, 1[`.
+    pub(super) fn fields<'a>(&self, fields: &'a [Field]) -> Result<Vec<&'a Field>> {
+        check_fpp(self.false_positive_probability)?;
+        self.columns
+            .iter()
+            .map(|name| {
+                let field = fields
+                    .iter()
+                    .find(|field| &field.name == name)
+                    .ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "The column \"{}\" of the bloom filter options is not a field of the schema",
+                            name
+                        ))
+                    })?;
+                let data_type = match field.data_type().to_logical_type() {
+                    DataType::Dictionary(_, values, _) => values.to_logical_type(),
+                    data_type => data_type,
+                };
+                match data_type {
+                    DataType::Utf8 | DataType::LargeUtf8 | DataType::Binary | DataType::LargeBinary => {
+                        Ok(field)
+                    }
+                    other => Err(not_implemented(other)),
+                }
+            })
+            .collect()
+    }
+}
+
+/// A split-block bloom filter, as declared in the parquet specification.
+///
+/// Values are hashed with xxHash64 (seed 0) of their (utf8 or binary) bytes, which makes it
+/// interoperable with filters written by parquet-mr and pyarrow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    blocks: Vec<[u32; 8]>,
+}
+
+/// Returns the number of bytes of a filter that holds `ndv` distinct values with a
+/// false-positive probability of `fpp`.
+fn optimal_num_bytes(ndv: usize, fpp: f64) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes.clamp(MIN_SIZE, MAX_SIZE).next_power_of_two()
+}
+
+fn block_mask(hash: u32) -> [u32; 8] {
+    let mut mask = [0u32; 8];
+    mask.iter_mut().zip(SALT.iter()).for_each(|(word, salt)| {
+        *word = 1 << (hash.wrapping_mul(*salt) >> 27);
+    });
+    mask
+}
+
+impl BloomFilter {
+    /// Returns a new empty [`BloomFilter`] of `num_bytes` bytes.
+    /// # Error
+    /// Errors iff `num_bytes` is not a non-zero multiple of 32.
+    pub fn try_new(num_bytes: usize) -> Result<Self> {
+        if num_bytes == 0 || num_bytes & (BLOCK_SIZE - 1) != 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A bloom filter must have a non-zero multiple of {} bytes",
+                BLOCK_SIZE
+            )));
+        }
+        Ok(Self {
+            blocks: vec![[0; 8]; num_bytes / BLOCK_SIZE],
+        })
+    }
+
+    /// Returns a new empty [`BloomFilter`] sized to hold `ndv` distinct values with a
+    /// false-positive probability of `fpp`.
+    /// # Error
+    /// Errors iff `fpp` is not in `]0, 1[`
+    pub fn try_with_fpp(ndv: usize, fpp: f64) -> Result<Self> {
+        check_fpp(fpp)?;
+        Self::try_new(optimal_num_bytes(ndv, fpp))
+    }
+
+    /// The number of bytes of the bitset of this filter
+    pub fn num_bytes(&self) -> usize {
+        self.blocks.len() * BLOCK_SIZE
+    }
+
+    /// Returns the hash of `value` used by this filter
+    #[inline]
+    pub fn hash(value: &[u8]) -> u64 {
+        xxh64(value, 0)
+    }
+
+    #[inline]
+    fn block_index(&self, hash: u64) -> usize {
+        (((hash >> 32) * self.blocks.len() as u64) >> 32) as usize
+    }
+
+    /// Inserts a hash (see [`BloomFilter::hash`]) into the filter
+    #[inline]
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = self.block_index(hash);
+        let mask = block_mask(hash as u32);
+        self.blocks[index]
+            .iter_mut()
+            .zip(mask.iter())
+            .for_each(|(word, mask)| *word |= mask);
+    }
+
+    /// Returns whether a hash (see [`BloomFilter::hash`]) may be in the set.
+    /// `false` means that the hash is definitely not in the set.
+    #[inline]
+    pub fn contains_hash(&self, hash: u64) -> bool {
+        let index = self.block_index(hash);
+        let mask = block_mask(hash as u32);
+        self.blocks[index]
+            .iter()
+            .zip(mask.iter())
+            .all(|(word, mask)| word & mask != 0)
+    }
+
+    /// Inserts `value` into the filter
+    #[inline]
+    pub fn insert(&mut self, value: &[u8]) {
+        self.insert_hash(Self::hash(value))
+    }
+
+    /// Returns whether `value` may be in the set.
+    /// `false` means that `value` is definitely not in the set.
+    #[inline]
+    pub fn contains(&self, value: &[u8]) -> bool {
+        self.contains_hash(Self::hash(value))
+    }
+
+    /// Writes this filter (its thrift header followed by its bitset) to `writer`,
+    /// returning the number of bytes written.
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<u64> {
+        let header = BloomFilterHeader::new(
+            self.num_bytes() as i32,
+            BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+            BloomFilterHash::XXHASH(XxHash {}),
+            BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+        );
+        let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+        let header_len = header
+            .write_to_out_protocol(&mut protocol)
+            .and_then(|len| protocol.flush().map(|_| len))
+            .map_err(thrift_error)?;
+
+        let bitset = self
+            .blocks
+            .iter()
+            .flatten()
+            .flat_map(|word| word.to_le_bytes())
+            .collect::<Vec<_>>();
+        writer.write_all(&bitset)?;
+        Ok((header_len + bitset.len()) as u64)
+    }
+
+    /// Reads a filter written by [`BloomFilter::write`] (or by any other parquet implementation)
+    /// from `reader`.
+    /// # Error
+    /// Errors iff the header is invalid or the bitset is not a non-zero multiple of 32 bytes.
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut protocol = TCompactInputProtocol::new(&mut *reader);
+        let header =
+            BloomFilterHeader::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+        if header.num_bytes < 0 {
+            return Err(ArrowError::oos(
+                "The bloom filter header must have a non-negative number of bytes",
+            ));
+        }
+
+        let mut filter = Self::try_new(header.num_bytes as usize).map_err(|_| {
+            ArrowError::oos("The bitset of a bloom filter must be a multiple of 32")
+        })?;
+        let mut bitset = vec![0; header.num_bytes as usize];
+        reader.read_exact(&mut bitset)?;
+        filter
+            .blocks
+            .iter_mut()
+            .flatten()
+            .zip(bitset.chunks_exact(4))
+            .for_each(|(word, bytes)| {
+                *word = u32::from_le_bytes(bytes.try_into().unwrap());
+            });
+        Ok(filter)
+    }
+}
+
+fn check_fpp(fpp: f64) -> Result<()> {
+    if fpp > 0.0 && fpp < 1.0 {
+        Ok(())
+    } else {
+        Err(ArrowError::InvalidArgumentError(
+            "The false-positive probability of a bloom filter must be in ]0, 1[".to_string(),
+        ))
+    }
+}
+
+fn not_implemented(data_type: &DataType) -> ArrowError {
+    ArrowError::NotYetImplemented(format!(
+        "Writing bloom filters for {:?} is not yet implemented",
+        data_type
+    ))
+}
+
+fn thrift_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ArrowError::ExternalFormat(format!("Failed to serialize the bloom filter: {}", error))
+}
+
+fn hashes<'a, I: Iterator<Item = Option<&'a [u8]>>>(iter: I) -> Vec<u64> {
+    iter.flatten().map(BloomFilter::hash).collect()
+}
+
+fn utf8_hashes<O: Offset>(array: &dyn Array) -> Vec<u64> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    hashes(array.iter().map(|x| x.map(|x| x.as_bytes())))
+}
+
+fn binary_hashes<O: Offset>(array: &dyn Array) -> Vec<u64> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    hashes(array.iter())
+}
+
+/// Returns the hashes of the non-null values of `array`. The hashes of a dictionary-encoded
+/// array are the hashes of its dictionary's values.
+fn array_hashes(array: &dyn Array) -> Result<Vec<u64>> {
+    Ok(match array.data_type().to_logical_type() {
+        DataType::Utf8 => utf8_hashes::<i32>(array),
+        DataType::LargeUtf8 => utf8_hashes::<i64>(array),
+        DataType::Binary => binary_hashes::<i32>(array),
+        DataType::LargeBinary => binary_hashes::<i64>(array),
+        DataType::Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            return array_hashes(array.values().as_ref());
+        }),
+        other => return Err(not_implemented(other)),
+    })
+}
+
+/// Returns a [`BloomFilter`] with `hashes`, sized by their number of distinct values.
+fn from_hashes(mut hashes: Vec<u64>, fpp: f64) -> Result<BloomFilter> {
+    hashes.sort_unstable();
+    hashes.dedup();
+    let mut filter = BloomFilter::try_with_fpp(hashes.len(), fpp)?;
+    hashes.into_iter().for_each(|hash| filter.insert_hash(hash));
+    Ok(filter)
+}
+
+/// Builds a [`BloomFilter`] with the non-null values of `array`, sized by its number of
+/// distinct values and `fpp` (the target false-positive probability).
+/// # Error
+/// Errors iff the array is not utf8 or binary (or a dictionary of them), or `fpp` is not in
+/// `]0, 1[`.
+pub fn build_bloom_filter(array: &dyn Array, fpp: f64) -> Result<BloomFilter> {
+    from_hashes(array_hashes(array)?, fpp)
+}
+
+fn is_delta_length(header: &DataPageHeader) -> bool {
+    let encoding = match header {
+        DataPageHeader::V1(header) => header.encoding(),
+        DataPageHeader::V2(header) => header.encoding(),
+    };
+    encoding == Encoding::DeltaLengthByteArray
+}
+
+/// Builds a [`BloomFilter`] with the non-null values of a serialized column chunk of the
+/// (utf8 or binary, or a dictionary of them) `field`, i.e. `pages` with `num_values` values
+/// compressed by `compression`.
+///
+/// The values of `DeltaLengthByteArray`-encoded pages are hashed as they are decoded, since
+/// they cannot be deserialized to arrays; the remaining pages are deserialized to arrays.
+pub(super) fn build_chunk_bloom_filter(
+    pages: Vec<u8>,
+    num_values: i64,
+    compression: Compression,
+    descriptor: ColumnDescriptor,
+    field: Field,
+    fpp: f64,
+) -> Result<BloomFilter> {
+    let mut hashes = vec![];
+
+    let filter: PageFilter = Arc::new(|_, header| is_delta_length(header));
+    let iter = PageIterator::new(
+        std::io::Cursor::new(pages.as_slice()),
+        num_values,
+        compression,
+        descriptor.clone(),
+        filter,
+        vec![],
+    );
+    let mut iter = BasicDecompressor::new(iter, vec![]);
+    while let Some(page) = iter.next()? {
+        let (_, _, values) = split_buffer(page, &descriptor);
+        let mut decoder = delta_length_byte_array::Decoder::new(values);
+        let lengths = decoder.by_ref().collect::<Vec<_>>();
+        let mut values = decoder.into_values();
+        for length in lengths {
+            let (value, remaining) = values.split_at(length as usize);
+            hashes.push(BloomFilter::hash(value));
+            values = remaining;
+        }
+    }
+
+    let type_ = descriptor.type_().clone();
+    let filter: PageFilter = Arc::new(|_, header| !is_delta_length(header));
+    let pages = PageIterator::new(
+        std::io::Cursor::new(pages),
+        num_values,
+        compression,
+        descriptor,
+        filter,
+        vec![],
+    );
+    let pages = BasicDecompressor::new(pages, vec![]);
+    for array in column_iter_to_arrays(vec![pages], vec![&type_], field, num_values as usize)? {
+        hashes.extend(array_hashes(array?.as_ref())?);
+    }
+    from_hashes(hashes, fpp)
+}
@@ -33,7 +33,9 @@ where
     encode_plain(array, is_optional, &mut buffer)?;
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array))
+        let mut statistics = build_statistics(array);
+        statistics.null_count = Some(levels::null_count(array, &nested));
+        Some(statistics)
     } else {
         None
     };
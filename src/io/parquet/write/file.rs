@@ -1,38 +1,390 @@
 use std::io::Write;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
-use parquet2::metadata::SchemaDescriptor;
-use parquet2::write::RowGroupIter;
-use parquet2::{metadata::KeyValue, write::WriteOptions};
+use parquet2::compression::Compression;
+use parquet2::metadata::KeyValue;
+use parquet2::metadata::{ColumnDescriptor, SchemaDescriptor};
+use parquet2::page::CompressedPage;
+use parquet2::write::{DynIter, DynStreamingIterator, RowGroupIter};
+use parquet2::FallibleStreamingIterator;
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{ColumnMetaData, FileMetaData, PageHeader};
 
-use crate::datatypes::Schema;
+use crate::datatypes::{Field, Schema};
 use crate::error::{ArrowError, Result};
 
-use super::{schema::schema_to_metadata_key, to_parquet_schema};
+use super::bloom_filter::{build_chunk_bloom_filter, BloomFilter};
+use super::{schema::schema_to_metadata_key, to_parquet_schema, WriteOptions};
 
 /// Attaches [`Schema`] to `key_value_metadata`
+/// # Errors
+/// This function errors iff the IPC format does not support a data type of `schema`.
 pub fn add_arrow_schema(
     schema: &Schema,
     key_value_metadata: Option<Vec<KeyValue>>,
-) -> Option<Vec<KeyValue>> {
-    key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
+) -> Result<Option<Vec<KeyValue>>> {
+    let key = schema_to_metadata_key(schema)?;
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    key_value_metadata.push(key);
+    Ok(Some(key_value_metadata))
+}
+
+/// The state of a [`FooterWriter`] shared with [`FileWriter`], since the writer is owned by
+/// parquet2's writer.
+#[derive(Debug, Default)]
+struct SharedState {
+    /// whether the footer is buffered
+    buffer_footer: AtomicBool,
+    /// whether each column chunk is buffered until parquet2 flushes it
+    buffer_chunks: AtomicBool,
+    /// the number of pages of the column chunk being written
+    num_pages: AtomicUsize,
+    /// the compression of the data pages of the column chunk being written
+    codec: Mutex<Option<Compression>>,
+    /// the error of building a bloom filter, that parquet2 would report as an i/o error
+    error: Mutex<Option<ArrowError>>,
+}
+
+/// The location of a column chunk rewritten by [`FooterWriter`]
+#[derive(Debug, Clone, Copy)]
+struct ChunkLocation {
+    data_page_offset: i64,
+    total_compressed_size: i64,
+    total_uncompressed_size: i64,
+    file_offset: i64,
+}
+
+/// The bloom filters built by a [`FooterWriter`]
+struct BloomFilters {
+    /// the field and descriptor of each column, when it is written with a bloom filter
+    columns: Vec<Option<(Field, ColumnDescriptor)>>,
+    false_positive_probability: f64,
+    /// the filter of each column chunk written, in order
+    filters: Vec<Option<BloomFilter>>,
+}
+
+impl BloomFilters {
+    /// Builds the filter of the next column chunk, whose `pages` have `num_values` values
+    /// compressed by `compression`.
+    fn push(&mut self, pages: &[u8], num_values: i64, compression: Compression) -> Result<()> {
+        let column = self.filters.len() % self.columns.len();
+        let filter = self.columns[column]
+            .as_ref()
+            .map(|(field, descriptor)| {
+                build_chunk_bloom_filter(
+                    pages.to_vec(),
+                    num_values,
+                    compression,
+                    descriptor.clone(),
+                    field.clone(),
+                    self.false_positive_probability,
+                )
+            })
+            .transpose()?;
+        self.filters.push(filter);
+        Ok(())
+    }
+}
+
+/// A [`Write`] that forwards to `writer`, or buffers to `footer` once `buffer_footer` is set.
+///
+/// When `buffer_chunks` is set, the bytes of each column chunk are buffered until parquet2
+/// flushes them (after the column chunk's metadata). The bloom filter of the column chunk is
+/// then built from its pages, and, when `write_crc` is set, it is forwarded with the checksum
+/// of each page declared in its header. Since this changes the size of the headers, the
+/// locations of the column chunks in the file are recorded in `chunks`, to be amended in the
+/// footer.
+struct FooterWriter<W: Write> {
+    writer: W,
+    state: Arc<SharedState>,
+    write_crc: bool,
+    bloom_filters: Option<BloomFilters>,
+    chunk: Vec<u8>,
+    chunks: Vec<ChunkLocation>,
+    footer: Vec<u8>,
+    /// the number of bytes written to `writer`
+    offset: u64,
+}
+
+fn invalid_data(error: parquet_format_async_temp::thrift::Error) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, error.to_string())
+}
+
+/// A page of a serialized column chunk: its header, the size of its header and its bytes
+type SerializedPage<'a> = (PageHeader, usize, &'a [u8]);
+
+/// Splits a serialized column chunk, composed of `num_pages` pages followed by its metadata.
+fn split_chunk(
+    mut data: &[u8],
+    num_pages: usize,
+) -> std::io::Result<(Vec<SerializedPage<'_>>, ColumnMetaData)> {
+    let mut pages = Vec::with_capacity(num_pages);
+    for _ in 0..num_pages {
+        let length = data.len();
+        let header = PageHeader::read_from_in_protocol(&mut TCompactInputProtocol::new(&mut data))
+            .map_err(invalid_data)?;
+        let header_size = length - data.len();
+
+        let size = header.compressed_page_size as usize;
+        if size > data.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "A page is larger than its column chunk",
+            ));
+        }
+        let (page, remaining) = data.split_at(size);
+        data = remaining;
+        pages.push((header, header_size, page));
+    }
+
+    let metadata =
+        ColumnMetaData::read_from_in_protocol(&mut TCompactInputProtocol::new(&mut data))
+            .map_err(invalid_data)?;
+    Ok((pages, metadata))
+}
+
+impl<W: Write> FooterWriter<W> {
+    /// Writes the column chunk in `self.chunk`, composed of `num_pages` pages followed by its
+    /// metadata, building its bloom filter and declaring the checksum of each page.
+    fn write_chunk(&mut self, num_pages: usize) -> std::io::Result<()> {
+        let chunk = std::mem::take(&mut self.chunk);
+        let (pages, mut metadata) = split_chunk(&chunk, num_pages)?;
+
+        if let Some(bloom_filters) = self.bloom_filters.as_mut() {
+            let compression = match self.state.codec.lock().unwrap().take() {
+                Some(compression) => compression,
+                None => Compression::try_from(metadata.codec).map_err(|error| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, error.to_string())
+                })?,
+            };
+            let size = pages
+                .iter()
+                .map(|(_, header_size, page)| header_size + page.len())
+                .sum::<usize>();
+            if let Err(error) = bloom_filters.push(&chunk[..size], metadata.num_values, compression)
+            {
+                let message = error.to_string();
+                *self.state.error.lock().unwrap() = Some(error);
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, message));
+            }
+        }
+
+        if !self.write_crc {
+            self.writer.write_all(&chunk)?;
+            self.offset += chunk.len() as u64;
+            return Ok(());
+        }
+
+        let mut result = Vec::with_capacity(chunk.len() + num_pages * 6);
+        let mut delta = 0i64;
+        for (mut header, header_size, page) in pages {
+            header.crc = Some(crc::crc32::checksum_ieee(page) as i32);
+
+            let mut protocol = TCompactOutputProtocol::new(&mut result);
+            let new_header_size = header
+                .write_to_out_protocol(&mut protocol)
+                .map_err(invalid_data)?;
+            protocol.flush().map_err(invalid_data)?;
+            result.extend_from_slice(page);
+            delta += new_header_size as i64 - header_size as i64;
+        }
+
+        if num_pages > 0 {
+            metadata.data_page_offset = self.offset as i64;
+        }
+        metadata.total_compressed_size += delta;
+        metadata.total_uncompressed_size += delta;
+        self.chunks.push(ChunkLocation {
+            data_page_offset: metadata.data_page_offset,
+            total_compressed_size: metadata.total_compressed_size,
+            total_uncompressed_size: metadata.total_uncompressed_size,
+            file_offset: (self.offset + result.len() as u64) as i64,
+        });
+
+        let mut protocol = TCompactOutputProtocol::new(&mut result);
+        metadata
+            .write_to_out_protocol(&mut protocol)
+            .map_err(invalid_data)?;
+        protocol.flush().map_err(invalid_data)?;
+
+        self.writer.write_all(&result)?;
+        self.offset += result.len() as u64;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for FooterWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.state.buffer_footer.load(Ordering::Relaxed) {
+            self.footer.extend_from_slice(buf);
+            Ok(buf.len())
+        } else if self.state.buffer_chunks.load(Ordering::Relaxed) {
+            self.chunk.extend_from_slice(buf);
+            Ok(buf.len())
+        } else {
+            let written = self.writer.write(buf)?;
+            self.offset += written as u64;
+            Ok(written)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // parquet2 flushes after writing the metadata of each column chunk
+        if !self.chunk.is_empty() {
+            let num_pages = self.state.num_pages.swap(0, Ordering::Relaxed);
+            self.write_chunk(num_pages)?;
+        }
+        self.writer.flush()
+    }
+}
+
+/// A column chunk that records the [`Compression`] of its data pages in `codec` and counts its
+/// pages, in `state`.
+struct RecordCompression<'a> {
+    iter: DynStreamingIterator<'a, CompressedPage, ArrowError>,
+    codec: &'a mut Option<Compression>,
+    state: &'a SharedState,
+}
+
+impl<'a> FallibleStreamingIterator for RecordCompression<'a> {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        self.iter.advance()?;
+        match self.iter.get() {
+            Some(CompressedPage::Data(page)) => {
+                *self.codec = Some(page.compression());
+                *self.state.codec.lock().unwrap() = Some(page.compression());
+                self.state.num_pages.fetch_add(1, Ordering::Relaxed);
+            }
+            Some(CompressedPage::Dict(_)) => {
+                self.state.num_pages.fetch_add(1, Ordering::Relaxed);
+            }
+            None => {}
+        }
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.iter.get()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+fn thrift_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ArrowError::ExternalFormat(format!("Failed to amend the parquet footer: {}", error))
+}
+
+/// Sets the codec and the bloom filter offset of the column chunks of the serialized `footer` to
+/// `codecs` and `bloom_filter_offsets` (one per column per row group), and their location to
+/// `chunks` (one per column chunk, when rewritten with checksums), returning the new footer.
+fn amend_footer(
+    footer: &[u8],
+    codecs: &[Vec<Option<Compression>>],
+    bloom_filter_offsets: &[Vec<Option<i64>>],
+    chunks: &[ChunkLocation],
+) -> Result<Vec<u8>> {
+    // the footer is the thrift-encoded metadata, its length (4 bytes) and the magic (4 bytes)
+    let (metadata, end) = footer.split_at(footer.len().saturating_sub(8));
+    let mut metadata =
+        FileMetaData::read_from_in_protocol(&mut TCompactInputProtocol::new(metadata))
+            .map_err(thrift_error)?;
+
+    metadata
+        .row_groups
+        .iter_mut()
+        .zip(codecs.iter().zip(bloom_filter_offsets))
+        .flat_map(|(row_group, (codecs, offsets))| {
+            row_group.columns.iter_mut().zip(codecs.iter().zip(offsets))
         })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]))
+        .for_each(|(column, (codec, offset))| {
+            if let Some(meta_data) = column.meta_data.as_mut() {
+                if let Some(codec) = codec {
+                    meta_data.codec = (*codec).into();
+                }
+                if offset.is_some() {
+                    meta_data.bloom_filter_offset = *offset;
+                }
+            }
+        });
+
+    if !chunks.is_empty() {
+        let mut chunks = chunks.iter();
+        for row_group in metadata.row_groups.iter_mut() {
+            for column in row_group.columns.iter_mut() {
+                let location = chunks.next().ok_or_else(|| {
+                    ArrowError::ExternalFormat(
+                        "Failed to amend the parquet footer: a column chunk was not written"
+                            .to_string(),
+                    )
+                })?;
+                column.file_offset = location.file_offset;
+                if let Some(meta_data) = column.meta_data.as_mut() {
+                    meta_data.data_page_offset = location.data_page_offset;
+                    meta_data.total_compressed_size = location.total_compressed_size;
+                    meta_data.total_uncompressed_size = location.total_uncompressed_size;
+                }
+            }
+            row_group.total_byte_size = row_group
+                .columns
+                .iter()
+                .filter_map(|column| column.meta_data.as_ref())
+                .map(|meta_data| meta_data.total_compressed_size)
+                .sum();
+            row_group.file_offset = row_group
+                .columns
+                .first()
+                .and_then(|column| column.meta_data.as_ref())
+                .map(|meta_data| meta_data.data_page_offset);
+        }
+    }
+
+    let mut footer = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut footer);
+    let length = metadata
+        .write_to_out_protocol(&mut protocol)
+        .map_err(thrift_error)? as i32;
+    protocol.flush().map_err(thrift_error)?;
+    footer.extend_from_slice(&length.to_le_bytes());
+    footer.extend_from_slice(&end[4..]);
+    Ok(footer)
 }
 
 /// An interface to write a parquet to a [`Write`]
+///
+/// The metadata of each column chunk records the [`Compression`] of its pages, that may differ
+/// from the compression of the [`WriteOptions`] (e.g. via
+/// [`RowGroupIterator::with_compression`](super::RowGroupIterator::with_compression)).
+///
+/// The bloom filters of the columns declared in [`WriteOptions::bloom_filters`] are built from
+/// the pages of each column chunk as it is written, buffering the column chunk in memory. They
+/// are written after the last row group and are referenced from the metadata of their column
+/// chunks.
+///
+/// When [`WriteOptions::write_crc`] is set, the header of each page declares the checksum (CRC32)
+/// of its bytes. Since parquet2 writes the headers without it, each column chunk is then buffered
+/// in memory and rewritten with the checksums before being written to the [`Write`].
 pub struct FileWriter<W: Write> {
-    writer: parquet2::write::FileWriter<W>,
+    writer: parquet2::write::FileWriter<FooterWriter<W>>,
     schema: Schema,
+    options: WriteOptions,
+    codecs: Vec<Vec<Option<Compression>>>,
+    state: Arc<SharedState>,
+    write_arrow_schema: bool,
 }
 
 // Accessors
 impl<W: Write> FileWriter<W> {
     /// The options assigned to the file
     pub fn options(&self) -> &WriteOptions {
-        self.writer.options()
+        &self.options
     }
 
     /// The [`SchemaDescriptor`] assigned to this file
@@ -49,35 +401,167 @@ impl<W: Write> FileWriter<W> {
 impl<W: Write> FileWriter<W> {
     /// Returns a new [`FileWriter`].
     /// # Error
-    /// If it is unable to derive a parquet schema from [`Schema`].
+    /// If it is unable to derive a parquet schema from [`Schema`], or a column of
+    /// [`WriteOptions::bloom_filters`] is not a utf8 or binary field (or a dictionary of them) of
+    /// the [`Schema`].
     pub fn try_new(writer: W, schema: Schema, options: WriteOptions) -> Result<Self> {
         let parquet_schema = to_parquet_schema(&schema)?;
 
+        let bloom_filters = options
+            .bloom_filters
+            .as_ref()
+            .map(|bloom_options| {
+                let fields = bloom_options.fields(&schema.fields)?;
+                // utf8 and binary fields (and dictionaries of them) are not nested, and thus have
+                // a single column
+                let columns = parquet_schema
+                    .columns()
+                    .iter()
+                    .map(|column| {
+                        fields
+                            .iter()
+                            .find(|field| field.name == column.path_in_schema()[0])
+                            .map(|field| ((*field).clone(), column.clone()))
+                    })
+                    .collect();
+                Result::Ok(BloomFilters {
+                    columns,
+                    false_positive_probability: bloom_options.false_positive_probability,
+                    filters: vec![],
+                })
+            })
+            .transpose()?;
+
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
+        let state = Arc::new(SharedState::default());
 
         Ok(Self {
-            writer: parquet2::write::FileWriter::new(writer, parquet_schema, options, created_by),
+            writer: parquet2::write::FileWriter::new(
+                FooterWriter {
+                    writer,
+                    state: state.clone(),
+                    write_crc: options.write_crc,
+                    bloom_filters,
+                    chunk: vec![],
+                    chunks: vec![],
+                    footer: vec![],
+                    offset: 0,
+                },
+                parquet_schema,
+                (&options).into(),
+                created_by,
+            ),
             schema,
+            options,
+            codecs: vec![],
+            state,
+            write_arrow_schema: true,
         })
     }
 
+    /// Sets whether the [`Schema`] is written to the key-value metadata of the file, under
+    /// `"ARROW:schema"` (the default). Readers use it to recover the exact arrow types of the
+    /// file (e.g. timezones, extension types and field metadata).
+    pub fn with_arrow_schema(mut self, write_arrow_schema: bool) -> Self {
+        self.write_arrow_schema = write_arrow_schema;
+        self
+    }
+
     /// Writes the header of the file
     pub fn start(&mut self) -> Result<()> {
-        Ok(self.writer.start()?)
+        self.writer.start()?;
+        // the magic number is written as is
+        let buffer_chunks = self.options.write_crc || self.options.bloom_filters.is_some();
+        self.state
+            .buffer_chunks
+            .store(buffer_chunks, Ordering::Relaxed);
+        Ok(())
     }
 
     /// Writes a row group to the file.
+    /// # Errors
+    /// Errors iff the row group errors, or the bloom filter of one of its column chunks cannot
+    /// be built from its pages (e.g. pages whose encoding cannot be read).
     pub fn write(
         &mut self,
         row_group: RowGroupIter<'_, ArrowError>,
         num_rows: usize,
     ) -> Result<()> {
-        Ok(self.writer.write(row_group, num_rows)?)
+        let mut codecs = vec![None; self.writer.schema().columns().len()];
+        let state = self.state.as_ref();
+        let row_group = DynIter::new(row_group.zip(codecs.iter_mut()).map(|(column, codec)| {
+            column.map(|iter| DynStreamingIterator::new(RecordCompression { iter, codec, state }))
+        }));
+        let result = self.writer.write(row_group, num_rows);
+        if let Some(error) = self.state.error.lock().unwrap().take() {
+            return Err(error);
+        }
+        result?;
+        self.codecs.push(codecs);
+        Ok(())
     }
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<(u64, W)> {
-        let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
-        Ok(self.writer.end(key_value_metadata)?)
+        let key_value_metadata = if self.write_arrow_schema {
+            add_arrow_schema(&self.schema, key_value_metadata)?
+        } else {
+            key_value_metadata
+        };
+        let compression = self.options.compression;
+        let amend = self
+            .codecs
+            .iter()
+            .flatten()
+            .any(|codec| matches!(codec, Some(codec) if *codec != compression))
+            || self.options.bloom_filters.is_some()
+            || self.options.write_crc;
+        if !amend {
+            let (size, writer) = self.writer.end(key_value_metadata)?;
+            return Ok((size, writer.writer));
+        }
+
+        // parquet2 declares the compression of the options on every column chunk and does not
+        // write bloom filters; the footer is buffered so that the bloom filters are written
+        // before it, and to declare the compression, bloom filter and location of each column
+        // chunk.
+        self.state.buffer_footer.store(true, Ordering::Relaxed);
+        let (_, mut writer) = self.writer.end(key_value_metadata)?;
+        let mut offset = writer.offset;
+        let mut filters = writer
+            .bloom_filters
+            .take()
+            .map(|bloom_filters| bloom_filters.filters)
+            .unwrap_or_default()
+            .into_iter();
+        let bloom_filter_offsets = self
+            .codecs
+            .iter()
+            .map(|codecs| {
+                codecs
+                    .iter()
+                    .map(|_| {
+                        filters
+                            .next()
+                            .flatten()
+                            .map(|filter| {
+                                let start = offset as i64;
+                                offset += filter.write(&mut writer.writer)?;
+                                Ok(start)
+                            })
+                            .transpose()
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let footer = amend_footer(
+            &writer.footer,
+            &self.codecs,
+            &bloom_filter_offsets,
+            &writer.chunks,
+        )?;
+        writer.writer.write_all(&footer)?;
+        Ok((offset + footer.len() as u64, writer.writer))
     }
 }
@@ -8,9 +8,10 @@ use parquet2::{
 
 use super::{binary::ord_binary, utils};
 use crate::{
-    array::{Array, FixedSizeBinaryArray},
+    array::{Array, FixedSizeBinaryArray, PrimitiveArray},
     error::Result,
     io::parquet::read::is_type_nullable,
+    types::NativeType,
 };
 
 pub(crate) fn encode_plain(array: &FixedSizeBinaryArray, is_optional: bool, buffer: &mut Vec<u8>) {
@@ -30,6 +31,7 @@ pub fn array_to_page(
     array: &FixedSizeBinaryArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    statistics: Option<ParquetStatistics>,
 ) -> Result<DataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
     let validity = array.validity();
@@ -47,12 +49,6 @@ pub fn array_to_page(
 
     encode_plain(array, is_optional, &mut buffer);
 
-    let statistics = if options.write_statistics {
-        build_statistics(array, descriptor.clone())
-    } else {
-        None
-    };
-
     utils::build_plain_page(
         buffer,
         array.len(),
@@ -90,3 +86,39 @@ pub(super) fn build_statistics(
         .map(|e| serialize_statistics(&*e))
         .ok()
 }
+
+/// Returns the `size` least significant bytes of `value` in big-endian.
+#[inline]
+pub(super) fn to_be_bytes_truncated<T: NativeType>(value: &T, size: usize) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let bytes = bytes.as_ref();
+    bytes[bytes.len() - size..].to_vec()
+}
+
+/// Builds the statistics of decimals encoded as big-endian fixed-len binaries of `size` bytes.
+/// Unlike [`build_statistics`], the min and max are computed over the (signed) integers.
+pub(super) fn build_statistics_decimal<T: NativeType + Ord>(
+    array: &PrimitiveArray<T>,
+    size: usize,
+    descriptor: ColumnDescriptor,
+) -> Option<ParquetStatistics> {
+    let pq_statistics = &ParquetStatistics {
+        max: None,
+        min: None,
+        null_count: Some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: array
+            .iter()
+            .flatten()
+            .max()
+            .map(|x| to_be_bytes_truncated(x, size)),
+        min_value: array
+            .iter()
+            .flatten()
+            .min()
+            .map(|x| to_be_bytes_truncated(x, size)),
+    };
+    deserialize_statistics(pq_statistics, descriptor)
+        .map(|e| serialize_statistics(&*e))
+        .ok()
+}
@@ -2,7 +2,7 @@ use parquet2::encoding::hybrid_rle::encode_u32;
 use parquet2::write::Version;
 
 use crate::{
-    array::Offset,
+    array::{Array, Offset},
     bitmap::{utils::BitmapIter, Bitmap},
     error::Result,
 };
@@ -165,6 +165,17 @@ impl<'a, O: Offset> NestedInfo<'a, O> {
     pub fn offsets(&self) -> &'a [O] {
         self.offsets
     }
+
+    /// The number of null lists
+    pub fn null_count(&self) -> usize {
+        self.validity.map(|x| x.null_count()).unwrap_or(0)
+    }
+}
+
+/// The null count of the statistics of the leaf column `values` of `nested`: null lists are
+/// counted as nulls of the leaf column (empty lists are not), like other implementations do.
+pub fn null_count<O: Offset>(values: &dyn Array, nested: &NestedInfo<O>) -> i64 {
+    (values.null_count() + nested.null_count()) as i64
 }
 
 fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
@@ -1,5 +1,6 @@
 //! APIs to write to Parquet format.
 mod binary;
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -8,6 +9,7 @@ mod levels;
 mod primitive;
 mod row_group;
 mod schema;
+#[cfg(feature = "io_parquet_async")]
 mod stream;
 mod utf8;
 mod utils;
@@ -20,6 +22,7 @@ use crate::error::{ArrowError, Result};
 use crate::io::parquet::read::is_type_nullable;
 use crate::io::parquet::write::levels::NestedInfo;
 use crate::types::days_ms;
+use crate::types::i256;
 use crate::types::NativeType;
 
 use parquet2::page::DataPage;
@@ -30,17 +33,54 @@ pub use parquet2::{
     metadata::{ColumnDescriptor, KeyValue, SchemaDescriptor},
     page::{CompressedDataPage, CompressedPage, EncodedPage},
     schema::types::ParquetType,
-    write::{
-        compress, Compressor, DynIter, DynStreamingIterator, RowGroupIter, Version, WriteOptions,
-    },
+    write::{compress, Compressor, DynIter, DynStreamingIterator, RowGroupIter, Version},
     FallibleStreamingIterator,
 };
 
+pub use bloom_filter::{build_bloom_filter, BloomFilter, BloomFilterOptions};
 pub use file::FileWriter;
-pub use row_group::{row_group_iter, RowGroupIterator};
+pub use row_group::{
+    row_group_iter, row_group_iter_with_compression, CompressionPerColumn, EncodingFallback,
+    RowGroupIterator,
+};
 pub use schema::to_parquet_type;
+#[cfg(feature = "io_parquet_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_async")))]
 pub use stream::FileStreamer;
 
+/// Currently supported options to write to parquet
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteOptions {
+    /// Whether to write statistics
+    pub write_statistics: bool,
+    /// Whether to use compression
+    pub compression: Compression,
+    /// Which Parquet version to use
+    pub version: Version,
+    /// Whether to declare the checksum (CRC32) of the bytes of each page in its header.
+    /// Only [`FileWriter`] writes checksums.
+    pub write_crc: bool,
+    /// The columns whose column chunks are written with a bloom filter, if any.
+    /// Only [`FileWriter`] writes bloom filters.
+    pub bloom_filters: Option<BloomFilterOptions>,
+}
+
+impl From<&WriteOptions> for parquet2::write::WriteOptions {
+    fn from(options: &WriteOptions) -> Self {
+        Self {
+            write_statistics: options.write_statistics,
+            compression: options.compression,
+            version: options.version,
+        }
+    }
+}
+
+impl From<WriteOptions> for parquet2::write::WriteOptions {
+    fn from(options: WriteOptions) -> Self {
+        (&options).into()
+    }
+}
+
 pub(self) fn decimal_length_from_precision(precision: usize) -> usize {
     // digits = floor(log_10(2^(8*n - 1) - 1))
     // ceil(digits) = log10(2^(8*n - 1) - 1)
@@ -73,6 +113,23 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
                 Encoding::DeltaLengthByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
+            | (
+                Encoding::DeltaBinaryPacked,
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Date32
+                    | DataType::Date64
+                    | DataType::Time32(_)
+                    | DataType::Time64(_)
+                    | DataType::Timestamp(_, _)
+                    | DataType::Duration(_),
+            )
             | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
             | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
     )
@@ -91,7 +148,7 @@ pub fn array_to_pages(
                 dictionary::array_to_pages::<$T>(
                     array.as_any().downcast_ref().unwrap(),
                     descriptor,
-                    options,
+                    options.into(),
                     encoding,
                 )
             })
@@ -101,7 +158,21 @@ pub fn array_to_pages(
     }
 }
 
+/// Returns `encoding` if `data_type` can be encoded by it (see [`can_encode`]), or
+/// [`Encoding::Plain`] otherwise, the encoding that data types fall back to.
+pub(crate) fn fallback_encoding(data_type: &DataType, encoding: Encoding) -> Encoding {
+    if can_encode(data_type, encoding) {
+        encoding
+    } else {
+        Encoding::Plain
+    }
+}
+
 /// Converts an [`Array`] to a [`CompressedPage`] based on options, descriptor and `encoding`.
+///
+/// Data types that cannot be encoded by `encoding` (see [`can_encode`]) fall back to
+/// [`Encoding::Plain`], that the header of the page declares.
+/// [`RowGroupIterator::encoding_fallbacks`] reports the fields that fall back.
 pub fn array_to_page(
     array: &dyn Array,
     descriptor: ColumnDescriptor,
@@ -109,12 +180,8 @@ pub fn array_to_page(
     encoding: Encoding,
 ) -> Result<EncodedPage> {
     let data_type = array.data_type();
-    if !can_encode(data_type, encoding) {
-        return Err(ArrowError::InvalidArgumentError(format!(
-            "The datatype {:?} cannot be encoded by {:?}",
-            data_type, encoding
-        )));
-    }
+    let encoding = fallback_encoding(data_type, encoding);
+    let options = options.into();
 
     match data_type.to_logical_type() {
         DataType::Boolean => {
@@ -125,37 +192,44 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt16 => primitive::array_to_page::<u16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt32 => primitive::array_to_page::<u32, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt64 => primitive::array_to_page::<u64, i64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int8 => primitive::array_to_page::<i8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int16 => primitive::array_to_page::<i16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
             primitive::array_to_page::<i32, i32>(
                 array.as_any().downcast_ref().unwrap(),
                 options,
                 descriptor,
+                encoding,
             )
         }
         DataType::Int64
@@ -166,16 +240,19 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float32 => primitive::array_to_page::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float64 => primitive::array_to_page::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Utf8 => utf8::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
@@ -203,7 +280,7 @@ pub fn array_to_page(
         ),
         DataType::Null => {
             let array = Int32Array::new_null(DataType::Int32, array.len());
-            primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+            primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
         }
         DataType::Interval(IntervalUnit::YearMonth) => {
             let array = array
@@ -221,7 +298,12 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().cloned(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            let statistics = if options.write_statistics {
+                fixed_len_bytes::build_statistics(&array, descriptor.clone())
+            } else {
+                None
+            };
+            fixed_len_bytes::array_to_page(&array, options, descriptor, statistics)
         }
         DataType::Interval(IntervalUnit::DayTime) => {
             let array = array
@@ -239,13 +321,22 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().cloned(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            let statistics = if options.write_statistics {
+                fixed_len_bytes::build_statistics(&array, descriptor.clone())
+            } else {
+                None
+            };
+            fixed_len_bytes::array_to_page(&array, options, descriptor, statistics)
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.write_statistics {
+                fixed_len_bytes::build_statistics(array, descriptor.clone())
+            } else {
+                None
+            };
+            fixed_len_bytes::array_to_page(array, options, descriptor, statistics)
         }
-        DataType::FixedSizeBinary(_) => fixed_len_bytes::array_to_page(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            descriptor,
-        ),
         DataType::Decimal(precision, _) => {
             let precision = *precision;
             let array = array
@@ -260,7 +351,7 @@ pub fn array_to_page(
                     values,
                     array.validity().cloned(),
                 );
-                primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+                primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
             } else if precision <= 18 {
                 let values = array.values().iter().map(|x| *x as i64);
                 let values = Buffer::from_trusted_len_iter(values);
@@ -269,9 +360,18 @@ pub fn array_to_page(
                     values,
                     array.validity().cloned(),
                 );
-                primitive::array_to_page::<i64, i64>(&array, options, descriptor)
+                primitive::array_to_page::<i64, i64>(&array, options, descriptor, encoding)
             } else {
                 let size = decimal_length_from_precision(precision);
+
+                // statistics are computed over the integers since the byte-wise order
+                // of two's complement big-endian values differs from their order
+                let statistics = if options.write_statistics {
+                    fixed_len_bytes::build_statistics_decimal(array, size, descriptor.clone())
+                } else {
+                    None
+                };
+
                 let mut values = Vec::<u8>::with_capacity(size * array.len());
                 array.values().iter().for_each(|x| {
                     let bytes = &x.to_be_bytes()[16 - size..];
@@ -282,9 +382,34 @@ pub fn array_to_page(
                     values.into(),
                     array.validity().cloned(),
                 );
-                fixed_len_bytes::array_to_page(&array, options, descriptor)
+                fixed_len_bytes::array_to_page(&array, options, descriptor, statistics)
             }
         }
+        DataType::Decimal256(_, _) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i256>>()
+                .unwrap();
+            let size = 32;
+
+            let statistics = if options.write_statistics {
+                fixed_len_bytes::build_statistics_decimal(array, size, descriptor.clone())
+            } else {
+                None
+            };
+
+            let mut values = Vec::<u8>::with_capacity(size * array.len());
+            array
+                .values()
+                .iter()
+                .for_each(|x| values.extend_from_slice(&x.to_be_bytes()));
+            let array = FixedSizeBinaryArray::from_data(
+                DataType::FixedSizeBinary(size),
+                values.into(),
+                array.validity().cloned(),
+            );
+            fixed_len_bytes::array_to_page(&array, options, descriptor, statistics)
+        }
         DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
             nested_array_to_page(array, descriptor, options)
         }
@@ -314,7 +439,7 @@ fn list_array_to_page<O: Offset>(
     validity: Option<&Bitmap>,
     values: &dyn Array,
     descriptor: ColumnDescriptor,
-    options: WriteOptions,
+    options: parquet2::write::WriteOptions,
 ) -> Result<DataPage> {
     use DataType::*;
     let is_optional = is_type_nullable(descriptor.type_());
@@ -393,7 +518,7 @@ fn list_array_to_page<O: Offset>(
 fn nested_array_to_page(
     array: &dyn Array,
     descriptor: ColumnDescriptor,
-    options: WriteOptions,
+    options: parquet2::write::WriteOptions,
 ) -> Result<DataPage> {
     match array.data_type() {
         DataType::List(_) => {
@@ -1,5 +1,5 @@
 use parquet2::{
-    encoding::Encoding,
+    encoding::{uleb128, zigzag_leb128, Encoding},
     metadata::ColumnDescriptor,
     page::DataPage,
     statistics::{serialize_statistics, ParquetStatistics, PrimitiveStatistics, Statistics},
@@ -10,7 +10,7 @@ use parquet2::{
 use super::super::utils;
 use crate::{
     array::{Array, PrimitiveArray},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
@@ -38,14 +38,101 @@ where
     }
 }
 
+/// The number of values of a block of `DELTA_BINARY_PACKED`
+const DELTA_BLOCK_SIZE: usize = 128;
+/// The number of mini-blocks of a block of `DELTA_BINARY_PACKED`
+const DELTA_MINI_BLOCKS: usize = 4;
+/// The number of values of a mini-block of `DELTA_BINARY_PACKED`
+const DELTA_MINI_BLOCK_SIZE: usize = DELTA_BLOCK_SIZE / DELTA_MINI_BLOCKS;
+
+fn encode_uleb128(value: u64, buffer: &mut Vec<u8>) {
+    let mut container = [0u8; 10];
+    let encoded_len = uleb128::encode(value, &mut container);
+    buffer.extend_from_slice(&container[..encoded_len]);
+}
+
+fn encode_zigzag(value: i64, buffer: &mut Vec<u8>) {
+    let (container, encoded_len) = zigzag_leb128::encode(value);
+    buffer.extend_from_slice(&container[..encoded_len]);
+}
+
+/// Bit-packs the `num_bits`-wide (up to 64) `values` in little-endian order into `buffer`.
+fn pack(values: &[u64], num_bits: usize, buffer: &mut Vec<u8>) {
+    let mut container = 0u128;
+    let mut container_bits = 0;
+    values.iter().for_each(|value| {
+        container |= (*value as u128) << container_bits;
+        container_bits += num_bits;
+        while container_bits >= 8 {
+            buffer.push(container as u8);
+            container >>= 8;
+            container_bits -= 8;
+        }
+    });
+    if container_bits > 0 {
+        buffer.push(container as u8);
+    }
+}
+
+/// Encodes `values` as `DELTA_BINARY_PACKED`, in blocks of 128 values divided in 4 mini-blocks.
+/// Deltas are computed with wrapping, as declared in the parquet specification, so that
+/// mini-blocks are bit-packed in at most 64 bits.
+fn encode_delta(values: &[i64], buffer: &mut Vec<u8>) {
+    // <block size in values> <number of miniblocks in a block> <total value count> <first value>
+    encode_uleb128(DELTA_BLOCK_SIZE as u64, buffer);
+    encode_uleb128(DELTA_MINI_BLOCKS as u64, buffer);
+    encode_uleb128(values.len() as u64, buffer);
+    encode_zigzag(values.first().copied().unwrap_or(0), buffer);
+
+    let deltas = values
+        .windows(2)
+        .map(|w| w[1].wrapping_sub(w[0]))
+        .collect::<Vec<_>>();
+
+    let mut mini_block = [0u64; DELTA_MINI_BLOCK_SIZE];
+    for block in deltas.chunks(DELTA_BLOCK_SIZE) {
+        let min_delta = *block.iter().min().unwrap();
+
+        let relative = block
+            .iter()
+            .map(|delta| delta.wrapping_sub(min_delta) as u64)
+            .collect::<Vec<_>>();
+
+        // <min delta> <list of bitwidths of miniblocks> <miniblocks>
+        encode_zigzag(min_delta, buffer);
+
+        let mut num_bits = [0u8; DELTA_MINI_BLOCKS];
+        relative
+            .chunks(DELTA_MINI_BLOCK_SIZE)
+            .zip(num_bits.iter_mut())
+            .for_each(|(values, num_bits)| {
+                let max = values.iter().max().unwrap();
+                *num_bits = (64 - max.leading_zeros()) as u8;
+            });
+        buffer.extend_from_slice(&num_bits);
+
+        // mini-blocks without values are omitted; the last one is padded with zeros
+        relative
+            .chunks(DELTA_MINI_BLOCK_SIZE)
+            .zip(num_bits.iter())
+            .filter(|(_, num_bits)| **num_bits > 0)
+            .for_each(|(values, num_bits)| {
+                mini_block.iter_mut().for_each(|x| *x = 0);
+                mini_block[..values.len()].copy_from_slice(values);
+                pack(&mini_block, *num_bits as usize, buffer);
+            });
+    }
+}
+
 pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<DataPage>
 where
     T: ArrowNativeType,
-    R: NativeType,
+    R: NativeType + num_traits::AsPrimitive<i64>,
     T: num_traits::AsPrimitive<R>,
 {
     let is_optional = is_type_nullable(descriptor.type_());
@@ -63,7 +150,33 @@ where
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    let encoding = match encoding {
+        Encoding::Plain => {
+            encode_plain(array, is_optional, &mut buffer);
+            Encoding::Plain
+        }
+        Encoding::DeltaBinaryPacked => {
+            let to_i64 = |x: &T| {
+                let x: R = x.as_();
+                let x: i64 = x.as_();
+                x
+            };
+            let values = if is_optional {
+                array.iter().flatten().map(to_i64).collect::<Vec<_>>()
+            } else {
+                array.values().iter().map(to_i64).collect::<Vec<_>>()
+            };
+            encode_delta(&values, &mut buffer);
+            Encoding::DeltaBinaryPacked
+        }
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Datatype {:?} cannot be encoded by {:?} encoding",
+                array.data_type(),
+                encoding
+            )))
+        }
+    };
 
     let statistics = if options.write_statistics {
         Some(build_statistics(array, descriptor.clone()))
@@ -80,7 +193,7 @@ where
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
 
@@ -1,18 +1,51 @@
+use std::collections::HashMap;
+
 use parquet2::FallibleStreamingIterator;
-use parquet2::{metadata::ColumnDescriptor, write::Compressor};
+use parquet2::{compression::Compression, metadata::ColumnDescriptor, write::Compressor};
 
 use crate::{
     array::Array,
     chunk::Chunk,
-    datatypes::Schema,
+    datatypes::{DataType, Schema},
     error::{ArrowError, Result},
 };
 
 use super::{
-    array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_pages, can_encode, fallback_encoding, to_parquet_schema, DynIter,
+    DynStreamingIterator, Encoding, RowGroupIter, SchemaDescriptor, WriteOptions,
 };
 
+/// The [`Compression`] of each column, declared by its index on the [`Schema`].
+/// Columns without a declared [`Compression`] use the compression of [`WriteOptions`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompressionPerColumn {
+    columns: HashMap<usize, Compression>,
+}
+
+impl CompressionPerColumn {
+    /// Creates a new [`CompressionPerColumn`] where every column uses the compression of
+    /// [`WriteOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares that the column at index `column` is compressed with `compression`.
+    pub fn with(mut self, column: usize, compression: Compression) -> Self {
+        self.columns.insert(column, compression);
+        self
+    }
+
+    /// Returns the [`Compression`] of the column at index `column`, or `default` if it was
+    /// not declared.
+    pub fn get(&self, column: usize, default: Compression) -> Compression {
+        self.columns.get(&column).copied().unwrap_or(default)
+    }
+
+    fn max_column(&self) -> Option<usize> {
+        self.columns.keys().max().copied()
+    }
+}
+
 /// Maps a [`Chunk`] and parquet-specific options to an [`RowGroupIter`] used to
 /// write to parquet
 pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
@@ -21,24 +54,74 @@ pub fn row_group_iter<A: AsRef<dyn Array> + 'static + Send + Sync>(
     columns: Vec<ColumnDescriptor>,
     options: WriteOptions,
 ) -> RowGroupIter<'static, ArrowError> {
+    row_group_iter_with_compression(
+        chunk,
+        encodings,
+        columns,
+        options,
+        &CompressionPerColumn::default(),
+    )
+}
+
+/// Maps a [`Chunk`] and parquet-specific options to an [`RowGroupIter`] used to
+/// write to parquet, compressing each column as declared by `compressions`.
+pub fn row_group_iter_with_compression<A: AsRef<dyn Array> + 'static + Send + Sync>(
+    chunk: Chunk<A>,
+    encodings: Vec<Encoding>,
+    columns: Vec<ColumnDescriptor>,
+    options: WriteOptions,
+    compressions: &CompressionPerColumn,
+) -> RowGroupIter<'static, ArrowError> {
+    let options = (0..columns.len())
+        .map(|column| WriteOptions {
+            compression: compressions.get(column, options.compression),
+            ..options.clone()
+        })
+        .collect::<Vec<_>>();
     DynIter::new(
         chunk
             .into_arrays()
             .into_iter()
-            .zip(columns.into_iter())
-            .zip(encodings.into_iter())
-            .map(move |((array, descriptor), encoding)| {
+            .zip(columns)
+            .zip(encodings)
+            .zip(options)
+            .map(move |(((array, descriptor), encoding), options)| {
+                let compression = options.compression;
                 array_to_pages(array.as_ref(), descriptor, options, encoding).map(move |pages| {
                     let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
-                    let compressed_pages =
-                        Compressor::new(encoded_pages, options.compression, vec![])
-                            .map_err(ArrowError::from);
+                    let compressed_pages = Compressor::new(encoded_pages, compression, vec![])
+                        .map_err(ArrowError::from);
                     DynStreamingIterator::new(compressed_pages)
                 })
             }),
     )
 }
 
+/// A warning that the column of a field is written with [`Encoding::Plain`], since the data type
+/// of the field cannot be encoded by the encoding declared for it (see [`can_encode`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingFallback {
+    /// The name of the field
+    pub field: String,
+    /// The data type of the field
+    pub data_type: DataType,
+    /// The encoding declared for the field
+    pub encoding: Encoding,
+}
+
+impl std::fmt::Display for EncodingFallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "The datatype {:?} of the field \"{}\" cannot be encoded by {:?}; it is encoded by {:?}",
+            self.data_type,
+            self.field,
+            self.encoding,
+            Encoding::Plain
+        )
+    }
+}
+
 /// An iterator adapter that converts an iterator over [`Chunk`] into an iterator
 /// of row groups.
 /// Use it to create an iterator consumable by the parquet's API.
@@ -47,10 +130,17 @@ pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Re
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    compressions: CompressionPerColumn,
+    fallbacks: Vec<EncodingFallback>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
     /// Creates a new [`RowGroupIterator`] from an iterator over [`Chunk`].
+    ///
+    /// Fields whose data type cannot be encoded by their encoding (see [`can_encode`]) fall back
+    /// to [`Encoding::Plain`], and are reported by [`RowGroupIterator::encoding_fallbacks`].
+    /// # Errors
+    /// This function errors iff a parquet schema cannot be derived from `schema`.
     pub fn try_new(
         iter: I,
         schema: &Schema,
@@ -59,6 +149,24 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
     ) -> Result<Self> {
         assert_eq!(schema.fields.len(), encodings.len());
 
+        let fallbacks = schema
+            .fields
+            .iter()
+            .zip(encodings.iter())
+            .filter(|(field, encoding)| !can_encode(field.data_type(), **encoding))
+            .map(|(field, encoding)| EncodingFallback {
+                field: field.name.clone(),
+                data_type: field.data_type().clone(),
+                encoding: *encoding,
+            })
+            .collect();
+        let encodings = schema
+            .fields
+            .iter()
+            .zip(encodings)
+            .map(|(field, encoding)| fallback_encoding(field.data_type(), encoding))
+            .collect();
+
         let parquet_schema = to_parquet_schema(schema)?;
 
         Ok(Self {
@@ -66,9 +174,36 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
             options,
             parquet_schema,
             encodings,
+            compressions: CompressionPerColumn::default(),
+            fallbacks,
         })
     }
 
+    /// Returns the fields that are written with [`Encoding::Plain`] instead of their
+    /// declared encoding, since their data type cannot be encoded by it.
+    pub fn encoding_fallbacks(&self) -> &[EncodingFallback] {
+        &self.fallbacks
+    }
+
+    /// Sets the [`Compression`] of each column of this [`RowGroupIterator`], that otherwise
+    /// use the compression of its [`WriteOptions`].
+    ///
+    /// The row groups must be written with a [`FileWriter`](super::FileWriter), that declares
+    /// the compression of each column chunk on the metadata of the file.
+    /// # Errors
+    /// This function errors iff `compressions` declares a column that is not in the schema.
+    pub fn with_compression(mut self, compressions: CompressionPerColumn) -> Result<Self> {
+        let num_columns = self.parquet_schema.columns().len();
+        if let Some(column) = compressions.max_column().filter(|x| *x >= num_columns) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The compression of column {} was declared but the schema has {} columns",
+                column, num_columns
+            )));
+        }
+        self.compressions = compressions;
+        Ok(self)
+    }
+
     /// Returns the [`SchemaDescriptor`] of the [`RowGroupIterator`].
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
@@ -81,18 +216,19 @@ impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chun
     type Item = Result<(RowGroupIter<'static, ArrowError>, usize)>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let options = self.options;
+        let options = self.options.clone();
 
         self.iter.next().map(|maybe_chunk| {
             let chunk = maybe_chunk?;
             let len = chunk.len();
             let encodings = self.encodings.clone();
             Ok((
-                row_group_iter(
+                row_group_iter_with_compression(
                     chunk,
                     encodings,
                     self.parquet_schema.columns().to_vec(),
                     options,
+                    &self.compressions,
                 ),
                 len,
             ))
@@ -19,8 +19,8 @@ use crate::{
 
 use super::super::ARROW_SCHEMA_META_KEY;
 
-pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
-    let serialized_schema = schema_to_bytes(schema, &default_ipc_fields(&schema.fields));
+pub fn schema_to_metadata_key(schema: &Schema) -> Result<KeyValue> {
+    let serialized_schema = schema_to_bytes(schema, &default_ipc_fields(&schema.fields))?;
 
     // manually prepending the length to the schema as arrow uses the legacy IPC format
     // TODO: change after addressing ARROW-9777
@@ -32,10 +32,10 @@ pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
 
     let encoded = base64::encode(&len_prefix_schema);
 
-    KeyValue {
+    Ok(KeyValue {
         key: ARROW_SCHEMA_META_KEY.to_string(),
         value: Some(encoded),
-    }
+    })
 }
 
 /// Creates a [`ParquetType`] from a [`Field`].
@@ -318,6 +318,25 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
                 None,
             )?)
         }
+        DataType::Decimal256(precision, scale) => {
+            let precision = *precision;
+            let scale = *scale;
+            let logical_type = Some(LogicalType::DECIMAL(DecimalType {
+                scale: scale as i32,
+                precision: precision as i32,
+            }));
+            Ok(ParquetType::try_from_primitive(
+                name,
+                PhysicalType::FixedLenByteArray(32),
+                repetition,
+                Some(PrimitiveConvertedType::Decimal(
+                    precision as i32,
+                    scale as i32,
+                )),
+                logical_type,
+                None,
+            )?)
+        }
         DataType::Interval(_) => Ok(ParquetType::try_from_primitive(
             name,
             PhysicalType::FixedLenByteArray(12),
@@ -1,8 +1,11 @@
 //! Contains `async` APIs to write to parquet.
 use futures::AsyncWrite;
 
+use parquet2::compression::Compression;
 use parquet2::metadata::{KeyValue, SchemaDescriptor};
-use parquet2::write::RowGroupIter;
+use parquet2::page::CompressedPage;
+use parquet2::write::{DynIter, DynStreamingIterator, RowGroupIter};
+use parquet2::FallibleStreamingIterator;
 
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
@@ -10,17 +13,57 @@ use crate::error::{ArrowError, Result};
 use super::file::add_arrow_schema;
 use super::{to_parquet_schema, WriteOptions};
 
+/// A column chunk that errors when its data pages are not compressed with `compression`.
+struct CheckCompression<'a> {
+    iter: DynStreamingIterator<'a, CompressedPage, ArrowError>,
+    compression: Compression,
+}
+
+impl<'a> FallibleStreamingIterator for CheckCompression<'a> {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        self.iter.advance()?;
+        match self.iter.get() {
+            Some(CompressedPage::Data(page)) if page.compression() != self.compression => {
+                Err(ArrowError::NotYetImplemented(format!(
+                    "FileStreamer only supports the compression of its options ({:?}), but a page is compressed with {:?}; use FileWriter to write columns with different compressions",
+                    self.compression,
+                    page.compression()
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.iter.get()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 /// An interface to write a parquet to a [`AsyncWrite`]
+///
+/// Contrarily to [`FileWriter`](super::FileWriter), all columns must be compressed with the
+/// compression of the [`WriteOptions`], the pages do not declare their checksum
+/// (see [`WriteOptions::write_crc`]) and no bloom filters are written
+/// (see [`WriteOptions::bloom_filters`]).
 pub struct FileStreamer<W: AsyncWrite + Unpin + Send> {
     writer: parquet2::write::FileStreamer<W>,
     schema: Schema,
+    options: WriteOptions,
+    write_arrow_schema: bool,
 }
 
 // Accessors
 impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
     /// The options assigned to the file
     pub fn options(&self) -> &WriteOptions {
-        self.writer.options()
+        &self.options
     }
 
     /// The [`SchemaDescriptor`] assigned to this file
@@ -44,28 +87,55 @@ impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
         let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
 
         Ok(Self {
-            writer: parquet2::write::FileStreamer::new(writer, parquet_schema, options, created_by),
+            writer: parquet2::write::FileStreamer::new(
+                writer,
+                parquet_schema,
+                (&options).into(),
+                created_by,
+            ),
             schema,
+            options,
+            write_arrow_schema: true,
         })
     }
 
+    /// Sets whether the [`Schema`] is written to the key-value metadata of the file, under
+    /// `"ARROW:schema"` (the default).
+    pub fn with_arrow_schema(mut self, write_arrow_schema: bool) -> Self {
+        self.write_arrow_schema = write_arrow_schema;
+        self
+    }
+
     /// Writes the header of the file
     pub async fn start(&mut self) -> Result<()> {
         Ok(self.writer.start().await?)
     }
 
     /// Writes a row group to the file.
+    /// # Errors
+    /// This function errors iff a page is not compressed with the compression of the
+    /// [`WriteOptions`] (e.g. via
+    /// [`RowGroupIterator::with_compression`](super::RowGroupIterator::with_compression)),
+    /// since the footer would declare the wrong compression for its column chunk.
     pub async fn write(
         &mut self,
         row_group: RowGroupIter<'_, ArrowError>,
         num_rows: usize,
     ) -> Result<()> {
+        let compression = self.options.compression;
+        let row_group = DynIter::new(row_group.map(|column| {
+            column.map(|iter| DynStreamingIterator::new(CheckCompression { iter, compression }))
+        }));
         Ok(self.writer.write(row_group, num_rows).await?)
     }
 
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub async fn end(self, key_value_metadata: Option<Vec<KeyValue>>) -> Result<(u64, W)> {
-        let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
+        let key_value_metadata = if self.write_arrow_schema {
+            add_arrow_schema(&self.schema, key_value_metadata)?
+        } else {
+            key_value_metadata
+        };
         Ok(self.writer.end(key_value_metadata).await?)
     }
 }
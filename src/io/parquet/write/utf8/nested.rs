@@ -34,7 +34,9 @@ where
     encode_plain(array, is_optional, &mut buffer);
 
     let statistics = if options.write_statistics {
-        Some(build_statistics(array, descriptor.clone()))
+        let mut statistics = build_statistics(array, descriptor.clone());
+        statistics.null_count = Some(levels::null_count(array, &nested));
+        Some(statistics)
     } else {
         None
     };
@@ -7,8 +7,51 @@ use crate::{
 
 use comfy_table::{Cell, Table};
 
+/// Options to [`write_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WriteOptions {
+    /// The maximum number of characters of a cell. Longer values are truncated and end with `...`
+    /// (or `..]` and `..}` for lists and structs), or are cut without an ending when
+    /// `max_width` is smaller than 3. `None` means no truncation.
+    pub max_width: Option<usize>,
+    /// The representation of null values. Defaults to an empty cell.
+    pub null: &'static str,
+}
+
+/// Truncates `value` to at most `max_width` characters, keeping the closing bracket of
+/// nested values. Widths too narrow for the ellipsis cut `value` without it.
+fn truncate(value: String, max_width: usize) -> String {
+    if value.chars().count() <= max_width {
+        return value;
+    }
+    if max_width < 3 {
+        return value.chars().take(max_width).collect();
+    }
+    let closing = match (value.chars().next(), value.chars().last()) {
+        (Some('['), Some(']')) => "..]",
+        (Some('{'), Some('}')) => "..}",
+        _ => "...",
+    };
+    let mut truncated = value
+        .chars()
+        .take(max_width - closing.len())
+        .collect::<String>();
+    truncated.push_str(closing);
+    truncated
+}
+
 /// Returns a visual representation of [`Chunk`]
 pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(batches: &[Chunk<A>], names: &[N]) -> String {
+    write_with_options(batches, names, WriteOptions::default())
+}
+
+/// Returns a visual representation of [`Chunk`] as an aligned table, with each column as wide as
+/// its widest (possibly truncated) value, declared by `options`.
+pub fn write_with_options<A: AsRef<dyn Array>, N: AsRef<str>>(
+    batches: &[Chunk<A>],
+    names: &[N],
+    options: WriteOptions,
+) -> String {
     let mut table = Table::new();
     table.load_preset("||--+-++|    ++++++");
 
@@ -23,7 +66,7 @@ pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(batches: &[Chunk<A>], names: &[
         let displayes = batch
             .arrays()
             .iter()
-            .map(|array| get_display(array.as_ref(), ""))
+            .map(|array| get_display(array.as_ref(), options.null))
             .collect::<Vec<_>>();
 
         for row in 0..batch.len() {
@@ -31,6 +74,9 @@ pub fn write<A: AsRef<dyn Array>, N: AsRef<str>>(batches: &[Chunk<A>], names: &[
             (0..batch.arrays().len()).for_each(|col| {
                 let mut string = String::new();
                 displayes[col](&mut string, row).unwrap();
+                if let Some(max_width) = options.max_width {
+                    string = truncate(string, max_width);
+                }
                 cells.push(Cell::new(string));
             });
             table.add_row(cells);
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use super::*;
-use crate::types::days_ms;
+use crate::types::{days_ms, i256};
 
 impl PartialEq for dyn Scalar + '_ {
     fn eq(&self, that: &dyn Scalar) -> bool {
@@ -85,6 +85,9 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
         DataType::Decimal(_, _) => {
             dyn_eq!(i128, lhs, rhs)
         }
+        DataType::Decimal256(_, _) => {
+            dyn_eq!(i256, lhs, rhs)
+        }
         DataType::Interval(IntervalUnit::DayTime) => {
             dyn_eq!(days_ms, lhs, rhs)
         }
@@ -105,6 +108,11 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
             let rhs = rhs.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
             lhs == rhs
         }
+        DataType::Utf8View => {
+            let lhs = lhs.as_any().downcast_ref::<Utf8ViewScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Utf8ViewScalar>().unwrap();
+            lhs == rhs
+        }
         DataType::Binary => {
             let lhs = lhs.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
             let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
@@ -146,6 +154,16 @@ fn equal(lhs: &dyn Scalar, rhs: &dyn Scalar) -> bool {
                 .unwrap();
             lhs == rhs
         }
+        DataType::FixedSizeList(_, _) => {
+            let lhs = lhs.as_any().downcast_ref::<FixedSizeListScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<FixedSizeListScalar>().unwrap();
+            lhs == rhs
+        }
+        DataType::Map(_, _) => {
+            let lhs = lhs.as_any().downcast_ref::<MapScalar>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<MapScalar>().unwrap();
+            lhs == rhs
+        }
         other => unimplemented!("{:?}", other),
     }
 }
@@ -0,0 +1,58 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::{array::*, datatypes::DataType};
+
+use super::Scalar;
+
+/// The scalar equivalent of [`MapArray`]. Like [`MapArray`], this struct holds a dynamically-typed
+/// [`Array`] (a [`StructArray`] of keys and values). The only difference is that this has only
+/// one element.
+#[derive(Debug, Clone)]
+pub struct MapScalar {
+    values: Option<Arc<dyn Array>>,
+    data_type: DataType,
+}
+
+impl PartialEq for MapScalar {
+    fn eq(&self, other: &Self) -> bool {
+        (self.data_type == other.data_type)
+            && (self.values.is_some() == other.values.is_some())
+            && ((self.values.is_none()) | (self.values.as_ref() == other.values.as_ref()))
+    }
+}
+
+impl MapScalar {
+    /// returns a new [`MapScalar`]
+    /// # Panics
+    /// iff
+    /// * the `data_type` is not `Map`
+    /// * the child of the `data_type` is not equal to the `values`
+    #[inline]
+    pub fn new(data_type: DataType, values: Option<Arc<dyn Array>>) -> Self {
+        let inner_data_type = MapArray::get_field(&data_type).data_type();
+        if let Some(values) = &values {
+            assert_eq!(inner_data_type, values.data_type());
+        }
+        Self { values, data_type }
+    }
+
+    /// The entries of the [`MapScalar`]
+    pub fn values(&self) -> Option<&Arc<dyn Array>> {
+        self.values.as_ref()
+    }
+}
+
+impl Scalar for MapScalar {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        self.values.is_some()
+    }
+
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+}
@@ -11,6 +11,8 @@ mod primitive;
 pub use primitive::*;
 mod utf8;
 pub use utf8::*;
+mod utf8_view;
+pub use utf8_view::*;
 mod binary;
 pub use binary::*;
 mod boolean;
@@ -25,6 +27,8 @@ mod fixed_size_list;
 pub use fixed_size_list::*;
 mod fixed_size_binary;
 pub use fixed_size_binary::*;
+mod map;
+pub use map::*;
 
 /// Trait object declaring an optional value with a [`DataType`].
 /// This strait is often used in APIs that accept multiple scalar types.
@@ -78,7 +82,8 @@ macro_rules! dyn_new_list {
     }};
 }
 
-/// creates a new [`Scalar`] from an [`Array`].
+/// creates a new [`Scalar`] from the slot `index` of an [`Array`], i.e. a copy of its value,
+/// or a null [`Scalar`] of the array's [`DataType`] when the slot is null.
 pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
     use PhysicalType::*;
     match array.data_type().to_physical_type() {
@@ -106,6 +111,15 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
         }),
         Utf8 => dyn_new_utf8!(array, index, i32),
         LargeUtf8 => dyn_new_utf8!(array, index, i64),
+        Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            let value = if array.is_valid(index) {
+                Some(array.value(index))
+            } else {
+                None
+            };
+            Box::new(Utf8ViewScalar::new(value))
+        }
         Binary => dyn_new_binary!(array, index, i32),
         LargeBinary => dyn_new_binary!(array, index, i64),
         List => dyn_new_list!(array, index, i32),
@@ -144,7 +158,20 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
             };
             Box::new(FixedSizeListScalar::new(array.data_type().clone(), value))
         }
-        Union | Map => todo!(),
+        Union => {
+            // unions have no validity: their scalar is the scalar of the slot's field
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            array.value(index)
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let value = if array.is_valid(index) {
+                Some(array.value(index).into())
+            } else {
+                None
+            };
+            Box::new(MapScalar::new(array.data_type().clone(), value))
+        }
         Dictionary(key_type) => match_integer_type!(key_type, |$T| {
             let array = array
                 .as_any()
@@ -160,5 +187,12 @@ pub fn new_scalar(array: &dyn Array, index: usize) -> Box<dyn Scalar> {
                 value,
             ))
         }),
+        RunEndEncoded(run_end_type) => match_run_end_type!(run_end_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<RunEndEncodedArray<$T>>()
+                .unwrap();
+            array.value(index)
+        }),
     }
 }
@@ -0,0 +1,50 @@
+use crate::datatypes::DataType;
+
+use super::Scalar;
+
+/// The implementation of [`Scalar`] for utf8 views, semantically equivalent to
+/// [`Option<String>`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Utf8ViewScalar {
+    value: Option<String>,
+}
+
+impl Utf8ViewScalar {
+    /// Returns a new [`Utf8ViewScalar`]
+    #[inline]
+    pub fn new<P: Into<String>>(value: Option<P>) -> Self {
+        Self {
+            value: value.map(|x| x.into()),
+        }
+    }
+
+    /// Returns the value irrespectively of the validity.
+    #[inline]
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_ref().map(|x| x.as_ref())
+    }
+}
+
+impl<P: Into<String>> From<Option<P>> for Utf8ViewScalar {
+    #[inline]
+    fn from(v: Option<P>) -> Self {
+        Self::new(v)
+    }
+}
+
+impl Scalar for Utf8ViewScalar {
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.value.is_some()
+    }
+
+    #[inline]
+    fn data_type(&self) -> &DataType {
+        &DataType::Utf8View
+    }
+}
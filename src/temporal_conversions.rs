@@ -157,6 +157,27 @@ pub fn timestamp_to_datetime<T: chrono::TimeZone>(
     timezone.from_utc_datetime(&timestamp_to_naive_datetime(timestamp, time_unit))
 }
 
+/// Converts a [`NaiveDateTime`] into a timestamp in `time_unit`, returning `None` if it
+/// overflows an `i64` (e.g. for nanoseconds outside of ~1677-2262).
+///
+/// Fractions of a second finer than `time_unit` are truncated, i.e. the timestamp is rounded
+/// towards the past (also before the unix epoch).
+#[inline]
+pub fn naive_datetime_to_timestamp(datetime: &NaiveDateTime, time_unit: TimeUnit) -> Option<i64> {
+    let datetime = datetime.and_utc();
+    let seconds = datetime.timestamp();
+    let nanoseconds = datetime.timestamp_subsec_nanos() as i64;
+    let (factor, divisor) = match time_unit {
+        TimeUnit::Second => return Some(seconds),
+        TimeUnit::Millisecond => (MILLISECONDS, 1_000_000),
+        TimeUnit::Microsecond => (MICROSECONDS, 1_000),
+        TimeUnit::Nanosecond => (NANOSECONDS, 1),
+    };
+    seconds
+        .checked_mul(factor)?
+        .checked_add(nanoseconds / divisor)
+}
+
 /// Calculates the scale factor between two TimeUnits. The function returns the
 /// scale that should multiply the TimeUnit "b" to have the same time scale as
 /// the TimeUnit "a".
@@ -327,47 +348,73 @@ pub fn utf8_to_naive_timestamp_ns<O: Offset>(
     PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
 }
 
-fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
-    let new_year = (year * 12 + (month - 1) as i32 + months) / 12;
-    let new_month = (year * 12 + (month - 1) as i32 + months) % 12 + 1;
-    chrono::NaiveDate::from_ymd(new_year, new_month as u32, 1)
+/// Returns the number of days of `month` (1-based) of `year`, or `None` if it is out of the
+/// range of [`NaiveDate`]
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let (year, month) = if month == 12 {
+        (year.checked_add(1)?, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)?
+        .pred_opt()
+        .map(|x| x.day())
 }
 
-fn get_days_between_months(year: i32, month: u32, months: i32) -> i64 {
-    add_month(year, month, months)
-        .signed_duration_since(chrono::NaiveDate::from_ymd(year, month, 1))
-        .num_days()
+/// Adds the months and then the days of `interval` to `datetime`, clamping the day to the
+/// last day of the month (e.g. Jan 31 + 1 month is Feb 28 or 29). Returns `None` if the result
+/// is out of the range of [`NaiveDateTime`].
+fn add_months_days(datetime: NaiveDateTime, interval: months_days_ns) -> Option<NaiveDateTime> {
+    let months =
+        (datetime.year() as i64) * 12 + datetime.month0() as i64 + interval.months() as i64;
+    let year = i32::try_from(months.div_euclid(12)).ok()?;
+    let month = months.rem_euclid(12) as u32 + 1;
+    let day = datetime.day().min(days_in_month(year, month)?);
+
+    NaiveDate::from_ymd_opt(year, month, day)?
+        .and_time(datetime.time())
+        .checked_add_signed(chrono::Duration::days(interval.days() as i64))
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units without timezone.
+///
+/// The months of the interval are added first (clamping the day to the last day of the
+/// resulting month), then its days and then its nanoseconds.
+/// # Panics
+/// This function panics iff the result overflows (e.g. is outside of ~1677-2262 for
+/// nanoseconds); see [`checked_add_naive_interval`] for a version that does not panic.
 #[inline]
 pub fn add_naive_interval(timestamp: i64, time_unit: TimeUnit, interval: months_days_ns) -> i64 {
-    // convert seconds to a DateTime of a given offset.
-    let datetime = match time_unit {
-        TimeUnit::Second => timestamp_s_to_datetime(timestamp),
-        TimeUnit::Millisecond => timestamp_ms_to_datetime(timestamp),
-        TimeUnit::Microsecond => timestamp_us_to_datetime(timestamp),
-        TimeUnit::Nanosecond => timestamp_ns_to_datetime(timestamp),
-    };
+    checked_add_naive_interval(timestamp, time_unit, interval)
+        .expect("adding an interval to a timestamp overflows")
+}
 
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days = get_days_between_months(datetime.year(), datetime.month(), interval.months())
-        + interval.days() as i64;
+/// Adds an `interval` to a `timestamp` in `time_unit` units without timezone, as in
+/// [`add_naive_interval`].
+/// Returns `None` if the result overflows (e.g. is outside of ~1677-2262 for nanoseconds).
+#[inline]
+pub fn checked_add_naive_interval(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    interval: months_days_ns,
+) -> Option<i64> {
+    let datetime = timestamp_to_naive_datetime(timestamp, time_unit);
 
-    // add; no leap hours are considered
-    let new_datetime_tz = datetime
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+    let new_datetime = add_months_days(datetime, interval)?
+        .checked_add_signed(chrono::Duration::nanoseconds(interval.ns()))?;
 
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
-    }
+    naive_datetime_to_timestamp(&new_datetime, time_unit)
 }
 
 /// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`.
+///
+/// The months of the interval are added first (clamping the day to the last day of the
+/// resulting month), then its days and then its nanoseconds. Months and days are added to the
+/// local time in `timezone`, so that e.g. adding a day across a DST transition keeps the local
+/// time of the day; nanoseconds are added to the instant.
+/// # Panics
+/// This function panics iff the result overflows (e.g. is outside of ~1677-2262 for
+/// nanoseconds); see [`checked_add_interval`] for a version that does not panic.
 #[inline]
 pub fn add_interval<T: chrono::TimeZone>(
     timestamp: i64,
@@ -375,23 +422,37 @@ pub fn add_interval<T: chrono::TimeZone>(
     interval: months_days_ns,
     timezone: &T,
 ) -> i64 {
+    checked_add_interval(timestamp, time_unit, interval, timezone)
+        .expect("adding an interval to a timestamp overflows")
+}
+
+/// Adds an `interval` to a `timestamp` in `time_unit` units and timezone `timezone`, as in
+/// [`add_interval`].
+/// Returns `None` if the result overflows (e.g. is outside of ~1677-2262 for nanoseconds).
+#[inline]
+pub fn checked_add_interval<T: chrono::TimeZone>(
+    timestamp: i64,
+    time_unit: TimeUnit,
+    interval: months_days_ns,
+    timezone: &T,
+) -> Option<i64> {
     // convert seconds to a DateTime of a given offset.
     let datetime_tz = timestamp_to_datetime(timestamp, time_unit, timezone);
 
-    // compute the number of days in the interval, which depends on the particular year and month (leap days)
-    let delta_days =
-        get_days_between_months(datetime_tz.year(), datetime_tz.month(), interval.months())
-            + interval.days() as i64;
-
-    // add; tz will take care of leap hours
-    let new_datetime_tz = datetime_tz
-        + chrono::Duration::nanoseconds(delta_days * 24 * 60 * 60 * 1_000_000_000 + interval.ns());
+    let local = add_months_days(datetime_tz.naive_local(), interval)?;
+    let new_datetime = match timezone.from_local_datetime(&local) {
+        chrono::LocalResult::Single(datetime) => datetime.naive_utc(),
+        chrono::LocalResult::Ambiguous(earliest, _) => earliest.naive_utc(),
+        // `local` is in a gap, which the offset before the change moves past
+        chrono::LocalResult::None => {
+            local
+                - chrono::Duration::seconds(
+                    chrono::Offset::fix(datetime_tz.offset()).local_minus_utc() as i64,
+                )
+        }
+    };
+    let new_datetime =
+        new_datetime.checked_add_signed(chrono::Duration::nanoseconds(interval.ns()))?;
 
-    // convert back to the target unit
-    match time_unit {
-        TimeUnit::Second => new_datetime_tz.timestamp_millis() / 1000,
-        TimeUnit::Millisecond => new_datetime_tz.timestamp_millis(),
-        TimeUnit::Microsecond => new_datetime_tz.timestamp_nanos() / 1000,
-        TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
-    }
+    naive_datetime_to_timestamp(&new_datetime, time_unit)
 }
@@ -325,3 +325,381 @@ impl Neg for months_days_ns {
         Self::new(-self.months(), -self.days(), -self.ns())
     }
 }
+
+/// The in-memory representation of a signed 256-bit integer in two's complement, used by the
+/// [`DataType::Decimal256`](crate::datatypes::DataType::Decimal256) logical type.
+///
+/// Arithmetic operators (`+`, `-`, `*` and unary `-`) panic on overflow; use the
+/// `checked_*` and `wrapping_*` variants to handle it.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Zeroable, Pod)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct i256 {
+    low: u128,
+    high: i128,
+}
+
+impl i256 {
+    /// The value zero.
+    pub const ZERO: Self = Self { low: 0, high: 0 };
+    /// The value one.
+    pub const ONE: Self = Self { low: 1, high: 0 };
+    /// The smallest value, `-2^255`.
+    pub const MIN: Self = Self {
+        low: 0,
+        high: i128::MIN,
+    };
+    /// The largest value, `2^255 - 1`.
+    pub const MAX: Self = Self {
+        low: u128::MAX,
+        high: i128::MAX,
+    };
+
+    /// A new [`i256`] from its most significant 128 bits (`high`) and least significant 128 bits
+    /// (`low`).
+    #[inline]
+    pub fn from_words(high: i128, low: u128) -> Self {
+        Self { low, high }
+    }
+
+    /// The most significant 128 bits
+    #[inline]
+    pub fn high(&self) -> i128 {
+        self.high
+    }
+
+    /// The least significant 128 bits
+    #[inline]
+    pub fn low(&self) -> u128 {
+        self.low
+    }
+
+    /// Whether this value is smaller than zero
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.high < 0
+    }
+
+    /// Returns this value as an `i128`, or `None` if it does not fit in one.
+    #[inline]
+    pub fn to_i128(&self) -> Option<i128> {
+        let low = self.low as i128;
+        if (low < 0 && self.high == -1) || (low >= 0 && self.high == 0) {
+            Some(low)
+        } else {
+            None
+        }
+    }
+
+    /// The 4 words of 64 bits of this value, from the least to the most significant.
+    #[inline]
+    fn to_words(self) -> [u64; 4] {
+        let high = self.high as u128;
+        [
+            self.low as u64,
+            (self.low >> 64) as u64,
+            high as u64,
+            (high >> 64) as u64,
+        ]
+    }
+
+    #[inline]
+    fn from_u64_words(words: [u64; 4]) -> Self {
+        Self {
+            low: words[0] as u128 | (words[1] as u128) << 64,
+            high: (words[2] as u128 | (words[3] as u128) << 64) as i128,
+        }
+    }
+
+    /// The absolute value of this number, as words of 64 bits (see [`i256::to_words`]).
+    /// It is correct for [`i256::MIN`], since `2^255` fits in 256 unsigned bits.
+    #[inline]
+    fn unsigned_abs(self) -> [u64; 4] {
+        if self.is_negative() {
+            self.wrapping_neg().to_words()
+        } else {
+            self.to_words()
+        }
+    }
+
+    /// Wrapping (modular) addition.
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        let (low, carry) = self.low.overflowing_add(rhs.low);
+        let high = self.high.wrapping_add(rhs.high).wrapping_add(carry as i128);
+        Self { low, high }
+    }
+
+    /// Checked addition. Returns `None` iff an overflow occurred.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let result = self.wrapping_add(rhs);
+        // overflow iff both operands have the same sign and the result has a different one
+        if self.is_negative() == rhs.is_negative() && result.is_negative() != self.is_negative() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Wrapping (modular) subtraction.
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        let (low, borrow) = self.low.overflowing_sub(rhs.low);
+        let high = self
+            .high
+            .wrapping_sub(rhs.high)
+            .wrapping_sub(borrow as i128);
+        Self { low, high }
+    }
+
+    /// Checked subtraction. Returns `None` iff an overflow occurred.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let result = self.wrapping_sub(rhs);
+        // overflow iff the operands have different signs and the result has the sign of `rhs`
+        if self.is_negative() != rhs.is_negative() && result.is_negative() != self.is_negative() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Wrapping (modular) negation.
+    #[inline]
+    pub fn wrapping_neg(self) -> Self {
+        Self::ZERO.wrapping_sub(self)
+    }
+
+    /// Checked negation. Returns `None` iff `self == i256::MIN`.
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        if self == Self::MIN {
+            None
+        } else {
+            Some(self.wrapping_neg())
+        }
+    }
+
+    /// Wrapping (modular) multiplication.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        let product = mul_words(self.to_words(), rhs.to_words());
+        Self::from_u64_words(product[..4].try_into().unwrap())
+    }
+
+    /// Checked multiplication. Returns `None` iff an overflow occurred.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let product = mul_words(self.unsigned_abs(), rhs.unsigned_abs());
+        if product[4..].iter().any(|word| *word != 0) {
+            return None;
+        }
+        let magnitude = Self::from_u64_words(product[..4].try_into().unwrap());
+        if self.is_negative() != rhs.is_negative() {
+            // the magnitude may be up to `2^255`, whose negation is `i256::MIN`
+            if magnitude.is_negative() && magnitude != Self::MIN {
+                None
+            } else {
+                Some(magnitude.wrapping_neg())
+            }
+        } else if magnitude.is_negative() {
+            None
+        } else {
+            Some(magnitude)
+        }
+    }
+}
+
+/// The full product of two unsigned 256-bit numbers represented as words of 64 bits, from the
+/// least to the most significant.
+fn mul_words(lhs: [u64; 4], rhs: [u64; 4]) -> [u64; 8] {
+    let mut result = [0u64; 8];
+    for (i, lhs) in lhs.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, rhs) in rhs.iter().enumerate() {
+            let value = (*lhs as u128) * (*rhs as u128) + result[i + j] as u128 + carry;
+            result[i + j] = value as u64;
+            carry = value >> 64;
+        }
+        result[i + 4] = carry as u64;
+    }
+    result
+}
+
+impl From<i128> for i256 {
+    #[inline]
+    fn from(value: i128) -> Self {
+        Self {
+            low: value as u128,
+            high: if value < 0 { -1 } else { 0 },
+        }
+    }
+}
+
+impl PartialOrd for i256 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for i256 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.high
+            .cmp(&other.high)
+            .then_with(|| self.low.cmp(&other.low))
+    }
+}
+
+impl std::ops::Add for i256 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).expect("attempt to add with overflow")
+    }
+}
+
+impl std::ops::Sub for i256 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl std::ops::Mul for i256 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Neg for i256 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl num_traits::Zero for i256 {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+}
+
+impl num_traits::One for i256 {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+macro_rules! i256_op {
+    ($trait:ident, $method:ident, $output:ty, $body:expr) => {
+        impl num_traits::$trait for i256 {
+            #[inline]
+            fn $method(&self, rhs: &Self) -> $output {
+                $body(*self, *rhs)
+            }
+        }
+    };
+}
+
+i256_op!(WrappingAdd, wrapping_add, Self, i256::wrapping_add);
+i256_op!(WrappingSub, wrapping_sub, Self, i256::wrapping_sub);
+i256_op!(WrappingMul, wrapping_mul, Self, i256::wrapping_mul);
+i256_op!(CheckedAdd, checked_add, Option<Self>, i256::checked_add);
+i256_op!(CheckedSub, checked_sub, Option<Self>, i256::checked_sub);
+i256_op!(CheckedMul, checked_mul, Option<Self>, i256::checked_mul);
+
+impl num_traits::WrappingNeg for i256 {
+    #[inline]
+    fn wrapping_neg(&self) -> Self {
+        i256::wrapping_neg(*self)
+    }
+}
+
+impl num_traits::CheckedNeg for i256 {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        i256::checked_neg(*self)
+    }
+}
+
+impl std::fmt::Display for i256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(value) = self.to_i128() {
+            return write!(f, "{}", value);
+        }
+        const CHUNK: u64 = 10_000_000_000_000_000_000; // 10^19, the largest power of 10 in a u64
+
+        // the digits in chunks of 19, from the least to the most significant
+        let mut words = self.unsigned_abs();
+        let mut chunks = vec![];
+        while words.iter().any(|word| *word != 0) {
+            let mut remainder = 0u128;
+            for word in words.iter_mut().rev() {
+                let value = (remainder << 64) | *word as u128;
+                *word = (value / CHUNK as u128) as u64;
+                remainder = value % CHUNK as u128;
+            }
+            chunks.push(remainder as u64);
+        }
+
+        if self.is_negative() {
+            write!(f, "-")?;
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap())?;
+        chunks.try_for_each(|chunk| write!(f, "{:019}", chunk))
+    }
+}
+
+impl NativeType for i256 {
+    const PRIMITIVE: PrimitiveType = PrimitiveType::Int256;
+    type Bytes = [u8; 32];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.low.to_le_bytes());
+        result[16..].copy_from_slice(&self.high.to_le_bytes());
+        result
+    }
+
+    #[inline]
+    fn to_ne_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.low.to_ne_bytes());
+        result[16..].copy_from_slice(&self.high.to_ne_bytes());
+        result
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        let mut result = [0; 32];
+        result[..16].copy_from_slice(&self.high.to_be_bytes());
+        result[16..].copy_from_slice(&self.low.to_be_bytes());
+        result
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        let high = i128::from_be_bytes(bytes[..16].try_into().unwrap());
+        let low = u128::from_be_bytes(bytes[16..].try_into().unwrap());
+        Self { low, high }
+    }
+}
@@ -82,3 +82,31 @@ fn test_from_two_lists() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn extend_validity_sliced() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2), Some(3)]),
+        Some(vec![Some(4i32), Some(5), Some(6)]),
+        Some(vec![Some(7i32), None, Some(9)]),
+    ];
+    let array = create_list_array(data);
+    let array = array.slice(1, 2);
+
+    let mut a = GrowableFixedSizeList::new(vec![&array], true, 3);
+    a.extend(0, 1, 1);
+    a.extend_validity(1);
+    a.extend(0, 0, 1);
+
+    let result: FixedSizeListArray = a.into();
+
+    let expected = vec![
+        Some(vec![Some(7i32), None, Some(9)]),
+        None,
+        Some(vec![Some(4i32), Some(5), Some(6)]),
+    ];
+    let expected = create_list_array(expected);
+
+    assert_eq!(result, expected);
+    assert_eq!(result.values().len(), 9);
+}
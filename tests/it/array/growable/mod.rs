@@ -6,8 +6,10 @@ mod fixed_size_list;
 mod list;
 mod null;
 mod primitive;
+mod run_end;
 mod struct_;
 mod utf8;
+mod utf8_view;
 
 /*
 #[cfg(test)]
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use arrow2::array::growable::{Growable, GrowableRunEndEncoded};
+use arrow2::array::*;
+
+#[test]
+fn basic() {
+    // [1, 1, 1, None, None, 3, 3, 3, 3, 3]
+    let array = RunEndEncodedArray::<i32>::from_data(
+        Int32Array::from_slice([3, 5, 10]),
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+    );
+
+    let mut growable = GrowableRunEndEncoded::new(&[&array], true, 0);
+    growable.extend(0, 2, 4);
+    growable.extend_validity(2);
+    growable.extend(0, 8, 2);
+
+    let result: RunEndEncodedArray<i32> = growable.into();
+
+    // [1, None, None, 3, None, None, 3, 3]
+    let expected = RunEndEncodedArray::<i32>::from_data(
+        Int32Array::from_slice([1, 3, 4, 6, 8]),
+        Arc::new(Int32Array::from([Some(1), None, Some(3), None, Some(3)])),
+    );
+    assert_eq!(result, expected);
+    assert_eq!(result.run_ends(), expected.run_ends());
+}
+
+#[test]
+fn sliced() {
+    let array = RunEndEncodedArray::<i64>::from_data(
+        Int64Array::from_slice([2, 4]),
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+    )
+    .slice(1, 3);
+    let other = RunEndEncodedArray::<i64>::from_data(
+        Int64Array::from_slice([1]),
+        Arc::new(Utf8Array::<i32>::from_slice(["c"])),
+    );
+
+    let mut growable = GrowableRunEndEncoded::new(&[&array, &other], false, 0);
+    growable.extend(0, 0, 3);
+    growable.extend(1, 0, 1);
+
+    let result: RunEndEncodedArray<i64> = growable.into();
+
+    let expected = RunEndEncodedArray::<i64>::from_data(
+        Int64Array::from_slice([1, 3, 4]),
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+    );
+    assert_eq!(result, expected);
+}
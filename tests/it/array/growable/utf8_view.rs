@@ -0,0 +1,52 @@
+use arrow2::array::growable::{Growable, GrowableUtf8View};
+use arrow2::array::*;
+
+const LONG: &str = "a string longer than 12 bytes";
+const OTHER: &str = "another string longer than 12 bytes";
+
+#[test]
+fn basic() {
+    let array = Utf8ViewArray::from([Some("a"), Some(LONG), None, Some("bb")]);
+
+    let mut growable = GrowableUtf8View::new(vec![&array], false, 0);
+    growable.extend(0, 1, 3);
+    growable.extend_validity(1);
+
+    let result: Utf8ViewArray = growable.into();
+
+    let expected = Utf8ViewArray::from([Some(LONG), None, Some("bb"), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn multiple() {
+    let a = Utf8ViewArray::from_slice(["a", LONG]);
+    let b = Utf8ViewArray::from([Some(OTHER), None, Some("c")]);
+
+    let mut growable = GrowableUtf8View::new(vec![&a, &b], false, 0);
+    growable.extend(1, 0, 2);
+    growable.extend(0, 0, 2);
+    growable.extend(1, 2, 1);
+
+    let result: Utf8ViewArray = growable.into();
+
+    let expected = Utf8ViewArray::from([Some(OTHER), None, Some("a"), Some(LONG), Some("c")]);
+    assert_eq!(result, expected);
+    // views of `b` reference its buffer, after the buffer of `a`
+    assert_eq!(result.view(0).buffer_index(), 1);
+    assert_eq!(result.view(3).buffer_index(), 0);
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8ViewArray::from_slice(["a", LONG, OTHER, "d"]);
+    let array = array.slice(1, 3);
+
+    let mut growable = GrowableUtf8View::new(vec![&array], false, 0);
+    growable.extend(0, 1, 2);
+
+    let result: Utf8ViewArray = growable.into();
+
+    let expected = Utf8ViewArray::from_slice([OTHER, "d"]);
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::*,
+    datatypes::{DataType, Field},
+};
+
+fn new_builder(keys_sorted: bool) -> MutableMapArray<MutableUtf8Array<i32>, Int32Vec> {
+    let data_type = MutableMapArray::<MutableUtf8Array<i32>, Int32Vec>::default_datatype(
+        &DataType::Utf8,
+        &DataType::Int32,
+        keys_sorted,
+    );
+    MutableMapArray::try_new(data_type, MutableUtf8Array::new(), Int32Vec::new()).unwrap()
+}
+
+#[test]
+fn mutable() {
+    let mut array = new_builder(false);
+    array.push_keys_values(["b", "a"], [Some(1), None]).unwrap();
+    array.try_push_valid().unwrap();
+    array.try_push_null().unwrap();
+    array.push_keys_values(["c"], [Some(3)]).unwrap();
+    array.push_keys_values(["d"], [Some(4)]).unwrap();
+    array.try_push_valid().unwrap();
+    array.try_push_valid().unwrap();
+    assert_eq!(array.len(), 4);
+
+    let array: MapArray = array.into();
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 2, 4, 4]);
+    assert_eq!(array.null_count(), 1);
+    assert!(array.is_null(1));
+
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let expected = StructArray::from_data(
+        entries,
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["b", "a", "c", "d"])),
+            Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])),
+        ],
+        None,
+    );
+    let field = array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(field, &expected);
+
+    let first = array.value(0);
+    assert_eq!(expected.slice(0, 2), first.as_ref());
+    assert_eq!(array.value(3).len(), 0);
+}
+
+#[test]
+fn mutable_sorted() {
+    let mut array = new_builder(true);
+    array
+        .push_keys_values(["a", "b"], [Some(1), Some(2)])
+        .unwrap();
+    array.try_push_valid().unwrap();
+
+    // out of order
+    assert!(array.push_keys_values(["b", "a"], [None, None]).is_err());
+    // duplicated
+    assert!(array.push_keys_values(["a", "a"], [None, None]).is_err());
+
+    array.push_keys_values(["c"], [Some(1)]).unwrap();
+    // the entries of a sorted map are pushed at once
+    assert!(array.push_keys_values(["d"], [Some(1)]).is_err());
+    array.try_push_valid().unwrap();
+
+    let array: MapArray = array.into();
+    assert_eq!(array.offsets().as_slice(), &[0, 2, 3]);
+}
+
+#[test]
+fn mutable_invalid() {
+    let mut array = new_builder(false);
+    assert!(array.push_keys_values(["a"], [Some(1), Some(2)]).is_err());
+
+    let data_type = DataType::List(Box::new(Field::new("a", DataType::Int32, true)));
+    assert!(
+        MutableMapArray::try_new(data_type, MutableUtf8Array::<i32>::new(), Int32Vec::new())
+            .is_err()
+    );
+
+    let data_type = MutableMapArray::<MutableUtf8Array<i32>, Int32Vec>::default_datatype(
+        &DataType::Utf8,
+        &DataType::Int64,
+        false,
+    );
+    assert!(
+        MutableMapArray::try_new(data_type, MutableUtf8Array::<i32>::new(), Int32Vec::new())
+            .is_err()
+    );
+}
@@ -6,11 +6,14 @@ mod fixed_size_binary;
 mod fixed_size_list;
 mod growable;
 mod list;
+mod map;
 mod ord;
 mod primitive;
+mod run_end;
 mod struct_;
 mod union;
 mod utf8;
+mod utf8_view;
 
 use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
 use arrow2::bitmap::Bitmap;
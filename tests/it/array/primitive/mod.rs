@@ -5,7 +5,7 @@ use arrow2::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::*,
-    types::{days_ms, months_days_ns},
+    types::{days_ms, i256, months_days_ns},
 };
 
 mod mutable;
@@ -259,6 +259,53 @@ fn debug_decimal1() {
     );
 }
 
+#[test]
+fn decimal256() {
+    let large = i256::from(i128::MAX) * i256::from(1000);
+    let data = vec![Some(i256::from(12345)), None, Some(-large)];
+    let array = PrimitiveArray::<i256>::from(data.clone()).to(DataType::Decimal256(76, 2));
+    assert_eq!(array.data_type(), &DataType::Decimal256(76, 2));
+    assert_eq!(array.value(0), i256::from(12345));
+    assert_eq!(array.value(2), -large);
+    assert!(array.is_null(1));
+    assert_eq!(array.iter().map(|x| x.copied()).collect::<Vec<_>>(), data);
+
+    let array = array.slice(1, 2);
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.value(1), -large);
+    assert_eq!(
+        format!("{:?}", array),
+        "Decimal256(76, 2)[None, -1701411834604692317316873037158841057270.00]"
+    );
+}
+
+#[test]
+fn debug_decimal256() {
+    let array = PrimitiveArray::<i256>::from([Some(i256::from(-5)), None, Some(i256::from(12345))])
+        .to(DataType::Decimal256(5, 2));
+    assert_eq!(
+        format!("{:?}", array),
+        "Decimal256(5, 2)[-0.05, None, 123.45]"
+    );
+}
+
+#[test]
+fn i256_display() {
+    assert_eq!(
+        i256::MAX.to_string(),
+        "57896044618658097711785492504343953926634992332820282019728792003956564819967"
+    );
+    assert_eq!(
+        i256::MIN.to_string(),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(i256::from(-10).to_string(), "-10");
+    assert_eq!(
+        i256::from(u64::MAX as i128 * 1_000_000).to_string(),
+        "18446744073709551615000000"
+    );
+}
+
 #[test]
 fn debug_interval_days_ms() {
     let array = DaysMsArray::from(&[Some(days_ms::new(1, 1)), None, Some(days_ms::new(2, 2))]);
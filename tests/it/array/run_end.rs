@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, IntegerType};
+use arrow2::scalar::{new_scalar, PrimitiveScalar, Scalar};
+
+fn array() -> RunEndEncodedArray<i32> {
+    // [1, 1, 1, None, None, 3, 3, 3, 3, 3]
+    RunEndEncodedArray::from_data(
+        Int32Array::from_slice([3, 5, 10]),
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+    )
+}
+
+fn scalar(value: Option<i32>) -> Box<dyn Scalar> {
+    Box::new(PrimitiveScalar::new(DataType::Int32, value))
+}
+
+#[test]
+fn basics() {
+    let array = array();
+    assert_eq!(array.len(), 10);
+    assert_eq!(
+        array.data_type(),
+        &DataType::RunEndEncoded(IntegerType::Int32, Box::new(DataType::Int32))
+    );
+    assert_eq!(array.physical_index(0), 0);
+    assert_eq!(array.physical_index(2), 0);
+    assert_eq!(array.physical_index(3), 1);
+    assert_eq!(array.physical_index(9), 2);
+    assert_eq!(array.value(4), scalar(None));
+    assert_eq!(array.value(5), scalar(Some(3)));
+}
+
+#[test]
+fn value_after_slice() {
+    let array = array().slice(2, 5);
+    assert_eq!(array.len(), 5);
+    assert_eq!(array.offset(), 2);
+    // the run ends are not rewritten
+    assert_eq!(array.run_ends(), &Int32Array::from_slice([3, 5, 10]));
+    assert_eq!(array.value(0), scalar(Some(1)));
+    assert_eq!(array.value(1), scalar(None));
+    assert_eq!(array.value(2), scalar(None));
+    assert_eq!(array.value(3), scalar(Some(3)));
+    assert_eq!(array.value(4), scalar(Some(3)));
+
+    let array = array.slice(3, 2);
+    assert_eq!(array.offset(), 5);
+    assert_eq!(array.value(0), scalar(Some(3)));
+}
+
+#[test]
+fn iter() {
+    let array = array().slice(1, 6);
+    let expected = [Some(1), Some(1), None, None, Some(3), Some(3)]
+        .into_iter()
+        .map(scalar)
+        .collect::<Vec<_>>();
+    assert_eq!(array.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(array.iter().size_hint(), (6, Some(6)));
+}
+
+#[test]
+fn try_new_errors() {
+    let values = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    // not increasing
+    assert!(RunEndEncodedArray::try_new(Int32Array::from_slice([3, 3]), values.clone()).is_err());
+    // not positive
+    assert!(RunEndEncodedArray::try_new(Int32Array::from_slice([0, 3]), values.clone()).is_err());
+    // different lengths
+    assert!(RunEndEncodedArray::try_new(Int32Array::from_slice([3]), values.clone()).is_err());
+    // nulls
+    assert!(RunEndEncodedArray::try_new(Int32Array::from([Some(1), None]), values).is_err());
+}
+
+#[test]
+fn equal() {
+    // same logical values, different runs
+    let other = RunEndEncodedArray::from_data(
+        Int32Array::from_slice([1, 3, 5, 10]),
+        Arc::new(Int32Array::from([Some(1), Some(1), None, Some(3)])),
+    );
+    assert_eq!(array(), other);
+    assert_eq!(array().slice(1, 3), other.slice(1, 3));
+    assert!(array().slice(0, 3) != other.slice(1, 3));
+}
+
+#[test]
+fn empty_and_null() {
+    let data_type = DataType::RunEndEncoded(IntegerType::Int64, Box::new(DataType::Utf8));
+    let array = new_empty_array(data_type.clone());
+    assert_eq!(array.len(), 0);
+
+    let array = new_null_array(data_type, 3);
+    let array = array
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i64>>()
+        .unwrap();
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.null_count(), 3);
+    assert!(array.iter().all(|x| !x.is_valid()));
+}
+
+#[test]
+fn null_count() {
+    let array = array();
+    assert_eq!(array.null_count(), 2);
+    assert!(array.is_null(3));
+    assert!(array.is_valid(5));
+
+    assert_eq!(array.slice(4, 4).null_count(), 1);
+    assert_eq!(array.slice(5, 5).null_count(), 0);
+}
+
+#[test]
+fn int16_run_ends() {
+    let data_type = DataType::RunEndEncoded(IntegerType::Int16, Box::new(DataType::Int32));
+    assert_eq!(new_empty_array(data_type.clone()).len(), 0);
+    let nulls = new_null_array(data_type.clone(), 3);
+    assert_eq!(nulls.null_count(), 3);
+
+    let array = RunEndEncodedArray::from_data(
+        Int16Array::from_slice([3, 5]),
+        Arc::new(Int32Array::from([Some(1), None])),
+    );
+    assert_eq!(array.data_type(), &data_type);
+    assert_eq!(array.value(4), scalar(None));
+
+    let boxed: Box<dyn Array> = Box::new(array.clone());
+    assert_eq!(boxed.as_ref(), &array as &dyn Array);
+    assert_eq!(format!("{:?}", boxed), format!("{:?}", array));
+    assert_eq!(new_scalar(boxed.as_ref(), 0), scalar(Some(1)));
+}
@@ -45,3 +45,112 @@ fn slice() -> Result<()> {
     assert_eq!(expected, result);
     Ok(())
 }
+
+#[test]
+fn mutable_dense() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, None, UnionMode::Dense);
+    let mut array = MutableUnionArray::new(
+        data_type.clone(),
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    );
+
+    array.try_push::<MutablePrimitiveArray<i32>, _>(0, Some(1))?;
+    array.try_push::<MutableUtf8Array<i32>, _>(1, Some("a"))?;
+    array.push_type(0)?;
+    array
+        .field_mut::<MutablePrimitiveArray<i32>>(0)
+        .unwrap()
+        .push(None);
+    array.try_push::<MutableUtf8Array<i32>, _>(1, Some("b"))?;
+    assert_eq!(array.offsets(), Some([0, 0, 1, 1].as_ref()));
+
+    let array: UnionArray = array.into();
+
+    let types = Buffer::from_slice([0, 1, 0, 1]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[Some(1), None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b")])) as Arc<dyn Array>,
+    ];
+    let offsets = Some(Buffer::from_slice([0, 0, 1, 1]));
+    let expected = UnionArray::from_data(data_type, types, fields, offsets);
+    assert_eq!(array, expected);
+
+    assert_eq!(array.index(3), (1, 1));
+    let value = array.value(1);
+    let value = value
+        .as_any()
+        .downcast_ref::<arrow2::scalar::Utf8Scalar<i32>>()
+        .unwrap();
+    assert_eq!(value.value(), Some("a"));
+    assert!(!array.value(2).is_valid());
+    Ok(())
+}
+
+#[test]
+fn mutable_sparse_with_ids() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Sparse);
+    let mut array = MutableUnionArray::new(
+        data_type.clone(),
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    );
+
+    array.try_push::<MutableUtf8Array<i32>, _>(7, Some("a"))?;
+    array.try_push::<MutablePrimitiveArray<i32>, _>(5, Some(2))?;
+    assert_eq!(array.offsets(), None);
+
+    let array: UnionArray = array.into();
+
+    let types = Buffer::from_slice([7, 5]);
+    let fields = vec![
+        Arc::new(Int32Array::from(&[None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), None])) as Arc<dyn Array>,
+    ];
+    let expected = UnionArray::from_data(data_type, types, fields, None);
+    assert_eq!(array, expected);
+    Ok(())
+}
+
+#[test]
+fn mutable_invalid() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let data_type = DataType::Union(fields, Some(vec![5, 7]), UnionMode::Dense);
+    let mut array = MutableUnionArray::new(
+        data_type.clone(),
+        vec![
+            Box::new(MutablePrimitiveArray::<i32>::new()),
+            Box::new(MutableUtf8Array::<i32>::new()),
+        ],
+    );
+
+    // the type id is not in the union
+    assert!(array.push_type(0).is_err());
+    // the field of the type id is of another type
+    assert!(array
+        .try_push::<MutablePrimitiveArray<i32>, _>(7, Some(1))
+        .is_err());
+    assert!(array.is_empty());
+
+    // the arrays do not match the fields
+    assert!(MutableUnionArray::try_new(
+        data_type,
+        vec![Box::new(MutablePrimitiveArray::<i32>::new())]
+    )
+    .is_err());
+}
@@ -0,0 +1,140 @@
+use arrow2::{array::*, bitmap::Bitmap, buffer::Buffer, datatypes::DataType};
+
+const LONG: &str = "a string longer than 12 bytes";
+
+#[test]
+fn basics() {
+    let array = Utf8ViewArray::from([Some("hello"), None, Some(LONG), Some("")]);
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.value(0), "hello");
+    assert_eq!(array.value(2), LONG);
+    assert_eq!(array.value(3), "");
+    assert!(array.is_valid(0));
+    assert!(!array.is_valid(1));
+    assert_eq!(
+        array.validity(),
+        Some(&Bitmap::from([true, false, true, true]))
+    );
+
+    assert!(array.view(0).is_inline());
+    assert!(!array.view(2).is_inline());
+    assert_eq!(array.view(2).prefix(), *b"a st");
+    assert_eq!(array.view(2).length() as usize, LONG.len());
+    // only the long string is stored in the data buffers
+    assert_eq!(array.buffers().len(), 1);
+    assert_eq!(array.buffers()[0].as_slice(), LONG.as_bytes());
+}
+
+#[test]
+fn inline_boundary() {
+    let array = Utf8ViewArray::from_slice(["123456789012", "1234567890123"]);
+    assert!(array.view(0).is_inline());
+    assert_eq!(array.view(0).inline_bytes(), b"123456789012");
+    assert!(!array.view(1).is_inline());
+    assert_eq!(array.value(1), "1234567890123");
+}
+
+#[test]
+fn slice() {
+    let array = Utf8ViewArray::from([Some("a"), Some(LONG), None, Some("bb")]);
+    let array = array.slice(1, 3);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.value(0), LONG);
+    assert_eq!(array.value(2), "bb");
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some(LONG), None, Some("bb")]
+    );
+}
+
+#[test]
+fn iter() {
+    let data = vec![Some("hello"), None, Some(LONG)];
+    let array: Utf8ViewArray = data.clone().into_iter().collect();
+    assert_eq!(array.iter().collect::<Vec<_>>(), data);
+    assert_eq!(array.iter().rev().collect::<Vec<_>>(), {
+        let mut data = data.clone();
+        data.reverse();
+        data
+    });
+    assert_eq!(
+        array.values_iter().collect::<Vec<_>>(),
+        vec!["hello", "", LONG]
+    );
+}
+
+#[test]
+fn try_new_errors() {
+    let view = View::new(LONG.as_bytes(), 0, 0).to_i128();
+
+    // no buffer
+    let views = Buffer::from(vec![view]);
+    assert!(Utf8ViewArray::try_new(DataType::Utf8View, views.clone(), vec![], None).is_err());
+
+    // out of bounds
+    let buffers = vec![Buffer::from(b"a string".to_vec())];
+    assert!(Utf8ViewArray::try_new(DataType::Utf8View, views.clone(), buffers, None).is_err());
+
+    // wrong prefix
+    let mut other = LONG.as_bytes().to_vec();
+    other[0] = b'b';
+    let buffers = vec![Buffer::from(other)];
+    assert!(Utf8ViewArray::try_new(DataType::Utf8View, views.clone(), buffers, None).is_err());
+
+    // invalid utf8
+    let views = Buffer::from(vec![View::new(&[0xff, 0xff], 0, 0).to_i128()]);
+    assert!(Utf8ViewArray::try_new(DataType::Utf8View, views, vec![], None).is_err());
+
+    // wrong data type
+    assert!(Utf8ViewArray::try_new(DataType::Utf8, Buffer::new(), vec![], None).is_err());
+
+    // wrong validity
+    let views = Buffer::from(vec![View::new(b"a", 0, 0).to_i128()]);
+    let validity = Some(Bitmap::from([true, false]));
+    assert!(Utf8ViewArray::try_new(DataType::Utf8View, views, vec![], validity).is_err());
+}
+
+#[test]
+fn mutable() {
+    let mut array = MutableUtf8ViewArray::new();
+    array.push(Some("hi"));
+    array.push::<&str>(None);
+    array.push_value(LONG);
+    assert_eq!(array.len(), 3);
+
+    let array: Utf8ViewArray = array.into();
+    assert_eq!(
+        array.iter().collect::<Vec<_>>(),
+        vec![Some("hi"), None, Some(LONG)]
+    );
+}
+
+#[test]
+fn mutable_multiple_buffers() {
+    // each string fills more than half of a data buffer
+    let value = "a".repeat(1024 * 1024 + 1);
+    let array = Utf8ViewArray::from_slice([&value, &value, &value]);
+    assert_eq!(array.buffers().len(), 3);
+    assert_eq!(array.view(1).buffer_index(), 1);
+    assert_eq!(array.view(1).offset(), 0);
+    assert!(array.values_iter().all(|x| x == value));
+}
+
+#[test]
+fn equal() {
+    let a = Utf8ViewArray::from([Some("a"), Some(LONG), None]);
+    let b = Utf8ViewArray::from([Some("a"), Some(LONG), None]);
+    assert_eq!(a, b);
+    let b = Utf8ViewArray::from([Some("a"), Some(LONG), Some("c")]);
+    assert!(a != b);
+}
+
+#[test]
+fn empty_and_null() {
+    let array = new_empty_array(DataType::Utf8View);
+    assert_eq!(array.len(), 0);
+
+    let array = new_null_array(DataType::Utf8View, 2);
+    let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![None, None]);
+}
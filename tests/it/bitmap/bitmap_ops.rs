@@ -4,6 +4,49 @@ use arrow2::bitmap::Bitmap;
 
 use crate::bitmap::bitmap_strategy;
 
+/// naive, bit-by-bit, version of a binary operation
+fn naive(lhs: &Bitmap, rhs: &Bitmap, op: impl Fn(bool, bool) -> bool) -> Bitmap {
+    lhs.iter().zip(rhs.iter()).map(|(x, y)| op(x, y)).collect()
+}
+
+fn bitmap(len: usize, offset: usize) -> Bitmap {
+    (0..len + offset)
+        .map(|i| i % 3 == 0 || i % 7 == 1)
+        .collect::<Bitmap>()
+        .slice(offset, len)
+}
+
+#[test]
+fn misaligned_offsets() {
+    // offsets that are not multiple of 8 (sub-byte) and lengths not multiple of 64
+    for (lhs_offset, rhs_offset) in [(0, 3), (5, 0), (1, 7), (13, 70), (64, 1)] {
+        for len in [0, 1, 7, 63, 64, 65, 130] {
+            let lhs = bitmap(len, lhs_offset);
+            let rhs = !&bitmap(len, rhs_offset);
+
+            assert_eq!(lhs.and(&rhs).unwrap(), naive(&lhs, &rhs, |x, y| x & y));
+            assert_eq!(lhs.or(&rhs).unwrap(), naive(&lhs, &rhs, |x, y| x | y));
+            assert_eq!(lhs.xor(&rhs).unwrap(), naive(&lhs, &rhs, |x, y| x ^ y));
+            let not: Bitmap = lhs.iter().map(|x| !x).collect();
+            assert_eq!(lhs.not(), not);
+
+            let result = lhs.and(&rhs).unwrap();
+            assert_eq!(result.len(), len);
+            let null_count = result.iter().filter(|x| !x).count();
+            assert_eq!(result.null_count(), null_count);
+        }
+    }
+}
+
+#[test]
+fn different_lengths() {
+    let lhs = bitmap(10, 0);
+    let rhs = bitmap(11, 3);
+    assert!(lhs.and(&rhs).is_err());
+    assert!(lhs.or(&rhs).is_err());
+    assert!(lhs.xor(&rhs).is_err());
+}
+
 proptest! {
     /// Asserts that !bitmap equals all bits flipped
     #[test]
@@ -14,3 +57,20 @@ proptest! {
         assert_eq!(!&bitmap, not_bitmap);
     }
 }
+
+proptest! {
+    /// Asserts that `and`, `or` and `xor` of two arbitrarily sliced bitmaps equal their
+    /// bit-by-bit counterparts
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn binary_ops(lhs in bitmap_strategy(), rhs in bitmap_strategy()) {
+        let len = lhs.len().min(rhs.len());
+        let lhs = lhs.slice(0, len);
+        let rhs_offset = rhs.len() - len;
+        let rhs = rhs.slice(rhs_offset, len);
+
+        assert_eq!(lhs.and(&rhs).unwrap(), naive(&lhs, &rhs, |x, y| x & y));
+        assert_eq!(lhs.or(&rhs).unwrap(), naive(&lhs, &rhs, |x, y| x | y));
+        assert_eq!(lhs.xor(&rhs).unwrap(), naive(&lhs, &rhs, |x, y| x ^ y));
+    }
+}
@@ -72,7 +72,40 @@ fn count_zeros_1() {
     assert_eq!(count_zeros(input, 10, 90), 60);
 }
 
+#[test]
+fn count_ones_basics() {
+    let input: &[u8] = &[
+        0b01001001, 0b00000001, 0b00000010, 0b00000100, 0b00001000, 0b00010000, 0b00100000,
+        0b01000000, 0b11111111, 0b10000001,
+    ];
+    assert_eq!(count_ones(input, 0, 0), 0);
+    assert_eq!(count_ones(input, 0, 8), 3);
+    assert_eq!(count_ones(input, 1, 7), 2);
+    assert_eq!(count_ones(input, 3, 1), 1);
+    assert_eq!(count_ones(input, 0, 72), 18);
+    // a full word in the middle and partial bytes on both ends
+    assert_eq!(count_ones(input, 3, 74), 18);
+    assert_eq!(count_ones(input, 8, 72), 17);
+}
+
 proptest! {
+    /// Asserts that `Bitmap::count_set_bits_range` equals the number of set bits in the range
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn count_set_bits_range(
+        (bitmap, offset, length) in bitmap_strategy().prop_flat_map(|bitmap| {
+            let len = bitmap.len();
+            (Just(bitmap), 0..=len)
+        }).prop_flat_map(|(bitmap, offset)| {
+            let len = bitmap.len();
+            (Just(bitmap), Just(offset), 0..=len - offset)
+        })
+    ) {
+        let naive: usize = (offset..offset + length).map(|x| bitmap.get_bit(x) as usize).sum();
+        assert_eq!(bitmap.count_set_bits_range(offset, length), naive);
+        assert_eq!(bitmap.null_count_range(offset, length), length - naive);
+    }
+
     /// Asserts that `Bitmap::null_count` equals the number of unset bits
     #[test]
     #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
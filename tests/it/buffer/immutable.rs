@@ -44,6 +44,16 @@ fn from_trusted_len_iter() {
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
 
+#[test]
+fn from_trusted_len_iter_safe() {
+    let buffer = Buffer::<i32>::from_trusted_len_iter([0, 1, 2].iter().map(|x| x * 2));
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.as_slice(), &[0, 2, 4]);
+
+    let buffer = Buffer::<i32>::from_trusted_len_iter(vec![].into_iter());
+    assert!(buffer.is_empty());
+}
+
 #[test]
 fn try_from_trusted_len_iter() {
     let iter = (0..3).map(Result::<_, String>::Ok);
@@ -74,3 +84,42 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[test]
+fn into_mut_unique() {
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]);
+    let mut vec = buffer.into_mut().unwrap_right();
+    vec[0] = 10;
+    assert_eq!(vec, vec![10, 1, 2]);
+}
+
+#[test]
+fn into_mut_shared() {
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]);
+    let shared = buffer.clone();
+    let buffer = buffer.into_mut().unwrap_left();
+    assert_eq!(buffer.as_slice(), &[0, 1, 2]);
+
+    // once the other reference is dropped it becomes unique
+    drop(shared);
+    assert_eq!(buffer.into_mut().unwrap_right(), vec![0, 1, 2]);
+}
+
+#[test]
+fn into_mut_sliced() {
+    // offsetted slices can't be converted
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]).slice(1, 2);
+    assert_eq!(buffer.into_mut().unwrap_left().as_slice(), &[1, 2]);
+
+    // slices from the start are truncated
+    let buffer = Buffer::<i32>::from(vec![0, 1, 2]).slice(0, 2);
+    assert_eq!(buffer.into_mut().unwrap_right(), vec![0, 1]);
+}
+
+#[test]
+fn into_mut_array() {
+    // buffers sharing a region with an array are not unique
+    let array = arrow2::array::PrimitiveArray::<i32>::from_slice([0, 1, 2]);
+    let buffer = array.values().clone();
+    assert!(buffer.into_mut().is_left());
+}
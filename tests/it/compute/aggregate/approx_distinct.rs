@@ -0,0 +1,68 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::approx_count_distinct;
+
+fn assert_within(estimate: u64, expected: u64, relative_error: f64) {
+    let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+    assert!(
+        error < relative_error,
+        "estimate {} of {} has a relative error of {}",
+        estimate,
+        expected,
+        error
+    );
+}
+
+#[test]
+fn primitive() {
+    let array = Int64Array::from_vec((0..100_000).collect());
+    let estimate = approx_count_distinct(&array, 14).unwrap();
+    assert_within(estimate, 100_000, 0.03);
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i32>::from_iter_values((0..100_000).map(|x| format!("value {}", x)));
+    let estimate = approx_count_distinct(&array, 14).unwrap();
+    assert_within(estimate, 100_000, 0.03);
+}
+
+#[test]
+fn binary() {
+    let array = BinaryArray::<i64>::from_iter_values((0u32..100_000).map(|x| x.to_le_bytes()));
+    let estimate = approx_count_distinct(&array, 14).unwrap();
+    assert_within(estimate, 100_000, 0.03);
+}
+
+#[test]
+fn duplicates_and_nulls() {
+    // 1000 distinct values repeated 50 times, of which the multiples of 7 are null
+    let array = UInt32Array::from_iter((0..50_000u32).map(|x| x % 1000).map(|x| {
+        if x % 7 == 0 {
+            None
+        } else {
+            Some(x)
+        }
+    }));
+    let expected = (0..1000u32).filter(|x| x % 7 != 0).count() as u64;
+    let estimate = approx_count_distinct(&array, 14).unwrap();
+    assert_within(estimate, expected, 0.03);
+}
+
+#[test]
+fn small() {
+    let array = Int32Array::from(&[Some(1), None, Some(2), Some(1), Some(3)]);
+    assert_eq!(approx_count_distinct(&array, 12).unwrap(), 3);
+
+    let array = Int32Array::from(&[None, None]);
+    assert_eq!(approx_count_distinct(&array, 12).unwrap(), 0);
+
+    let array = NullArray::from_data(arrow2::datatypes::DataType::Null, 3);
+    assert_eq!(approx_count_distinct(&array, 12).unwrap(), 0);
+}
+
+#[test]
+fn invalid_precision() {
+    let array = Int32Array::from_slice([1, 2]);
+    assert!(approx_count_distinct(&array, 3).is_err());
+    assert!(approx_count_distinct(&array, 19).is_err());
+}
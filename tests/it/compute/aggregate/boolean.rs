@@ -0,0 +1,61 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{all, any};
+
+#[test]
+fn no_nulls() {
+    let a = BooleanArray::from_slice([true, true, true]);
+    assert_eq!(any(&a), Some(true));
+    assert_eq!(all(&a), Some(true));
+
+    let a = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(any(&a), Some(true));
+    assert_eq!(all(&a), Some(false));
+
+    let a = BooleanArray::from_slice([false, false]);
+    assert_eq!(any(&a), Some(false));
+    assert_eq!(all(&a), Some(false));
+}
+
+#[test]
+fn mixed() {
+    let a = BooleanArray::from([Some(true), None, Some(true)]);
+    assert_eq!(any(&a), Some(true));
+    assert_eq!(all(&a), Some(true));
+
+    let a = BooleanArray::from([Some(false), None, Some(false)]);
+    assert_eq!(any(&a), Some(false));
+    assert_eq!(all(&a), Some(false));
+
+    let a = BooleanArray::from([Some(false), None, Some(true)]);
+    assert_eq!(any(&a), Some(true));
+    assert_eq!(all(&a), Some(false));
+}
+
+#[test]
+fn ignores_values_under_nulls() {
+    let a = BooleanArray::from_slice([true, false, false])
+        .with_validity(Some([false, true, true].into()));
+    assert_eq!(any(&a), Some(false));
+
+    let a = BooleanArray::from_slice([false, true, true])
+        .with_validity(Some([false, true, true].into()));
+    assert_eq!(all(&a), Some(true));
+}
+
+#[test]
+fn all_null() {
+    let a = BooleanArray::from([None, None]);
+    assert_eq!(any(&a), None);
+    assert_eq!(all(&a), None);
+
+    let a = BooleanArray::from_slice([]);
+    assert_eq!(any(&a), None);
+    assert_eq!(all(&a), None);
+}
+
+#[test]
+fn sliced() {
+    let a = BooleanArray::from([Some(true), None, Some(false), Some(false)]).slice(1, 3);
+    assert_eq!(any(&a), Some(false));
+    assert_eq!(all(&a), Some(false));
+}
@@ -1,6 +1,6 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_max,
+    min_max_string, min_primitive, min_string,
 };
 use arrow2::{array::*, datatypes::DataType};
 
@@ -186,3 +186,48 @@ fn test_binary_min_max_1() {
     assert_eq!(Some("a".as_bytes()), min_binary(&a));
     assert_eq!(Some("b".as_bytes()), max_binary(&a));
 }
+
+#[test]
+fn min_max_single_pass() {
+    let a = Int32Array::from(&[None, Some(5), Some(-2), None, Some(9)]);
+    assert_eq!(min_max(&a), Some((-2, 9)));
+
+    let a = Int32Array::from_slice([5, 6, 7, 8, 9]).slice(1, 3);
+    assert_eq!(min_max(&a), Some((6, 8)));
+
+    let a = Int32Array::from_slice([3]);
+    assert_eq!(min_max(&a), Some((3, 3)));
+}
+
+#[test]
+fn min_max_empty_and_all_null() {
+    let a = Int32Array::from_slice([]);
+    assert_eq!(min_max(&a), None);
+
+    let a = Int32Array::from(&[None, None]);
+    assert_eq!(min_max(&a), None);
+}
+
+#[test]
+fn min_max_ignores_nan() {
+    let a = Float64Array::from_slice([f64::NAN, 1.0, f64::NAN, -3.5, 2.0]);
+    assert_eq!(min_max(&a), Some((-3.5, 2.0)));
+
+    let a = Float32Array::from(&[Some(f32::NAN), None, Some(f32::INFINITY), Some(0.5)]);
+    assert_eq!(min_max(&a), Some((0.5, f32::INFINITY)));
+
+    let a = Float64Array::from(&[Some(f64::NAN), None, Some(f64::NAN)]);
+    assert_eq!(min_max(&a), None);
+}
+
+#[test]
+fn min_max_string_single_pass() {
+    let a = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c")]);
+    assert_eq!(min_max_string(&a), Some(("a", "c")));
+
+    let a = Utf8Array::<i64>::from_slice(["b", "bb", "a"]);
+    assert_eq!(min_max_string(&a), Some(("a", "bb")));
+
+    let a = Utf8Array::<i32>::from([None::<&str>, None]);
+    assert_eq!(min_max_string(&a), None);
+}
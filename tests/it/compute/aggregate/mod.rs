@@ -1,3 +1,7 @@
+#[cfg(feature = "compute_hash")]
+mod approx_distinct;
+mod boolean;
 mod memory;
 mod min_max;
+mod quantile;
 mod sum;
@@ -0,0 +1,86 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::{median, quantile, Interpolation};
+
+// the non-null values are [3.0, 1.0, 4.0, 1.5, 9.0, 2.6]
+fn array() -> Float64Array {
+    Float64Array::from(&[
+        Some(3.0),
+        Some(1.0),
+        None,
+        Some(4.0),
+        Some(1.5),
+        Some(9.0),
+        None,
+        Some(2.6),
+    ])
+}
+
+fn assert_approx(result: Option<f64>, expected: f64) {
+    let result = result.unwrap();
+    assert!(
+        (result - expected).abs() < 1e-12,
+        "{} != {}",
+        result,
+        expected
+    );
+}
+
+// expected values are `numpy.quantile(values, q, method=...)`
+#[test]
+fn quantiles() {
+    let array = array();
+    let cases = [
+        (0.0, [1.0, 1.0, 1.0, 1.0, 1.0]),
+        (0.3, [2.05, 1.5, 2.6, 2.6, 2.05]),
+        (0.5, [2.8, 2.6, 3.0, 2.6, 2.8]),
+        (0.75, [3.75, 3.0, 4.0, 4.0, 3.5]),
+        (1.0, [9.0, 9.0, 9.0, 9.0, 9.0]),
+    ];
+    let interpolations = [
+        Interpolation::Linear,
+        Interpolation::Lower,
+        Interpolation::Higher,
+        Interpolation::Nearest,
+        Interpolation::Midpoint,
+    ];
+    for (q, expected) in cases {
+        for (interpolation, expected) in interpolations.iter().zip(expected) {
+            assert_approx(quantile(&array, q, *interpolation), expected);
+        }
+    }
+}
+
+#[test]
+fn median_() {
+    assert_approx(median(&array()), 2.8);
+
+    let array = Int32Array::from_slice([5, 1, 3]);
+    assert_eq!(median(&array), Some(3.0));
+}
+
+#[test]
+fn does_not_mutate() {
+    let array = array();
+    let expected = array.clone();
+    quantile(&array, 0.3, Interpolation::Linear);
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn single_value() {
+    let array = UInt8Array::from(&[None, Some(7)]);
+    assert_eq!(quantile(&array, 0.3, Interpolation::Linear), Some(7.0));
+}
+
+#[test]
+fn all_null() {
+    let array = Int64Array::from(&[None, None]);
+    assert_eq!(median(&array), None);
+    assert_eq!(median(&Int64Array::from_slice([])), None);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    quantile(&array(), 1.5, Interpolation::Linear);
+}
@@ -99,3 +99,16 @@ fn test_div_scalar_checked() {
     let result = a.checked_div(&0);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_div_checked_overflow() {
+    let a = Int64Array::from_slice([i64::MIN, i64::MIN, i64::MAX]);
+    let b = Int64Array::from_slice([-1, 1, -1]);
+    let result = checked_div(&a, &b);
+    let expected = Int64Array::from([None, Some(i64::MIN), Some(-i64::MAX)]);
+    assert_eq!(result, expected);
+
+    let result = checked_div_scalar(&a, &-1);
+    let expected = Int64Array::from([None, None, Some(-i64::MAX)]);
+    assert_eq!(result, expected);
+}
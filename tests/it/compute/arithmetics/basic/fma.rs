@@ -0,0 +1,45 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::basic::*;
+
+#[test]
+fn test_fma() {
+    let a = Float64Array::from([Some(0.1), Some(2.5), None, Some(-3.0), Some(1e10)]);
+    let b = Float64Array::from([Some(0.2), Some(4.0), Some(1.0), None, Some(1e-10)]);
+    let c = Float64Array::from([Some(0.3), None, Some(1.0), Some(1.0), Some(-1.0)]);
+    let result = fma(&a, &b, &c).unwrap();
+
+    // same validity and values as separate multiplication and addition
+    let expected = add(&mul(&a, &b), &c);
+    assert_eq!(result.validity(), expected.validity());
+    assert_eq!(
+        result.validity(),
+        Some(&[true, false, false, false, true].into())
+    );
+    result
+        .iter()
+        .zip(expected.iter())
+        .for_each(|(result, expected)| match (result, expected) {
+            (Some(result), Some(expected)) => assert!((result - expected).abs() < 1e-12),
+            (None, None) => {}
+            _ => unreachable!(),
+        });
+}
+
+#[test]
+fn test_fma_f32() {
+    let a = Float32Array::from_slice([1.5, 2.0]);
+    let b = Float32Array::from_slice([2.0, -0.5]);
+    let c = Float32Array::from_slice([0.5, 1.0]);
+    assert_eq!(
+        fma(&a, &b, &c).unwrap(),
+        Float32Array::from_slice([3.5, 0.0])
+    );
+}
+
+#[test]
+fn test_fma_mismatched_length() {
+    let a = Float64Array::from_slice([1.0, 2.0]);
+    let b = Float64Array::from_slice([1.0]);
+    assert!(fma(&a, &b, &a).is_err());
+    assert!(fma(&a, &a, &b).is_err());
+}
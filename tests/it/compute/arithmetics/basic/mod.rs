@@ -1,5 +1,6 @@
 mod add;
 mod div;
+mod fma;
 mod mul;
 mod pow;
 mod rem;
@@ -34,6 +34,28 @@ fn test_mul_panic() {
     let _ = mul(&a, &b);
 }
 
+#[test]
+fn test_mul_i256() {
+    use arrow2::types::i256;
+
+    let max = i256::from(i128::MAX);
+    let a = PrimitiveArray::<i256>::from([Some(max), None, Some(i256::from(-3)), Some(i256::MAX)]);
+    let b = PrimitiveArray::<i256>::from([Some(max), Some(max), Some(max), Some(i256::from(2))]);
+
+    // (2^127 - 1)^2 = 2^254 - 2^128 + 1
+    let square = i256::from_words(i128::MAX >> 1, 1);
+    let result = checked_mul(&a, &b);
+    let expected =
+        PrimitiveArray::<i256>::from([Some(square), None, Some(i256::from(-3) * max), None]);
+    assert_eq!(result, expected);
+
+    let result = mul(&a.slice(0, 3), &b.slice(0, 3));
+    assert_eq!(result, expected.slice(0, 3));
+
+    let result = wrapping_mul(&a, &b);
+    assert_eq!(result.value(3), i256::from(-2));
+}
+
 #[test]
 fn test_mul_checked() {
     let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
@@ -16,3 +16,73 @@ fn test_raise_power_scalar_checked() {
     let expected = Int8Array::from(&[Some(1i8), None, None]);
     assert_eq!(expected, actual);
 }
+
+#[test]
+fn test_pow_scalar() {
+    let a = Int32Array::from(&[Some(-2), None, Some(3), Some(0)]);
+    let actual = pow_scalar(&a, 3u32);
+    let expected = Int32Array::from(&[Some(-8), None, Some(27), Some(0)]);
+    assert_eq!(expected, actual);
+
+    let actual = pow_scalar(&a, 2u32);
+    let expected = Int32Array::from(&[Some(4), None, Some(9), Some(0)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_pow_scalar_zero_exponent() {
+    let a = Int64Array::from(&[Some(-2), None, Some(0)]);
+    let actual = pow_scalar(&a, 0u32);
+    let expected = Int64Array::from(&[Some(1), None, Some(1)]);
+    assert_eq!(expected, actual);
+
+    let a = Float64Array::from(&[Some(-2.0), None, Some(0.0)]);
+    let actual = pow_scalar(&a, 0i32);
+    let expected = Float64Array::from(&[Some(1.0), None, Some(1.0)]);
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn test_pow_scalar_float() {
+    let a = Float32Array::from(&[Some(-2.0), None, Some(4.0)]);
+    let actual = pow_scalar(&a, -2i32);
+    let expected = Float32Array::from(&[Some(0.25), None, Some(0.0625)]);
+    assert_eq!(expected, actual);
+
+    let actual = powf_scalar(&a, 0.5);
+    assert!(actual.value(0).is_nan());
+    assert_eq!(actual.value(2), 2.0);
+    assert!(!actual.is_valid(1));
+}
+
+#[test]
+fn test_pow_scalar_overflow_modes() {
+    let a = Int8Array::from(&[Some(-7), None, Some(7), Some(-2)]);
+
+    let actual = checked_pow_scalar(&a, 3);
+    let expected = Int8Array::from(&[None, None, None, Some(-8)]);
+    assert_eq!(expected, actual);
+
+    let actual = wrapping_pow_scalar(&a, 3);
+    let expected = Int8Array::from(&[Some(-87), None, Some(87), Some(-8)]);
+    assert_eq!(expected, actual);
+
+    let actual = saturating_pow_scalar(&a, 3);
+    let expected = Int8Array::from(&[Some(-128), None, Some(127), Some(-8)]);
+    assert_eq!(expected, actual);
+
+    let (actual, overflow) = overflowing_pow_scalar(&a, 3);
+    let expected = Int8Array::from(&[Some(-87), None, Some(87), Some(-8)]);
+    assert_eq!(expected, actual);
+    assert_eq!(
+        overflow.iter().collect::<Vec<_>>(),
+        vec![true, false, true, false]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_pow_scalar_panics_on_overflow() {
+    let a = Int8Array::from(&[Some(7)]);
+    pow_scalar(&a, 3u32);
+}
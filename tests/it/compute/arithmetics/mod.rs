@@ -1,11 +1,13 @@
 mod basic;
 mod decimal;
 mod time;
+mod trig;
 
 use arrow2::array::*;
 use arrow2::compute::arithmetics::*;
 use arrow2::datatypes::DataType::*;
 use arrow2::datatypes::{IntervalUnit, TimeUnit};
+use arrow2::error::ArrowError;
 use arrow2::scalar::PrimitiveScalar;
 
 #[test]
@@ -26,6 +28,86 @@ fn test_add_scalar() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn test_scalar_ops() {
+    let a = Int32Array::from(&[None, Some(6), None, Some(7)]);
+    let b: PrimitiveScalar<i32> = Some(2i32).into();
+
+    let result = sub_scalar(&a, &b);
+    let expected = Int32Array::from(&[None, Some(4), None, Some(5)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = mul_scalar(&a, &b);
+    let expected = Int32Array::from(&[None, Some(12), None, Some(14)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = div_scalar(&a, &b);
+    let expected = Int32Array::from(&[None, Some(3), None, Some(3)]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = rem_scalar(&a, &b);
+    let expected = Int32Array::from(&[None, Some(0), None, Some(1)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_add_null_scalar() {
+    let a = Int32Array::from(&[None, Some(6), None, Some(6)]);
+    let b = PrimitiveScalar::<i32>::new(Int32, None);
+    let result = add_scalar(&a, &b);
+    let expected = Int32Array::new_null(Int32, 4);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+#[should_panic(
+    expected = "The operation \"add_scalar\" is not supported between an array of Int32 and a scalar of Int64"
+)]
+fn test_add_scalar_mismatched_type() {
+    let a = Int32Array::from(&[None, Some(6)]);
+    let b: PrimitiveScalar<i64> = Some(1i64).into();
+    assert!(!can_add(a.data_type(), &Int64));
+    add_scalar(&a, &b);
+}
+
+#[test]
+fn test_try_scalar_mismatched_type() {
+    let a = Int32Array::from(&[None, Some(6)]);
+    let b: PrimitiveScalar<i64> = Some(1i64).into();
+    for result in [
+        try_add_scalar(&a, &b),
+        try_sub_scalar(&a, &b),
+        try_mul_scalar(&a, &b),
+        try_div_scalar(&a, &b),
+        try_rem_scalar(&a, &b),
+    ] {
+        assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+    }
+
+    let b: PrimitiveScalar<i32> = Some(1i32).into();
+    let expected = Int32Array::from(&[None, Some(7)]);
+    assert_eq!(expected, try_add_scalar(&a, &b).unwrap().as_ref());
+}
+
+#[test]
+fn test_checked_div() {
+    let a = Int64Array::from([Some(10), Some(10), Some(i64::MIN), None]);
+    let b = Int64Array::from([Some(2), Some(0), Some(-1), Some(1)]);
+    assert!(can_checked_div(a.data_type(), b.data_type()));
+    let result = checked_div(&a, &b);
+    let expected = Int64Array::from([Some(5), None, None, None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let b: PrimitiveScalar<i64> = Some(0i64).into();
+    let result = checked_div_scalar(&a, &b);
+    assert_eq!(
+        result.as_ref(),
+        &Int64Array::new_null(Int64, 4) as &dyn Array
+    );
+
+    assert!(!can_checked_div(&Float64, &Float64));
+}
+
 #[test]
 fn consistency() {
     let datatypes = vec![
@@ -1,7 +1,8 @@
 use arrow2::array::*;
 use arrow2::compute::arithmetics::time::*;
-use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::datatypes::{DataType, IntervalUnit, TimeUnit};
 use arrow2::scalar::*;
+use arrow2::types::months_days_ns;
 
 #[test]
 fn test_adding_timestamp() {
@@ -317,3 +318,55 @@ fn test_date64() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_add_interval_month_end() {
+    // 2021-01-31T10:00:00, 2020-01-31T10:00:00 (leap year) and null
+    let timestamp = PrimitiveArray::from([Some(1612087200), Some(1580464800), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let interval = months_days_ns::new(1, 0, 0);
+    let interval = PrimitiveArray::from_slice([interval, interval, interval])
+        .to(DataType::Interval(IntervalUnit::MonthDayNano));
+
+    let result = add_interval(&timestamp, &interval).unwrap();
+
+    // 2021-02-28T10:00:00, 2020-02-29T10:00:00 and null
+    let expected = PrimitiveArray::from([Some(1614506400), Some(1582970400), None])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_interval_overflow() {
+    // 2262-01-01T00:00:00 and null
+    let timestamp = PrimitiveArray::from([Some(9_214_646_400_000_000_000), None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let interval = PrimitiveScalar::new(
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        Some(months_days_ns::new(12, 0, 0)),
+    );
+
+    let result = add_interval_scalar(&timestamp, &interval).unwrap();
+
+    let expected = PrimitiveArray::<i64>::from([None, None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_add_interval_negative() {
+    // 2021-03-31T10:00:00+01:00
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string()));
+    let timestamp = PrimitiveArray::from_slice([1617184800000 - 3_600_000]).to(data_type.clone());
+    let interval = PrimitiveScalar::new(
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        Some(months_days_ns::new(-1, -1, -1_000_000_000)),
+    );
+
+    let result = add_interval_scalar(&timestamp, &interval).unwrap();
+
+    // months are added first (clamped to Feb 28), then days and then nanoseconds:
+    // 2021-02-27T09:59:59+01:00
+    let expected = PrimitiveArray::from_slice([1614419999000 - 3_600_000]).to(data_type);
+    assert_eq!(result, expected);
+}
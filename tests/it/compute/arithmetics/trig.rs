@@ -0,0 +1,90 @@
+use std::f64::consts::{E, FRAC_PI_2, FRAC_PI_4, PI};
+
+use arrow2::array::*;
+use arrow2::compute::arithmetics::trig::*;
+
+fn assert_approx(result: &Float64Array, expected: &[Option<f64>]) {
+    assert_eq!(result.len(), expected.len());
+    result
+        .iter()
+        .zip(expected.iter())
+        .for_each(|(result, expected)| match (result, expected) {
+            (Some(result), Some(expected)) if expected.is_nan() => assert!(result.is_nan()),
+            (Some(result), Some(expected)) => assert!(
+                (result - expected).abs() < 1e-12,
+                "{} != {}",
+                result,
+                expected
+            ),
+            (result, expected) => assert_eq!(result.copied(), *expected),
+        });
+}
+
+#[test]
+fn trigonometric() {
+    let a = Float64Array::from(&[Some(0.0), None, Some(FRAC_PI_2), Some(PI)]);
+
+    assert_approx(&sin(&a), &[Some(0.0), None, Some(1.0), Some(0.0)]);
+    assert_approx(&cos(&a), &[Some(1.0), None, Some(0.0), Some(-1.0)]);
+
+    let a = Float64Array::from(&[Some(0.0), None, Some(FRAC_PI_4)]);
+    assert_approx(&tan(&a), &[Some(0.0), None, Some(1.0)]);
+}
+
+#[test]
+fn inverse_trigonometric() {
+    let a = Float64Array::from(&[Some(0.0), None, Some(1.0), Some(2.0)]);
+
+    assert_approx(
+        &asin(&a),
+        &[Some(0.0), None, Some(FRAC_PI_2), Some(f64::NAN)],
+    );
+    assert_approx(
+        &acos(&a),
+        &[Some(FRAC_PI_2), None, Some(0.0), Some(f64::NAN)],
+    );
+    assert_approx(
+        &atan(&a),
+        &[Some(0.0), None, Some(FRAC_PI_4), Some(2.0f64.atan())],
+    );
+}
+
+#[test]
+fn exponential_and_logarithms() {
+    let a = Float64Array::from(&[Some(0.0), None, Some(1.0), Some(-1.0)]);
+    assert_approx(&exp(&a), &[Some(1.0), None, Some(E), Some(1.0 / E)]);
+
+    let a = Float64Array::from(&[Some(1.0), None, Some(E), Some(-1.0)]);
+    assert_approx(&ln(&a), &[Some(0.0), None, Some(1.0), Some(f64::NAN)]);
+
+    let a = Float64Array::from(&[Some(1.0), None, Some(1000.0), Some(-1.0)]);
+    assert_approx(&log10(&a), &[Some(0.0), None, Some(3.0), Some(f64::NAN)]);
+
+    let a = Float64Array::from(&[Some(4.0), None, Some(2.0), Some(-1.0)]);
+    assert_approx(
+        &sqrt(&a),
+        &[Some(2.0), None, Some(2.0f64.sqrt()), Some(f64::NAN)],
+    );
+}
+
+#[test]
+fn test_atan2() {
+    let y = Float64Array::from(&[Some(1.0), None, Some(1.0), Some(-1.0)]);
+    let x = Float64Array::from(&[Some(1.0), Some(1.0), None, Some(-1.0)]);
+
+    assert_approx(
+        &atan2(&y, &x),
+        &[Some(FRAC_PI_4), None, None, Some(-3.0 * FRAC_PI_4)],
+    );
+}
+
+#[test]
+fn float32() {
+    let a = Float32Array::from(&[Some(4.0f32), None, Some(-1.0)]);
+    let result = sqrt(&a);
+
+    assert_eq!(result.value(0), 2.0);
+    assert!(!result.is_valid(1));
+    assert!(result.value(2).is_nan());
+    assert_eq!(result.data_type(), a.data_type());
+}
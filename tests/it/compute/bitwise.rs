@@ -39,3 +39,75 @@ fn test_not() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_bitwise_u64() {
+    let a = UInt64Array::from(&[Some(0b1100), None, Some(u64::MAX)]);
+    let b = UInt64Array::from(&[Some(0b1010), Some(1), None]);
+
+    assert_eq!(and(&a, &b), UInt64Array::from(&[Some(0b1000), None, None]));
+    assert_eq!(or(&a, &b), UInt64Array::from(&[Some(0b1110), None, None]));
+    assert_eq!(xor(&a, &b), UInt64Array::from(&[Some(0b0110), None, None]));
+}
+
+#[test]
+fn test_shift_left() {
+    let a = Int32Array::from(&[Some(1), Some(-1), None, Some(1), Some(3)]);
+    let b = Int32Array::from(&[Some(2), Some(1), Some(1), None, Some(33)]);
+    let result = shift_left(&a, &b);
+    // shifts by >= 32 are masked: 33 shifts by 1
+    let expected = Int32Array::from(&[Some(4), Some(-2), None, None, Some(6)]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_shift_right() {
+    let a = Int32Array::from(&[Some(8), Some(-8), None, Some(i32::MIN)]);
+    let b = Int32Array::from(&[Some(2), Some(1), Some(1), Some(32)]);
+    let result = shift_right(&a, &b);
+    // signed values are shifted arithmetically and 32 is masked to 0
+    let expected = Int32Array::from(&[Some(2), Some(-4), None, Some(i32::MIN)]);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_shift_u64() {
+    let a = UInt64Array::from(&[Some(1), None, Some(u64::MAX)]);
+    let b = UInt64Array::from(&[Some(63), Some(1), Some(65)]);
+
+    assert_eq!(
+        shift_left(&a, &b),
+        UInt64Array::from(&[Some(1 << 63), None, Some(u64::MAX << 1)])
+    );
+    assert_eq!(
+        shift_right(&a, &b),
+        UInt64Array::from(&[Some(0), None, Some(u64::MAX >> 1)])
+    );
+}
+
+#[test]
+fn test_shift_scalar() {
+    let a = Int32Array::from(&[Some(1), None, Some(-16)]);
+
+    assert_eq!(
+        shift_left_scalar(&a, &3),
+        Int32Array::from(&[Some(8), None, Some(-128)])
+    );
+    assert_eq!(
+        shift_right_scalar(&a, &2),
+        Int32Array::from(&[Some(0), None, Some(-4)])
+    );
+    // shifts by >= 32 are masked
+    assert_eq!(
+        shift_left_scalar(&a, &35),
+        Int32Array::from(&[Some(8), None, Some(-128)])
+    );
+
+    let a = UInt64Array::from(&[Some(u64::MAX), None]);
+    assert_eq!(
+        shift_right_scalar(&a, &60),
+        UInt64Array::from(&[Some(0b1111), None])
+    );
+}
@@ -1,5 +1,8 @@
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{
+    can_cast_types, cast, dictionary_encode, dictionary_to_values, utf8_to_date32_with_format,
+    utf8_to_date64_with_format, utf8_to_timestamp_ns_with_format, CastOptions,
+};
 use arrow2::datatypes::*;
 use arrow2::types::NativeType;
 
@@ -296,31 +299,73 @@ fn int32_to_decimal_scaled() {
 fn decimal_to_decimal() {
     // increase scale and precision
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+        .to(DataType::Decimal(2, 0));
 
-    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(3, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected = Int128Array::from(&[Some(20), Some(100), Some(-20), Some(-100), None])
-        .to(DataType::Decimal(2, 1));
+        .to(DataType::Decimal(3, 1));
     assert_eq!(c, &expected)
 }
 
 #[test]
 fn decimal_to_decimal_scaled() {
     // decrease precision
-    // 10 and -10 can't be represented with precision 1 and scale 1
+    // 10 and -10 can't be represented with precision 2 and scale 1
     let array = Int128Array::from(&[Some(2), Some(10), Some(-2), Some(-10), None])
-        .to(DataType::Decimal(1, 0));
+        .to(DataType::Decimal(2, 0));
 
-    let b = cast(&array, &DataType::Decimal(1, 1), CastOptions::default()).unwrap();
+    let b = cast(&array, &DataType::Decimal(2, 1), CastOptions::default()).unwrap();
     let c = b.as_any().downcast_ref::<PrimitiveArray<i128>>().unwrap();
 
     let expected =
-        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(1, 1));
+        Int128Array::from(&[Some(20), None, Some(-20), None, None]).to(DataType::Decimal(2, 1));
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn decimal_to_decimal_scale_up() {
+    // 123.45 and -0.01 as Decimal(10, 2) to Decimal(10, 4)
+    let array = Int128Array::from([Some(12345), Some(-1), None]).to(DataType::Decimal(10, 2));
+
+    let b = cast(&array, &DataType::Decimal(10, 4), CastOptions::default()).unwrap();
+
+    let expected =
+        Int128Array::from([Some(1234500), Some(-100), None]).to(DataType::Decimal(10, 4));
+    assert_eq!(b.as_ref(), &expected as &dyn Array)
+}
+
+#[test]
+fn decimal_to_decimal_scale_down_rounding() {
+    // 1.2345, 1.2350, 1.2349, -1.2350, -1.2349, 0.0050, -0.0050 to 2 decimal places
+    let array = Int128Array::from_slice([12345, 12350, 12349, -12350, -12349, 50, -50])
+        .to(DataType::Decimal(10, 4));
+
+    let b = cast(&array, &DataType::Decimal(10, 2), CastOptions::default()).unwrap();
+
+    let expected =
+        Int128Array::from_slice([123, 124, 123, -124, -123, 1, -1]).to(DataType::Decimal(10, 2));
+    assert_eq!(b.as_ref(), &expected as &dyn Array)
+}
+
+#[test]
+fn decimal_to_decimal_precision_overflow() {
+    // 99999999.99, 99.99 and -99999999.99 as Decimal(10, 2)
+    let array =
+        Int128Array::from_slice([9999999999, 9999, -9999999999]).to(DataType::Decimal(10, 2));
+
+    // 99999999.9900 needs 12 digits
+    let b = cast(&array, &DataType::Decimal(10, 4), CastOptions::default()).unwrap();
+    let expected = Int128Array::from([None, Some(999900), None]).to(DataType::Decimal(10, 4));
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+    // 99999999.99 rounds to 100000000.0, that needs 10 digits
+    let b = cast(&array, &DataType::Decimal(9, 1), CastOptions::default()).unwrap();
+    let expected = Int128Array::from([None, Some(1000), None]).to(DataType::Decimal(9, 1));
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn decimal_to_decimal_fast() {
     // increase precision
@@ -401,6 +446,43 @@ fn bool_to_f64() {
     assert_eq!(c, &expected);
 }
 
+#[test]
+fn bool_to_i8() {
+    let array = BooleanArray::from([Some(true), Some(false), None]);
+    let b = cast(&array, &DataType::Int8, CastOptions::default()).unwrap();
+
+    let expected = Int8Array::from([Some(1), Some(0), None]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn i8_to_bool() {
+    let array = Int8Array::from([Some(0), Some(-1), None, Some(2)]);
+    let b = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(true)]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn f64_to_bool() {
+    let array = Float64Array::from([Some(0.0), Some(-0.0), Some(f64::NAN), None, Some(0.5)]);
+
+    // NaN is null
+    let b = cast(&array, &DataType::Boolean, CastOptions::default()).unwrap();
+    let expected = BooleanArray::from([Some(false), Some(false), None, None, Some(true)]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+
+    // NaN is different from zero
+    let options = CastOptions {
+        wrapped: true,
+        ..CastOptions::default()
+    };
+    let b = cast(&array, &DataType::Boolean, options).unwrap();
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), None, Some(true)]);
+    assert_eq!(b.as_ref(), &expected as &dyn Array);
+}
+
 #[test]
 fn bool_to_utf8() {
     let array = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -602,6 +684,97 @@ fn timestamp_to_timestamp() {
     );
 }
 
+#[test]
+fn timestamp_ms_to_ns() {
+    let tz = Some("Europe/Paris".to_string());
+    let array = Int64Array::from(&[Some(1), None, Some(-1000), Some(i64::MAX / 1000)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, tz.clone()));
+
+    let to_type = DataType::Timestamp(TimeUnit::Nanosecond, tz);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    // the timezone is preserved and the last value overflows
+    let expected =
+        Int64Array::from(&[Some(1_000_000), None, Some(-1_000_000_000), None]).to(to_type.clone());
+    assert_eq!(result.data_type(), &to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn timestamp_ns_to_s_truncates() {
+    let tz = Some("+01:00".to_string());
+    let array = Int64Array::from(&[Some(1_999_999_999), None, Some(-1_500_000_000)])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, tz.clone()));
+
+    let to_type = DataType::Timestamp(TimeUnit::Second, tz);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let expected = Int64Array::from(&[Some(1), None, Some(-1)]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn timestamp_ms_to_ns_wrapped() {
+    let array = Int64Array::from_slice([i64::MAX / 1000])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let options = CastOptions {
+        wrapped: true,
+        partial: false,
+    };
+    let result = cast(&array, &to_type, options).unwrap();
+
+    let expected = Int64Array::from_slice([(i64::MAX / 1000).wrapping_mul(1_000_000)]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn duration_s_to_ms() {
+    let array = Int64Array::from(&[Some(1), None, Some(-2), Some(i64::MAX / 10)])
+        .to(DataType::Duration(TimeUnit::Second));
+
+    let to_type = DataType::Duration(TimeUnit::Millisecond);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let expected = Int64Array::from(&[Some(1000), None, Some(-2000), None]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn duration_ns_to_us() {
+    test_primitive_to_primitive(
+        &[1_999i64, -1_999],
+        DataType::Duration(TimeUnit::Nanosecond),
+        &[1i64, -1],
+        DataType::Duration(TimeUnit::Microsecond),
+    );
+}
+
+#[test]
+fn time64us_to_time32s() {
+    test_primitive_to_primitive(
+        &[3_599_999_999i64, 1_000_000],
+        DataType::Time64(TimeUnit::Microsecond),
+        &[3_599i32, 1],
+        DataType::Time32(TimeUnit::Second),
+    );
+}
+
+#[test]
+fn time64ns_to_time32ms_overflow() {
+    let array = Int64Array::from(&[Some(86_399_999_999_999), Some(i64::MAX)])
+        .to(DataType::Time64(TimeUnit::Nanosecond));
+
+    let to_type = DataType::Time32(TimeUnit::Millisecond);
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    // the last value does not fit in an `i32`
+    let expected = Int32Array::from(&[Some(86_399_999), None]).to(to_type);
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn utf8_to_dict() {
     let array = Utf8Array::<i32>::from(&[Some("one"), None, Some("three"), Some("one")]);
@@ -649,6 +822,134 @@ fn i32_to_dict() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn i32_to_run_end_encoded() {
+    let array = Int32Array::from(&[Some(1), Some(1), None, None, Some(3), Some(1)]);
+
+    let cast_type = DataType::RunEndEncoded(IntegerType::Int32, Box::new(DataType::Int64));
+    assert!(can_cast_types(array.data_type(), &cast_type));
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+
+    let expected = RunEndEncodedArray::<i32>::from_data(
+        Int32Array::from_slice([2, 4, 5, 6]),
+        std::sync::Arc::new(Int64Array::from([Some(1), None, Some(3), Some(1)])),
+    );
+    assert_eq!(expected, result.as_ref());
+
+    let result = result.slice(1, 4);
+    let result = result
+        .as_any()
+        .downcast_ref::<RunEndEncodedArray<i32>>()
+        .unwrap();
+    assert_eq!(result.physical_index(0), 0);
+    assert_eq!(result.physical_index(3), 2);
+
+    let cast_type = DataType::RunEndEncoded(IntegerType::Int32, Box::new(DataType::Utf8));
+    assert!(!can_cast_types(array.data_type(), &cast_type));
+
+    let cast_type = DataType::RunEndEncoded(IntegerType::Int16, Box::new(DataType::Int32));
+    assert!(can_cast_types(array.data_type(), &cast_type));
+    let result = cast(&array, &cast_type, CastOptions::default()).expect("cast failed");
+    assert_eq!(result.data_type(), &cast_type);
+
+    // the run ends must be Int16, Int32 or Int64
+    for run_end_type in [IntegerType::Int8, IntegerType::UInt32] {
+        let cast_type = DataType::RunEndEncoded(run_end_type, Box::new(DataType::Int32));
+        assert!(!can_cast_types(array.data_type(), &cast_type));
+        assert!(cast(&array, &cast_type, CastOptions::default()).is_err());
+    }
+}
+
+#[test]
+fn utf8_to_utf8_view_round_trip() {
+    let long = "a string longer than 12 bytes";
+    let array = Utf8Array::<i32>::from([Some("hi"), None, Some(long), Some("")]);
+
+    assert!(can_cast_types(array.data_type(), &DataType::Utf8View));
+    let result = cast(&array, &DataType::Utf8View, CastOptions::default()).expect("cast failed");
+    let expected = Utf8ViewArray::from([Some("hi"), None, Some(long), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    let view = result.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+    assert!(view.view(0).is_inline());
+    assert!(!view.view(2).is_inline());
+
+    for to_type in [DataType::Utf8, DataType::LargeUtf8] {
+        assert!(can_cast_types(&DataType::Utf8View, &to_type));
+        let back = cast(result.as_ref(), &to_type, CastOptions::default()).expect("cast failed");
+        assert_eq!(back.data_type(), &to_type);
+        let back = cast(back.as_ref(), &DataType::Utf8, CastOptions::default()).unwrap();
+        assert_eq!(array, back.as_ref());
+    }
+}
+
+#[test]
+fn utf8_to_utf8_view_sliced() {
+    let long = "a string longer than 12 bytes";
+    let array = Utf8Array::<i64>::from([Some("a"), Some(long), None, Some("b")]);
+    let array = array.slice(1, 3);
+
+    let result = cast(&array, &DataType::Utf8View, CastOptions::default()).expect("cast failed");
+    let expected = Utf8ViewArray::from([Some(long), None, Some("b")]);
+    assert_eq!(expected, result.as_ref());
+
+    let result = result.slice(1, 2);
+    let result = cast(
+        result.as_ref(),
+        &DataType::LargeUtf8,
+        CastOptions::default(),
+    )
+    .unwrap();
+    let expected = Utf8Array::<i64>::from([None, Some("b")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn dictionary_encode_round_trip() {
+    let arrays: Vec<Box<dyn Array>> = vec![
+        Box::new(Int64Array::from([Some(1), None, Some(3), Some(1), Some(3)])),
+        Box::new(Int32Array::from([Some(1), None, Some(1)]).to(DataType::Date32)),
+        Box::new(Utf8Array::<i64>::from([
+            Some("a"),
+            Some("b"),
+            None,
+            Some("a"),
+        ])),
+        Box::new(BinaryArray::<i32>::from([Some(b"a"), None, Some(b"a")])),
+    ];
+    for array in arrays {
+        let encoded = dictionary_encode::<i16>(array.as_ref()).unwrap();
+        assert_eq!(dictionary_to_values(&encoded), array);
+    }
+}
+
+#[test]
+fn dictionary_encode_deduplicates() {
+    let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a"), Some("b")]);
+    let encoded = dictionary_encode::<u8>(&array).unwrap();
+
+    let values = Utf8Array::<i32>::from_slice(["a", "b"]);
+    assert_eq!(encoded.values().as_ref(), &values as &dyn Array);
+    let keys = UInt8Array::from([Some(0), Some(1), None, Some(0), Some(1)]);
+    assert_eq!(encoded.keys(), &keys);
+}
+
+#[test]
+fn dictionary_encode_key_overflow() {
+    let array = Int32Array::from_vec((0..300).collect());
+    assert!(dictionary_encode::<u8>(&array).is_err());
+    assert_eq!(
+        dictionary_encode::<u16>(&array).unwrap().values().len(),
+        300
+    );
+}
+
+#[test]
+fn dictionary_encode_unsupported() {
+    let array = Float64Array::from_slice([1.0]);
+    assert!(dictionary_encode::<u8>(&array).is_err());
+}
+
 #[test]
 fn list_to_list() {
     let data = vec![
@@ -759,6 +1060,150 @@ fn null_array_from_and_to_others() {
     typed_test!(Float64Array, Float64);
 }
 
+#[test]
+fn null_array_to_any() {
+    let array = NullArray::new_null(DataType::Null, 3);
+    let to_types = vec![
+        DataType::Utf8,
+        DataType::Timestamp(TimeUnit::Second, Some("UTC".to_string())),
+        DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+        DataType::FixedSizeList(Box::new(Field::new("a", DataType::Int32, true)), 2),
+    ];
+    for to_type in to_types {
+        assert!(can_cast_types(&DataType::Null, &to_type));
+        let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+        assert_eq!(result.as_ref(), new_null_array(to_type, 3).as_ref());
+    }
+}
+
+#[test]
+fn any_to_null_array() {
+    let array = StructArray::new_null(
+        DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+        2,
+    );
+    assert!(can_cast_types(array.data_type(), &DataType::Null));
+    let result = cast(&array, &DataType::Null, CastOptions::default()).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new_null(DataType::Null, 2) as &dyn Array
+    );
+}
+
+#[test]
+fn utf8_to_naive_timestamp_with_format() {
+    let array = Utf8Array::<i32>::from([
+        Some("12/31/1999 23:00"),
+        Some("01/01/1970 00:01"),
+        Some("1999-12-31T23:00:00"),
+        None,
+    ]);
+
+    let result = utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M", None, false).unwrap();
+    let expected = Int64Array::from([Some(946681200000000000), Some(60000000000), None, None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(result, expected);
+
+    assert!(utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M", None, true).is_err());
+
+    // values without a time are at midnight
+    let array = Utf8Array::<i32>::from_slice(["01/02/1970"]);
+    let result = utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y", None, true).unwrap();
+    let expected = Int64Array::from_slice([86400000000000])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_timestamp_with_format_out_of_range() {
+    // nanoseconds since the epoch overflow i64 after 2262
+    let array = Utf8Array::<i32>::from_slice(["01/01/2300", "01/01/2000"]);
+    let result = utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y", None, false).unwrap();
+    let expected = Int64Array::from([None, Some(946684800000000000)])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(result, expected);
+    assert!(utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y", None, true).is_err());
+
+    let tz = Some("+02:00".to_string());
+    let result = utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y", tz.clone(), false).unwrap();
+    assert!(result.is_null(0));
+    assert!(result.is_valid(1));
+
+    let array = Utf8Array::<i32>::from_slice(["01/01/2300 +0100"]);
+    let result = utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %z", tz, false);
+    assert!(result.unwrap().is_null(0));
+}
+
+#[test]
+fn utf8_to_timestamp_with_format_and_tz() {
+    let tz = "+02:00".to_string();
+    let array = Utf8Array::<i64>::from([Some("12/31/1999 23:00"), None, Some("12/31/1999")]);
+
+    // the values are local times of the timezone
+    let result =
+        utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M", Some(tz.clone()), false)
+            .unwrap();
+    let expected = Int64Array::from([Some(946674000000000000), None, None])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, Some(tz.clone())));
+    assert_eq!(result, expected);
+
+    assert!(
+        utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M", Some(tz.clone()), true).is_err()
+    );
+
+    // offsets in the values take precedence over the timezone
+    let array = Utf8Array::<i32>::from_slice(["12/31/1999 23:00 +0100"]);
+    let result =
+        utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M %z", Some(tz.clone()), true)
+            .unwrap();
+    let expected = Int64Array::from_slice([946677600000000000])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, Some(tz)));
+    assert_eq!(result, expected);
+
+    let result = utf8_to_timestamp_ns_with_format(
+        &array,
+        "%m/%d/%Y",
+        Some("Not/A_Timezone".to_string()),
+        false,
+    );
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn utf8_to_timestamp_with_format_and_chrono_tz() {
+    let tz = "America/New_York".to_string();
+    let array = Utf8Array::<i32>::from_slice(["12/31/1999 23:00", "07/01/2000 23:00"]);
+
+    let result =
+        utf8_to_timestamp_ns_with_format(&array, "%m/%d/%Y %H:%M", Some(tz.clone()), true).unwrap();
+    // EST (-05:00) and EDT (-04:00)
+    let expected = Int64Array::from_slice([946699200000000000, 962506800000000000])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, Some(tz)));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn utf8_to_date_with_format() {
+    let array = Utf8Array::<i32>::from([
+        Some("01/02/1970"),
+        Some("12/31/1969"),
+        Some("13/01/2000"),
+        None,
+    ]);
+
+    let result = utf8_to_date32_with_format(&array, "%m/%d/%Y", false).unwrap();
+    let expected = Int32Array::from([Some(1), Some(-1), None, None]).to(DataType::Date32);
+    assert_eq!(result, expected);
+    assert!(utf8_to_date32_with_format(&array, "%m/%d/%Y", true).is_err());
+
+    let result = utf8_to_date64_with_format(&array, "%m/%d/%Y", false).unwrap();
+    let expected =
+        Int64Array::from([Some(86400000), Some(-86400000), None, None]).to(DataType::Date64);
+    assert_eq!(result, expected);
+    assert!(utf8_to_date64_with_format(&array, "%m/%d/%Y", true).is_err());
+}
+
 /*
 #[test]
 fn dict_to_dict_bad_index_value_primitive() {
@@ -824,6 +1269,7 @@ fn dict_to_dict_bad_index_value_utf8() {
     );
 }
 
+
 #[test]
 fn utf8_to_date32() {
     use chrono::NaiveDate;
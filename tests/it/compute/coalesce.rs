@@ -0,0 +1,53 @@
+use arrow2::array::*;
+use arrow2::compute::coalesce::coalesce;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn three_columns() {
+    let a = Int32Array::from(&[Some(1), None, None, None, Some(5), None]);
+    let b = Int32Array::from(&[Some(10), Some(20), None, None, None, None]);
+    let c = Int32Array::from(&[Some(100), Some(200), Some(300), None, None, Some(600)]);
+
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+
+    let expected = Int32Array::from(&[Some(1), Some(20), Some(300), None, Some(5), Some(600)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let a = Utf8Array::<i32>::from([None, Some("a"), None]);
+    let b = Utf8Array::<i32>::from([Some("b"), None, None]);
+    let c = Utf8Array::<i32>::from([Some("c"), Some("c"), Some("c")]);
+
+    let result = coalesce(&[&a, &b, &c]).unwrap();
+
+    let expected = Utf8Array::<i32>::from([Some("b"), Some("a"), Some("c")]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn single() {
+    let a = Int32Array::from(&[Some(1), None]);
+    let result = coalesce(&[&a]).unwrap();
+    assert_eq!(a, result.as_ref());
+}
+
+#[test]
+fn different_types() {
+    let a = Int32Array::from_slice([1]);
+    let b = Int32Array::from_slice([1]).to(DataType::Date32);
+    assert!(coalesce(&[&a, &b]).is_err());
+}
+
+#[test]
+fn different_lengths() {
+    let a = Int32Array::from_slice([1]);
+    let b = Int32Array::from_slice([1, 2]);
+    assert!(coalesce(&[&a, &b]).is_err());
+}
+
+#[test]
+fn empty() {
+    assert!(coalesce(&[]).is_err());
+}
@@ -2,6 +2,7 @@ use arrow2::array::*;
 use arrow2::compute::comparison::{self, boolean::*};
 use arrow2::datatypes::{DataType::*, IntervalUnit};
 use arrow2::datatypes::{IntegerType, TimeUnit};
+use arrow2::error::{ArrowError, Result};
 use arrow2::scalar::new_scalar;
 
 #[test]
@@ -20,6 +21,7 @@ fn consistency() {
         Int64,
         Float32,
         Float64,
+        Decimal256(76, 2),
         Interval(IntervalUnit::YearMonth),
         Interval(IntervalUnit::MonthDayNano),
         Interval(IntervalUnit::DayTime),
@@ -328,3 +330,308 @@ mod tests {
         check_mask(&out, &[false, true, true]);
     }
 }
+
+fn utf8_dictionary(keys: &[Option<i32>], values: &[Option<&str>]) -> DictionaryArray<i32> {
+    let values = std::sync::Arc::new(Utf8Array::<i32>::from(values));
+    DictionaryArray::from_data(Int32Array::from(keys), values)
+}
+
+#[test]
+fn dictionary_same_dictionary() {
+    let values = std::sync::Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b"), None]))
+        as std::sync::Arc<dyn Array>;
+    let lhs = DictionaryArray::from_data(
+        Int32Array::from([Some(0), Some(1), None, Some(0), Some(2)]),
+        values.clone(),
+    );
+    let rhs = DictionaryArray::from_data(
+        Int32Array::from([Some(0), Some(0), Some(1), None, Some(2)]),
+        values,
+    );
+
+    let expected = BooleanArray::from([Some(true), Some(false), None, None, None]);
+    assert_eq!(comparison::dictionary::eq(&lhs, &rhs), expected);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from([Some(false), Some(true), None, None, None]);
+    assert_eq!(comparison::dictionary::neq(&lhs, &rhs), expected);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_duplicated_values() {
+    // different keys pointing to equal values are equal
+    let lhs = utf8_dictionary(
+        &[Some(0), Some(1), Some(2)],
+        &[Some("a"), Some("a"), Some("b")],
+    );
+    let rhs = utf8_dictionary(
+        &[Some(1), Some(0), Some(0)],
+        &[Some("a"), Some("a"), Some("b")],
+    );
+
+    let expected = BooleanArray::from_slice([true, true, false]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_different_dictionaries() {
+    let lhs = utf8_dictionary(
+        &[Some(0), Some(1), Some(2), None, Some(1)],
+        &[Some("a"), Some("b"), Some("c")],
+    );
+    let rhs = utf8_dictionary(
+        &[Some(2), Some(0), Some(1), Some(0), Some(3)],
+        &[Some("b"), Some("c"), Some("a"), None],
+    );
+
+    let expected = BooleanArray::from([Some(true), Some(true), Some(true), None, None]);
+    assert_eq!(comparison::eq(&lhs, &rhs), expected);
+
+    let rhs = utf8_dictionary(
+        &[Some(0), Some(0), Some(0), Some(0), Some(0)],
+        &[Some("b"), Some("c"), Some("a"), None],
+    );
+    let expected = BooleanArray::from([Some(true), Some(false), Some(true), None, Some(false)]);
+    assert_eq!(comparison::neq(&lhs, &rhs), expected);
+}
+
+#[test]
+fn dictionary_float_nan() {
+    // like the comparison of the values, `NaN` does not equal `NaN`
+    let values =
+        std::sync::Arc::new(Float64Array::from_slice([f64::NAN, 1.0])) as std::sync::Arc<dyn Array>;
+    let lhs = DictionaryArray::from_data(Int32Array::from_slice([0, 1, 0]), values.clone());
+    let rhs = DictionaryArray::from_data(Int32Array::from_slice([0, 1, 1]), values);
+
+    let expected = BooleanArray::from_slice([false, true, false]);
+    assert_eq!(comparison::dictionary::eq(&lhs, &rhs), expected);
+    assert_eq!(
+        comparison::primitive::eq(
+            &Float64Array::from_slice([f64::NAN, 1.0, f64::NAN]),
+            &Float64Array::from_slice([f64::NAN, 1.0, 1.0])
+        ),
+        expected
+    );
+
+    let expected = BooleanArray::from_slice([true, false, true]);
+    assert_eq!(comparison::dictionary::neq(&lhs, &rhs), expected);
+}
+
+#[test]
+fn can_eq_dictionary() {
+    use arrow2::compute::comparison::{can_eq, can_neq};
+    let data_type = Dictionary(IntegerType::Int8, Box::new(Utf8), false);
+    assert!(can_eq(&data_type));
+    assert!(can_neq(&data_type));
+}
+
+#[test]
+fn decimal256() {
+    use arrow2::compute::comparison::{can_lt, eq, lt, primitive};
+    use arrow2::types::i256;
+
+    let large = i256::from(i128::MAX) * i256::from(4);
+    let lhs = PrimitiveArray::<i256>::from([Some(large), Some(-large), None, Some(i256::from(1))])
+        .to(Decimal256(76, 0));
+    let rhs = PrimitiveArray::<i256>::from_slice([
+        i256::from(i128::MAX),
+        i256::from(i128::MIN),
+        i256::ZERO,
+        i256::from(1),
+    ])
+    .to(Decimal256(76, 0));
+
+    assert!(can_lt(lhs.data_type()));
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(false)]);
+    assert_eq!(lt(&lhs, &rhs), expected);
+    assert_eq!(primitive::lt(&lhs, &rhs), expected);
+
+    let expected = BooleanArray::from([Some(false), Some(false), None, Some(true)]);
+    assert_eq!(eq(&lhs, &rhs), expected);
+
+    // sliced
+    let result = primitive::gt(&lhs.slice(0, 2), &rhs.slice(0, 2));
+    assert_eq!(result, BooleanArray::from_slice([true, false]));
+}
+
+#[test]
+fn compare_scalar_int32_eq() {
+    use arrow2::compute::comparison::{compare_scalar, Operator};
+    use arrow2::scalar::PrimitiveScalar;
+
+    let array = Int32Array::from([Some(1), None, Some(2), Some(1)]);
+    let scalar = PrimitiveScalar::<i32>::from(Some(1));
+    let result = compare_scalar(&array, &scalar, Operator::Eq).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), None, Some(false), Some(true)])
+    );
+
+    // a null scalar results in nulls
+    let scalar = PrimitiveScalar::<i32>::from(None);
+    let result = compare_scalar(&array, &scalar, Operator::Eq).unwrap();
+    assert_eq!(result, BooleanArray::new_null(Boolean, 4));
+
+    let result = compare_scalar(&array, &arrow2::scalar::NullScalar::new(), Operator::Eq).unwrap();
+    assert_eq!(result, BooleanArray::new_null(Boolean, 4));
+
+    // different types error
+    let scalar = PrimitiveScalar::<i64>::from(Some(1));
+    assert!(compare_scalar(&array, &scalar, Operator::Eq).is_err());
+}
+
+#[test]
+fn compare_scalar_utf8_gt() {
+    use arrow2::compute::comparison::{compare_scalar, Operator};
+    use arrow2::scalar::Utf8Scalar;
+
+    let array = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c")]);
+    let scalar = Utf8Scalar::<i32>::from(Some("b"));
+    let result = compare_scalar(&array, &scalar, Operator::Gt).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(false), None, Some(false), Some(true)])
+    );
+
+    let result = compare_scalar(&array, &scalar, Operator::GtEq).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), None, Some(false), Some(true)])
+    );
+
+    let scalar = Utf8Scalar::<i64>::from(Some("b"));
+    assert!(compare_scalar(&array, &scalar, Operator::Gt).is_err());
+}
+
+#[test]
+fn compare_scalar_unsupported() {
+    use arrow2::compute::comparison::{compare_scalar, Operator};
+    use arrow2::scalar::PrimitiveScalar;
+    use arrow2::types::days_ms;
+
+    let array = PrimitiveArray::<days_ms>::from_slice([days_ms::new(1, 2)])
+        .to(Interval(IntervalUnit::DayTime));
+    let scalar =
+        PrimitiveScalar::<days_ms>::new(Interval(IntervalUnit::DayTime), Some(days_ms::new(1, 2)));
+    assert!(compare_scalar(&array, &scalar, Operator::Eq)
+        .unwrap()
+        .value(0));
+    assert!(compare_scalar(&array, &scalar, Operator::Lt).is_err());
+}
+
+#[test]
+fn between_inclusive() -> Result<()> {
+    use arrow2::compute::comparison::{between, can_between, primitive};
+    use arrow2::scalar::PrimitiveScalar;
+
+    // more than 8 values to cover both the chunks and the remainder
+    let array = Int32Array::from([
+        Some(0),
+        Some(1),
+        None,
+        Some(2),
+        Some(3),
+        Some(4),
+        Some(5),
+        Some(6),
+        Some(7),
+        Some(1),
+    ]);
+    let lo = PrimitiveScalar::<i32>::from(Some(1));
+    let hi = PrimitiveScalar::<i32>::from(Some(5));
+
+    assert!(can_between(array.data_type()));
+    let expected = BooleanArray::from([
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+        Some(true),
+        Some(true),
+        Some(true),
+        Some(false),
+        Some(false),
+        Some(true),
+    ]);
+    assert_eq!(between(&array, &lo, &hi, (true, true))?, expected);
+    assert_eq!(primitive::between(&array, 1, 5, (true, true)), expected);
+
+    // exclusive on both ends
+    let expected = BooleanArray::from([
+        Some(false),
+        Some(false),
+        None,
+        Some(true),
+        Some(true),
+        Some(true),
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(false),
+    ]);
+    assert_eq!(between(&array, &lo, &hi, (false, false))?, expected);
+
+    // a null bound results in nulls
+    let hi = PrimitiveScalar::<i32>::from(None);
+    assert_eq!(
+        between(&array, &lo, &hi, (true, true))?,
+        BooleanArray::new_null(Boolean, 10)
+    );
+    Ok(())
+}
+
+#[test]
+fn between_half_open() -> Result<()> {
+    use arrow2::compute::comparison::{between, utf8};
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    let array = Float64Array::from([Some(1.0), Some(1.5), None, Some(2.0)]);
+    let lo = PrimitiveScalar::<f64>::from(Some(1.0));
+    let hi = PrimitiveScalar::<f64>::from(Some(2.0));
+    assert_eq!(
+        between(&array, &lo, &hi, (true, false))?,
+        BooleanArray::from([Some(true), Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        between(&array, &lo, &hi, (false, true))?,
+        BooleanArray::from([Some(false), Some(true), None, Some(true)])
+    );
+
+    let array = Utf8Array::<i32>::from([Some("apple"), Some("banana"), None, Some("cherry")]);
+    let lo = Utf8Scalar::<i32>::from(Some("apple"));
+    let hi = Utf8Scalar::<i32>::from(Some("cherry"));
+    assert_eq!(
+        between(&array, &lo, &hi, (true, false))?,
+        BooleanArray::from([Some(true), Some(true), None, Some(false)])
+    );
+    assert_eq!(
+        utf8::between(&array, "apple", "cherry", (false, true)),
+        BooleanArray::from([Some(false), Some(true), None, Some(true)])
+    );
+    Ok(())
+}
+
+#[test]
+fn between_errors() {
+    use arrow2::compute::comparison::between;
+    use arrow2::scalar::{BooleanScalar, PrimitiveScalar};
+
+    // mismatched types
+    let array = Int32Array::from_slice([1, 2, 3]);
+    let lo = PrimitiveScalar::<i32>::from(Some(1));
+    let hi = PrimitiveScalar::<i64>::from(Some(3));
+    assert!(matches!(
+        between(&array, &lo, &hi, (true, true)),
+        Err(ArrowError::InvalidArgumentError(_))
+    ));
+
+    // unsupported type
+    let array = BooleanArray::from_slice([true, false]);
+    let lo = BooleanScalar::from(Some(false));
+    let hi = BooleanScalar::from(Some(true));
+    assert!(matches!(
+        between(&array, &lo, &hi, (true, true)),
+        Err(ArrowError::NotYetImplemented(_))
+    ));
+}
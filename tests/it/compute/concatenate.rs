@@ -1,5 +1,6 @@
 use arrow2::array::*;
 use arrow2::compute::concatenate::concatenate;
+use arrow2::datatypes::DataType;
 use arrow2::error::Result;
 
 #[test]
@@ -115,3 +116,196 @@ fn boolean_primitive_arrays() -> Result<()> {
 
     Ok(())
 }
+
+fn fixed_size_list(data: Vec<Option<Vec<Option<i32>>>>, size: usize) -> FixedSizeListArray {
+    let mut array = MutableFixedSizeListArray::new(MutablePrimitiveArray::<i32>::new(), size);
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn fixed_size_list_arrays() -> Result<()> {
+    let a = fixed_size_list(
+        vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![Some(4), None, Some(6)]),
+        ],
+        3,
+    );
+    let b = fixed_size_list(vec![None, Some(vec![Some(7), Some(8), Some(9)])], 3);
+
+    let result = concatenate(&[&a, &b])?;
+
+    let expected = fixed_size_list(
+        vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![Some(4), None, Some(6)]),
+            None,
+            Some(vec![Some(7), Some(8), Some(9)]),
+        ],
+        3,
+    );
+    assert_eq!(expected, result.as_ref());
+
+    // sliced
+    let result = concatenate(&[&a.slice(2, 1), &b.slice(1, 1), &a.slice(0, 2)])?;
+    let expected = fixed_size_list(
+        vec![
+            Some(vec![Some(4), None, Some(6)]),
+            Some(vec![Some(7), Some(8), Some(9)]),
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+        ],
+        3,
+    );
+    assert_eq!(expected, result.as_ref());
+    let result = result
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap();
+    assert_eq!(result.values().len(), 12);
+    Ok(())
+}
+
+#[test]
+fn fixed_size_list_different_sizes() {
+    let a = fixed_size_list(vec![Some(vec![Some(1), Some(2), Some(3)])], 3);
+    let b = fixed_size_list(vec![Some(vec![Some(1), Some(2)])], 2);
+
+    let error = concatenate(&[&a, &b]).unwrap_err().to_string();
+    assert!(error.contains("different sizes (3 and 2)"));
+}
+
+#[test]
+fn large_binary_arrays() -> Result<()> {
+    let a = BinaryArray::<i64>::from([Some(b"hello".as_ref()), None]);
+    let b = BinaryArray::<i64>::from([Some(b"world".as_ref()), Some(b"")]);
+
+    let result = concatenate(&[&a, &b.slice(1, 1), &b])?;
+
+    let expected = BinaryArray::<i64>::from([
+        Some(b"hello".as_ref()),
+        None,
+        Some(b""),
+        Some(b"world"),
+        Some(b""),
+    ]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn large_list_arrays() -> Result<()> {
+    let large_list = |data: Vec<Option<Vec<Option<i32>>>>| {
+        let mut array = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+        array.try_extend(data).unwrap();
+        ListArray::<i64>::from(array)
+    };
+    let a = large_list(vec![Some(vec![Some(1), None]), None]);
+    let b = large_list(vec![Some(vec![]), Some(vec![Some(2), Some(3)])]);
+
+    let result = concatenate(&[&a, &b.slice(1, 1), &b])?;
+
+    let expected = large_list(vec![
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![Some(2), Some(3)]),
+        Some(vec![]),
+        Some(vec![Some(2), Some(3)]),
+    ]);
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
+fn utf8_dictionary<K: DictionaryKey>(data: &[Option<&str>]) -> DictionaryArray<K> {
+    let mut array = MutableDictionaryArray::<K, MutableUtf8Array<i32>>::new();
+    array.try_extend(data.iter().copied()).unwrap();
+    array.into()
+}
+
+#[test]
+fn dictionaries() -> Result<()> {
+    // "b" overlaps, "a" and "c" are disjoint
+    let a = utf8_dictionary::<i32>(&[Some("a"), Some("b"), None, Some("a")]);
+    let b = utf8_dictionary::<i32>(&[Some("c"), Some("b"), Some("c"), None]);
+
+    let result = concatenate(&[&a, &b.slice(1, 3), &b])?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    // each value is in the dictionary once, in order of first appearance
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    assert_eq!(result.values().as_ref(), &values as &dyn Array);
+
+    let keys = Int32Array::from([
+        Some(0),
+        Some(1),
+        None,
+        Some(0),
+        Some(1),
+        Some(2),
+        None,
+        Some(2),
+        Some(1),
+        Some(2),
+        None,
+    ]);
+    assert_eq!(result.keys(), &keys);
+    Ok(())
+}
+
+#[test]
+fn dictionaries_null_values() -> Result<()> {
+    let values = Utf8Array::<i32>::from([Some("a"), None]);
+    let a = DictionaryArray::<i8>::from_data(
+        Int8Array::from_slice([1, 0]),
+        std::sync::Arc::new(values),
+    );
+    let values = Utf8Array::<i32>::from([None, Some("a"), Some("b")]);
+    let b = DictionaryArray::<i8>::from_data(
+        Int8Array::from_slice([2, 0, 1]),
+        std::sync::Arc::new(values),
+    );
+
+    let result = concatenate(&[&a, &b])?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("b")]);
+    assert_eq!(result.values().as_ref(), &values as &dyn Array);
+    assert_eq!(result.keys(), &Int8Array::from_slice([1, 0, 2, 1, 0]));
+    Ok(())
+}
+
+#[test]
+fn dictionaries_overflow() {
+    // 200 distinct values can't be represented by `i8` keys
+    let a = (0..100).map(|x| x.to_string()).collect::<Vec<_>>();
+    let b = (100..200).map(|x| x.to_string()).collect::<Vec<_>>();
+    let a = utf8_dictionary::<i8>(&a.iter().map(|x| Some(x.as_str())).collect::<Vec<_>>());
+    let b = utf8_dictionary::<i8>(&b.iter().map(|x| Some(x.as_str())).collect::<Vec<_>>());
+
+    assert!(concatenate(&[&a, &b]).is_err());
+
+    // but overlapping values are unified and thus fit
+    assert!(concatenate(&[&a, &a]).is_ok());
+}
+
+#[test]
+fn null_arrays() -> Result<()> {
+    let a = NullArray::new_null(DataType::Null, 2);
+    let b = NullArray::new_null(DataType::Null, 3);
+
+    let result = concatenate(&[&a, &b.slice(1, 2)])?;
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new_null(DataType::Null, 4) as &dyn Array
+    );
+    Ok(())
+}
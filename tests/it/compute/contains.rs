@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::compute::contains::contains;
+use arrow2::compute::contains::{can_is_in, contains, is_in};
+use arrow2::datatypes::DataType;
 
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
@@ -59,3 +60,79 @@ fn test_contains_binary() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn is_in_primitive() {
+    let values = Int32Array::from([Some(1), Some(2), None, Some(3)]);
+    let set = Int32Array::from_slice([3, 1, 1]);
+    let result = is_in(&values, &set).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+
+    // logical types are supported
+    let values = values.to(DataType::Date32);
+    let set = set.to(DataType::Date32);
+    assert_eq!(is_in(&values, &set).unwrap(), expected);
+}
+
+// SQL: `2 IN (1, NULL)` is NULL, `1 IN (1, NULL)` is true and `NULL IN (...)` is NULL
+#[test]
+fn is_in_set_with_nulls() {
+    let values = Int32Array::from([Some(1), Some(2), None]);
+    let set = Int32Array::from([Some(1), None]);
+    let result = is_in(&values, &set).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(true), None, None]));
+}
+
+#[test]
+fn is_in_empty_set() {
+    let values = Int32Array::from([Some(1), None]);
+    let set = Int32Array::from_slice([]);
+    let result = is_in(&values, &set).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), None]));
+}
+
+#[test]
+fn is_in_float() {
+    let values = Float64Array::from([Some(1.5), Some(f64::NAN), Some(2.0)]);
+    let set = Float64Array::from_slice([1.5, f64::NAN]);
+    let result = is_in(&values, &set).unwrap();
+    assert_eq!(result, BooleanArray::from_slice([true, true, false]));
+}
+
+#[test]
+fn is_in_utf8() {
+    let values = Utf8Array::<i64>::from([Some("a"), Some("b"), None, Some("c")]);
+    let set = Utf8Array::<i64>::from([Some("c"), Some("a"), None]);
+    let result = is_in(&values, &set.slice(0, 2)).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), Some(false), None, Some(true)])
+    );
+
+    let result = is_in(&values, &set).unwrap();
+    assert_eq!(
+        result,
+        BooleanArray::from([Some(true), None, None, Some(true)])
+    );
+}
+
+#[test]
+fn is_in_binary() {
+    let values = BinaryArray::<i32>::from([Some(b"a".as_ref()), Some(b"bb"), None]);
+    let set = BinaryArray::<i32>::from_slice([b"bb"]);
+    let result = is_in(&values, &set).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), Some(true), None]));
+}
+
+#[test]
+fn is_in_errors() {
+    let values = Int32Array::from_slice([1]);
+    let set = Int64Array::from_slice([1]);
+    assert!(is_in(&values, &set).is_err());
+
+    let values = BooleanArray::from_slice([true]);
+    assert!(!can_is_in(values.data_type()));
+    assert!(is_in(&values, &values).is_err());
+    assert!(can_is_in(&DataType::LargeUtf8));
+}
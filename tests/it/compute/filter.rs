@@ -1,6 +1,7 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
 use arrow2::compute::filter::*;
+use arrow2::datatypes::DataType;
 
 #[test]
 fn array_slice() {
@@ -128,6 +129,69 @@ fn masked_true_values() {
     assert_eq!(expected, c.as_ref());
 }
 
+fn list(data: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    array.into()
+}
+
+#[test]
+fn build_filter_many_columns() {
+    let mask = BooleanArray::from_slice([true, false, true, true, false]);
+    let filter = build_filter(&mask).unwrap();
+
+    let ints = Int32Array::from([Some(1), Some(2), None, Some(4), Some(5)]);
+    let expected = Int32Array::from([Some(1), None, Some(4)]);
+    assert_eq!(filter(&ints).as_ref(), &expected as &dyn Array);
+
+    let strings = Utf8Array::<i64>::from([Some("a"), Some("b"), Some("c"), None, Some("e")]);
+    let expected = Utf8Array::<i64>::from([Some("a"), Some("c"), None]);
+    assert_eq!(filter(&strings).as_ref(), &expected as &dyn Array);
+
+    let binaries = BinaryArray::<i32>::from([Some(b"a"), Some(b"b"), Some(b"c"), None, Some(b"e")]);
+    let expected = BinaryArray::<i32>::from([Some(b"a"), Some(b"c"), None]);
+    assert_eq!(filter(&binaries).as_ref(), &expected as &dyn Array);
+
+    let binaries = BinaryArray::<i64>::from([Some(b"a"), Some(b"b"), Some(b"c"), None, Some(b"e")]);
+    let expected = BinaryArray::<i64>::from([Some(b"a"), Some(b"c"), None]);
+    assert_eq!(filter(&binaries).as_ref(), &expected as &dyn Array);
+
+    let lists = list(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        None,
+        Some(vec![Some(3)]),
+    ]);
+    let expected = list(vec![Some(vec![Some(1), Some(2)]), Some(vec![]), None]);
+    assert_eq!(filter(&lists).as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn build_filter_with_null_mask() {
+    let mask = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    let filter = build_filter(&mask).unwrap();
+
+    let ints = Int32Array::from_slice([1, 2, 3, 4]);
+    assert_eq!(
+        filter(&ints).as_ref(),
+        &Int32Array::from_slice([1, 4]) as &dyn Array
+    );
+
+    let strings = Utf8Array::<i32>::from_slice(["a", "b", "c", "d"]);
+    let expected = Utf8Array::<i32>::from_slice(["a", "d"]);
+    assert_eq!(filter(&strings).as_ref(), &expected as &dyn Array);
+
+    let lists = list(vec![
+        Some(vec![Some(1)]),
+        Some(vec![Some(2)]),
+        Some(vec![Some(3)]),
+        Some(vec![Some(4)]),
+    ]);
+    let expected = list(vec![Some(vec![Some(1)]), Some(vec![Some(4)])]);
+    assert_eq!(filter(&lists).as_ref(), &expected as &dyn Array);
+}
+
 /*
 #[test]
 fn dictionary_array() {
@@ -193,3 +257,93 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn large_binary() {
+    let array =
+        BinaryArray::<i64>::from([Some(b"hello".as_ref()), None, Some(b"world"), Some(b"")]);
+    let mask = BooleanArray::from_slice([true, true, false, true]);
+
+    let result = filter(&array, &mask).unwrap();
+    let expected = BinaryArray::<i64>::from([Some(b"hello".as_ref()), None, Some(b"")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // sliced
+    let result = filter(&array.slice(1, 3), &mask.slice(1, 3)).unwrap();
+    let expected = BinaryArray::<i64>::from([None, Some(b"".as_ref())]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn partition_primitive() {
+    let array = Int32Array::from(&[Some(1), Some(2), None, Some(4), Some(5)]);
+    let mask = BooleanArray::from([Some(true), None, Some(false), Some(true), None]);
+
+    let (trues, falses) = partition(&array, &mask, NullPartition::True).unwrap();
+    let expected = Int32Array::from(&[Some(1), Some(2), Some(4), Some(5)]);
+    assert_eq!(trues.as_ref(), &expected as &dyn Array);
+    let expected = Int32Array::from(&[None]);
+    assert_eq!(falses.as_ref(), &expected as &dyn Array);
+
+    let (trues, falses) = partition(&array, &mask, NullPartition::False).unwrap();
+    let expected = Int32Array::from_slice([1, 4]);
+    assert_eq!(trues.as_ref(), &expected as &dyn Array);
+    let expected = Int32Array::from(&[Some(2), None, Some(5)]);
+    assert_eq!(falses.as_ref(), &expected as &dyn Array);
+
+    let (trues, falses) = partition(&array, &mask, NullPartition::Drop).unwrap();
+    let expected = Int32Array::from_slice([1, 4]);
+    assert_eq!(trues.as_ref(), &expected as &dyn Array);
+    let expected = Int32Array::from(&[None]);
+    assert_eq!(falses.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn partition_utf8() {
+    let array = Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e"]);
+    let mask = BooleanArray::from([None, Some(false), Some(true), None, Some(false)]);
+
+    let (trues, falses) = partition(&array, &mask, NullPartition::True).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["a", "c", "d"]);
+    assert_eq!(trues.as_ref(), &expected as &dyn Array);
+    let expected = Utf8Array::<i32>::from_slice(["b", "e"]);
+    assert_eq!(falses.as_ref(), &expected as &dyn Array);
+
+    let (trues, falses) = partition(&array, &mask, NullPartition::Drop).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["c"]);
+    assert_eq!(trues.as_ref(), &expected as &dyn Array);
+    let expected = Utf8Array::<i32>::from_slice(["b", "e"]);
+    assert_eq!(falses.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn partition_without_nulls() {
+    let array = Int32Array::from_slice([1, 2, 3, 4, 5, 6, 7, 8, 9]).slice(1, 7);
+    let mask = BooleanArray::from_slice([true, false, false, true, true, false, true, false, true])
+        .slice(2, 7);
+
+    let (trues, falses) = partition(&array, &mask, NullPartition::Drop).unwrap();
+    let expected = Int32Array::from_slice([3, 4, 6, 8]);
+    assert_eq!(trues.as_ref(), &expected as &dyn Array);
+    let expected = Int32Array::from_slice([2, 5, 7]);
+    assert_eq!(falses.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn partition_different_lengths() {
+    let array = Int32Array::from_slice([1, 2]);
+    let mask = BooleanArray::from_slice([true]);
+    assert!(partition(&array, &mask, NullPartition::Drop).is_err());
+}
+
+#[test]
+fn null_array() {
+    let array = NullArray::new_null(DataType::Null, 5);
+    let mask = BooleanArray::from([Some(true), Some(false), None, Some(true), Some(true)]);
+
+    let result = filter(&array, &mask).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new_null(DataType::Null, 3) as &dyn Array
+    );
+}
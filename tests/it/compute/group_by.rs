@@ -0,0 +1,71 @@
+use arrow2::array::*;
+use arrow2::compute::group_by::group_by;
+
+#[test]
+fn utf8_int32() {
+    let a = Utf8Array::<i32>::from([
+        Some("a"),
+        Some("b"),
+        Some("a"),
+        None,
+        Some("b"),
+        None,
+        Some("a"),
+    ]);
+    let b = Int32Array::from([
+        Some(1),
+        Some(1),
+        Some(2),
+        Some(1),
+        Some(1),
+        Some(1),
+        Some(1),
+    ]);
+
+    let (distinct, groups) = group_by(&[&a, &b]).unwrap();
+
+    let expected_a = Utf8Array::<i32>::from([Some("a"), Some("b"), Some("a"), None]);
+    let expected_b = Int32Array::from([Some(1), Some(1), Some(2), Some(1)]);
+    assert_eq!(distinct.len(), 2);
+    assert_eq!(expected_a, distinct[0].as_ref());
+    assert_eq!(expected_b, distinct[1].as_ref());
+    assert_eq!(groups, vec![vec![0, 6], vec![1, 4], vec![2], vec![3, 5]]);
+}
+
+#[test]
+fn nulls_are_a_group() {
+    let a = Int32Array::from([None, Some(0), None, Some(0)]);
+    let b = Int32Array::from([Some(0), None, None, None]);
+
+    let (distinct, groups) = group_by(&[&a, &b]).unwrap();
+
+    assert_eq!(
+        Int32Array::from([None, Some(0), None]),
+        distinct[0].as_ref()
+    );
+    assert_eq!(
+        Int32Array::from([Some(0), None, None]),
+        distinct[1].as_ref()
+    );
+    assert_eq!(groups, vec![vec![0], vec![1, 3], vec![2]]);
+}
+
+#[test]
+fn empty() {
+    let a = Int32Array::from_slice([]);
+    let (distinct, groups) = group_by(&[&a]).unwrap();
+    assert_eq!(distinct[0].len(), 0);
+    assert!(groups.is_empty());
+}
+
+#[test]
+fn errors() {
+    assert!(group_by(&[]).is_err());
+
+    let a = Int32Array::from_slice([1]);
+    let b = Int32Array::from_slice([1, 2]);
+    assert!(group_by(&[&a, &b]).is_err());
+
+    let a = Float32Array::from_slice([1.0]);
+    assert!(group_by(&[&a]).is_err());
+}
@@ -1,7 +1,9 @@
-use arrow2::array::new_null_array;
+use std::sync::Arc;
+
+use arrow2::array::*;
 use arrow2::compute::hash::*;
 use arrow2::datatypes::DataType::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{Field, TimeUnit};
 
 #[test]
 fn consistency() {
@@ -36,6 +38,17 @@ fn consistency() {
         Duration(TimeUnit::Millisecond),
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
+        List(Box::new(Field::new("item", Int32, true))),
+        LargeList(Box::new(Field::new("item", Utf8, true))),
+        List(Box::new(Field::new("item", Float32, true))),
+        Struct(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", Utf8, true),
+        ]),
+        Struct(vec![
+            Field::new("a", Int32, true),
+            Field::new("b", Float64, true),
+        ]),
     ];
 
     datatypes.into_iter().for_each(|d1| {
@@ -47,3 +60,65 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn list() {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1), None]),
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(1)]),
+        Some(vec![Some(1), None]),
+        Some(vec![]),
+        Some(vec![None]),
+        Some(vec![]),
+        None,
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let hashes = hash(&array).unwrap();
+    assert_eq!(hashes.validity(), array.validity());
+    // equal rows hash equally
+    assert_eq!(hashes.value(0), hashes.value(2));
+    assert_eq!(hashes.value(1), hashes.value(4));
+    assert_eq!(hashes.value(5), hashes.value(7));
+    // different rows hash differently
+    assert_ne!(hashes.value(0), hashes.value(1));
+    assert_ne!(hashes.value(0), hashes.value(3));
+    assert_ne!(hashes.value(1), hashes.value(3));
+    assert_ne!(hashes.value(5), hashes.value(6));
+
+    // hashes do not depend on the offset of the array
+    let sliced = hash(&array.slice(2, 3)).unwrap();
+    assert_eq!(sliced.values().as_slice(), &hashes.values()[2..5]);
+}
+
+#[test]
+fn struct_() {
+    let fields = vec![Field::new("a", Int32, true), Field::new("b", Utf8, true)];
+    let a = Int32Array::from(&[Some(1), Some(1), Some(1), None, None, Some(2)]);
+    let b = Utf8Array::<i32>::from([Some("x"), Some("y"), Some("x"), None, None, Some("x")]);
+    let array = StructArray::from_data(
+        Struct(fields),
+        vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b)],
+        None,
+    );
+
+    let hashes = hash(&array).unwrap();
+    assert_eq!(hashes.value(0), hashes.value(2));
+    assert_eq!(hashes.value(3), hashes.value(4));
+    assert_ne!(hashes.value(0), hashes.value(1));
+    assert_ne!(hashes.value(0), hashes.value(3));
+    assert_ne!(hashes.value(0), hashes.value(5));
+}
+
+#[test]
+fn unsupported_nested() {
+    let data = vec![Some(vec![Some(1.0f32)])];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<f32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+    assert!(hash(&array).is_err());
+}
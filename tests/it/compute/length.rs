@@ -86,3 +86,11 @@ fn consistency() {
         }
     });
 }
+
+#[test]
+fn binary() {
+    let array = BinaryArray::<i32>::from([Some(b"hello".as_ref()), None, Some(&[0, 1])]);
+    let result = length(&array).unwrap();
+    let expected = Int32Array::from([Some(5), None, Some(2)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
@@ -10,6 +10,8 @@ mod boolean;
 mod boolean_kleene;
 #[cfg(feature = "compute_cast")]
 mod cast;
+#[cfg(feature = "compute_coalesce")]
+mod coalesce;
 #[cfg(feature = "compute_comparison")]
 mod comparison;
 #[cfg(feature = "compute_concatenate")]
@@ -18,6 +20,8 @@ mod concatenate;
 mod contains;
 #[cfg(feature = "compute_filter")]
 mod filter;
+#[cfg(feature = "compute_group_by")]
+mod group_by;
 #[cfg(feature = "compute_hash")]
 mod hash;
 #[cfg(feature = "compute_if_then_else")]
@@ -32,6 +36,8 @@ mod limit;
 mod lower;
 #[cfg(feature = "compute_merge_sort")]
 mod merge_sort;
+#[cfg(feature = "compute_nullif")]
+mod nullif;
 #[cfg(feature = "compute_partition")]
 mod partition;
 #[cfg(feature = "compute_regex_match")]
@@ -44,7 +50,11 @@ mod substring;
 mod take;
 #[cfg(feature = "compute_temporal")]
 mod temporal;
+#[cfg(feature = "compute_unique")]
+mod unique;
 #[cfg(feature = "compute_upper")]
 mod upper;
+#[cfg(feature = "compute_utf8")]
+mod utf8;
 #[cfg(feature = "compute_window")]
 mod window;
@@ -0,0 +1,67 @@
+use arrow2::array::*;
+use arrow2::compute::nullif::*;
+use arrow2::datatypes::DataType;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+#[test]
+fn primitive() {
+    let lhs = Int32Array::from(&[None, Some(1), Some(2), Some(3), None]);
+    let rhs = Int32Array::from(&[Some(1), Some(1), None, Some(4), None]);
+
+    let result = nullif(&lhs, &rhs);
+
+    let expected = Int32Array::from(&[None, None, Some(2), Some(3), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn primitive_scalar() {
+    let lhs = Int32Array::from(&[None, Some(1), Some(2), Some(1)]);
+    let rhs = PrimitiveScalar::<i32>::from(Some(1));
+
+    let result = nullif_scalar(&lhs, &rhs);
+
+    let expected = Int32Array::from(&[None, None, Some(2), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn utf8() {
+    let lhs = Utf8Array::<i32>::from([None, Some("a"), Some("b"), Some("c"), Some("")]);
+    let rhs = Utf8Array::<i32>::from([Some("a"), Some("a"), None, Some("cc"), Some("")]);
+
+    let result = nullif(&lhs, &rhs);
+
+    let expected = Utf8Array::<i32>::from([None, None, Some("b"), Some("c"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn large_utf8_scalar() {
+    let lhs = Utf8Array::<i64>::from([None, Some("a"), Some("b"), Some("a")]);
+    let rhs = Utf8Scalar::<i64>::new(Some("a"));
+
+    let result = nullif_scalar(&lhs, &rhs);
+
+    let expected = Utf8Array::<i64>::from([None, None, Some("b"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn binary() {
+    let lhs = BinaryArray::<i32>::from([Some(b"a".as_ref()), None, Some(b"b")]);
+    let rhs = BinaryArray::<i32>::from([Some(b"a".as_ref()), Some(b"a"), Some(b"a")]);
+
+    let result = binary_nullif(&lhs, &rhs);
+
+    let expected = BinaryArray::<i32>::from([None, None, Some(b"b".as_ref())]);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn can_nullif_types() {
+    assert!(can_nullif(&DataType::Int32, &DataType::Int32));
+    assert!(can_nullif(&DataType::LargeUtf8, &DataType::LargeUtf8));
+    assert!(!can_nullif(&DataType::Utf8, &DataType::LargeUtf8));
+    assert!(!can_nullif(&DataType::Boolean, &DataType::Boolean));
+}
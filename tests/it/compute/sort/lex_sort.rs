@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::compute::sort::{lexsort, lexsort_to_indices, SortColumn, SortOptions};
 
 fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
     let sorted = lexsort::<i32>(&input, None).unwrap();
@@ -73,6 +73,83 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 
+#[test]
+fn utf8_asc_int32_desc() {
+    let c1 = Utf8Array::<i32>::from([Some("b"), Some("a"), None, Some("b"), Some("a"), Some("b")]);
+    let c2 = Int32Array::from([Some(1), Some(2), Some(5), None, Some(3), Some(4)]);
+    let input = vec![
+        SortColumn {
+            values: &c1,
+            options: Some(SortOptions {
+                descending: false,
+                nulls_first: false,
+            }),
+        },
+        SortColumn {
+            values: &c2,
+            options: Some(SortOptions {
+                descending: true,
+                nulls_first: true,
+            }),
+        },
+    ];
+    let expected = vec![
+        Box::new(Utf8Array::<i32>::from([
+            Some("a"),
+            Some("a"),
+            Some("b"),
+            Some("b"),
+            Some("b"),
+            None,
+        ])) as Box<dyn Array>,
+        Box::new(Int32Array::from([
+            Some(3),
+            Some(2),
+            None,
+            Some(4),
+            Some(1),
+            Some(5),
+        ])) as Box<dyn Array>,
+    ];
+    test_lex_sort_arrays(input, expected);
+}
+
+#[test]
+fn stable() {
+    let c1 = Int32Array::from_slice([1, 0, 1, 0, 1, 0]);
+    let c2 = BooleanArray::from([Some(true), None, Some(true), None, Some(true), None]);
+    let input = vec![
+        SortColumn {
+            values: &c1,
+            options: None,
+        },
+        SortColumn {
+            values: &c2,
+            options: None,
+        },
+    ];
+
+    let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 3, 5, 0, 2, 4]));
+
+    let indices = lexsort_to_indices::<i32>(&input, Some(4)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 3, 5, 0]));
+}
+
+#[test]
+fn stable_single_column() {
+    let c1 = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("b"), None, Some("a")]);
+    let input = vec![SortColumn {
+        values: &c1,
+        options: None,
+    }];
+
+    let indices = lexsort_to_indices::<i32>(&input, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 4, 2, 5, 0, 3]));
+    let indices = lexsort_to_indices::<i32>(&input, Some(3)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 4, 2]));
+}
+
 /*
     // test sort with nulls first
     let input = vec![
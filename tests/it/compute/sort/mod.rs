@@ -1,4 +1,5 @@
 mod lex_sort;
+mod rank;
 
 use arrow2::array::*;
 use arrow2::compute::sort::*;
@@ -603,3 +604,51 @@ fn consistency() {
         }
     });
 }
+
+// the indices that stably sort `data`
+fn stable_sort_indices<T: Ord + Copy>(data: &[Option<T>], options: &SortOptions) -> Vec<i32> {
+    let mut indices = (0..data.len() as i32).collect::<Vec<_>>();
+    indices.sort_by(
+        |lhs, rhs| match (data[*lhs as usize], data[*rhs as usize]) {
+            (None, None) => std::cmp::Ordering::Equal,
+            (None, Some(_)) if options.nulls_first => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (Some(_), None) if options.nulls_first => std::cmp::Ordering::Greater,
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (Some(l), Some(r)) if options.descending => r.cmp(&l),
+            (Some(l), Some(r)) => l.cmp(&r),
+        },
+    );
+    indices
+}
+
+#[test]
+fn limit_is_prefix_of_stable_sort() {
+    let data = (0..100)
+        .map(|x| (x % 7 != 3).then(|| (x * 37) % 11))
+        .collect::<Vec<_>>();
+    let ints = Int32Array::from(&data);
+    let strings = Utf8Array::<i32>::from(
+        data.iter()
+            .map(|x| x.map(|x| format!("{:02}", x)))
+            .collect::<Vec<_>>(),
+    );
+
+    for (descending, nulls_first) in [(false, false), (false, true), (true, false), (true, true)] {
+        let options = SortOptions {
+            descending,
+            nulls_first,
+        };
+        let expected = stable_sort_indices(&data, &options);
+        for limit in [0, 1, 5, 14, 15, 50, 99, 100, 130] {
+            let expected = Int32Array::from_slice(&expected[..limit.min(data.len())]);
+
+            let result = sort_to_indices::<i32>(&ints, &options, Some(limit)).unwrap();
+            assert_eq!(result, expected, "{:?} {}", options, limit);
+            let result = sort_to_indices::<i32>(&strings, &options, Some(limit)).unwrap();
+            assert_eq!(result, expected, "{:?} {}", options, limit);
+        }
+        let result = sort_to_indices::<i32>(&ints, &options, None).unwrap();
+        assert_eq!(result, Int32Array::from_slice(&expected));
+    }
+}
@@ -0,0 +1,85 @@
+use arrow2::array::*;
+use arrow2::compute::sort::{rank, RankMethod, RankOptions, SortOptions};
+
+fn ignore_nulls() -> RankOptions {
+    RankOptions {
+        ignore_nulls: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn methods() {
+    let array = Int32Array::from_slice([3, 1, 2, 2, 5, 2]);
+    let options = RankOptions::default();
+
+    let result = rank(&array, RankMethod::Min, &options).unwrap();
+    assert_eq!(UInt32Array::from_slice([5, 1, 2, 2, 6, 2]), result.as_ref());
+
+    let result = rank(&array, RankMethod::Max, &options).unwrap();
+    assert_eq!(UInt32Array::from_slice([5, 1, 4, 4, 6, 4]), result.as_ref());
+
+    let result = rank(&array, RankMethod::Dense, &options).unwrap();
+    assert_eq!(UInt32Array::from_slice([3, 1, 2, 2, 4, 2]), result.as_ref());
+
+    let result = rank(&array, RankMethod::First, &options).unwrap();
+    assert_eq!(UInt32Array::from_slice([5, 1, 2, 3, 6, 4]), result.as_ref());
+
+    let result = rank(&array, RankMethod::Average, &options).unwrap();
+    let expected = Float64Array::from_slice([5.0, 1.0, 3.0, 3.0, 6.0, 3.0]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn dense_with_nulls() {
+    let array = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("b"), None]);
+
+    let result = rank(&array, RankMethod::Dense, &ignore_nulls()).unwrap();
+    let expected = UInt32Array::from([Some(2), None, Some(1), Some(2), None]);
+    assert_eq!(expected, result.as_ref());
+
+    // nulls are ranked as a group, first by default
+    let result = rank(&array, RankMethod::Dense, &RankOptions::default()).unwrap();
+    assert_eq!(UInt32Array::from_slice([3, 1, 2, 3, 1]), result.as_ref());
+}
+
+#[test]
+fn min_with_nulls() {
+    let array = Int32Array::from([Some(2), None, Some(1), Some(2), None, Some(3)]);
+
+    let result = rank(&array, RankMethod::Min, &ignore_nulls()).unwrap();
+    let expected = UInt32Array::from([Some(2), None, Some(1), Some(2), None, Some(4)]);
+    assert_eq!(expected, result.as_ref());
+
+    let options = RankOptions {
+        sort: SortOptions {
+            descending: false,
+            nulls_first: false,
+        },
+        ignore_nulls: false,
+    };
+    let result = rank(&array, RankMethod::Min, &options).unwrap();
+    assert_eq!(UInt32Array::from_slice([2, 5, 1, 2, 5, 4]), result.as_ref());
+}
+
+#[test]
+fn descending() {
+    let array = Int32Array::from_slice([1, 3, 3, 2]);
+    let options = RankOptions {
+        sort: SortOptions {
+            descending: true,
+            nulls_first: true,
+        },
+        ignore_nulls: false,
+    };
+
+    let result = rank(&array, RankMethod::Min, &options).unwrap();
+    assert_eq!(UInt32Array::from_slice([4, 1, 1, 3]), result.as_ref());
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from_slice([]);
+    let result = rank(&array, RankMethod::Average, &RankOptions::default()).unwrap();
+    assert_eq!(result.len(), 0);
+}
@@ -296,6 +296,108 @@ fn without_nulls_large_binary() -> Result<()> {
     without_null_binarys::<i64>()
 }
 
+fn negative_start_utf8<O: Offset>() -> Result<()> {
+    let cases = vec![
+        // start counts characters from the end, not bytes
+        (
+            vec!["héllo", "", "日本語"],
+            -2,
+            None,
+            vec!["lo", "", "本語"],
+        ),
+        (
+            vec!["héllo", "", "日本語"],
+            -4,
+            Some(2),
+            vec!["él", "", "日本"],
+        ),
+        (vec!["😇🔥🥺", "", "π1π"], -1, None, vec!["🥺", "", "π"]),
+        // start before the beginning clamps to the beginning
+        (
+            vec!["héllo", "", "日本語"],
+            -10,
+            Some(2),
+            vec!["hé", "", "日本"],
+        ),
+        (
+            vec!["héllo", "", "日本語"],
+            i64::MIN,
+            None,
+            vec!["héllo", "", "日本語"],
+        ),
+        // start beyond the end is empty
+        (vec!["héllo", "", "日本語"], 5, None, vec!["", "", ""]),
+        (
+            vec!["héllo", "", "日本語"],
+            i64::MAX,
+            Some(1),
+            vec!["", "", ""],
+        ),
+        // length beyond the end clamps to the end
+        (
+            vec!["héllo", "", "日本語"],
+            -3,
+            Some(u64::MAX),
+            vec!["llo", "", "日本語"],
+        ),
+    ];
+
+    cases
+        .into_iter()
+        .try_for_each::<_, Result<()>>(|(array, start, length, expected)| {
+            let array = Utf8Array::<O>::from_slice(&array);
+            let result = substring(&array, start, &length)?;
+            let result = result.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+            let expected = Utf8Array::<O>::from_slice(&expected);
+            assert_eq!(&expected, result);
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+#[test]
+fn negative_start_string() -> Result<()> {
+    negative_start_utf8::<i32>()
+}
+
+#[test]
+fn negative_start_large_string() -> Result<()> {
+    negative_start_utf8::<i64>()
+}
+
+fn out_of_range_binary<O: Offset>() -> Result<()> {
+    let cases = vec![
+        (i64::MIN, None, vec!["hello", "", "word"]),
+        (i64::MAX, None, vec!["", "", ""]),
+        (-2, Some(u64::MAX), vec!["lo", "", "rd"]),
+        (1, Some(u64::MAX), vec!["ello", "", "ord"]),
+    ];
+
+    cases
+        .into_iter()
+        .try_for_each::<_, Result<()>>(|(start, length, expected)| {
+            let array = BinaryArray::<O>::from_slice(["hello", "", "word"]);
+            let result = substring(&array, start, &length)?;
+            let result = result.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+            let expected = BinaryArray::<O>::from_slice(&expected);
+            assert_eq!(&expected, result);
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+#[test]
+fn out_of_range_binary_i32() -> Result<()> {
+    out_of_range_binary::<i32>()
+}
+
+#[test]
+fn out_of_range_large_binary() -> Result<()> {
+    out_of_range_binary::<i64>()
+}
+
 #[test]
 fn consistency() {
     use arrow2::datatypes::DataType::*;
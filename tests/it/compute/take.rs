@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use arrow2::compute::take::{can_take, take};
+use arrow2::compute::take::{can_take, take, take_sorted, take_with_bounds, OutOfBounds};
 use arrow2::datatypes::{DataType, Field, IntervalUnit};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
@@ -238,6 +238,54 @@ fn list_with_none() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn large_list_with_none() {
+    let data = vec![
+        Some(vec![Some(0i32), Some(1)]),
+        None,
+        Some(vec![Some(2i32), Some(3), None]),
+        Some(vec![]),
+    ];
+    let mut array = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i64> = array.into();
+
+    let indices = PrimitiveArray::from([Some(2i32), None, Some(1), Some(0)]);
+    let result = take(&array, &indices).unwrap();
+
+    let data_expected = vec![
+        Some(vec![Some(2i32), Some(3), None]),
+        None,
+        None,
+        Some(vec![Some(0i32), Some(1)]),
+    ];
+    let mut expected = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    expected.try_extend(data_expected).unwrap();
+    let expected: ListArray<i64> = expected.into();
+
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(result.data_type(), array.data_type());
+
+    // sliced
+    let result = take(&array.slice(2, 2), &PrimitiveArray::from_slice([1i32, 0])).unwrap();
+    let mut expected = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+    expected
+        .try_extend(vec![Some(vec![]), Some(vec![Some(2i32), Some(3), None])])
+        .unwrap();
+    let expected: ListArray<i64> = expected.into();
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn large_binary() {
+    let array = BinaryArray::<i64>::from([Some(b"hello".as_ref()), None, Some(b"world")]);
+    let indices = PrimitiveArray::from([Some(2i32), None, Some(1), Some(0)]);
+    let result = take(&array, &indices).unwrap();
+
+    let expected = BinaryArray::<i64>::from([Some(b"world".as_ref()), None, None, Some(b"hello")]);
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn list_both_validity() {
     let values = vec![
@@ -313,3 +361,181 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn out_of_bounds_error() {
+    let values = Int32Array::from_slice([1, 2, 3]);
+
+    let indices = Int32Array::from([Some(2), None, Some(0)]);
+    let result = take_with_bounds(&values, &indices, OutOfBounds::Error).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from([Some(3), None, Some(1)]) as &dyn Array
+    );
+
+    let indices = Int32Array::from_slice([0, 3]);
+    assert!(take_with_bounds(&values, &indices, OutOfBounds::Error).is_err());
+
+    let indices = Int32Array::from_slice([0, -1]);
+    assert!(take_with_bounds(&values, &indices, OutOfBounds::Error).is_err());
+}
+
+#[test]
+fn out_of_bounds_null() {
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+    let indices = Int64Array::from([Some(2), Some(5), None, Some(-3), Some(1), Some(0)]);
+
+    let result = take_with_bounds(&values, &indices, OutOfBounds::Null).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("c"), None, None, None, None, Some("a")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let empty = Utf8Array::<i32>::new_empty(DataType::Utf8);
+    let result = take_with_bounds(&empty, &indices, OutOfBounds::Null).unwrap();
+    assert_eq!(result.len(), 6);
+    assert_eq!(result.null_count(), 6);
+}
+
+#[test]
+fn out_of_bounds_clamp() {
+    let values = BooleanArray::from_slice([true, false]);
+
+    let indices = UInt32Array::from([Some(0), Some(7), None, Some(2)]);
+    let result = take_with_bounds(&values, &indices, OutOfBounds::Clamp).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(false)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let indices = Int32Array::from_slice([-1]);
+    assert!(take_with_bounds(&values, &indices, OutOfBounds::Clamp).is_err());
+
+    let empty = BooleanArray::new_empty(DataType::Boolean);
+    let indices = UInt32Array::from_slice([0]);
+    assert!(take_with_bounds(&empty, &indices, OutOfBounds::Clamp).is_err());
+}
+
+// gathers one by one, without the fast path of sorted indices
+fn naive_take<T: NativeType>(
+    values: &PrimitiveArray<T>,
+    indices: &Int32Array,
+) -> PrimitiveArray<T> {
+    indices
+        .iter()
+        .map(|index| {
+            let index = *index? as usize;
+            values.is_valid(index).then(|| values.value(index))
+        })
+        .collect::<PrimitiveArray<T>>()
+        .to(values.data_type().clone())
+}
+
+#[test]
+fn sorted_primitive() {
+    let values = Int64Array::from(&[
+        Some(0),
+        None,
+        Some(2),
+        Some(3),
+        None,
+        Some(5),
+        Some(6),
+        Some(7),
+        Some(8),
+        Some(9),
+        Some(10),
+    ])
+    .slice(1, 10);
+
+    let cases = [
+        Int32Array::from_slice([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]),
+        Int32Array::from_slice([1, 2, 3, 6, 7, 9]),
+        Int32Array::from_slice([0, 0, 1, 1, 9]),
+        Int32Array::from([None, Some(2), Some(3), None, None, Some(4), Some(8), None]),
+        Int32Array::from_slice([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).slice(3, 5),
+        // unsorted
+        Int32Array::from_slice([3, 4, 5, 0, 1, 2]),
+        Int32Array::from([Some(9), None, Some(0)]),
+    ];
+    for indices in cases {
+        let expected = naive_take(&values, &indices);
+        let result = take(&values, &indices).unwrap();
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+        let result = take_sorted(&values, &indices).unwrap();
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+
+    let values = Float32Array::from_slice([1.0, 2.0, 3.0, 4.0]);
+    let indices = Int32Array::from_slice([1, 2, 3]);
+    let result = take(&values, &indices).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Float32Array::from_slice([2.0, 3.0, 4.0]) as &dyn Array
+    );
+    assert!(result.validity().is_none());
+}
+
+#[test]
+fn sorted_boolean() {
+    let values = BooleanArray::from(vec![
+        Some(true),
+        Some(false),
+        None,
+        Some(true),
+        Some(true),
+        Some(false),
+        None,
+        Some(false),
+        Some(true),
+        Some(true),
+        Some(false),
+    ])
+    .slice(1, 10);
+    let indices = Int32Array::from([Some(0), Some(1), Some(2), None, Some(3), Some(4), Some(8)]);
+    let expected = BooleanArray::from(vec![
+        Some(false),
+        None,
+        Some(true),
+        None,
+        Some(true),
+        Some(false),
+        Some(true),
+    ]);
+
+    let result = take(&values, &indices).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    let result = take_sorted(&values, &indices).unwrap();
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let values =
+        BooleanArray::from_slice([true, false, true, true, false, false, true, false, true]);
+    let indices = Int32Array::from_slice([0, 1, 2, 3, 4, 5, 6, 7, 8]).slice(1, 8);
+    let result = take_sorted(&values, &indices).unwrap();
+    assert_eq!(result.as_ref(), &values.slice(1, 8) as &dyn Array);
+}
+
+#[test]
+fn sorted_other_types() {
+    let values = Utf8Array::<i32>::from_slice(["a", "b", "c"]);
+    let indices = Int32Array::from([Some(0), None, Some(2)]);
+    let result = take_sorted(&values, &indices).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+#[should_panic]
+fn sorted_out_of_bounds() {
+    let values = Int32Array::from_slice([1, 2, 3]);
+    let indices = Int32Array::from_slice([1, 2, 3]);
+    let _ = take_sorted(&values, &indices);
+}
+
+#[test]
+fn null_array() {
+    let values = NullArray::new_null(DataType::Null, 5);
+    let indices = Int32Array::from(&[Some(4), None, Some(0)]);
+
+    let result = take(&values, &indices).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &NullArray::new_null(DataType::Null, 3) as &dyn Array
+    );
+}
@@ -323,6 +323,42 @@ fn consistency_iso_week() {
     consistency_check(can_iso_week, iso_week);
 }
 
+#[test]
+fn consistency_iso_year() {
+    consistency_check(can_iso_year, iso_year);
+}
+
+#[test]
+fn iso_week_year_boundaries() {
+    // 2021-01-01, 2020-12-31, 2021-01-04 and 2019-12-30
+    let array = Int32Array::from(&[Some(18628), Some(18627), None, Some(18631), Some(18260)])
+        .to(DataType::Date32);
+
+    let expected = UInt32Array::from(&[Some(53), Some(53), None, Some(1), Some(1)]);
+    assert_eq!(iso_week(&array).unwrap(), expected);
+
+    let expected = Int32Array::from(&[Some(2020), Some(2020), None, Some(2021), Some(2020)]);
+    assert_eq!(iso_year(&array).unwrap(), expected);
+}
+
+#[test]
+fn iso_year_timezone() {
+    // 2021-01-03T23:30:00Z, a Sunday in UTC and a Monday in +01:00
+    let array = Int64Array::from_slice([1609716600]);
+
+    let naive = array
+        .clone()
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(iso_year(&naive).unwrap(), Int32Array::from_slice([2020]));
+
+    let tz = array.to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string()),
+    ));
+    assert_eq!(iso_year(&tz).unwrap(), Int32Array::from_slice([2021]));
+    assert_eq!(iso_week(&tz).unwrap(), UInt32Array::from_slice([1]));
+}
+
 fn consistency_check<O: arrow2::types::NativeType>(
     can_extract: fn(&DataType) -> bool,
     extract: fn(&dyn Array) -> arrow2::error::Result<PrimitiveArray<O>>,
@@ -372,3 +408,115 @@ fn consistency_check<O: arrow2::types::NativeType>(
         }
     });
 }
+
+fn trunc(values: &[Option<i64>], data_type: &DataType, unit: TruncUnit) -> Vec<Option<i64>> {
+    let array = Int64Array::from(values).to(data_type.clone());
+    let result = date_trunc(&array, unit).unwrap();
+    assert_eq!(result.data_type(), data_type);
+    result.iter().map(|x| x.copied()).collect()
+}
+
+#[test]
+fn date_trunc_naive() {
+    // Mon May 24 2021 17:25:30 and Wed Dec 31 1969 23:59:30
+    let values = [Some(1621877130000), None, Some(-30000)];
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+
+    let cases = [
+        (TruncUnit::Second, [1621877130000, -30000]),
+        (TruncUnit::Minute, [1621877100000, -60000]),
+        (TruncUnit::Hour, [1621875600000, -3600000]),
+        (TruncUnit::Day, [1621814400000, -86400000]),
+        (TruncUnit::Week, [1621814400000, -259200000]),
+        (TruncUnit::Month, [1619827200000, -2678400000]),
+        (TruncUnit::Quarter, [1617235200000, -7948800000]),
+        (TruncUnit::Year, [1609459200000, -31536000000]),
+    ];
+    for (unit, [first, last]) in cases {
+        assert_eq!(
+            trunc(&values, &data_type, unit),
+            vec![Some(first), None, Some(last)],
+            "{:?}",
+            unit
+        );
+    }
+}
+
+#[test]
+fn date_trunc_fixed_offset() {
+    // 2021-05-24T19:25:30+02:00
+    let values = [Some(1621877130)];
+    let data_type = DataType::Timestamp(TimeUnit::Second, Some("+02:00".to_string()));
+
+    // 2021-05-24T19:00:00+02:00
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Hour),
+        vec![Some(1621875600)]
+    );
+    // 2021-05-24T00:00:00+02:00
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Day),
+        vec![Some(1621807200)]
+    );
+    // 2021-05-01T00:00:00+02:00
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Month),
+        vec![Some(1619820000)]
+    );
+}
+
+#[test]
+fn date_trunc_half_hour_offset() {
+    // 2021-05-24T19:25:30.500+05:30
+    let values = [Some(1621864530500)];
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+05:30".to_string()));
+
+    // 2021-05-24T19:00:00+05:30
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Hour),
+        vec![Some(1621863000000)]
+    );
+    // 2021-05-24T19:25:00+05:30
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Minute),
+        vec![Some(1621864500000)]
+    );
+    // 2021-05-24T19:25:30+05:30
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Second),
+        vec![Some(1621864530000)]
+    );
+    // 2021-05-24T00:00:00+05:30
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Day),
+        vec![Some(1621794600000)]
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn date_trunc_dst() {
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("America/New_York".to_string()));
+    let ns = |x: i64| Some(x * 1_000_000_000);
+
+    // 2021-11-07T01:30:45-04:00 and 2021-11-07T01:30:45-05:00 (the repeated hour)
+    let values = [ns(1636263045), ns(1636266645)];
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Hour),
+        vec![ns(1636261200), ns(1636264800)]
+    );
+
+    // 2021-03-20T12:00:00-04:00 -> 2021-03-01T00:00:00-05:00
+    // 2021-11-20T07:00:00-05:00 -> 2021-11-01T00:00:00-04:00
+    let values = [ns(1616256000), ns(1637409600)];
+    assert_eq!(
+        trunc(&values, &data_type, TruncUnit::Month),
+        vec![ns(1614574800), ns(1635739200)]
+    );
+}
+
+#[test]
+fn date_trunc_unsupported() {
+    let array = Int64Array::from_slice([1]).to(DataType::Date64);
+    assert!(date_trunc(&array, TruncUnit::Day).is_err());
+}
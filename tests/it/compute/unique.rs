@@ -0,0 +1,59 @@
+use arrow2::array::*;
+use arrow2::compute::unique::{can_unique, unique};
+use arrow2::datatypes::DataType;
+
+#[test]
+fn primitive() {
+    let array = Int32Array::from([Some(3), Some(1), None, Some(3), Some(2), None, Some(1)]);
+    let result = unique(&array).unwrap();
+
+    let expected = Int32Array::from([Some(3), Some(1), None, Some(2)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn primitive_no_nulls() {
+    let array = UInt64Array::from_slice([5, 5, 4, 5, 3, 4]);
+    let result = unique(&array).unwrap();
+
+    let expected = UInt64Array::from_slice([5, 4, 3]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(result.null_count(), 0);
+}
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i64>::from([None, Some("b"), Some("a"), None, Some("b"), Some("")]);
+    let result = unique(&array).unwrap();
+
+    let expected = Utf8Array::<i64>::from([None, Some("b"), Some("a"), Some("")]);
+    assert_eq!(expected, result.as_ref());
+
+    // sliced
+    let result = unique(&array.slice(1, 4)).unwrap();
+    let expected = Utf8Array::<i64>::from([Some("b"), Some("a"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn binary() {
+    let array = BinaryArray::<i32>::from([Some(b"a"), Some(b"b"), Some(b"a"), None]);
+    let result = unique(&array).unwrap();
+
+    let expected = BinaryArray::<i32>::from([Some(b"a"), Some(b"b"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn empty() {
+    let array = Int32Array::from_slice([]);
+    let result = unique(&array).unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn unsupported() {
+    assert!(!can_unique(&DataType::Float32));
+    let array = Float32Array::from_slice([1.0, 1.0]);
+    assert!(unique(&array).is_err());
+}
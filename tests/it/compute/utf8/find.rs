@@ -0,0 +1,40 @@
+use arrow2::array::*;
+use arrow2::compute::utf8::{find, find_array};
+
+#[test]
+fn basics() {
+    let array = Utf8Array::<i32>::from([Some("abcabc"), Some("xyz"), None, Some("")]);
+    let expected = Int32Array::from([Some(2), Some(0), None, Some(0)]);
+    assert_eq!(find(&array, "bc"), expected);
+
+    // an empty needle is at the start
+    let expected = Int32Array::from([Some(1), Some(1), None, Some(1)]);
+    assert_eq!(find(&array, ""), expected);
+}
+
+#[test]
+fn multibyte() {
+    // positions are in characters, not bytes
+    let array = Utf8Array::<i64>::from_slice(["日本語テキスト", "ümlaut", "naïve"]);
+
+    let expected = Int64Array::from_slice([4, 0, 0]);
+    assert_eq!(find(&array, "テ"), expected);
+
+    let expected = Int64Array::from_slice([0, 3, 0]);
+    assert_eq!(find(&array, "l"), expected);
+
+    let expected = Int64Array::from_slice([0, 0, 3]);
+    assert_eq!(find(&array, "ïv"), expected);
+}
+
+#[test]
+fn element_wise() {
+    let haystack = Utf8Array::<i32>::from([Some("日本語"), Some("abc"), None, Some("abc")]);
+    let needle = Utf8Array::<i32>::from([Some("語"), None, Some("a"), Some("d")]);
+
+    let expected = Int32Array::from([Some(3), None, None, Some(0)]);
+    assert_eq!(find_array(&haystack, &needle).unwrap(), expected);
+
+    let needle = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(find_array(&haystack, &needle).is_err());
+}
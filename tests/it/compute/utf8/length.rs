@@ -0,0 +1,27 @@
+use arrow2::array::*;
+use arrow2::compute::length::length;
+use arrow2::compute::utf8::char_length;
+
+#[test]
+fn multibyte() {
+    let array = Utf8Array::<i32>::from([Some("héllo"), Some("💖"), None, Some(""), Some("abc")]);
+
+    let expected = Int32Array::from([Some(5), Some(1), None, Some(0), Some(3)]);
+    assert_eq!(char_length(&array), expected);
+
+    // the number of bytes differs for non-ASCII strings
+    let expected = Int32Array::from([Some(6), Some(4), None, Some(0), Some(3)]);
+    assert_eq!(length(&array).unwrap().as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn large() {
+    let array = Utf8Array::<i64>::from_slice(["日本語", "ümlaut"]);
+    assert_eq!(char_length(&array), Int64Array::from_slice([3, 6]));
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from([Some("ab"), None, Some("ü")]).slice(1, 2);
+    assert_eq!(char_length(&array), Int32Array::from([None, Some(1)]));
+}
@@ -0,0 +1,6 @@
+mod find;
+mod length;
+mod pad;
+mod regex_replace;
+mod split;
+mod trim;
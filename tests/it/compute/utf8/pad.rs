@@ -0,0 +1,50 @@
+use arrow2::array::*;
+use arrow2::compute::utf8::{lpad, rpad};
+
+#[test]
+fn pad() {
+    let array = Utf8Array::<i32>::from([Some("abc"), None, Some(""), Some("abcdef")]);
+
+    let expected = Utf8Array::<i32>::from([Some("xyabc"), None, Some("xyxyx"), Some("abcde")]);
+    assert_eq!(lpad(&array, 5, "xy"), expected);
+
+    let expected = Utf8Array::<i32>::from([Some("abcxy"), None, Some("xyxyx"), Some("abcde")]);
+    assert_eq!(rpad(&array, 5, "xy"), expected);
+}
+
+#[test]
+fn multibyte() {
+    // "ü" and "é" are 2 bytes and "日本" is 6 bytes, but they are 1 and 2 code points
+    let array = Utf8Array::<i64>::from_slice(["ü", "日本", "éééé"]);
+
+    let expected = Utf8Array::<i64>::from_slice(["日本ü", "日日本", "ééé"]);
+    assert_eq!(lpad(&array, 3, "日本"), expected);
+
+    let expected = Utf8Array::<i64>::from_slice(["ü日本", "日本日", "ééé"]);
+    assert_eq!(rpad(&array, 3, "日本"), expected);
+}
+
+#[test]
+fn code_points() {
+    // "e" followed by a combining acute accent is one grapheme cluster but two code points
+    let array = Utf8Array::<i32>::from_slice(["e\u{301}"]);
+    assert_eq!(
+        lpad(&array, 3, "*"),
+        Utf8Array::<i32>::from_slice(["*e\u{301}"])
+    );
+}
+
+#[test]
+fn empty_fill() {
+    let array = Utf8Array::<i32>::from_slice(["ab", "abcd"]);
+    let expected = Utf8Array::<i32>::from_slice(["ab", "abc"]);
+    assert_eq!(lpad(&array, 3, ""), expected);
+    assert_eq!(rpad(&array, 3, ""), expected);
+}
+
+#[test]
+fn zero_width() {
+    let array = Utf8Array::<i32>::from([Some("ab"), None]);
+    let expected = Utf8Array::<i32>::from([Some(""), None]);
+    assert_eq!(lpad(&array, 0, "x"), expected);
+}
@@ -0,0 +1,47 @@
+use arrow2::array::*;
+use arrow2::compute::utf8::regex_replace;
+use arrow2::error::ArrowError;
+
+#[test]
+fn replace_first() {
+    let array = Utf8Array::<i32>::from([Some("aaa"), None, Some("bab"), Some("")]);
+    let result = regex_replace(&array, "a", "c", false).unwrap();
+
+    let expected = Utf8Array::<i32>::from([Some("caa"), None, Some("bcb"), Some("")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn replace_all() {
+    let array = Utf8Array::<i64>::from([Some("a1b22c333"), None, Some("abc")]);
+    let result = regex_replace(&array, r"\d+", "#", true).unwrap();
+
+    let expected = Utf8Array::<i64>::from([Some("a#b#c#"), None, Some("abc")]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn capture_groups() {
+    let array = Utf8Array::<i32>::from_slice(["John Smith", "Jane Doe", "Prince"]);
+
+    let result = regex_replace(&array, r"(\w+) (\w+)", "$2, $1", false).unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["Smith, John", "Doe, Jane", "Prince"]);
+    assert_eq!(result, expected);
+
+    let result = regex_replace(
+        &array,
+        r"(?P<first>\w+) (?P<last>\w+)",
+        "${last}_${first}",
+        true,
+    )
+    .unwrap();
+    let expected = Utf8Array::<i32>::from_slice(["Smith_John", "Doe_Jane", "Prince"]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn invalid_regex() {
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    let result = regex_replace(&array, "(a", "b", true);
+    assert!(matches!(result, Err(ArrowError::InvalidArgumentError(_))));
+}
@@ -0,0 +1,76 @@
+use arrow2::array::*;
+use arrow2::compute::utf8::{split, splitn};
+
+fn list<O: Offset>(data: Vec<Option<Vec<&str>>>) -> ListArray<O> {
+    let mut array = MutableListArray::<O, MutableUtf8Array<O>>::new();
+    array
+        .try_extend(
+            data.into_iter()
+                .map(|x| x.map(|x| x.into_iter().map(Some).collect::<Vec<_>>())),
+        )
+        .unwrap();
+    array.into()
+}
+
+#[test]
+fn basics() {
+    let array = Utf8Array::<i32>::from([Some("a,b,c"), None, Some(""), Some("abc")]);
+    let result = split(&array, ",");
+
+    let expected = list::<i32>(vec![
+        Some(vec!["a", "b", "c"]),
+        None,
+        Some(vec![""]),
+        Some(vec!["abc"]),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn multi_char_pattern() {
+    let array = Utf8Array::<i64>::from_slice(["a::b::c", "::a", "a:b"]);
+    let result = split(&array, "::");
+
+    let expected = list::<i64>(vec![
+        Some(vec!["a", "b", "c"]),
+        Some(vec!["", "a"]),
+        Some(vec!["a:b"]),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn consecutive_patterns() {
+    let array = Utf8Array::<i32>::from_slice(["a,,b", ",,", "ü,,é,"]);
+    let result = split(&array, ",");
+
+    let expected = list::<i32>(vec![
+        Some(vec!["a", "", "b"]),
+        Some(vec!["", "", ""]),
+        Some(vec!["ü", "", "é", ""]),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn limit() {
+    let array = Utf8Array::<i32>::from([Some("a,b,c"), Some("a"), None, Some("")]);
+    let result = splitn(&array, 2, ",");
+
+    let expected = list::<i32>(vec![
+        Some(vec!["a", "b,c"]),
+        Some(vec!["a"]),
+        None,
+        Some(vec![""]),
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn sliced() {
+    let array = Utf8Array::<i32>::from_slice(["a b", "c d", "e"]).slice(1, 2);
+    let result = split(&array, " ");
+
+    let expected = list::<i32>(vec![Some(vec!["c", "d"]), Some(vec!["e"])]);
+    assert_eq!(result, expected);
+}
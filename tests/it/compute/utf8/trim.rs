@@ -0,0 +1,36 @@
+use arrow2::array::*;
+use arrow2::compute::utf8::{trim_end_matches, trim_matches, trim_start_matches};
+
+#[test]
+fn quotes() {
+    let array = Utf8Array::<i32>::from([
+        Some("\"'a*b'\"*"),
+        None,
+        Some("*'\"*"),
+        Some("a\"b"),
+        Some(""),
+    ]);
+
+    let expected = Utf8Array::<i32>::from([Some("a*b"), None, Some(""), Some("a\"b"), Some("")]);
+    assert_eq!(trim_matches(&array, "\"'*"), expected);
+
+    let expected =
+        Utf8Array::<i32>::from([Some("a*b'\"*"), None, Some(""), Some("a\"b"), Some("")]);
+    assert_eq!(trim_start_matches(&array, "\"'*"), expected);
+
+    let expected = Utf8Array::<i32>::from([Some("\"'a*b"), None, Some(""), Some("a\"b"), Some("")]);
+    assert_eq!(trim_end_matches(&array, "\"'*"), expected);
+}
+
+#[test]
+fn multibyte() {
+    let array = Utf8Array::<i64>::from_slice(["«é»", "»»日本««", "x«y"]);
+    let expected = Utf8Array::<i64>::from_slice(["é", "日本", "x«y"]);
+    assert_eq!(trim_matches(&array, "«»"), expected);
+}
+
+#[test]
+fn empty_set() {
+    let array = Utf8Array::<i32>::from_slice([" a "]);
+    assert_eq!(trim_matches(&array, ""), array);
+}
@@ -1,11 +1,14 @@
-use arrow2::array::{new_null_array, Int32Array};
+use arrow2::array::{
+    new_null_array, Array, BooleanArray, Float64Array, Int32Array, Int64Array, Int8Array, Utf8Array,
+};
 use arrow2::compute::window::*;
 use arrow2::datatypes::DataType;
+use arrow2::scalar::{new_scalar, BooleanScalar, PrimitiveScalar, Utf8Scalar};
 
 #[test]
 fn shift_pos() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]);
-    let result = shift(&array, 1).unwrap();
+    let result = shift(&array, 1, None).unwrap();
 
     let expected = Int32Array::from(&[None, Some(1), None]);
 
@@ -15,15 +18,155 @@ fn shift_pos() {
 #[test]
 fn shift_many() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
-    assert!(shift(&array, 10).is_err());
+    let result = shift(&array, 10, None).unwrap();
+    assert_eq!(
+        new_null_array(DataType::Date32, 3).as_ref(),
+        result.as_ref()
+    );
+
+    let fill = new_scalar(&Int32Array::from_slice([7]).to(DataType::Date32), 0);
+    let result = shift(&array, -10, Some(fill.as_ref())).unwrap();
+    let expected = Int32Array::from_slice([7, 7, 7]).to(DataType::Date32);
+    assert_eq!(&expected as &dyn Array, result.as_ref());
 }
 
 #[test]
 fn shift_max() {
     let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
-    let result = shift(&array, 3).unwrap();
+    let result = shift(&array, 3, None).unwrap();
 
     let expected = new_null_array(DataType::Date32, 3);
 
     assert_eq!(expected.as_ref(), result.as_ref());
 }
+
+#[test]
+fn shift_lag_with_fill() {
+    let array = Int32Array::from(&[Some(1), None, Some(3), Some(4)]);
+    let fill = PrimitiveScalar::from(Some(0i32));
+    let result = shift(&array, 1, Some(&fill)).unwrap();
+
+    let expected = Int32Array::from(&[Some(0), Some(1), None, Some(3)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn shift_lead_with_fill() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("c"), Some("d")]);
+    let fill = Utf8Scalar::<i32>::new(Some("z"));
+    let result = shift(&array, -2, Some(&fill)).unwrap();
+
+    let expected = Utf8Array::<i32>::from([Some("c"), Some("d"), Some("z"), Some("z")]);
+    assert_eq!(expected, result.as_ref());
+
+    // a null fill is the same as no fill
+    let fill = Utf8Scalar::<i32>::new(None::<&str>);
+    let result = shift(&array, -2, Some(&fill)).unwrap();
+    let expected = Utf8Array::<i32>::from([Some("c"), Some("d"), None, None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn shift_zero_and_boolean() {
+    let array = BooleanArray::from_slice([true, false, true]);
+    let fill = BooleanScalar::new(Some(false));
+    let result = shift(&array, 0, Some(&fill)).unwrap();
+    assert_eq!(array, result.as_ref());
+
+    let result = shift(&array, -1, Some(&fill)).unwrap();
+    assert_eq!(
+        BooleanArray::from_slice([false, true, false]),
+        result.as_ref()
+    );
+}
+
+#[test]
+fn shift_wrong_fill() {
+    let array = Int32Array::from_slice([1, 2]);
+    let fill = PrimitiveScalar::from(Some(0i64));
+    assert!(shift(&array, 1, Some(&fill)).is_err());
+}
+
+#[test]
+fn cumulative() {
+    let array = Int32Array::from(&[Some(2), None, Some(-1), Some(3), None]);
+
+    assert_eq!(
+        cumsum(&array),
+        Int32Array::from(&[Some(2), None, Some(1), Some(4), None])
+    );
+    assert_eq!(
+        cumprod(&array),
+        Int32Array::from(&[Some(2), None, Some(-2), Some(-6), None])
+    );
+    assert_eq!(
+        cummin(&array),
+        Int32Array::from(&[Some(2), None, Some(-1), Some(-1), None])
+    );
+    assert_eq!(
+        cummax(&array),
+        Int32Array::from(&[Some(2), None, Some(2), Some(3), None])
+    );
+}
+
+#[test]
+fn cumulative_leading_null() {
+    let array = Float64Array::from(&[None, None, Some(1.5), Some(0.5)]);
+
+    assert_eq!(
+        cumsum(&array),
+        Float64Array::from(&[None, None, Some(1.5), Some(2.0)])
+    );
+    assert_eq!(
+        cumprod(&array),
+        Float64Array::from(&[None, None, Some(1.5), Some(0.75)])
+    );
+    assert_eq!(
+        cummin(&array),
+        Float64Array::from(&[None, None, Some(1.5), Some(0.5)])
+    );
+    assert_eq!(
+        cummax(&array),
+        Float64Array::from(&[None, None, Some(1.5), Some(1.5)])
+    );
+}
+
+#[test]
+fn cumulative_all_null() {
+    let array = Int64Array::from(&[None, None, None]);
+
+    assert_eq!(cumsum(&array), array);
+    assert_eq!(checked_cumsum(&array), array);
+    assert_eq!(cumprod(&array), array);
+    assert_eq!(cummin(&array), array);
+    assert_eq!(cummax(&array), array);
+}
+
+#[test]
+fn cumulative_checked() {
+    let array = Int8Array::from(&[Some(100), None, Some(27), Some(1), Some(-100)]);
+    assert_eq!(
+        checked_cumsum(&array),
+        Int8Array::from(&[Some(100), None, Some(127), None, None])
+    );
+
+    let array = Int8Array::from(&[Some(2), Some(8), Some(8), None, Some(1)]);
+    assert_eq!(
+        checked_cumprod(&array),
+        Int8Array::from(&[Some(2), Some(16), None, None, None])
+    );
+}
+
+#[test]
+fn cumulative_keeps_data_type() {
+    let array = Int32Array::from(&[Some(1), None, Some(3)]).to(DataType::Date32);
+    assert_eq!(cummax(&array).data_type(), &DataType::Date32);
+    assert_eq!(checked_cumsum(&array).data_type(), &DataType::Date32);
+}
+
+#[test]
+#[should_panic]
+fn cumsum_overflow() {
+    let array = Int8Array::from(&[Some(100), Some(100)]);
+    cumsum(&array);
+}
@@ -0,0 +1,86 @@
+use arrow2::datatypes::*;
+
+#[test]
+fn primitive_width() {
+    use DataType::*;
+    let cases = [
+        (Int8, Some(1)),
+        (UInt8, Some(1)),
+        (Int16, Some(2)),
+        (UInt16, Some(2)),
+        (Float16, Some(2)),
+        (Int32, Some(4)),
+        (UInt32, Some(4)),
+        (Float32, Some(4)),
+        (Date32, Some(4)),
+        (Time32(TimeUnit::Millisecond), Some(4)),
+        (Interval(IntervalUnit::YearMonth), Some(4)),
+        (Int64, Some(8)),
+        (UInt64, Some(8)),
+        (Float64, Some(8)),
+        (Date64, Some(8)),
+        (Time64(TimeUnit::Nanosecond), Some(8)),
+        (
+            Timestamp(TimeUnit::Second, Some("+01:00".to_string())),
+            Some(8),
+        ),
+        (Duration(TimeUnit::Microsecond), Some(8)),
+        (Interval(IntervalUnit::DayTime), Some(8)),
+        (Interval(IntervalUnit::MonthDayNano), Some(16)),
+        (Decimal(10, 2), Some(16)),
+        (Decimal256(50, 2), Some(32)),
+        (FixedSizeBinary(7), Some(7)),
+        (
+            Extension("ext".to_string(), Box::new(FixedSizeBinary(5)), None),
+            Some(5),
+        ),
+        (Extension("ext".to_string(), Box::new(Utf8), None), None),
+        (Null, None),
+        (Boolean, None),
+        (Binary, None),
+        (LargeBinary, None),
+        (Utf8, None),
+        (LargeUtf8, None),
+        (Utf8View, None),
+        (List(Box::new(Field::new("item", Int32, true))), None),
+        (LargeList(Box::new(Field::new("item", Int32, true))), None),
+        (
+            FixedSizeList(Box::new(Field::new("item", Int32, true)), 2),
+            None,
+        ),
+        (Struct(vec![Field::new("a", Int32, true)]), None),
+        (
+            Union(vec![Field::new("a", Int32, true)], None, UnionMode::Dense),
+            None,
+        ),
+        (Dictionary(IntegerType::Int32, Box::new(Utf8), false), None),
+    ];
+    for (data_type, expected) in cases {
+        assert_eq!(data_type.primitive_width(), expected, "{:?}", data_type);
+    }
+}
+
+#[test]
+fn primitive_width_matches_physical_size() {
+    use DataType::*;
+    let cases = [
+        (Int8, std::mem::size_of::<i8>()),
+        (Decimal(10, 2), std::mem::size_of::<i128>()),
+        (Float64, std::mem::size_of::<f64>()),
+        (
+            Interval(IntervalUnit::MonthDayNano),
+            std::mem::size_of::<arrow2::types::months_days_ns>(),
+        ),
+        (
+            Interval(IntervalUnit::DayTime),
+            std::mem::size_of::<arrow2::types::days_ms>(),
+        ),
+        (
+            Decimal256(50, 2),
+            std::mem::size_of::<arrow2::types::i256>(),
+        ),
+    ];
+    for (data_type, expected) in cases {
+        assert_eq!(data_type.primitive_width(), Some(expected));
+    }
+}
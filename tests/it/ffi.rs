@@ -1,6 +1,8 @@
 use arrow2::array::*;
 use arrow2::bitmap::Bitmap;
-use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow2::error::ArrowError;
 use arrow2::{error::Result, ffi};
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -15,8 +17,8 @@ fn _test_round_trip(array: Arc<dyn Array>, expected: Box<dyn Array>) -> Result<(
     let schema_ptr = Box::into_raw(schema_ptr);
 
     unsafe {
-        ffi::export_array_to_c(array, array_ptr);
-        ffi::export_field_to_c(&field, schema_ptr);
+        ffi::export_array_to_c(array, array_ptr)?;
+        ffi::export_field_to_c(&field, schema_ptr)?;
     }
 
     let array_ptr = unsafe { Box::from_raw(array_ptr) };
@@ -46,7 +48,7 @@ fn test_round_trip_schema(field: Field) -> Result<()> {
 
     let schema_ptr = Box::into_raw(schema_ptr);
 
-    unsafe { ffi::export_field_to_c(&field, schema_ptr) };
+    unsafe { ffi::export_field_to_c(&field, schema_ptr)? };
 
     let schema_ptr = unsafe { Box::from_raw(schema_ptr) };
 
@@ -74,6 +76,32 @@ fn i64() -> Result<()> {
     test_round_trip(data)
 }
 
+#[test]
+fn decimal() -> Result<()> {
+    let data = Int128Array::from(&[Some(2), None, Some(1), None]).to(DataType::Decimal(5, 2));
+    test_round_trip(data)
+}
+
+#[test]
+fn decimal256() -> Result<()> {
+    use arrow2::types::i256;
+    let data = PrimitiveArray::<i256>::from([Some(i256::from(2)), None, Some(i256::MIN), None])
+        .to(DataType::Decimal256(76, 2));
+    test_round_trip(data)
+}
+
+#[test]
+fn months_days_ns() -> Result<()> {
+    use arrow2::types::months_days_ns;
+    let data = MonthsDaysNsArray::from([
+        Some(months_days_ns::new(1, 1, 2)),
+        None,
+        Some(months_days_ns::new(-1, 2, -3)),
+        Some(months_days_ns::new(i32::MAX, i32::MIN, i64::MAX)),
+    ]);
+    test_round_trip(data)
+}
+
 #[test]
 fn utf8() -> Result<()> {
     let data = Utf8Array::<i32>::from(&vec![Some("a"), None, Some("bb"), None]);
@@ -215,5 +243,218 @@ fn extension() -> Result<()> {
         DataType::Extension("a".to_string(), Box::new(DataType::Int32), None),
         true,
     );
-    test_round_trip_schema(field)
+    test_round_trip_schema(field)?;
+
+    let field = Field::new(
+        "a",
+        DataType::Extension(
+            "a".to_string(),
+            Box::new(DataType::Int32),
+            Some("some metadata".to_string()),
+        ),
+        true,
+    );
+    test_round_trip_schema(field.clone())?;
+
+    // the field's own metadata is kept apart from the extension's
+    let mut metadata = BTreeMap::new();
+    metadata.insert("some".to_string(), "stuff".to_string());
+    test_round_trip_schema(field.with_metadata(metadata))
+}
+
+#[test]
+fn extension_array() -> Result<()> {
+    let data_type = DataType::Extension(
+        "my_extension".to_string(),
+        Box::new(DataType::Int32),
+        Some("some metadata".to_string()),
+    );
+    let array = Int32Array::from([Some(2), None, Some(1), None]).to(data_type);
+    test_round_trip(array)
+}
+
+#[test]
+fn nested_extension_array() -> Result<()> {
+    let data_type =
+        DataType::Extension("my_extension".to_string(), Box::new(DataType::Int32), None);
+    let values = Int32Array::from([Some(2), None, Some(1), None]).to(data_type.clone());
+    let field = Field::new("item", data_type, true);
+    let array = ListArray::<i32>::from_data(
+        DataType::List(Box::new(field)),
+        vec![0, 1, 3, 4].into(),
+        Arc::new(values),
+        None,
+    );
+    test_round_trip(array)
+}
+
+#[test]
+fn run_end_encoded_errors() {
+    let array = RunEndEncodedArray::<i32>::from_data(
+        Int32Array::from_slice([2, 3]),
+        Arc::new(Int32Array::from_slice([1, 2])),
+    );
+    let field = Field::new("a", array.data_type().clone(), true);
+    // nested in a struct, so that the error is raised by a child
+    let struct_field = Field::new("s", DataType::Struct(vec![field.clone()]), true);
+
+    let mut array_ptr = ffi::Ffi_ArrowArray::empty();
+    let mut schema_ptr = ffi::Ffi_ArrowSchema::empty();
+    unsafe {
+        assert!(matches!(
+            ffi::export_array_to_c(Arc::new(array), &mut array_ptr),
+            Err(ArrowError::NotYetImplemented(_))
+        ));
+        assert!(matches!(
+            ffi::export_field_to_c(&field, &mut schema_ptr),
+            Err(ArrowError::NotYetImplemented(_))
+        ));
+        assert!(matches!(
+            ffi::export_field_to_c(&struct_field, &mut schema_ptr),
+            Err(ArrowError::NotYetImplemented(_))
+        ));
+    }
+}
+
+#[test]
+fn utf8_view_errors() {
+    let array = Utf8ViewArray::from([Some("a"), None, Some("a string longer than 12 bytes")]);
+    let field = Field::new("a", array.data_type().clone(), true);
+
+    let mut array_ptr = ffi::Ffi_ArrowArray::empty();
+    let mut schema_ptr = ffi::Ffi_ArrowSchema::empty();
+    unsafe {
+        assert!(matches!(
+            ffi::export_array_to_c(Arc::new(array), &mut array_ptr),
+            Err(ArrowError::NotYetImplemented(_))
+        ));
+        assert!(matches!(
+            ffi::export_field_to_c(&field, &mut schema_ptr),
+            Err(ArrowError::NotYetImplemented(_))
+        ));
+    }
+}
+
+fn stream_chunks() -> (Schema, Vec<Chunk<Arc<dyn Array>>>) {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunks = vec![
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b"), None])),
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(4), Some(5), None, Some(6)]).slice(1, 2))
+                as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from([None, Some("c"), Some("d")]).slice(1, 2)),
+        ]),
+    ];
+    (schema, chunks)
+}
+
+/// Increments a counter when dropped
+struct DropCounter(Arc<std::sync::atomic::AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn stream_round_trip() -> Result<()> {
+    let (schema, chunks) = stream_chunks();
+
+    let dropped = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let counter = DropCounter(dropped.clone());
+    let iter = chunks.clone().into_iter().map(move |chunk| {
+        let _ = &counter;
+        Ok(chunk)
+    });
+
+    let stream = Box::new(ffi::export_stream(Box::new(iter), schema.clone()));
+    let reader = unsafe { ffi::import_stream(stream)? };
+    assert_eq!(reader.schema(), &schema);
+
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, chunks);
+
+    // the reader releases the stream (and its iterator) exactly once when dropped
+    assert_eq!(dropped.load(std::sync::atomic::Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[test]
+fn stream_release_without_consuming() -> Result<()> {
+    let (schema, chunks) = stream_chunks();
+
+    let dropped = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+    let counter = DropCounter(dropped.clone());
+    let iter = chunks.into_iter().map(move |chunk| {
+        let _ = &counter;
+        Ok(chunk)
+    });
+
+    let stream = Box::new(ffi::export_stream(Box::new(iter), schema));
+    let mut reader = unsafe { ffi::import_stream(stream)? };
+    assert!(reader.next().is_some());
+    assert_eq!(dropped.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+    drop(reader);
+    assert_eq!(dropped.load(std::sync::atomic::Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[test]
+fn stream_errors() -> Result<()> {
+    let (schema, chunks) = stream_chunks();
+
+    let iter =
+        chunks
+            .into_iter()
+            .map(Ok)
+            .chain(std::iter::once(Err(ArrowError::InvalidArgumentError(
+                "some error".to_string(),
+            ))));
+
+    let stream = Box::new(ffi::export_stream(Box::new(iter), schema));
+    let reader = unsafe { ffi::import_stream(stream)? };
+
+    let result = reader.collect::<Vec<_>>();
+    assert_eq!(result.len(), 3);
+    assert!(result[0].is_ok());
+    assert!(result[1].is_ok());
+    let error = result[2].as_ref().unwrap_err().to_string();
+    assert!(error.contains("some error"));
+    Ok(())
+}
+
+#[test]
+fn stream_wrong_schema() -> Result<()> {
+    let (_, chunks) = stream_chunks();
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+
+    let stream = Box::new(ffi::export_stream(
+        Box::new(chunks.into_iter().map(Ok)),
+        schema,
+    ));
+    let mut reader = unsafe { ffi::import_stream(stream)? };
+    assert!(reader.next().unwrap().is_err());
+    Ok(())
+}
+
+#[test]
+fn stream_unsupported_schema() {
+    let array = Utf8ViewArray::from_slice(["a"]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let chunk = Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]);
+
+    let stream = Box::new(ffi::export_stream(
+        Box::new(std::iter::once(Ok(chunk))),
+        schema,
+    ));
+    let error = unsafe { ffi::import_stream(stream) }.err().unwrap();
+    assert!(error.to_string().contains("Utf8View"));
 }
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use arrow2::chunk::Chunk;
+use arrow2::io::avro::Block;
 use avro_rs::types::{Record, Value};
 use avro_rs::{Codec, Writer};
 use avro_rs::{Days, Decimal, Duration, Millis, Months, Schema as AvroSchema};
@@ -9,6 +10,7 @@ use arrow2::array::*;
 use arrow2::datatypes::*;
 use arrow2::error::Result;
 use arrow2::io::avro::read;
+use fallible_streaming_iterator::FallibleStreamingIterator;
 
 pub(super) fn schema() -> (AvroSchema, Schema) {
     let raw_schema = r#"
@@ -279,3 +281,180 @@ fn read_projected() -> Result<()> {
         true, false, false, false, false, false, false, false, false, false, false, false,
     ])
 }
+
+fn zigzag(n: i64, buffer: &mut Vec<u8>) {
+    let mut n = ((n << 1) ^ (n >> 63)) as u64;
+    while n & !0x7F != 0 {
+        buffer.push(((n & 0x7F) | 0x80) as u8);
+        n >>= 7;
+    }
+    buffer.push(n as u8);
+}
+
+fn bytes(bytes: &[u8], buffer: &mut Vec<u8>) {
+    zigzag(bytes.len() as i64, buffer);
+    buffer.extend_from_slice(bytes);
+}
+
+const MARKER: [u8; 16] = [7; 16];
+
+/// Writes an uncompressed Avro file whose schema is `schema` with `blocks`, each its number of
+/// rows and its (already encoded) data
+fn write_file(schema: &str, blocks: &[(i64, &[u8])]) -> Vec<u8> {
+    let mut file = b"Obj\x01".to_vec();
+    zigzag(1, &mut file);
+    bytes(b"avro.schema", &mut file);
+    bytes(schema.as_bytes(), &mut file);
+    zigzag(0, &mut file);
+    file.extend_from_slice(&MARKER);
+
+    for (rows, data) in blocks {
+        zigzag(*rows, &mut file);
+        bytes(data, &mut file);
+        file.extend_from_slice(&MARKER);
+    }
+    file
+}
+
+fn write_logical() -> Vec<u8> {
+    let schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "decimal", "type": {
+                "type": "fixed",
+                "name": "decimal",
+                "size": 8,
+                "logicalType": "decimal",
+                "precision": 15,
+                "scale": 2
+            }},
+            {"name": "nullable_decimal", "type": ["null", {
+                "type": "fixed",
+                "name": "nullable_decimal",
+                "size": 8,
+                "logicalType": "decimal",
+                "precision": 15,
+                "scale": 2
+            }], "default": null},
+            {"name": "timestamp", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+            {"name": "nullable_timestamp", "type": [
+                "null", {"type": "long", "logicalType": "timestamp-micros"}
+            ], "default": null}
+        ]
+    }
+"#;
+
+    let mut data = vec![];
+    // decimals are big-endian two's complement
+    data.extend_from_slice(&123456i64.to_be_bytes());
+    zigzag(0, &mut data);
+    zigzag(1_609_459_200_000_001, &mut data);
+    zigzag(0, &mut data);
+
+    data.extend_from_slice(&(-123456i64).to_be_bytes());
+    zigzag(1, &mut data);
+    data.extend_from_slice(&1i64.to_be_bytes());
+    zigzag(-1, &mut data);
+    zigzag(1, &mut data);
+    zigzag(1, &mut data);
+
+    write_file(schema, &[(2, &data)])
+}
+
+#[test]
+fn read_logical_types() -> Result<()> {
+    let avro = write_logical();
+
+    let (result, schema) = read_avro(&avro, None)?;
+
+    let timestamp = DataType::Timestamp(TimeUnit::Microsecond, Some("00:00".to_string()));
+    let expected_schema = Schema::from(vec![
+        Field::new("decimal", DataType::Decimal(15, 2), false),
+        Field::new("nullable_decimal", DataType::Decimal(15, 2), true),
+        Field::new("timestamp", timestamp.clone(), false),
+        Field::new("nullable_timestamp", timestamp.clone(), true),
+    ]);
+    assert_eq!(schema, expected_schema);
+
+    let expected = Chunk::try_new(vec![
+        Arc::new(Int128Array::from_slice([123456, -123456]).to(DataType::Decimal(15, 2)))
+            as Arc<dyn Array>,
+        Arc::new(Int128Array::from([None, Some(1)]).to(DataType::Decimal(15, 2))),
+        Arc::new(Int64Array::from_slice([1_609_459_200_000_001, -1]).to(timestamp.clone())),
+        Arc::new(Int64Array::from([None, Some(1)]).to(timestamp)),
+    ])?;
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+const INT_SCHEMA: &str =
+    r#"{"type": "record", "name": "test", "fields": [{"name": "a", "type": "int"}]}"#;
+
+fn int_block(values: &[i64]) -> Vec<u8> {
+    let mut data = vec![];
+    values.iter().for_each(|x| zigzag(*x, &mut data));
+    data
+}
+
+#[test]
+fn read_blocks_without_decoding() -> Result<()> {
+    let first = int_block(&[1, 2]);
+    let second = int_block(&[3, 4, 5]);
+    let avro = write_file(INT_SCHEMA, &[(2, &first), (3, &second)]);
+
+    let mut file = avro.as_slice();
+    let (avro_schemas, schema, codec, marker) = read::read_metadata(&mut file)?;
+    let mut blocks = read::BlockStreamIterator::new(file, marker);
+
+    // count the rows without decoding
+    let mut rows = 0;
+    let mut second_block = None;
+    while let Some(block) = blocks.next()? {
+        rows += block.number_of_rows;
+        if rows > 2 {
+            second_block = Some(block.clone());
+        }
+    }
+    assert_eq!(rows, 5);
+
+    // and only decode the second block
+    let mut block = second_block.unwrap();
+    let mut decompressed = Block::new(0, vec![]);
+    read::decompress_block(&mut block, &mut decompressed, codec)?;
+    let chunk = read::deserialize(&decompressed, &schema.fields, &avro_schemas, &[true])?;
+    assert_eq!(
+        chunk.arrays()[0].as_ref(),
+        &Int32Array::from_slice([3, 4, 5]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn read_corrupted_sync_marker() -> Result<()> {
+    let data = int_block(&[1, 2]);
+    let mut avro = write_file(INT_SCHEMA, &[(2, &data), (2, &data)]);
+    // corrupt the marker of the last block
+    *avro.last_mut().unwrap() = 0;
+
+    let mut file = avro.as_slice();
+    let (_, _, _, marker) = read::read_metadata(&mut file)?;
+    let mut blocks = read::BlockStreamIterator::new(file, marker);
+
+    assert!(blocks.next()?.is_some());
+    assert!(blocks.next().is_err());
+    Ok(())
+}
+
+#[test]
+fn read_negative_block_size() -> Result<()> {
+    let avro = write_file(INT_SCHEMA, &[(-1, &[])]);
+
+    let mut file = avro.as_slice();
+    let (_, _, _, marker) = read::read_metadata(&mut file)?;
+    let mut blocks = read::BlockStreamIterator::new(file, marker);
+
+    assert!(blocks.next().is_err());
+    Ok(())
+}
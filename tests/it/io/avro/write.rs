@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
 use arrow2::datatypes::*;
@@ -197,3 +199,84 @@ fn check_large_format() -> Result<()> {
 
     Ok(())
 }
+
+fn struct_schema() -> Schema {
+    let fields = vec![
+        Field::new("int32", DataType::Int32, false),
+        Field::new("utf8", DataType::Utf8, true),
+        Field::new("boolean", DataType::Boolean, true),
+    ];
+    Schema::from(vec![
+        Field::new("struct", DataType::Struct(fields.clone()), false),
+        Field::new("struct nullable", DataType::Struct(fields), true),
+    ])
+}
+
+fn struct_data() -> Chunk<Box<dyn Array>> {
+    let struct_dt = struct_schema().fields[0].data_type().clone();
+    let values = vec![
+        Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("c")])),
+        Arc::new(BooleanArray::from([None, Some(true), Some(false)])),
+    ];
+
+    Chunk::new(vec![
+        Box::new(StructArray::from_data(
+            struct_dt.clone(),
+            values.clone(),
+            None,
+        )) as Box<dyn Array>,
+        Box::new(StructArray::from_data(
+            struct_dt,
+            values,
+            Some([true, false, true].into()),
+        )),
+    ])
+}
+
+#[test]
+fn struct_() -> Result<()> {
+    let write_schema = struct_schema();
+    let write_data = struct_data();
+
+    let data = write_avro(
+        &write_data,
+        &write_schema,
+        Some(write::Compression::Deflate),
+    )?;
+    let (result, read_schema) = read_avro(&data, None)?;
+
+    // the reader names the fields of records after the record
+    let fields = |name: &str| {
+        vec![
+            Field::new(format!("{}.int32", name), DataType::Int32, false),
+            Field::new(format!("{}.utf8", name), DataType::Utf8, true),
+            Field::new(format!("{}.boolean", name), DataType::Boolean, true),
+        ]
+    };
+    let expected_schema = Schema::from(vec![
+        Field::new("struct", DataType::Struct(fields("struct")), false),
+        Field::new(
+            "struct nullable",
+            DataType::Struct(fields("struct nullable")),
+            true,
+        ),
+    ]);
+    assert_eq!(read_schema, expected_schema);
+
+    for ((result, expected), field) in result
+        .columns()
+        .iter()
+        .zip(write_data.columns().iter())
+        .zip(expected_schema.fields.iter())
+    {
+        let expected = expected.as_any().downcast_ref::<StructArray>().unwrap();
+        let expected = StructArray::from_data(
+            field.data_type().clone(),
+            expected.values().to_vec(),
+            expected.validity().cloned(),
+        );
+        assert_eq!(result.as_ref(), &expected as &dyn Array);
+    }
+    Ok(())
+}
@@ -218,6 +218,28 @@ fn deserialize_timestamp() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn deserialize_timestamp_out_of_range() -> Result<()> {
+    let input = "1970-01-01T00:00:01\n2300-01-01T00:00:00\n1600-01-01T00:00:00+00:00";
+
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+    let expected = Int64Array::from([Some(1_000_000_000), None, None]).to(data_type.clone());
+    let result = test_deserialize(input, data_type)?;
+    assert_eq!(expected, result.as_ref());
+
+    // seconds do not overflow
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let expected = Int64Array::from([Some(1), Some(10413792000), None]).to(data_type.clone());
+    let result = test_deserialize(input, data_type)?;
+    assert_eq!(expected, result.as_ref());
+
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".to_string()));
+    let expected = Int64Array::from([None, None, None]).to(data_type.clone());
+    let result = test_deserialize(input, data_type)?;
+    assert_eq!(expected, result.as_ref());
+    Ok(())
+}
+
 proptest! {
     #[test]
     #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
@@ -241,3 +263,266 @@ proptest! {
         assert_eq!(infer(v.as_bytes()), DataType::Timestamp(TimeUnit::Millisecond, Some("-02:00".to_string())));
     }
 }
+
+#[test]
+fn null_tokens() -> Result<()> {
+    let data = "a,b,c\n1,x,NA\nNA,null,y\n,,\\N\n4,NA,z";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Utf8, true),
+    ];
+    let mut null_tokens = NullTokens::new();
+    null_tokens.insert(0, "NA");
+    null_tokens.insert(1, "null");
+    null_tokens.insert(2, "NA");
+    null_tokens.insert(2, "\\N");
+
+    let options = DeserializeOptions {
+        null_tokens,
+        ..Default::default()
+    };
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let columns = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        |rows, column, datatype, line| {
+            deserialize_column_with_options(rows, column, datatype, line, &options)
+        },
+    )?;
+
+    let expected: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Int64Array::from([Some(1), None, None, Some(4)])),
+        // tokens are per column: `NA` is a value of "b"
+        Arc::new(Utf8Array::<i32>::from([
+            Some("x"),
+            None,
+            Some(""),
+            Some("NA"),
+        ])),
+        Arc::new(Utf8Array::<i32>::from([None, Some("y"), None, Some("z")])),
+    ];
+    assert_eq!(columns.arrays(), expected.as_slice());
+    Ok(())
+}
+
+#[test]
+fn infer_with_formats() -> Result<()> {
+    let data = "a,b,c,d\n\
+        31/12/2021,2021-12-31T10:00:00+02:00,2021-12-31 10:00,31/12/2021\n\
+        ,2022-01-01T00:30:00+02:00,2022-01-01 00:30,2021-12-31\n\
+        01/02/2022,,2022-02-01 12:00,01/02/2022";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+
+    let formats = TemporalFormats {
+        date: vec!["%Y-%m-%d".to_string(), "%d/%m/%Y".to_string()],
+        timestamp: vec![
+            "%Y-%m-%dT%H:%M:%S%:z".to_string(),
+            "%Y-%m-%d %H:%M".to_string(),
+        ],
+    };
+    let (fields, chosen, _) = infer_schema_with_formats(&mut reader, None, true, &infer, &formats)?;
+
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("a", DataType::Date32, true),
+            Field::new(
+                "b",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("002:00".to_string())),
+                true
+            ),
+            Field::new("c", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+            // no format parses every value
+            Field::new("d", DataType::Utf8, true),
+        ]
+    );
+    assert_eq!(
+        chosen,
+        vec![
+            Some("%d/%m/%Y".to_string()),
+            Some("%Y-%m-%dT%H:%M:%S%:z".to_string()),
+            Some("%Y-%m-%d %H:%M".to_string()),
+            None
+        ]
+    );
+
+    let options = DeserializeOptions {
+        formats: chosen,
+        ..Default::default()
+    };
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let columns = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        |rows, column, datatype, line| {
+            deserialize_column_with_options(rows, column, datatype, line, &options)
+        },
+    )?;
+
+    let expected = Int32Array::from([Some(18992), None, Some(19024)]).to(DataType::Date32);
+    assert_eq!(columns.arrays()[0].as_ref(), &expected as &dyn Array);
+    let expected = Int64Array::from([Some(1640937600000), Some(1640989800000), None])
+        .to(fields[1].data_type().clone());
+    assert_eq!(columns.arrays()[1].as_ref(), &expected as &dyn Array);
+    let expected = Int64Array::from_slice([1640944800000, 1640997000000, 1643716800000])
+        .to(fields[2].data_type().clone());
+    assert_eq!(columns.arrays()[2].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn boolean_tokens() -> Result<()> {
+    let data = "a,b\nyes,1\nNo,0\nno,2\nYES,1";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+
+    let tokens = BooleanTokens {
+        truthy: vec!["yes".to_string(), "1".to_string()],
+        falsy: vec!["no".to_string(), "0".to_string()],
+        ..Default::default()
+    };
+    let (fields, _) = infer_schema(&mut reader, None, true, &|bytes| {
+        infer_with_boolean_tokens(bytes, &tokens)
+    })?;
+    // "2" is not a boolean
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("a", DataType::Boolean, true),
+            Field::new("b", DataType::Utf8, true),
+        ]
+    );
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let rows = &rows[..rows_read];
+
+    let deserialize = |column: usize, boolean_tokens: &BooleanTokens| {
+        let options = DeserializeOptions {
+            boolean_tokens: boolean_tokens.clone(),
+            ..Default::default()
+        };
+        deserialize_column_with_options(rows, column, DataType::Boolean, 1, &options)
+    };
+    let result = deserialize(1, &tokens)?;
+    let expected = BooleanArray::from([Some(true), Some(false), None, Some(true)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = deserialize(0, &tokens)?;
+    let expected = BooleanArray::from_slice([true, false, false, true]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let case_sensitive = BooleanTokens {
+        case_sensitive: true,
+        ..tokens.clone()
+    };
+    let result = deserialize(0, &case_sensitive)?;
+    let expected = BooleanArray::from([Some(true), None, Some(false), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let strict = BooleanTokens {
+        strict: true,
+        ..tokens
+    };
+    assert!(deserialize(0, &strict).is_ok());
+    let error = deserialize(1, &strict).unwrap_err();
+    assert!(error.to_string().contains("line 3"), "{}", error);
+    Ok(())
+}
+
+#[test]
+fn combined_options() -> Result<()> {
+    let data = "a,b,c\nyes,31/12/2021,NA\nNA,NA,no\nno,01/02/2022,yes";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+
+    let fields = vec![
+        Field::new("a", DataType::Boolean, true),
+        Field::new("b", DataType::Date32, true),
+        Field::new("c", DataType::Boolean, true),
+    ];
+    let mut null_tokens = NullTokens::new();
+    null_tokens.insert(0, "NA");
+    null_tokens.insert(1, "NA");
+    null_tokens.insert(2, "NA");
+    let options = DeserializeOptions {
+        null_tokens,
+        formats: vec![None, Some("%d/%m/%Y".to_string())],
+        boolean_tokens: BooleanTokens {
+            truthy: vec!["yes".to_string()],
+            falsy: vec!["no".to_string()],
+            strict: true,
+            ..Default::default()
+        },
+    };
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let columns = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        |rows, column, datatype, line| {
+            deserialize_column_with_options(rows, column, datatype, line, &options)
+        },
+    )?;
+
+    let expected: Vec<Arc<dyn Array>> = vec![
+        Arc::new(BooleanArray::from([Some(true), None, Some(false)])),
+        Arc::new(Int32Array::from([Some(18992), None, Some(19024)]).to(DataType::Date32)),
+        Arc::new(BooleanArray::from([None, Some(false), Some(true)])),
+    ];
+    assert_eq!(columns.arrays(), expected.as_slice());
+    Ok(())
+}
+
+#[test]
+fn projected_rows() -> Result<()> {
+    let data = "a,b,c,d\n1,\"x,y\",2.5,true\n3,\"z\"\"\",,false";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+
+    let projection = [3, 1];
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows_projected(&mut reader, 0, &mut rows, &projection)?;
+    assert_eq!(rows_read, 2);
+
+    let projected_fields = projection
+        .iter()
+        .map(|i| fields[*i].clone())
+        .collect::<Vec<_>>();
+    let columns = deserialize_batch(
+        &rows[..rows_read],
+        &projected_fields,
+        None,
+        0,
+        deserialize_column,
+    )?;
+
+    let expected: Vec<Arc<dyn Array>> = vec![
+        Arc::new(BooleanArray::from_slice([true, false])),
+        Arc::new(Utf8Array::<i32>::from_slice(["x,y", "z\""])),
+    ];
+    assert_eq!(columns.arrays(), expected.as_slice());
+    Ok(())
+}
+
+#[test]
+fn projected_rows_missing_column() -> Result<()> {
+    let data = "a,b\n1,2\n3,4";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    assert!(read_rows_projected(&mut reader, 0, &mut rows, &[0, 2]).is_err());
+    Ok(())
+}
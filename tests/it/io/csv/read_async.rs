@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use futures::io::Cursor;
 
 use arrow2::array::*;
@@ -48,3 +50,47 @@ async fn read() -> Result<()> {
     assert_eq!("Aberdeen, Aberdeen City, UK", city.value(13));
     Ok(())
 }
+
+#[tokio::test]
+async fn read_projected() -> Result<()> {
+    let data = "a,b,c\n1,\"x,y\",true\n3,\"z\",false";
+    let mut reader = AsyncReaderBuilder::new().create_reader(Cursor::new(data.as_bytes()));
+
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer).await?;
+
+    let projection = [2, 1];
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows_projected(&mut reader, 0, &mut rows, &projection).await?;
+    assert_eq!(rows_read, 2);
+
+    let projected_fields = projection
+        .iter()
+        .map(|i| fields[*i].clone())
+        .collect::<Vec<_>>();
+    let columns = deserialize_batch(
+        &rows[..rows_read],
+        &projected_fields,
+        None,
+        0,
+        deserialize_column,
+    )?;
+
+    let expected: Vec<Arc<dyn Array>> = vec![
+        Arc::new(BooleanArray::from_slice([true, false])),
+        Arc::new(Utf8Array::<i32>::from_slice(["x,y", "z"])),
+    ];
+    assert_eq!(columns.arrays(), expected.as_slice());
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_projected_missing_column() -> Result<()> {
+    let data = "a,b\n1,2\n3,4";
+    let mut reader = AsyncReaderBuilder::new().create_reader(Cursor::new(data.as_bytes()));
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    assert!(read_rows_projected(&mut reader, 0, &mut rows, &[0, 2])
+        .await
+        .is_err());
+    Ok(())
+}
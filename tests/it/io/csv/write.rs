@@ -314,3 +314,81 @@ fn write_tz_timezone_formatted_tz() -> Result<()> {
         },
     )
 }
+
+#[test]
+fn csv_writer_chunks() -> Result<()> {
+    use arrow2::io::csv::read;
+
+    let chunks = (0..3)
+        .map(|i| {
+            Chunk::new(vec![
+                Arc::new(Int64Array::from([Some(i), None])) as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from_slice([
+                    format!("a;{}", i),
+                    format!("b{}", i),
+                ])),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let options = CsvWriterOptions {
+        delimiter: b';',
+        quote_style: QuoteStyle::NonNumeric,
+        ..Default::default()
+    };
+    let header = Some(vec!["a".to_string(), "b".to_string()]);
+    let mut writer = CsvWriter::new(Cursor::new(vec![]), header, options);
+    for chunk in &chunks {
+        writer.write_chunk(chunk)?;
+    }
+    let data = writer.finish()?.into_inner();
+
+    assert_eq!(
+        String::from_utf8(data.clone()).unwrap(),
+        "\"a\";\"b\"\n0;\"a;0\"\n\"\";\"b0\"\n1;\"a;1\"\n\"\";\"b1\"\n2;\"a;2\"\n\"\";\"b2\"\n"
+    );
+
+    let mut reader = read::ReaderBuilder::new()
+        .delimiter(b';')
+        .from_reader(Cursor::new(data));
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let mut rows = vec![read::ByteRecord::default(); 10];
+    let rows_read = read::read_rows(&mut reader, 0, &mut rows)?;
+    let result = read::deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        read::deserialize_column,
+    )?;
+
+    let expected = Chunk::new(vec![
+        Arc::new(Int64Array::from([
+            Some(0),
+            None,
+            Some(1),
+            None,
+            Some(2),
+            None,
+        ])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice([
+            "a;0", "b0", "a;1", "b1", "a;2", "b2",
+        ])),
+    ]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn csv_writer_mismatched_header() {
+    let header = Some(vec!["a".to_string()]);
+    let mut writer = CsvWriter::new(Cursor::new(vec![]), header, CsvWriterOptions::default());
+    let chunk = Chunk::new(vec![
+        Arc::new(Int64Array::from_slice([1])) as Arc<dyn Array>,
+        Arc::new(Int64Array::from_slice([2])),
+    ]);
+    assert!(writer.write_chunk(&chunk).is_err());
+}
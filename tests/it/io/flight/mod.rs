@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::flight::*;
+use arrow2::io::ipc::read::Dictionaries;
+use arrow2::io::ipc::write::{default_ipc_fields, WriteOptions};
+
+fn data() -> (Schema, Chunk<Arc<dyn Array>>) {
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary
+        .try_extend([Some("a"), None, Some("b"), Some("a")])
+        .unwrap();
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+    let int = Int32Array::from([Some(1), None, Some(3), Some(4)]);
+
+    let schema = Schema::from(vec![
+        Field::new("dictionary", dictionary.data_type().clone(), true),
+        Field::new("int", int.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![Arc::new(dictionary) as Arc<dyn Array>, Arc::new(int)]);
+    (schema, chunk)
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let (schema, chunk) = data();
+    let fields = default_ipc_fields(&schema.fields);
+
+    let schema_data = serialize_schema(&schema, Some(&fields))?;
+    let (dictionaries_data, batch_data) =
+        serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+    assert_eq!(dictionaries_data.len(), 1);
+
+    let (schema, ipc_schema) = deserialize_schemas(&schema_data.data_header)?;
+
+    let mut dictionaries = Dictionaries::default();
+    for data in &dictionaries_data {
+        deserialize_dictionary(data, &schema.fields, &ipc_schema, &mut dictionaries)?;
+    }
+    let result = deserialize_batch(&batch_data, &schema.fields, &ipc_schema, &dictionaries)?;
+
+    assert_eq!(result, data().1);
+    Ok(())
+}
+
+#[test]
+fn round_trip_messages() -> Result<()> {
+    let (schema, chunk) = data();
+    let fields = default_ipc_fields(&schema.fields);
+
+    let (dictionaries_data, batch_data) =
+        serialize_batch(&chunk, &fields, &WriteOptions::default())?;
+    let (_, ipc_schema) = deserialize_schemas(&serialize_schema(&schema, None)?.data_header)?;
+
+    let mut dictionaries = Dictionaries::default();
+    let mut chunks = vec![];
+    for data in dictionaries_data.iter().chain(std::iter::once(&batch_data)) {
+        if let Some(chunk) =
+            deserialize_message(data, &schema.fields, &ipc_schema, &mut dictionaries)?
+        {
+            chunks.push(chunk);
+        }
+    }
+
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn deserialize_dictionary_of_batch() {
+    let (schema, chunk) = data();
+    let fields = default_ipc_fields(&schema.fields);
+
+    let (_, batch_data) = serialize_batch(&chunk, &fields, &WriteOptions::default()).unwrap();
+    let (_, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, None).unwrap().data_header).unwrap();
+
+    let mut dictionaries = Dictionaries::default();
+    assert!(
+        deserialize_dictionary(&batch_data, &schema.fields, &ipc_schema, &mut dictionaries)
+            .is_err()
+    );
+}
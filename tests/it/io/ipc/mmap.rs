@@ -0,0 +1,126 @@
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ipc::read::{mmap::mmap, read_file_metadata, FileReader};
+use arrow2::io::ipc::write::{FileWriter, WriteOptions};
+
+fn write(schema: &Schema, chunks: &[Chunk<Arc<dyn Array>>]) -> Result<Vec<u8>> {
+    let options = WriteOptions { compression: None };
+    let mut writer = FileWriter::try_new(vec![], schema, None, options)?;
+    for chunk in chunks {
+        writer.write(chunk, None)?;
+    }
+    writer.finish()?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn sum_int64_of_mmapped_file() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let chunks = (0..3i64)
+        .map(|i| {
+            let array = Int64Array::from_values((0..1000).map(|x| x * (i + 1)));
+            Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+        })
+        .collect::<Vec<_>>();
+    let data = write(&schema, &chunks)?;
+
+    let path = std::env::temp_dir().join(format!("arrow2_mmap_{}.arrow", std::process::id()));
+    std::fs::File::create(&path)?.write_all(&data)?;
+
+    let file = std::fs::File::open(&path)?;
+    let mmapped = Arc::new(unsafe { memmap2::Mmap::map(&file)? });
+    let metadata = read_file_metadata(&mut Cursor::new(mmapped.as_ref()))?;
+
+    let range = mmapped.as_ptr_range();
+    let mut sum = 0;
+    for i in 0..chunks.len() {
+        let chunk = mmap(&metadata, mmapped.clone(), i)?;
+        let array = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        // the values are not copied
+        assert!(range.contains(&(array.values().as_ptr() as *const u8)));
+        sum += array.values().iter().sum::<i64>();
+    }
+    assert_eq!(sum, (1 + 2 + 3) * (0..1000).sum::<i64>());
+
+    // the arrays keep the mapping alive
+    let chunk = mmap(&metadata, mmapped, 0)?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(chunk.arrays()[0].as_ref(), chunks[0].arrays()[0].as_ref());
+    Ok(())
+}
+
+#[test]
+fn round_trip_nested_and_dictionary() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb", "ccc"]);
+    let dictionary = DictionaryArray::<i32>::from_data(
+        PrimitiveArray::from(&[Some(2), None, Some(0), Some(1)]),
+        Arc::new(values),
+    );
+    let utf8 = Utf8Array::<i64>::from([Some("hello"), None, Some(""), Some("world")]);
+    let list_data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let list = ListArray::<i32>::from_data(
+        list_data_type.clone(),
+        vec![0, 2, 2, 3, 5].into(),
+        Arc::new(Int32Array::from(&[
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            Some(5),
+        ])),
+        Some([true, false, true, true].into()),
+    );
+    let boolean = BooleanArray::from(&[Some(true), None, Some(false), Some(true)]);
+    let struct_fields = vec![
+        Field::new("utf8", utf8.data_type().clone(), true),
+        Field::new("boolean", DataType::Boolean, true),
+    ];
+    let struct_ = StructArray::from_data(
+        DataType::Struct(struct_fields),
+        vec![Arc::new(utf8), Arc::new(boolean)],
+        None,
+    );
+
+    let schema = Schema::from(vec![
+        Field::new("dictionary", dictionary.data_type().clone(), true),
+        Field::new("list", list_data_type, true),
+        Field::new("struct", struct_.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![
+        Arc::new(dictionary) as Arc<dyn Array>,
+        Arc::new(list),
+        Arc::new(struct_),
+    ]);
+    let data = Arc::new(write(&schema, std::slice::from_ref(&chunk))?);
+
+    let metadata = read_file_metadata(&mut Cursor::new(data.as_ref()))?;
+    let result = mmap(&metadata, data.clone(), 0)?;
+
+    let expected = FileReader::new(Cursor::new(data.as_ref()), metadata, None)
+        .next()
+        .unwrap()?;
+    assert_eq!(result.len(), expected.len());
+    for (result, expected) in result.arrays().iter().zip(expected.arrays()) {
+        assert_eq!(result.as_ref(), expected.as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds_chunk() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let chunk = Chunk::new(vec![Arc::new(Int64Array::from_slice([1])) as Arc<dyn Array>]);
+    let data = Arc::new(write(&schema, &[chunk])?);
+
+    let metadata = read_file_metadata(&mut Cursor::new(data.as_ref()))?;
+    assert!(mmap(&metadata, data, 1).is_err());
+    Ok(())
+}
@@ -1,4 +1,5 @@
 mod common;
+mod mmap;
 mod read;
 mod write;
 
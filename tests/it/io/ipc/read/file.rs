@@ -1,7 +1,13 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::sync::Arc;
 
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::{FileWriter, WriteOptions};
 
 use super::super::common::read_gzip_json;
 
@@ -182,3 +188,89 @@ fn read_projected() -> Result<()> {
     test_projection("1.0.0-littleendian", "generated_dictionary", 2)?;
     test_projection("1.0.0-littleendian", "generated_nested", 0)
 }
+
+#[test]
+fn read_projected_nested() -> Result<()> {
+    let int = Int32Array::from(&[Some(1), None, Some(3)]);
+    let item = Field::new("item", DataType::Int32, true);
+    let inner_list = DataType::List(Box::new(item));
+    let list = ListArray::<i32>::from_data(
+        DataType::List(Box::new(Field::new("item", inner_list.clone(), true))),
+        vec![0, 1, 1, 3].into(),
+        Arc::new(ListArray::<i32>::from_data(
+            inner_list,
+            vec![0, 2, 2, 3].into(),
+            Arc::new(Int32Array::from(&[Some(1), None, Some(2)])),
+            Some([true, false, true].into()),
+        )),
+        Some([true, false, true].into()),
+    );
+    let struct_ = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Boolean, true),
+        ]),
+        vec![
+            Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("c")])),
+            Arc::new(BooleanArray::from(&[Some(true), Some(false), None])),
+        ],
+        Some([true, true, false].into()),
+    );
+    let utf8 = Utf8Array::<i64>::from([None, Some("bb"), Some("ccc")]);
+    let dictionary = DictionaryArray::<i32>::from_data(
+        PrimitiveArray::from(&[Some(1), Some(0), None]),
+        Arc::new(Utf8Array::<i32>::from_slice(["x", "y"])),
+    );
+
+    let columns: Vec<Arc<dyn Array>> = vec![
+        Arc::new(int),
+        Arc::new(struct_),
+        Arc::new(utf8),
+        Arc::new(list),
+        Arc::new(dictionary),
+    ];
+    let schema = Schema::from(
+        columns
+            .iter()
+            .enumerate()
+            .map(|(i, array)| Field::new(format!("c{}", i), array.data_type().clone(), true))
+            .collect::<Vec<_>>(),
+    );
+    let chunk = Chunk::try_new(columns)?;
+
+    let mut writer =
+        FileWriter::try_new(vec![], &schema, None, WriteOptions { compression: None })?;
+    writer.write(&chunk, None)?;
+    writer.write(&chunk, None)?;
+    writer.finish()?;
+    let data = writer.into_inner();
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, Some(vec![2, 3]));
+
+    assert_eq!(
+        reader.schema().fields,
+        vec![schema.fields[2].clone(), schema.fields[3].clone()]
+    );
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 2);
+    for result in chunks {
+        assert_eq!(result.arrays().len(), 2);
+        assert_eq!(result.arrays()[0], chunk.arrays()[2]);
+        assert_eq!(result.arrays()[1], chunk.arrays()[3]);
+    }
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn read_projected_out_of_bounds() {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let mut writer =
+        FileWriter::try_new(vec![], &schema, None, WriteOptions { compression: None }).unwrap();
+    writer.finish().unwrap();
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_file_metadata(&mut reader).unwrap();
+    FileReader::new(reader, metadata, Some(vec![0, 1]));
+}
@@ -1,7 +1,16 @@
 use std::fs::File;
+use std::io::Cursor;
+use std::sync::Arc;
 
+use arrow_format::ipc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::ipc::write::{schema_to_bytes, write};
+use arrow2::io::ipc::IpcField;
 
 use crate::io::ipc::common::read_gzip_json;
 
@@ -100,3 +109,146 @@ fn read_generated_200_compression_lz4() -> Result<()> {
 fn read_generated_200_compression_zstd() -> Result<()> {
     test_file("2.0.0-compression", "generated_zstd")
 }
+
+/// Writes `array` as the body of an IPC message whose header is built by `header` from its
+/// record batch
+fn write_message<F>(stream: &mut Vec<u8>, array: &dyn Array, header: F)
+where
+    F: FnOnce(ipc::RecordBatch) -> ipc::MessageHeader,
+{
+    let mut buffers = vec![];
+    let mut arrow_data = vec![];
+    let mut nodes = vec![];
+    write(
+        array,
+        &mut buffers,
+        &mut arrow_data,
+        &mut nodes,
+        &mut 0,
+        true,
+        None,
+    )
+    .unwrap();
+    let batch = ipc::RecordBatch {
+        length: array.len() as i64,
+        nodes: Some(nodes),
+        buffers: Some(buffers),
+        compression: None,
+    };
+    let message = ipc::Message {
+        version: ipc::MetadataVersion::V5,
+        header: Some(header(batch)),
+        body_length: arrow_data.len() as i64,
+        custom_metadata: None,
+    };
+    let mut builder = ipc::planus::Builder::new();
+    write_bytes(stream, builder.finish(&message, None), &arrow_data);
+}
+
+fn write_bytes(stream: &mut Vec<u8>, message: &[u8], body: &[u8]) {
+    let padding = (8 - message.len() % 8) % 8;
+    stream.extend_from_slice(&[0xff; 4]);
+    stream.extend_from_slice(&((message.len() + padding) as i32).to_le_bytes());
+    stream.extend_from_slice(message);
+    stream.extend_from_slice(&vec![0; padding]);
+    stream.extend_from_slice(body);
+}
+
+fn write_dictionary_batch(stream: &mut Vec<u8>, values: &dyn Array, is_delta: bool) {
+    write_message(stream, values, |batch| {
+        ipc::MessageHeader::DictionaryBatch(Box::new(ipc::DictionaryBatch {
+            id: 0,
+            data: Some(Box::new(batch)),
+            is_delta,
+        }))
+    })
+}
+
+fn write_record_batch(stream: &mut Vec<u8>, array: &dyn Array) {
+    write_message(stream, array, |batch| {
+        ipc::MessageHeader::RecordBatch(Box::new(batch))
+    })
+}
+
+/// A stream with a single field of dictionary-encoded strings (dictionary id 0)
+fn dictionary_stream() -> Vec<u8> {
+    let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+    let schema = Schema::from(vec![Field::new("a", data_type, true)]);
+    let ipc_fields = vec![IpcField {
+        fields: vec![],
+        dictionary_id: Some(0),
+    }];
+
+    let mut stream = vec![];
+    write_bytes(
+        &mut stream,
+        &schema_to_bytes(&schema, &ipc_fields).unwrap(),
+        &[],
+    );
+    stream
+}
+
+fn keys(keys: &[Option<i32>], values: &[&str]) -> DictionaryArray<i32> {
+    DictionaryArray::from_data(
+        PrimitiveArray::from(keys),
+        Arc::new(Utf8Array::<i32>::from_slice(values)),
+    )
+}
+
+fn read_all(stream: Vec<u8>) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let mut reader = Cursor::new(stream);
+    let metadata = read_stream_metadata(&mut reader)?;
+    StreamReader::new(reader, metadata)
+        .map(|state| state.map(|state| state.unwrap()))
+        .collect()
+}
+
+#[test]
+fn read_delta_dictionary() -> Result<()> {
+    let mut stream = dictionary_stream();
+
+    let first = keys(&[Some(0), Some(1), None], &["a", "b"]);
+    write_dictionary_batch(&mut stream, first.values().as_ref(), false);
+    write_record_batch(&mut stream, &first);
+
+    // appends "c" and "d" to the dictionary
+    let delta = Utf8Array::<i32>::from_slice(["c", "d"]);
+    write_dictionary_batch(&mut stream, &delta, true);
+    let second = keys(&[Some(3), None, Some(0), Some(2)], &["a", "b", "c", "d"]);
+    write_record_batch(&mut stream, &second);
+
+    // replaces the dictionary
+    let third = keys(&[Some(0)], &["e"]);
+    write_dictionary_batch(&mut stream, third.values().as_ref(), false);
+    write_record_batch(&mut stream, &third);
+    // end of stream
+    stream.extend_from_slice(&[0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+
+    let chunks = read_all(stream)?;
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks[0].arrays()[0].as_ref(), &first as &dyn Array);
+    assert_eq!(chunks[1].arrays()[0].as_ref(), &second as &dyn Array);
+    assert_eq!(chunks[2].arrays()[0].as_ref(), &third as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn read_delta_dictionary_without_dictionary() {
+    let mut stream = dictionary_stream();
+    let delta = Utf8Array::<i32>::from_slice(["c"]);
+    write_dictionary_batch(&mut stream, &delta, true);
+    write_record_batch(&mut stream, &keys(&[Some(0)], &["c"]));
+
+    assert!(read_all(stream).is_err());
+}
+
+#[test]
+fn read_record_batch_before_dictionary() {
+    let mut stream = dictionary_stream();
+    let array = keys(&[Some(0)], &["a"]);
+    write_record_batch(&mut stream, &array);
+    write_dictionary_batch(&mut stream, array.values().as_ref(), false);
+
+    let error = read_all(stream).unwrap_err().to_string();
+    assert!(error.contains("Dictionary id 0 not found"), "{}", error);
+}
@@ -1,9 +1,10 @@
+use std::collections::BTreeMap;
 use std::io::Cursor;
 use std::sync::Arc;
 
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
+use arrow2::datatypes::{DataType, Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::{read_file_metadata, FileReader};
 use arrow2::io::ipc::{write::*, IpcField};
@@ -349,6 +350,24 @@ fn write_sliced_utf8() -> Result<()> {
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
 
+#[test]
+fn write_months_days_ns() -> Result<()> {
+    use arrow2::types::months_days_ns;
+    let array = Arc::new(MonthsDaysNsArray::from([
+        Some(months_days_ns::new(1, 1, 2)),
+        None,
+        Some(months_days_ns::new(-1, 2, -3)),
+        Some(months_days_ns::new(i32::MAX, i32::MIN, i64::MAX)),
+    ])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array.clone()])?;
+    round_trip(columns, schema.clone(), None, None)?;
+
+    // sliced
+    let columns = Chunk::try_new(vec![array.slice(1, 3).into()])?;
+    round_trip(columns, schema, None, None)
+}
+
 #[test]
 fn write_sliced_list() -> Result<()> {
     let data = vec![
@@ -365,3 +384,120 @@ fn write_sliced_list() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_uncompressible() -> Result<()> {
+    // buffers that do not compress smaller are written uncompressed, prefixed by `-1`
+    let array = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), false)]);
+    let columns = Chunk::try_new(vec![array])?;
+
+    let mut expected = (-1i64).to_le_bytes().to_vec();
+    expected.extend([1i32, 2, 3].iter().flat_map(|x| x.to_le_bytes()));
+
+    for compression in [Compression::LZ4, Compression::ZSTD] {
+        let result = write_(
+            std::slice::from_ref(&columns),
+            &schema,
+            None,
+            Some(compression),
+        )?;
+        assert!(result.windows(expected.len()).any(|x| x == expected));
+
+        round_trip(columns.clone(), schema.clone(), None, Some(compression))?;
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_compressed_mixed() -> Result<()> {
+    // a compressible and an uncompressible buffer in the same body
+    let a = Arc::new(Int64Array::from_vec(vec![1; 1000])) as Arc<dyn Array>;
+    let mut state = 1u32;
+    let b = (0..1000)
+        .map(|_| {
+            state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+            state
+        })
+        .collect::<Vec<_>>();
+    let b = Arc::new(UInt32Array::from_vec(b)) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), false),
+        Field::new("b", b.data_type().clone(), false),
+    ]);
+    let columns = Chunk::try_new(vec![a, b])?;
+
+    for compression in [Compression::LZ4, Compression::ZSTD] {
+        let result = write_(
+            std::slice::from_ref(&columns),
+            &schema,
+            None,
+            Some(compression),
+        )?;
+        // `a` is compressed and `b` is not
+        assert!(result.len() < 8000);
+        assert!(result.len() > 4000);
+        round_trip(columns.clone(), schema.clone(), None, Some(compression))?;
+    }
+    Ok(())
+}
+
+#[test]
+fn write_field_metadata() -> Result<()> {
+    let metadata = BTreeMap::from([("unit".to_string(), "seconds".to_string())]);
+
+    let array = Int64Array::from_slice([1, 2, 3]);
+    let extension = DataType::Extension("duration".to_string(), Box::new(DataType::Int64), None);
+    let extension_array = Int64Array::from_slice([4, 5, 6]).to(extension.clone());
+    let inner = Field::new("item", DataType::Int64, true).with_metadata(metadata.clone());
+    let list = ListArray::<i32>::from_data(
+        DataType::List(Box::new(inner)),
+        vec![0, 1, 1, 3].into(),
+        Arc::new(array.clone()),
+        None,
+    );
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, true).with_metadata(metadata.clone()),
+        Field::new("b", extension, true).with_metadata(metadata.clone()),
+        Field::new("c", list.data_type().clone(), true),
+    ])
+    .with_metadata(BTreeMap::from([("key".to_string(), "value".to_string())]));
+    let columns = Chunk::try_new(vec![
+        Arc::new(array) as Arc<dyn Array>,
+        Arc::new(extension_array),
+        Arc::new(list),
+    ])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn write_run_end_encoded_errors() {
+    let array = RunEndEncodedArray::<i32>::from_data(
+        Int32Array::from_slice([2, 3]),
+        Arc::new(Int32Array::from_slice([1, 2])),
+    );
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let options = WriteOptions { compression: None };
+
+    let result = FileWriter::try_new(vec![], &schema, None, options);
+    assert!(matches!(
+        result,
+        Err(arrow2::error::ArrowError::NotYetImplemented(_))
+    ));
+}
+
+#[test]
+fn write_utf8_view_errors() {
+    let array = Utf8ViewArray::from([Some("a"), None, Some("a string longer than 12 bytes")]);
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let options = WriteOptions { compression: None };
+
+    let result = FileWriter::try_new(vec![], &schema, None, options);
+    assert!(matches!(
+        result,
+        Err(arrow2::error::ArrowError::NotYetImplemented(_))
+    ));
+}
@@ -226,13 +226,17 @@ fn case_struct() -> (String, Vec<Field>, Vec<Box<dyn Array>>) {
 
     // build expected output
     let d = Utf8Array::<i32>::from(&vec![Some("text"), None, Some("text"), None]);
-    let c = StructArray::from_data(DataType::Struct(vec![d_field]), vec![Arc::new(d)], None);
+    let c = StructArray::from_data(
+        DataType::Struct(vec![d_field]),
+        vec![Arc::new(d)],
+        Some(Bitmap::from([true, false, true, false])),
+    );
 
     let b = BooleanArray::from(vec![Some(true), Some(false), Some(true), None]);
     let expected = StructArray::from_data(
         DataType::Struct(vec![Field::new("b", DataType::Boolean, true), c_field]),
         vec![Arc::new(b), Arc::new(c)],
-        None,
+        Some(Bitmap::from([true, true, true, false])),
     );
 
     (data, fields, vec![Box::new(expected) as Box<dyn Array>])
@@ -268,7 +272,11 @@ fn case_nested_list() -> (String, Vec<Field>, Vec<Box<dyn Array>>) {
         None,
     ]);
 
-    let c = StructArray::from_data(DataType::Struct(vec![d_field]), vec![Arc::new(d)], None);
+    let c = StructArray::from_data(
+        DataType::Struct(vec![d_field]),
+        vec![Arc::new(d)],
+        Some(Bitmap::from([true, true, false, true, true, true])),
+    );
 
     let b = BooleanArray::from(vec![
         Some(true),
@@ -308,3 +316,95 @@ fn case(case: &str) -> (String, Vec<Field>, Vec<Box<dyn Array>>) {
         _ => todo!(),
     }
 }
+
+fn list_of_struct() -> ListArray<i32> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let values = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![
+            Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4), None])),
+            Arc::new(Utf8Array::<i32>::from([
+                Some("a"),
+                Some("b"),
+                None,
+                Some("d"),
+                Some("e"),
+            ])),
+        ],
+        Some(Bitmap::from([true, true, true, false, true])),
+    );
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Struct(fields), true)));
+    // the null list (row 2) spans a value
+    ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 2, 2, 3, 5, 5]),
+        Arc::new(values),
+        Some(Bitmap::from([true, true, false, true, true])),
+    )
+}
+
+#[test]
+fn round_trip_list_of_struct() -> Result<()> {
+    let array = list_of_struct();
+    let field = Field::new("c", array.data_type().clone(), true);
+
+    let buf = write_batch(
+        Chunk::new(vec![Arc::new(array.clone()) as Arc<dyn Array>]),
+        vec!["c".to_string()],
+        json_write::LineDelimited::default(),
+    )?;
+    let data = String::from_utf8(buf).unwrap();
+    assert_eq!(
+        data,
+        r#"{"c":[{"a":1,"b":"a"},{"a":null,"b":"b"}]}
+{"c":[]}
+{"c":null}
+{"c":[null,{"a":null,"b":"e"}]}
+{"c":[]}
+"#
+    );
+
+    let result = read_batch(data, &[field])?;
+    // the values of null slots are not serialized
+    let expected = ListArray::<i32>::from_data(
+        array.data_type().clone(),
+        Buffer::from_slice([0, 2, 2, 2, 4, 4]),
+        Arc::new(StructArray::from_data(
+            array.values().data_type().clone(),
+            vec![
+                Arc::new(Int32Array::from([Some(1), None, None, None])),
+                Arc::new(Utf8Array::<i32>::from([
+                    Some("a"),
+                    Some("b"),
+                    None,
+                    Some("e"),
+                ])),
+            ],
+            Some(Bitmap::from([true, true, false, true])),
+        )),
+        array.validity().cloned(),
+    );
+    assert_eq!(result.arrays()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn write_sliced_list_of_struct() -> Result<()> {
+    let array = list_of_struct().slice(2, 2);
+
+    let buf = write_batch(
+        Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]),
+        vec!["c".to_string()],
+        json_write::LineDelimited::default(),
+    )?;
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"c":null}
+{"c":[null,{"a":null,"b":"e"}]}
+"#
+    );
+    Ok(())
+}
@@ -209,3 +209,203 @@ fn read_json() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn infer_iter_merges() -> Result<()> {
+    let data = r#"{"a":1, "b":null, "c":[1], "d":true}
+
+    {"a":2.5, "b":"text", "c":[2.5], "d":null}
+    {"a":3, "b":null, "c":null, "d":false}
+    {"a":"text", "b":1, "c":true, "d":"text"}
+    "#;
+
+    let (fields, num_lines) = read::infer_iter(&mut Cursor::new(data), Some(3))?;
+    // the empty line is not sampled
+    assert_eq!(num_lines, 3);
+    // fields are ordered by their first non-null value
+    let expected = vec![
+        Field::new("a", DataType::Float64, true),
+        Field::new(
+            "c",
+            DataType::List(Box::new(Field::new("item", DataType::Float64, true))),
+            true,
+        ),
+        Field::new("d", DataType::Boolean, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    assert_eq!(fields, expected);
+
+    // the last line is conflicting
+    let (fields, num_lines) = read::infer_iter(&mut Cursor::new(data), None)?;
+    assert_eq!(num_lines, 4);
+    let expected = vec![
+        Field::new("a", DataType::Utf8, true),
+        Field::new("c", DataType::Utf8, true),
+        Field::new("d", DataType::Utf8, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    assert_eq!(fields, expected);
+    Ok(())
+}
+
+#[test]
+fn infer_iter_empty() -> Result<()> {
+    let (fields, num_lines) = read::infer_iter(&mut Cursor::new("\n\n"), Some(10))?;
+    assert!(fields.is_empty());
+    assert_eq!(num_lines, 0);
+    Ok(())
+}
+
+#[test]
+fn deserialize_to_schema() -> Result<()> {
+    let rows = [
+        r#"{"a": 1, "b": 1.5, "c": "2021-01-02 03:04:05", "d": {"e": [1, 2]}, "z": "ignored"}"#,
+        r#"{"a": null, "b": 2, "d": null}"#,
+        r#"{"a": -3, "c": "2021-01-03 00:00:00", "d": {"e": null}}"#,
+    ];
+    let e_field = Field::new(
+        "e",
+        DataType::List(Box::new(Field::new("item", DataType::Int16, true))),
+        true,
+    );
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int8, true),
+        Field::new("b", DataType::Float32, true),
+        Field::new("c", DataType::Timestamp(TimeUnit::Second, None), true),
+        Field::new("d", DataType::Struct(vec![e_field.clone()]), true),
+    ]);
+
+    let options = read::DeserializeOptions {
+        format: Some("%Y-%m-%d %H:%M:%S".to_string()),
+    };
+    let chunk = read::deserialize_to(&rows, &schema, &options)?;
+
+    let a = Int8Array::from([Some(1), None, Some(-3)]);
+    let b = Float32Array::from([Some(1.5), Some(2.0), None]);
+    let c = Int64Array::from([Some(1609556645), None, Some(1609632000)])
+        .to(DataType::Timestamp(TimeUnit::Second, None));
+    let e = ListArray::<i32>::from_data(
+        e_field.data_type().clone(),
+        vec![0, 2, 2, 2].into(),
+        Arc::new(Int16Array::from_slice([1, 2])),
+        Some([true, false, false].into()),
+    );
+    let d = StructArray::from_data(
+        DataType::Struct(vec![e_field]),
+        vec![Arc::new(e)],
+        Some([true, false, true].into()),
+    );
+
+    assert_eq!(chunk.columns().len(), 4);
+    assert_eq!(chunk.columns()[0].as_ref(), &a as &dyn Array);
+    assert_eq!(chunk.columns()[1].as_ref(), &b as &dyn Array);
+    assert_eq!(chunk.columns()[2].as_ref(), &c as &dyn Array);
+    assert_eq!(chunk.columns()[3].as_ref(), &d as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn deserialize_to_cannot_coerce() {
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        DataType::Struct(vec![Field::new("b", DataType::UInt8, true)]),
+        true,
+    )]);
+
+    for (row, value) in [
+        (r#"{"a": {"b": 256}}"#, "256"),
+        (r#"{"a": {"b": 1.5}}"#, "1.5"),
+        (r#"{"a": {"b": "1"}}"#, "\"1\""),
+    ] {
+        let error = read::deserialize_to(&[row], &schema, &Default::default()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "External format error: Cannot coerce the JSON value {} of field \"a.b\" to UInt8",
+                value
+            )
+        );
+    }
+
+    let options = Default::default();
+    assert!(read::deserialize_to(&[r#"{"a": 1}"#], &schema, &options).is_err());
+    assert!(read::deserialize_to(&["[1]"], &schema, &options).is_err());
+}
+
+#[test]
+fn deserialize_to_timestamps() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        Field::new(
+            "b",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string())),
+            true,
+        ),
+    ]);
+    let options = read::DeserializeOptions {
+        format: Some("%d/%m/%Y %H:%M %z".to_string()),
+    };
+    let rows = [r#"{"a": "01/01/1970 00:00 +0000", "b": "01/01/1970 01:00 +0100"}"#];
+    let chunk = read::deserialize_to(&rows, &schema, &options)?;
+    let a = Int64Array::from_slice([0]).to(schema.fields[0].data_type().clone());
+    let b = Int64Array::from_slice([0]).to(schema.fields[1].data_type().clone());
+    assert_eq!(chunk.columns()[0].as_ref(), &a as &dyn Array);
+    assert_eq!(chunk.columns()[1].as_ref(), &b as &dyn Array);
+
+    // out of the range of nanoseconds
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        DataType::Timestamp(TimeUnit::Nanosecond, None),
+        true,
+    )]);
+    let rows = [r#"{"a": "2300-01-01T00:00:00"}"#];
+    assert!(read::deserialize_to(&rows, &schema, &Default::default()).is_err());
+    let options = read::DeserializeOptions {
+        format: Some("%Y-%m-%dT%H:%M:%S".to_string()),
+    };
+    assert!(read::deserialize_to(&rows, &schema, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn infer_timestamps() -> Result<()> {
+    let data = r#"{"a": "2021-01-01T00:00:00+05:00", "b": "2021-01-01T00:00:00.5", "c": "2021-01-01"}
+{"a": "2021-01-01T00:00:00.123456789Z", "b": "2021-01-01 10:00:00", "c": "text"}
+{"a": "1969-12-31T23:59:59.9999999-00:30", "b": null, "c": null}
+"#;
+    let fields = read::infer(&mut Cursor::new(data), None)?;
+    let tz_type = DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string()));
+    let naive_type = DataType::Timestamp(TimeUnit::Microsecond, None);
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("a", tz_type.clone(), true),
+            Field::new("b", naive_type.clone(), true),
+            Field::new("c", DataType::Utf8, true),
+        ]
+    );
+
+    let rows = data.lines().collect::<Vec<_>>();
+    let chunk = read::deserialize(&rows, &fields)?;
+
+    // offsets are normalized to UTC and sub-microseconds are truncated (towards the past)
+    let expected =
+        Int64Array::from_slice([1609441200000000, 1609459200123456, 1800000000 - 1]).to(tz_type);
+    assert_eq!(chunk.columns()[0].as_ref(), &expected as &dyn Array);
+
+    let expected =
+        Int64Array::from([Some(1609459200500000), Some(1609495200000000), None]).to(naive_type);
+    assert_eq!(chunk.columns()[1].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn infer_timestamps_mixed() -> Result<()> {
+    // timestamps with and without offset are not compatible
+    let data = r#"{"a": "2021-01-01T00:00:00+05:00"}
+{"a": "2021-01-01T00:00:00"}
+"#;
+    let fields = read::infer(&mut Cursor::new(data), None)?;
+    assert_eq!(fields, vec![Field::new("a", DataType::Utf8, true)]);
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+
+fn round_trip(schema: Schema) -> Result<()> {
+    let json = schema.to_json();
+    // through a string, as exchanged with other tools
+    let json = serde_json::from_str(&json.to_string()).unwrap();
+    let result = Schema::from_json(&json)?;
+    assert_eq!(result, schema);
+    Ok(())
+}
+
+fn metadata(key: &str, value: &str) -> BTreeMap<String, String> {
+    let mut metadata = BTreeMap::new();
+    metadata.insert(key.to_string(), value.to_string());
+    metadata
+}
+
+#[test]
+fn nested_struct() -> Result<()> {
+    let inner = DataType::Struct(vec![
+        Field::new("c", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+        Field::new(
+            "d",
+            DataType::List(Box::new(Field::new("item", DataType::Utf8, false))),
+            true,
+        )
+        .with_metadata(metadata("inner", "1")),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new(
+            "a",
+            DataType::Struct(vec![
+                Field::new("b", DataType::Int64, false),
+                Field::new("inner", inner, true),
+            ]),
+            false,
+        )
+        .with_metadata(metadata("key", "value")),
+        Field::new("e", DataType::Decimal(10, 2), true),
+    ])
+    .with_metadata(metadata("schema", "metadata"));
+
+    round_trip(schema)
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new(
+            "a",
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+            true,
+        ),
+        Field::new(
+            "b",
+            DataType::List(Box::new(Field::new(
+                "item",
+                DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Int64), true),
+                false,
+            ))),
+            false,
+        )
+        .with_metadata(metadata("key", "value")),
+    ]);
+
+    let json = schema.to_json();
+    assert_eq!(json["fields"][0]["dictionary"]["id"], 0);
+    assert_eq!(json["fields"][1]["children"][0]["dictionary"]["id"], 1);
+    assert_eq!(json["fields"][1]["nullable"], false);
+
+    round_trip(schema)
+}
+
+#[test]
+fn invalid() {
+    let json = serde_json::json!({"fields": 1});
+    assert!(Schema::from_json(&json).is_err());
+}
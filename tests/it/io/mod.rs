@@ -4,15 +4,27 @@ mod print;
 #[cfg(feature = "io_json")]
 mod json;
 
+#[cfg(feature = "io_json")]
+mod ndjson;
+
 #[cfg(feature = "io_ipc")]
 mod ipc;
 
+#[cfg(feature = "io_json_integration")]
+mod json_integration;
+
+#[cfg(feature = "io_flight")]
+mod flight;
+
 #[cfg(feature = "io_parquet")]
 mod parquet;
 
 #[cfg(feature = "io_avro")]
 mod avro;
 
+#[cfg(feature = "io_orc")]
+mod orc;
+
 #[cfg(any(
     feature = "io_csv_read",
     feature = "io_csv_write",
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::ndjson::write::NdJsonWriter;
+
+#[test]
+fn write_chunks() -> Result<()> {
+    let list_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let struct_type = DataType::Struct(vec![Field::new("d", DataType::Utf8, true)]);
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", list_type.clone(), true),
+        Field::new("c", struct_type.clone(), true),
+    ]);
+
+    let mut writer = NdJsonWriter::new(vec![], schema);
+
+    let b = ListArray::<i32>::from_data(
+        list_type.clone(),
+        vec![0, 2, 2].into(),
+        Arc::new(Int32Array::from([Some(1), None])),
+        Some([true, false].into()),
+    );
+    let c = StructArray::from_data(
+        struct_type.clone(),
+        vec![Arc::new(Utf8Array::<i32>::from([Some("x"), None]))],
+        None,
+    );
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>,
+        Arc::new(b),
+        Arc::new(c),
+    ]);
+    writer.write_chunk(&chunk)?;
+
+    let b = ListArray::<i32>::from_data(
+        list_type,
+        vec![0, 1].into(),
+        Arc::new(Int32Array::from_slice([3])),
+        None,
+    );
+    let c = StructArray::from_data(
+        struct_type,
+        vec![Arc::new(Utf8Array::<i32>::from_slice(["y"]))],
+        Some([false].into()),
+    );
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([3])) as Arc<dyn Array>,
+        Arc::new(b),
+        Arc::new(c),
+    ]);
+    writer.write_chunk(&chunk)?;
+
+    let result = String::from_utf8(writer.into_inner()?).unwrap();
+    assert_eq!(
+        result,
+        r#"{"a":1,"b":[1,null],"c":{"d":"x"}}
+{"a":null,"b":null,"c":{"d":null}}
+{"a":3,"b":[3],"c":null}
+"#
+    );
+
+    let lines = result.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    for line in lines {
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert!(value.is_object());
+    }
+    Ok(())
+}
+
+#[test]
+fn write_mismatched_chunk() {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let mut writer = NdJsonWriter::new(vec![], schema);
+
+    let chunk = Chunk::new(vec![Arc::new(Int64Array::from_slice([1])) as Arc<dyn Array>]);
+    assert!(writer.write_chunk(&chunk).is_err());
+
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1])) as Arc<dyn Array>,
+        Arc::new(Int32Array::from_slice([1])),
+    ]);
+    assert!(writer.write_chunk(&chunk).is_err());
+    assert!(writer.into_inner().unwrap().is_empty());
+}
@@ -0,0 +1,112 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+use arrow2::io::orc::read;
+
+fn deserialize_column(path: &str, name: &str) -> Result<Arc<dyn Array>> {
+    let mut reader = File::open(path).unwrap();
+    let metadata = read::read_metadata(&mut reader)?;
+    let schema = read::infer_schema(&metadata.footer)?;
+
+    let (pos, field) = schema
+        .fields
+        .iter()
+        .enumerate()
+        .find(|f| f.1.name == name)
+        .unwrap();
+
+    let footer = read::read_stripe_footer(&mut reader, &metadata, 0, &mut vec![])?;
+    // column 0 is the root struct
+    let column =
+        read::read_stripe_column(&mut reader, &metadata, 0, footer, 1 + pos as u32, vec![])?;
+
+    read::deserialize(field.data_type.clone(), &column)
+}
+
+fn test_columns(path: &str) -> Result<()> {
+    assert_eq!(
+        deserialize_column(path, "float_nullable")?.as_ref(),
+        &Float32Array::from([Some(1.0), Some(2.0), None, Some(4.0), Some(5.0)]) as &dyn Array
+    );
+    assert_eq!(
+        deserialize_column(path, "double_nullable")?.as_ref(),
+        &Float64Array::from([Some(1.0), Some(2.0), None, Some(4.0), Some(5.0)]) as &dyn Array
+    );
+    assert_eq!(
+        deserialize_column(path, "bool_nullable")?.as_ref(),
+        &BooleanArray::from([Some(true), Some(false), None, Some(true), Some(false)]) as &dyn Array
+    );
+    assert_eq!(
+        deserialize_column(path, "int_nullable")?.as_ref(),
+        &Int32Array::from([Some(5), Some(-5), None, Some(1), Some(5)]) as &dyn Array
+    );
+    assert_eq!(
+        deserialize_column(path, "bigint_nullable")?.as_ref(),
+        &Int64Array::from([Some(1), Some(-6), None, Some(3_000_000_000), Some(2)]) as &dyn Array
+    );
+    assert_eq!(
+        deserialize_column(path, "utf8_nullable")?.as_ref(),
+        &Utf8Array::<i32>::from([Some("a"), Some("cccccc"), None, Some("ddd"), Some("a")])
+            as &dyn Array
+    );
+    assert_eq!(
+        deserialize_column(path, "int_required")?.as_ref(),
+        &Int32Array::from_slice([1, 2, 3, 4, 5]) as &dyn Array
+    );
+    assert_eq!(
+        deserialize_column(path, "tinyint_nullable")?.as_ref(),
+        &Int8Array::from([Some(1), Some(-1), None, Some(127), Some(-128)]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn read_uncompressed() -> Result<()> {
+    test_columns("fixtures/pyorc/test.orc")
+}
+
+#[test]
+fn read_zlib() -> Result<()> {
+    test_columns("fixtures/pyorc/test_zlib.orc")
+}
+
+#[test]
+fn infer_schema() -> Result<()> {
+    let mut reader = File::open("fixtures/pyorc/test.orc").unwrap();
+    let metadata = read::read_metadata(&mut reader)?;
+    let schema = read::infer_schema(&metadata.footer)?;
+
+    let data_types = schema
+        .fields
+        .iter()
+        .map(|f| f.data_type().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        data_types,
+        vec![
+            DataType::Float32,
+            DataType::Float64,
+            DataType::Boolean,
+            DataType::Int32,
+            DataType::Int64,
+            DataType::Utf8,
+            DataType::Int32,
+            DataType::Int8
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize_unsupported() -> Result<()> {
+    let mut reader = File::open("fixtures/pyorc/test.orc").unwrap();
+    let metadata = read::read_metadata(&mut reader)?;
+    let footer = read::read_stripe_footer(&mut reader, &metadata, 0, &mut vec![])?;
+    let column = read::read_stripe_column(&mut reader, &metadata, 0, footer, 1, vec![])?;
+
+    assert!(read::deserialize(DataType::Date32, &column).is_err());
+    Ok(())
+}
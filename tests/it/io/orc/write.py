@@ -0,0 +1,40 @@
+import os
+
+import pyorc
+
+
+data = {
+    "float_nullable": [1.0, 2.0, None, 4.0, 5.0],
+    "double_nullable": [1.0, 2.0, None, 4.0, 5.0],
+    "bool_nullable": [True, False, None, True, False],
+    "int_nullable": [5, -5, None, 1, 5],
+    "bigint_nullable": [1, -6, None, 3000000000, 2],
+    "utf8_nullable": ["a", "cccccc", None, "ddd", "a"],
+    "int_required": [1, 2, 3, 4, 5],
+    "tinyint_nullable": [1, -1, None, 127, -128],
+}
+
+schema = (
+    "struct<float_nullable:float,double_nullable:double,bool_nullable:boolean,"
+    "int_nullable:int,bigint_nullable:bigint,utf8_nullable:string,int_required:int,"
+    "tinyint_nullable:tinyint>"
+)
+
+
+def _write(file_name: str, compression, dict_key_size_threshold: float):
+    with open(file_name, "wb") as output:
+        writer = pyorc.Writer(
+            output,
+            schema,
+            compression=compression,
+            # 0.0 writes strings directly, 1.0 dictionary-encodes them
+            dict_key_size_threshold=dict_key_size_threshold,
+        )
+        for row in zip(*data.values()):
+            writer.write(row)
+        writer.close()
+
+
+os.makedirs("fixtures/pyorc", exist_ok=True)
+_write("fixtures/pyorc/test.orc", pyorc.CompressionKind.NONE, 0.0)
+_write("fixtures/pyorc/test_zlib.orc", pyorc.CompressionKind.ZLIB, 1.0)
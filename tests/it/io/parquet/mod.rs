@@ -9,6 +9,8 @@ use arrow2::{
 use crate::io::ipc::read_gzip_json;
 
 mod read;
+#[cfg(feature = "io_parquet_async")]
+mod read_async;
 mod write;
 
 type ArrayStats = (Arc<dyn Array>, Option<Box<dyn Statistics>>);
@@ -436,10 +438,13 @@ pub fn pyarrow_required(column: usize) -> Box<dyn Array> {
 
     match column {
         0 => Box::new(PrimitiveArray::<i64>::from(i64_values)),
-        3 => Box::new(BooleanArray::from_slice(&[
+        1 => Box::new(PrimitiveArray::<f64>::from_slice([
+            0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0,
+        ])),
+        3 => Box::new(BooleanArray::from_slice([
             true, true, false, false, false, true, true, true, true, true,
         ])),
-        2 => Box::new(Utf8Array::<i32>::from_slice(&[
+        2 => Box::new(Utf8Array::<i32>::from_slice([
             "Hello", "bbb", "aa", "", "bbb", "abc", "bbb", "bbb", "def", "aaa",
         ])),
         // decimal 9
@@ -479,6 +484,13 @@ pub fn pyarrow_required_statistics(column: usize) -> Option<Box<dyn Statistics>>
             min_value: Some(0),
             max_value: Some(9),
         }),
+        1 => Box::new(PrimitiveStatistics::<f64> {
+            data_type: DataType::Float64,
+            null_count: Some(0),
+            distinct_count: None,
+            min_value: Some(0.0),
+            max_value: Some(9.0),
+        }),
         3 => Box::new(BooleanStatistics {
             null_count: Some(0),
             distinct_count: None,
@@ -519,8 +531,19 @@ pub fn pyarrow_required_statistics(column: usize) -> Option<Box<dyn Statistics>>
     })
 }
 
+/// Wraps `values` in [`ListStatistics`] for each list level of `data_type`
+fn wrap_list_statistics(data_type: &DataType, values: Box<dyn Statistics>) -> Box<dyn Statistics> {
+    match data_type {
+        DataType::List(inner) | DataType::LargeList(inner) => Box::new(ListStatistics {
+            data_type: data_type.clone(),
+            values: wrap_list_statistics(&inner.data_type, values),
+        }),
+        _ => values,
+    }
+}
+
 pub fn pyarrow_nested_nullable_statistics(column: usize) -> Option<Box<dyn Statistics>> {
-    Some(match column {
+    let values: Box<dyn Statistics> = match column {
         3 => Box::new(PrimitiveStatistics::<i16> {
             data_type: DataType::Int16,
             distinct_count: None,
@@ -553,7 +576,11 @@ pub fn pyarrow_nested_nullable_statistics(column: usize) -> Option<Box<dyn Stati
             min_value: Some(0),
             max_value: Some(9),
         }),
-    })
+    };
+    Some(wrap_list_statistics(
+        pyarrow_nested_nullable(column).data_type(),
+        values,
+    ))
 }
 
 pub fn pyarrow_struct(column: usize) -> Box<dyn Array> {
@@ -638,6 +665,8 @@ fn integration_write(schema: &Schema, batches: &[Chunk<Arc<dyn Array>>]) -> Resu
         write_statistics: true,
         compression: Compression::Uncompressed,
         version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
     };
 
     let encodings = schema
@@ -652,8 +681,12 @@ fn integration_write(schema: &Schema, batches: &[Chunk<Arc<dyn Array>>]) -> Resu
         })
         .collect();
 
-    let row_groups =
-        RowGroupIterator::try_new(batches.iter().cloned().map(Ok), schema, options, encodings)?;
+    let row_groups = RowGroupIterator::try_new(
+        batches.iter().cloned().map(Ok),
+        schema,
+        options.clone(),
+        encodings,
+    )?;
 
     let writer = Cursor::new(vec![]);
 
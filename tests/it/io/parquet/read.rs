@@ -54,6 +54,72 @@ fn test_pyarrow_integration(
     Ok(())
 }
 
+fn test_pyarrow_byte_stream_split(version: usize, required: bool) -> Result<()> {
+    if std::env::var("ARROW2_IGNORE_PARQUET").is_ok() {
+        return Ok(());
+    }
+    let required_str = if required { "required" } else { "nullable" };
+    let path = format!(
+        "fixtures/pyarrow3/v{}/byte_stream_split/basic_{}_10.parquet",
+        version, required_str
+    );
+
+    // the float64 column is the one encoded with `BYTE_STREAM_SPLIT`
+    let column = 1;
+    let mut file = File::open(path).unwrap();
+    let (array, statistics) = read_column(&mut file, 0, column)?;
+
+    let (expected, expected_statistics) = if required {
+        (
+            pyarrow_required(column),
+            pyarrow_required_statistics(column),
+        )
+    } else {
+        (
+            pyarrow_nullable(column),
+            pyarrow_nullable_statistics(column),
+        )
+    };
+
+    assert_eq!(expected.as_ref(), array.as_ref());
+    assert_eq!(expected_statistics, statistics);
+
+    Ok(())
+}
+
+fn test_pyarrow_delta_binary_packed(version: usize, required: bool) -> Result<()> {
+    if std::env::var("ARROW2_IGNORE_PARQUET").is_ok() {
+        return Ok(());
+    }
+    let required_str = if required { "required" } else { "nullable" };
+    let path = format!(
+        "fixtures/pyarrow3/v{}/delta_binary_packed/basic_{}_10.parquet",
+        version, required_str
+    );
+
+    // the int64 column is the one encoded with `DELTA_BINARY_PACKED`
+    let column = 0;
+    let mut file = File::open(path).unwrap();
+    let (array, statistics) = read_column(&mut file, 0, column)?;
+
+    let (expected, expected_statistics) = if required {
+        (
+            pyarrow_required(column),
+            pyarrow_required_statistics(column),
+        )
+    } else {
+        (
+            pyarrow_nullable(column),
+            pyarrow_nullable_statistics(column),
+        )
+    };
+
+    assert_eq!(expected.as_ref(), array.as_ref());
+    assert_eq!(expected_statistics, statistics);
+
+    Ok(())
+}
+
 #[test]
 fn v1_int64_nullable() -> Result<()> {
     test_pyarrow_integration(0, 1, "basic", false, false, None)
@@ -365,6 +431,46 @@ fn v2_decimal_26_required_dict() -> Result<()> {
     test_pyarrow_integration(8, 2, "basic", true, true, None)
 }
 
+#[test]
+fn v1_float64_nullable_byte_stream_split() -> Result<()> {
+    test_pyarrow_byte_stream_split(1, false)
+}
+
+#[test]
+fn v1_float64_required_byte_stream_split() -> Result<()> {
+    test_pyarrow_byte_stream_split(1, true)
+}
+
+#[test]
+fn v2_float64_nullable_byte_stream_split() -> Result<()> {
+    test_pyarrow_byte_stream_split(2, false)
+}
+
+#[test]
+fn v2_float64_required_byte_stream_split() -> Result<()> {
+    test_pyarrow_byte_stream_split(2, true)
+}
+
+#[test]
+fn v1_int64_nullable_delta_binary_packed() -> Result<()> {
+    test_pyarrow_delta_binary_packed(1, false)
+}
+
+#[test]
+fn v1_int64_required_delta_binary_packed() -> Result<()> {
+    test_pyarrow_delta_binary_packed(1, true)
+}
+
+#[test]
+fn v2_int64_nullable_delta_binary_packed() -> Result<()> {
+    test_pyarrow_delta_binary_packed(2, false)
+}
+
+#[test]
+fn v2_int64_required_delta_binary_packed() -> Result<()> {
+    test_pyarrow_delta_binary_packed(2, true)
+}
+
 #[test]
 fn v1_struct_optional() -> Result<()> {
     test_pyarrow_integration(0, 1, "struct", false, false, None)
@@ -488,3 +594,620 @@ fn invalid_utf8() {
         error
     );
 }
+
+#[test]
+fn indexes_absent() -> Result<()> {
+    let array = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a1", DataType::Int32, false)]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_metadata(&mut reader)?;
+
+    let indexes = read_columns_indexes(&mut reader, &metadata.row_groups[0])?;
+    assert_eq!(indexes.len(), 1);
+    assert!(indexes[0].is_none());
+    Ok(())
+}
+
+#[test]
+fn indexes_negative_offset() -> Result<()> {
+    use parquet2::metadata::{ColumnChunkMetaData, RowGroupMetaData};
+
+    let array = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a1", DataType::Int32, false)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+
+    // a page index with a negative offset
+    let column = row_group.columns()[0].clone();
+    let descriptor = column.descriptor().clone();
+    let mut chunk = column.into_thrift();
+    chunk.column_index_offset = Some(-1);
+    chunk.column_index_length = Some(10);
+    chunk.offset_index_offset = Some(4);
+    chunk.offset_index_length = Some(10);
+    let column = ColumnChunkMetaData::try_from_thrift(descriptor, chunk)?;
+    let row_group = RowGroupMetaData::new(
+        vec![column],
+        row_group.num_rows(),
+        row_group.total_byte_size(),
+    );
+
+    let error = read_columns_indexes(&mut reader, &row_group).unwrap_err();
+    assert!(matches!(error, ArrowError::OutOfSpec(_)));
+    Ok(())
+}
+
+#[test]
+fn indexes_tiny_pages() -> Result<()> {
+    let path = "testing/parquet-testing/data/alltypes_tiny_pages.parquet";
+    let mut reader = std::fs::File::open(path)?;
+
+    let metadata = read_metadata(&mut reader)?;
+    let row_group = &metadata.row_groups[0];
+
+    let indexes = read_columns_indexes(&mut reader, row_group)?;
+    assert_eq!(indexes.len(), row_group.columns().len());
+
+    for index in indexes {
+        let index = index.unwrap();
+        assert!(!index.statistics.is_empty());
+        assert_eq!(index.statistics.len(), index.locations.len());
+        assert_eq!(index.locations[0].first_row_index, 0);
+        // locations are ordered by offset and by first row
+        index.locations.windows(2).for_each(|x| {
+            assert!(x[0].offset < x[1].offset);
+            assert!(x[0].first_row_index < x[1].first_row_index);
+        });
+    }
+    Ok(())
+}
+
+#[test]
+fn read_columns_parallel() -> Result<()> {
+    let num_columns = 16;
+    let arrays = (0..num_columns)
+        .map(|i| Arc::new(Int64Array::from_slice([i, i + 1, i + 2])) as Arc<dyn Array>)
+        .collect::<Vec<_>>();
+    let schema = Schema::from(
+        (0..num_columns)
+            .map(|i| Field::new(format!("c{}", i), DataType::Int64, false))
+            .collect::<Vec<_>>(),
+    );
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(arrays.clone())];
+    let encodings = vec![Encoding::Plain; num_columns as usize];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options.clone(), encodings)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+
+    let mut reader = Cursor::new(writer.into_inner());
+    let metadata = read_metadata(&mut reader)?;
+
+    // tasks spawned first finish last
+    let spawned = std::sync::atomic::AtomicU64::new(0);
+    let spawn = |task: Box<dyn FnOnce() + Send>| {
+        let delay = num_columns as u64 - spawned.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(delay));
+            task()
+        });
+    };
+
+    // project to all columns but the first, in reverse order
+    let fields = schema.fields[1..].iter().rev().cloned().collect::<Vec<_>>();
+    let result = read_columns_many_parallel(&mut reader, &metadata.row_groups[0], fields, spawn)?;
+
+    let expected = arrays[1..].iter().rev().cloned().collect::<Vec<_>>();
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn filter_row_groups() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    // 4 row groups whose column "a" has values in [10 * i, 10 * i + 2]
+    let chunks = (0..4i64)
+        .map(|i| {
+            Chunk::try_new(vec![
+                Arc::new(Int64Array::from_slice([10 * i, 10 * i + 1, 10 * i + 2]))
+                    as Arc<dyn Array>,
+                Arc::new(Utf8Array::<i32>::from_slice([
+                    i.to_string(),
+                    i.to_string(),
+                    i.to_string(),
+                ])) as Arc<dyn Array>,
+            ])
+        })
+        .collect::<Vec<_>>();
+    let row_groups = RowGroupIterator::try_new(
+        chunks.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain, Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+
+    // project to "b" while filtering on the statistics of "a"
+    let field = schema.fields[0].clone();
+    let mut reader = FileReader::try_new(Cursor::new(data), Some(&[1]), None, None, None)?;
+    reader.filter_row_groups(move |row_group| {
+        let statistics = deserialize_statistics(&field, row_group.columns())
+            .unwrap()
+            .pop()
+            .unwrap()
+            .unwrap();
+        let statistics = statistics
+            .as_any()
+            .downcast_ref::<PrimitiveStatistics<i64>>()
+            .unwrap();
+        statistics.max_value.unwrap() >= 25
+    });
+
+    let chunks = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 1);
+    let expected = Utf8Array::<i32>::from_slice(["3", "3", "3"]);
+    assert_eq!(chunks[0].arrays(), &[Arc::new(expected) as Arc<dyn Array>]);
+    Ok(())
+}
+
+/// Returns a file with a single column chunk declared as snappy-compressed, with two
+/// `DataPageV2`: the first with `is_compressed = false` (i.e. all its bytes are stored as-is),
+/// and the second with uncompressed levels and compressed values (as written by e.g. Spark).
+fn v2_partially_compressed_pages(first: &Int64Array, second: &Int64Array) -> Result<Vec<u8>> {
+    let num_rows = first.len() + second.len();
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+
+    let page = match array_to_page(first, descriptor.clone(), options.clone(), Encoding::Plain)? {
+        EncodedPage::Data(page) => page,
+        _ => unreachable!(),
+    };
+    let header = match page.header() {
+        DataPageHeader::V2(header) => {
+            let mut header = header.clone();
+            header.is_compressed = Some(false);
+            DataPageHeader::V2(header)
+        }
+        _ => unreachable!(),
+    };
+    let first = CompressedPage::Data(CompressedDataPage::new(
+        header,
+        page.buffer().to_vec(),
+        options.compression,
+        page.buffer().len(),
+        None,
+        descriptor.clone(),
+    ));
+
+    let page = array_to_page(second, descriptor, options.clone(), Encoding::Plain)?;
+    let second = compress(page, vec![], options.compression)?;
+
+    let pages = [first, second];
+    let pages = fallible_streaming_iterator::convert(pages.iter().map(Ok));
+    let columns = std::iter::once(Ok(DynStreamingIterator::new(pages)));
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    writer.write(DynIter::new(columns), num_rows)?;
+    let (_, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn v2_uncompressed_levels() -> Result<()> {
+    let first = Int64Array::from([Some(1), None, Some(3)]);
+    let second = Int64Array::from([None, Some(5), Some(6), None]);
+    let data = v2_partially_compressed_pages(&first, &second)?;
+
+    let (array, _) = read_column(&mut Cursor::new(data), 0, 0)?;
+
+    let expected = Int64Array::from([Some(1), None, Some(3), None, Some(5), Some(6), None]);
+    assert_eq!(array.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn verify_crc() -> Result<()> {
+    let array = Arc::new(Int64Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", DataType::Int64, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: true,
+        bloom_filters: None,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Chunk::try_new(vec![array.clone()])].into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (size, writer) = writer.end(None)?;
+    let mut data = writer.into_inner();
+    assert_eq!(size, data.len() as u64);
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    let column = metadata.row_groups[0].column(0).clone();
+    verify_column_chunk_crc(&mut reader, &column)?;
+    let (result, _) = read_column(&mut reader, 0, 0)?;
+    assert_eq!(result, array);
+
+    // flip a byte of the page's values (the last bytes of the pages of the column chunk)
+    let (start, length) = column.byte_range();
+    let index = (start + length) as usize - 1;
+    data[index] ^= 1;
+
+    let result = verify_column_chunk_crc(&mut Cursor::new(&data), &column);
+    assert!(matches!(result, Err(ArrowError::OutOfSpec(_))));
+    Ok(())
+}
+
+#[test]
+fn crc_round_trip() -> Result<()> {
+    let a = Arc::new(Int64Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from([Some("a"), Some("bb"), None])) as Arc<dyn Array>;
+    let c = Arc::new(DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice([0, 1, 0]),
+        Arc::new(Utf8Array::<i32>::from_slice(["x", "y"])),
+    )) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+        Field::new("c", c.data_type().clone(), true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+        write_crc: true,
+        bloom_filters: None,
+    };
+    let chunk = Chunk::try_new(vec![a.clone(), b.clone(), c.clone()])?;
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone()), Ok(chunk)].into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain, Encoding::Plain, Encoding::RleDictionary],
+    )?;
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (size, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+    assert_eq!(size, data.len() as u64);
+
+    let mut reader = Cursor::new(&data);
+    let metadata = read_metadata(&mut reader)?;
+    assert_eq!(metadata.row_groups.len(), 2);
+    for row_group in &metadata.row_groups {
+        for column in row_group.columns() {
+            verify_column_chunk_crc(&mut reader, column)?;
+        }
+    }
+
+    let reader = FileReader::try_new(Cursor::new(data), None, None, None, None)?;
+    for chunk in reader {
+        assert_eq!(chunk?.into_arrays(), vec![a.clone(), b.clone(), c.clone()]);
+    }
+    Ok(())
+}
+
+/// Returns a file with a single utf8 column chunk whose pages are `pages`
+fn utf8_column_chunk(pages: Vec<EncodedPage>, num_rows: usize) -> Result<Vec<u8>> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let pages = pages
+        .into_iter()
+        .map(|page| Ok(compress(page, vec![], options.compression)?))
+        .collect::<Result<Vec<_>>>()?;
+    let pages = fallible_streaming_iterator::convert(pages.iter().map(Ok));
+    let columns = std::iter::once(Ok(DynStreamingIterator::new(pages)));
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    writer.write(DynIter::new(columns), num_rows)?;
+    let (_, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+#[test]
+fn utf8_as_dictionary() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    let keys = PrimitiveArray::<i32>::from([Some(1), None, Some(0), Some(1), Some(1)]);
+    let expected = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+    let schema = Schema::from(vec![Field::new("a", expected.data_type().clone(), true)]);
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+    let pages = array_to_pages(&expected, descriptor, options, Encoding::RleDictionary)?
+        .collect::<Result<Vec<_>>>()?;
+    let data = utf8_column_chunk(pages, expected.len())?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let columns = read_columns(&mut reader, metadata.row_groups[0].columns(), "a")?;
+    let result = deserialize_as_dictionary(columns.into_iter().next().unwrap())?;
+
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn utf8_as_dictionary_mixed_pages() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    let keys = PrimitiveArray::<i32>::from_slice([1, 0]);
+    let dictionary = DictionaryArray::<i32>::from_data(keys, Arc::new(values));
+    let plain = Utf8Array::<i32>::from([Some("c"), None]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+    let schema = Schema::from(vec![Field::new("a", DataType::Utf8, true)]);
+    let descriptor = to_parquet_schema(&schema)?.columns()[0].clone();
+    // a dictionary page, a dictionary-encoded page and a plain page
+    let mut pages = array_to_pages(
+        &dictionary,
+        descriptor.clone(),
+        options.clone(),
+        Encoding::RleDictionary,
+    )?
+    .collect::<Result<Vec<_>>>()?;
+    pages.push(array_to_page(&plain, descriptor, options, Encoding::Plain)?);
+    let data = utf8_column_chunk(pages, 4)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    let columns = read_columns(&mut reader, metadata.row_groups[0].columns(), "a")?;
+    let result = deserialize_as_dictionary(columns[0].clone());
+    assert!(matches!(result, Err(ArrowError::NotYetImplemented(_))));
+
+    // the dense path reads it
+    let result = to_deserializer(columns, schema.fields[0].clone(), 4, None)?
+        .next()
+        .unwrap()?;
+    let expected = Utf8Array::<i32>::from([Some("bb"), Some("a"), Some("c"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn arrow_schema_and_key_value_metadata() -> Result<()> {
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string()));
+    let array = Arc::new(Int64Array::from_slice([1, 2, 3]).to(data_type.clone())) as Arc<dyn Array>;
+    let mut schema_metadata = Metadata::new();
+    schema_metadata.insert("origin".to_string(), "arrow".to_string());
+    let schema =
+        Schema::from(vec![Field::new("a1", data_type, false)]).with_metadata(schema_metadata);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let key_value_metadata = vec![
+        arrow2::io::parquet::write::KeyValue {
+            key: "writer".to_string(),
+            value: Some("test".to_string()),
+        },
+        arrow2::io::parquet::write::KeyValue {
+            key: "origin".to_string(),
+            value: Some("parquet".to_string()),
+        },
+    ];
+    let (_, writer) = writer.end(Some(key_value_metadata))?;
+    let data = writer.into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let read_schema = infer_schema(&metadata)?;
+
+    // the timezone is only declared in the arrow schema
+    assert_eq!(read_schema.fields, schema.fields);
+    // the key-value metadata is surfaced, and the arrow schema's metadata takes precedence
+    assert_eq!(
+        read_schema.metadata.get("writer"),
+        Some(&"test".to_string())
+    );
+    assert_eq!(
+        read_schema.metadata.get("origin"),
+        Some(&"arrow".to_string())
+    );
+    assert!(!read_schema.metadata.contains_key("ARROW:schema"));
+
+    let (_, result) = integration_read(&data)?;
+    assert_eq!(result[0].columns()[0].as_ref(), array.as_ref());
+    Ok(())
+}
+
+#[test]
+fn data_page_iterator_dictionary() -> Result<()> {
+    let values = Utf8Array::<i32>::from_slice(["a", "bb", "ccc"]);
+    let keys = PrimitiveArray::<i32>::from([Some(0), Some(1), None, Some(2), Some(1)]);
+    let array =
+        Arc::new(DictionaryArray::<i32>::from_data(keys, Arc::new(values))) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Snappy,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::RleDictionary],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    let mut reader = Cursor::new(writer.into_inner());
+
+    let metadata = read_metadata(&mut reader)?;
+    let column = &metadata.row_groups[0].columns()[0];
+    let pages = get_page_iterator(column, reader, None, vec![])?;
+    let pages = DataPageIterator::try_new(pages)?;
+
+    let dictionary = pages
+        .dictionary()
+        .unwrap()
+        .as_any()
+        .downcast_ref::<BinaryPageDict>()
+        .unwrap();
+    assert_eq!(dictionary.offsets(), &[0, 1, 3, 6]);
+    assert_eq!(dictionary.values(), b"abbccc");
+
+    let num_values = pages
+        .map(|page| {
+            let page = page?;
+            assert_eq!(page.encoding(), Encoding::RleDictionary);
+            Ok(page.num_values())
+        })
+        .sum::<Result<usize>>()?;
+    assert_eq!(num_values, 5);
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use futures::{io::Cursor, StreamExt};
+
+use arrow2::error::Result;
+
+use super::*;
+
+fn write_row_groups(chunks: Vec<Chunk<Arc<dyn Array>>>, schema: &Schema) -> Result<Vec<u8>> {
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = chunks.into_iter().map(Ok);
+    let encodings = schema.fields.iter().map(|_| Encoding::Plain).collect();
+    let row_groups = RowGroupIterator::try_new(iter, schema, options.clone(), encodings)?;
+
+    let mut writer = FileWriter::try_new(std::io::Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+fn chunks() -> Vec<Chunk<Arc<dyn Array>>> {
+    let a1 = Arc::new(Int32Array::from(&[Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let b1 = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])) as Arc<dyn Array>;
+    let a2 = Arc::new(Int32Array::from(&[Some(4), Some(5)])) as Arc<dyn Array>;
+    let b2 = Arc::new(Utf8Array::<i32>::from_slice(["d", "e"])) as Arc<dyn Array>;
+    vec![Chunk::new(vec![a1, b1]), Chunk::new(vec![a2, b2])]
+}
+
+fn schema() -> Schema {
+    Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ])
+}
+
+#[tokio::test]
+async fn read_row_groups() -> Result<()> {
+    let schema = schema();
+    let expected = chunks();
+    let data = write_row_groups(expected.clone(), &schema)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata_async(&mut reader).await?;
+    let schema = infer_schema(&metadata)?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    let mut stream = read_row_groups_async(reader, metadata.row_groups, schema.fields, None, None);
+
+    let mut result = vec![];
+    while let Some(deserializer) = stream.next().await {
+        for chunk in deserializer? {
+            result.push(chunk?);
+        }
+    }
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_row_groups_limit() -> Result<()> {
+    let schema = schema();
+    let data = write_row_groups(chunks(), &schema)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata_async(&mut reader).await?;
+    let schema = infer_schema(&metadata)?;
+
+    // only the first row group is needed, and only the first field is read
+    let stream = read_row_groups_async(
+        reader,
+        metadata.row_groups,
+        vec![schema.fields[0].clone()],
+        None,
+        Some(2),
+    );
+    let deserializers = stream.collect::<Vec<_>>().await;
+    assert_eq!(deserializers.len(), 1);
+
+    let result = deserializers
+        .into_iter()
+        .flat_map(|x| x.unwrap())
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(result.len(), 1);
+    let expected = Int32Array::from(&[Some(1), None]);
+    assert_eq!(result[0].arrays()[0].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[tokio::test]
+async fn file_streamer_compression_per_column() -> Result<()> {
+    let schema = schema();
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = chunks().into_iter().map(Ok);
+    let encodings = schema.fields.iter().map(|_| Encoding::Plain).collect();
+    let row_groups = RowGroupIterator::try_new(iter, &schema, options.clone(), encodings)?
+        .with_compression(CompressionPerColumn::new().with(1, Compression::Snappy))?;
+
+    let mut writer = FileStreamer::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start().await?;
+    let (group, len) = row_groups.into_iter().next().unwrap()?;
+    // the footer would declare the compression of the options for every column
+    let error = writer.write(group, len).await.unwrap_err();
+    assert!(error.to_string().contains("FileStreamer"));
+    Ok(())
+}
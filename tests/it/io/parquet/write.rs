@@ -38,11 +38,14 @@ fn round_trip(
         write_statistics: true,
         compression,
         version,
+        write_crc: false,
+        bloom_filters: None,
     };
 
     let iter = vec![Chunk::try_new(vec![array.clone()])];
 
-    let row_groups = RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![encoding])?;
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options.clone(), vec![encoding])?;
 
     let writer = Cursor::new(vec![]);
     let mut writer = FileWriter::try_new(writer, schema, options)?;
@@ -110,6 +113,42 @@ fn int64_optional_v2_compressed() -> Result<()> {
     )
 }
 
+#[test]
+fn int64_optional_v1_delta() -> Result<()> {
+    round_trip(
+        0,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn int64_required_v2_delta() -> Result<()> {
+    round_trip(
+        0,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn float64_optional_v2_delta_fallback() -> Result<()> {
+    round_trip(
+        1,
+        true,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
 #[test]
 fn utf8_optional_v1() -> Result<()> {
     round_trip(
@@ -519,3 +558,738 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+fn decimal_26_statistics(values: &[i128]) -> Result<(i128, i128)> {
+    let array = PrimitiveArray::<i128>::from_slice(values).to(DataType::Decimal(26, 0));
+    let array: Arc<dyn Array> = Arc::new(array);
+
+    let field = Field::new("a1", array.data_type().clone(), false);
+    let schema = Schema::from(vec![field]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+
+    let data = writer.into_inner();
+
+    let (result, stats) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+
+    let stats = stats.unwrap();
+    let stats = stats
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<i128>>()
+        .unwrap();
+    Ok((stats.min_value.unwrap(), stats.max_value.unwrap()))
+}
+
+#[test]
+fn decimal_26_negative_statistics() -> Result<()> {
+    let (min, max) = decimal_26_statistics(&[-256, -1, -1_000_000_000_000_000_000])?;
+    assert_eq!(min, -1_000_000_000_000_000_000);
+    assert_eq!(max, -1);
+    Ok(())
+}
+
+#[test]
+fn decimal_26_mixed_sign_statistics() -> Result<()> {
+    let (min, max) = decimal_26_statistics(&[5, -256, 7, -1, 0])?;
+    assert_eq!(min, -256);
+    assert_eq!(max, 7);
+    Ok(())
+}
+
+#[test]
+fn decimal256_round_trip() -> Result<()> {
+    use arrow2::types::i256;
+
+    let min = i256::from_words(-(1i128 << 100), 0);
+    let max = i256::from_words(1i128 << 100, 7);
+    let array = PrimitiveArray::<i256>::from([
+        Some(i256::from(-1i128)),
+        None,
+        Some(max),
+        Some(min),
+        Some(i256::ZERO),
+    ])
+    .to(DataType::Decimal256(76, 2));
+    let array: Arc<dyn Array> = Arc::new(array);
+
+    for write_arrow_schema in [true, false] {
+        let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+        let options = WriteOptions {
+            write_statistics: true,
+            compression: Compression::Uncompressed,
+            version: Version::V2,
+            write_crc: false,
+            bloom_filters: None,
+        };
+        let iter = vec![Chunk::try_new(vec![array.clone()])];
+        let row_groups = RowGroupIterator::try_new(
+            iter.into_iter(),
+            &schema,
+            options.clone(),
+            vec![Encoding::Plain],
+        )?;
+
+        let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?
+            .with_arrow_schema(write_arrow_schema);
+        writer.start()?;
+        for group in row_groups {
+            let (group, len) = group?;
+            writer.write(group, len)?;
+        }
+        let (_, writer) = writer.end(None)?;
+        let data = writer.into_inner();
+
+        // the parquet schema declares the decimal on 32 bytes
+        let metadata = read_metadata(&mut Cursor::new(&data))?;
+        assert_eq!(infer_schema(&metadata)?, schema);
+
+        let (result, stats) = read_column(&mut Cursor::new(data), 0, 0)?;
+        assert_eq!(array.as_ref(), result.as_ref());
+
+        let stats = stats.unwrap();
+        let stats = stats
+            .as_any()
+            .downcast_ref::<PrimitiveStatistics<i256>>()
+            .unwrap();
+        assert_eq!(stats.null_count, Some(1));
+        assert_eq!(stats.min_value, Some(min));
+        assert_eq!(stats.max_value, Some(max));
+    }
+    Ok(())
+}
+
+#[test]
+fn decimal256_negative_statistics() -> Result<()> {
+    use arrow2::types::{i256, NativeType};
+
+    // negative values encoded as 20-byte big-endian two's complement, whose byte-wise
+    // order is also their numerical order
+    let min = i256::from(-(1i128 << 100));
+    let max = i256::from(-1i128);
+    let values = [max, min]
+        .iter()
+        .flat_map(|x| x.to_be_bytes()[12..].to_vec())
+        .collect::<Vec<_>>();
+    let array = FixedSizeBinaryArray::from_data(DataType::FixedSizeBinary(20), values.into(), None);
+    let array: Arc<dyn Array> = Arc::new(array);
+
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), false)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: None,
+    };
+    let iter = vec![Chunk::try_new(vec![array])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let field = Field::new("a1", DataType::Decimal256(45, 0), false);
+    let stats = deserialize_statistics(&field, metadata.row_groups[0].columns())?;
+    let stats = stats[0].as_ref().unwrap();
+    let stats = stats
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<i256>>()
+        .unwrap();
+    assert_eq!(stats.data_type, DataType::Decimal256(45, 0));
+    assert_eq!(stats.null_count, Some(0));
+    assert_eq!(stats.min_value, Some(min));
+    assert_eq!(stats.max_value, Some(max));
+    Ok(())
+}
+
+#[test]
+fn bloom_filter_round_trip() -> Result<()> {
+    let present = (0..1000)
+        .map(|x| format!("value_{}", x))
+        .collect::<Vec<_>>();
+    let array = Utf8Array::<i32>::from_iter(
+        present
+            .iter()
+            .map(Some)
+            .chain(std::iter::once(None::<&String>)),
+    );
+
+    let filter = build_bloom_filter(&array, 0.01)?;
+
+    let mut data = vec![];
+    let size = filter.write(&mut data)?;
+    assert_eq!(size as usize, data.len());
+
+    let filter = BloomFilter::read(&mut data.as_slice())?;
+
+    for value in &present {
+        assert!(filter.contains(value.as_bytes()));
+    }
+    let false_positives = (0..1000)
+        .map(|x| format!("absent_{}", x))
+        .filter(|x| filter.contains(x.as_bytes()))
+        .count();
+    assert!(false_positives < 50);
+    Ok(())
+}
+
+fn bloom_filter_offsets(data: &[u8]) -> Result<Vec<Vec<Option<i64>>>> {
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    Ok(metadata
+        .row_groups
+        .iter()
+        .map(|row_group| {
+            row_group
+                .columns()
+                .iter()
+                .map(|column| {
+                    column
+                        .clone()
+                        .into_thrift()
+                        .meta_data
+                        .unwrap()
+                        .bloom_filter_offset
+                })
+                .collect()
+        })
+        .collect())
+}
+
+fn read_bloom_filter(data: &[u8], offset: Option<i64>) -> Result<BloomFilter> {
+    let mut reader = Cursor::new(data);
+    reader.seek(std::io::SeekFrom::Start(offset.unwrap() as u64))?;
+    BloomFilter::read(&mut reader)
+}
+
+#[test]
+fn bloom_filter_file_round_trip() -> Result<()> {
+    let values = (0..200).map(|x| format!("value_{}", x)).collect::<Vec<_>>();
+    let chunks = values
+        .chunks(100)
+        .map(|values| {
+            let strings = Utf8Array::<i32>::from_iter(values.iter().map(Some).chain([None]));
+            let delta = Utf8Array::<i64>::from_iter(values.iter().map(Some).chain([None]));
+            let mut dict = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+            dict.try_extend(values.iter().map(Some).chain([None]))?;
+            let dict: DictionaryArray<i32> = dict.into();
+            let ints = Int32Array::from_slice([1; 101]);
+            Chunk::try_new(vec![
+                Arc::new(ints) as Arc<dyn Array>,
+                Arc::new(strings),
+                Arc::new(delta),
+                Arc::new(dict),
+            ])
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let schema = Schema::from(vec![
+        Field::new("ints", DataType::Int32, false),
+        Field::new("strings", DataType::Utf8, true),
+        Field::new("delta", DataType::LargeUtf8, true),
+        Field::new("dict", chunks[0].arrays()[3].data_type().clone(), true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: Some(BloomFilterOptions {
+            columns: vec![
+                "strings".to_string(),
+                "delta".to_string(),
+                "dict".to_string(),
+            ],
+            false_positive_probability: 0.01,
+        }),
+    };
+    let encodings = vec![
+        Encoding::Plain,
+        Encoding::Plain,
+        Encoding::DeltaLengthByteArray,
+        Encoding::RleDictionary,
+    ];
+    let row_groups = RowGroupIterator::try_new(
+        chunks.clone().into_iter().map(Ok),
+        &schema,
+        options.clone(),
+        encodings,
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (size, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+    assert_eq!(size as usize, data.len());
+
+    let offsets = bloom_filter_offsets(&data)?;
+    assert_eq!(offsets.len(), 2);
+    for (row_group, offsets) in offsets.iter().enumerate() {
+        assert_eq!(offsets[0], None);
+        for offset in &offsets[1..] {
+            let filter = read_bloom_filter(&data, *offset)?;
+            for value in &values[row_group * 100..(row_group + 1) * 100] {
+                assert!(filter.contains(value.as_bytes()));
+            }
+            // the values of the other row group are (probably) not in this filter
+            let other = &values[(1 - row_group) * 100..(2 - row_group) * 100];
+            let false_positives = other
+                .iter()
+                .filter(|x| filter.contains(x.as_bytes()))
+                .count();
+            assert!(false_positives < 10);
+        }
+    }
+
+    // the file is still readable (`DeltaLengthByteArray` pages cannot be read)
+    let reader = FileReader::try_new(Cursor::new(data), Some(&[0, 1, 3]), None, None, None)?;
+    let read = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(read.len(), 2);
+    for (read, chunk) in read.iter().zip(chunks.iter()) {
+        assert_eq!(read.arrays()[1].as_ref(), chunk.arrays()[1].as_ref());
+        assert_eq!(read.arrays()[2].as_ref(), chunk.arrays()[3].as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn bloom_filter_with_crc() -> Result<()> {
+    let values = (0..100).map(|x| format!("value_{}", x)).collect::<Vec<_>>();
+    let array: Arc<dyn Array> = Arc::new(Utf8Array::<i32>::from_slice(&values));
+    let schema = Schema::from(vec![Field::new("strings", DataType::Utf8, false)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: true,
+        bloom_filters: Some(BloomFilterOptions {
+            columns: vec!["strings".to_string()],
+            false_positive_probability: 0.01,
+        }),
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(Chunk::try_new(vec![array.clone()])?)].into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+
+    let offsets = bloom_filter_offsets(&data)?;
+    let filter = read_bloom_filter(&data, offsets[0][0])?;
+    for value in &values {
+        assert!(filter.contains(value.as_bytes()));
+    }
+
+    let (read, _) = read_column(Cursor::new(data), 0, 0)?;
+    assert_eq!(read.as_ref(), array.as_ref());
+    Ok(())
+}
+
+#[test]
+fn bloom_filter_invalid_columns() {
+    let schema = Schema::from(vec![
+        Field::new("ints", DataType::Int32, false),
+        Field::new("strings", DataType::Utf8, false),
+    ]);
+    let options = |columns: &[&str], fpp| WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+        write_crc: false,
+        bloom_filters: Some(BloomFilterOptions {
+            columns: columns.iter().map(|x| x.to_string()).collect(),
+            false_positive_probability: fpp,
+        }),
+    };
+
+    let writer = |options| FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options);
+    assert!(writer(options(&["strings"], 0.01)).is_ok());
+    assert!(writer(options(&["absent"], 0.01)).is_err());
+    assert!(writer(options(&["ints"], 0.01)).is_err());
+    assert!(writer(options(&["strings"], 1.0)).is_err());
+}
+
+#[test]
+fn bloom_filter_hash() {
+    // xxHash64 of the empty string with seed 0, as used by parquet-mr
+    assert_eq!(BloomFilter::hash(b""), 0xef46db3751d8e999);
+}
+
+#[test]
+fn list_int64_statistics() -> Result<()> {
+    let array: Arc<dyn Array> = pyarrow_nested_nullable(0).into();
+
+    let field = Field::new("a1", array.data_type().clone(), true);
+    let schema = Schema::from(vec![field]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+
+    let data = writer.into_inner();
+
+    let (_, stats) = read_column(&mut Cursor::new(data), 0, 0)?;
+    let stats = stats.unwrap();
+    let stats = stats.as_any().downcast_ref::<ListStatistics>().unwrap();
+    assert_eq!(&stats.data_type, array.data_type());
+
+    // 1 null value and 2 null lists; the empty list is not counted
+    assert_eq!(stats.null_count(), Some(3));
+    let values = stats
+        .values
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<i64>>()
+        .unwrap();
+    assert_eq!(values.min_value, Some(0));
+    assert_eq!(values.max_value, Some(10));
+    assert_eq!(values.null_count, Some(3));
+    Ok(())
+}
+
+fn write_single_array(array: Arc<dyn Array>, encoding: Encoding) -> Result<Vec<u8>> {
+    let field = Field::new("a1", array.data_type().clone(), true);
+    let schema = Schema::from(vec![field]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options.clone(), vec![encoding])?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_size, writer) = writer.end(None)?;
+    Ok(writer.into_inner())
+}
+
+fn delta_round_trip(array: Arc<dyn Array>) -> Result<Vec<u8>> {
+    let data = write_single_array(array.clone(), Encoding::DeltaBinaryPacked)?;
+
+    let (result, _) = read_column(&mut Cursor::new(data.clone()), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(data)
+}
+
+#[test]
+fn delta_timestamps() -> Result<()> {
+    // monotonically increasing, with nulls, spanning multiple blocks
+    let array = Arc::new(
+        Int64Array::from_iter(
+            (0..1000i64).map(|x| (x % 7 != 0).then(|| 1_600_000_000_000 + x * 1000 + x % 3)),
+        )
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None)),
+    ) as Arc<dyn Array>;
+
+    let delta = delta_round_trip(array.clone())?;
+    let plain = write_single_array(array, Encoding::Plain)?;
+    assert!(delta.len() * 4 < plain.len());
+    Ok(())
+}
+
+#[test]
+fn delta_int32() -> Result<()> {
+    let array = Arc::new(Int32Array::from_iter(
+        (0..300).map(|x| Some((x * x) % 1000 - 500)),
+    )) as Arc<dyn Array>;
+    delta_round_trip(array).map(|_| ())
+}
+
+#[test]
+fn delta_large_deltas() -> Result<()> {
+    // deltas that overflow `i64` and mini-blocks of up to 64 bits
+    let array = Arc::new(Int64Array::from_slice([i64::MIN, 0, i64::MAX, 1, -1])) as Arc<dyn Array>;
+    let data = delta_round_trip(array)?;
+
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+    let column = &metadata.row_groups[0].columns()[0];
+    assert!(column
+        .column_encoding()
+        .contains(&Encoding::DeltaBinaryPacked.into()));
+
+    let array = Arc::new(Int64Array::from_iter(
+        (0..300i64).map(|x| Some(x.wrapping_mul(0x5DEECE66D_i64 << 20))),
+    )) as Arc<dyn Array>;
+    delta_round_trip(array).map(|_| ())
+}
+
+#[test]
+fn delta_fallback_warning() -> Result<()> {
+    let array = Arc::new(Float64Array::from([Some(1.5), None, Some(-2.0)])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", DataType::Float64, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+    let row_groups = RowGroupIterator::try_new(
+        vec![Chunk::try_new(vec![array.clone()])].into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::DeltaBinaryPacked],
+    )?;
+    assert_eq!(
+        row_groups.encoding_fallbacks(),
+        &[EncodingFallback {
+            field: "a".to_string(),
+            data_type: DataType::Float64,
+            encoding: Encoding::DeltaBinaryPacked,
+        }]
+    );
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema, options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let column = &metadata.row_groups[0].columns()[0];
+    assert!(column.column_encoding().contains(&Encoding::Plain.into()));
+    assert!(!column
+        .column_encoding()
+        .contains(&Encoding::DeltaBinaryPacked.into()));
+
+    let (result, _) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(result, array);
+    Ok(())
+}
+
+#[test]
+fn delta_empty_and_single() -> Result<()> {
+    delta_round_trip(Arc::new(Int64Array::from_slice([1])))?;
+    delta_round_trip(Arc::new(Int64Array::from(&[None, None])))?;
+    Ok(())
+}
+
+#[test]
+fn compression_per_column() -> Result<()> {
+    let strings = Arc::new(Utf8Array::<i32>::from([
+        Some("a"),
+        None,
+        Some("bb"),
+        Some("a"),
+    ])) as Arc<dyn Array>;
+    let ints = Arc::new(Int32Array::from([Some(1), Some(2), None, Some(4)])) as Arc<dyn Array>;
+    let longs = Arc::new(Int64Array::from_slice([1, 2, 3, 4])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("strings", DataType::Utf8, true),
+        Field::new("ints", DataType::Int32, true),
+        Field::new("longs", DataType::Int64, false),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Snappy,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+    let compressions = CompressionPerColumn::new()
+        .with(0, Compression::Zstd)
+        .with(1, Compression::Uncompressed);
+
+    let chunk = Chunk::try_new(vec![strings, ints, longs])?;
+    let row_groups = RowGroupIterator::try_new(
+        vec![Ok(chunk.clone())].into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain; 3],
+    )?
+    .with_compression(compressions)?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?;
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    let data = writer.into_inner();
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    let codecs = metadata.row_groups[0]
+        .columns()
+        .iter()
+        .map(|column| column.compression())
+        .collect::<Vec<_>>();
+    // the last column was not declared and falls back to the compression of the options
+    assert_eq!(
+        codecs,
+        vec![
+            Compression::Zstd,
+            Compression::Uncompressed,
+            Compression::Snappy
+        ]
+    );
+
+    let (read_schema, chunks) = integration_read(&data)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(chunks, vec![chunk]);
+    Ok(())
+}
+
+#[test]
+fn compression_per_column_out_of_bounds() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+    let iter = vec![Chunk::try_new(vec![
+        Arc::new(Int32Array::from_slice([1])) as Arc<dyn Array>
+    ])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+
+    assert!(row_groups
+        .with_compression(CompressionPerColumn::new().with(1, Compression::Zstd))
+        .is_err());
+    Ok(())
+}
+
+fn write_timestamp_tz(write_arrow_schema: bool) -> Result<(Schema, Vec<u8>)> {
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, Some("Europe/Paris".to_string()));
+    let array = Arc::new(Int64Array::from([Some(1), None, Some(3)]).to(data_type.clone()))
+        as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a1", data_type, true)]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+        write_crc: false,
+        bloom_filters: None,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options.clone(),
+        vec![Encoding::Plain],
+    )?;
+
+    let mut writer = FileWriter::try_new(Cursor::new(vec![]), schema.clone(), options)?
+        .with_arrow_schema(write_arrow_schema);
+    writer.start()?;
+    for group in row_groups {
+        let (group, len) = group?;
+        writer.write(group, len)?;
+    }
+    let (_, writer) = writer.end(None)?;
+    Ok((schema, writer.into_inner()))
+}
+
+#[test]
+fn arrow_schema_timezone() -> Result<()> {
+    let (schema, data) = write_timestamp_tz(true)?;
+
+    let (read_schema, chunks) = integration_read(&data)?;
+    assert_eq!(read_schema, schema);
+    assert_eq!(
+        chunks[0].columns()[0].data_type(),
+        schema.fields[0].data_type()
+    );
+    Ok(())
+}
+
+#[test]
+fn without_arrow_schema() -> Result<()> {
+    let (schema, data) = write_timestamp_tz(false)?;
+
+    let metadata = read_metadata(&mut Cursor::new(&data))?;
+    assert!(metadata.key_value_metadata().is_none());
+
+    // the types are inferred from the parquet schema, that has no timezone
+    let read_schema = infer_schema(&metadata)?;
+    assert_ne!(read_schema.fields, schema.fields);
+    Ok(())
+}
@@ -381,3 +381,77 @@ fn write_union() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn write_mixed_with_options() -> Result<()> {
+    let int = Int32Array::from(&[Some(1), None, Some(300)]);
+    let utf8 = Utf8Array::<i32>::from([Some("short"), Some("a rather long value"), None]);
+
+    let mut list = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    list.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![Some(1), None, Some(3), Some(4), Some(5)]),
+    ])?;
+    let list: ListArray<i32> = list.into();
+
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let values = vec![
+        Arc::new(Int32Array::from(&[Some(1), None, Some(2)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([
+            Some("a"),
+            Some("b"),
+            Some("cccccc"),
+        ])) as Arc<dyn Array>,
+    ];
+    let struct_ = StructArray::from_data(DataType::Struct(fields), values, None);
+
+    let batch = Chunk::try_new(vec![&int as &dyn Array, &utf8, &list, &struct_])?;
+
+    let options = WriteOptions {
+        max_width: Some(10),
+        null: "null",
+    };
+    let table = write_with_options(&[batch], &["int", "utf8", "list", "struct"], options);
+
+    let expected = vec![
+        "+------+------------+------------+------------+",
+        "| int  | utf8       | list       | struct     |",
+        "+------+------------+------------+------------+",
+        "| 1    | short      | [1, 2]     | {a: 1, ..} |",
+        "| null | a rathe... | null       | {a: nul..} |",
+        "| 300  | null       | [1, nul..] | {a: 2, ..} |",
+        "+------+------------+------------+------------+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
+
+#[test]
+fn write_narrow_width() -> Result<()> {
+    let utf8 = Utf8Array::<i32>::from([Some("abc"), Some("a"), None]);
+    let batch = Chunk::try_new(vec![&utf8 as &dyn Array])?;
+
+    let options = WriteOptions {
+        max_width: Some(2),
+        null: "null",
+    };
+    let table = write_with_options(&[batch], &["a"], options);
+
+    let expected = vec![
+        "+----+", "| a  |", "+----+", "| ab |", "| a  |", "| nu |", "+----+",
+    ];
+
+    let actual: Vec<&str> = table.lines().collect();
+
+    assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use arrow2::{
-    array::{Array, BooleanArray},
+    array::{
+        Array, BooleanArray, Int32Array, ListArray, MutableListArray, MutablePrimitiveArray,
+        TryExtend,
+    },
     datatypes::{DataType, Field},
-    scalar::{ListScalar, Scalar},
+    scalar::{new_scalar, ListScalar, Scalar},
 };
 
 #[allow(clippy::eq_op)]
@@ -40,3 +43,30 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn from_array() {
+    let data = vec![Some(vec![Some(1i32), None]), None, Some(vec![Some(3i32)])];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let scalar = new_scalar(&array, 0);
+    let expected = ListScalar::<i32>::new(
+        array.data_type().clone(),
+        Some(Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+
+    let scalar = new_scalar(&array, 1);
+    assert!(!scalar.is_valid());
+    assert_eq!(scalar.data_type(), array.data_type());
+
+    // sliced
+    let scalar = new_scalar(&array.slice(2, 1), 0);
+    let expected = ListScalar::<i32>::new(
+        array.data_type().clone(),
+        Some(Arc::new(Int32Array::from([Some(3)])) as Arc<dyn Array>),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use arrow2::{
+    array::{Array, Int32Array, MapArray, StructArray, Utf8Array},
+    bitmap::Bitmap,
+    datatypes::{DataType, Field},
+    scalar::{new_scalar, MapScalar, Scalar},
+};
+
+fn entries() -> DataType {
+    DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ])
+}
+
+fn values(keys: &[&str], values: &[Option<i32>]) -> Arc<dyn Array> {
+    Arc::new(StructArray::from_data(
+        entries(),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(keys)),
+            Arc::new(Int32Array::from(values)),
+        ],
+        None,
+    ))
+}
+
+#[allow(clippy::eq_op)]
+#[test]
+fn equal() {
+    let dt = DataType::Map(Box::new(Field::new("entries", entries(), false)), false);
+    let a = MapScalar::new(dt.clone(), Some(values(&["a"], &[Some(1)])));
+    let b = MapScalar::new(dt.clone(), None);
+    assert_eq!(a, a);
+    assert_eq!(b, b);
+    assert!(a != b);
+    let b = MapScalar::new(dt, Some(values(&["a"], &[None])));
+    assert!(a != b);
+    assert_eq!(b, b);
+}
+
+#[test]
+fn basics() {
+    let dt = DataType::Map(Box::new(Field::new("entries", entries(), false)), false);
+    let a = MapScalar::new(dt.clone(), Some(values(&["a"], &[Some(1)])));
+
+    assert_eq!(
+        values(&["a"], &[Some(1)]).as_ref(),
+        a.values().unwrap().as_ref()
+    );
+    assert_eq!(a.data_type(), &dt);
+    assert!(a.is_valid());
+
+    let _: &dyn std::any::Any = a.as_any();
+}
+
+#[test]
+fn from_array() {
+    let dt = DataType::Map(Box::new(Field::new("entries", entries(), false)), false);
+    let array = MapArray::from_data(
+        dt.clone(),
+        vec![0, 2, 2, 3].into(),
+        values(&["a", "b", "c"], &[Some(1), None, Some(3)]),
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let scalar = new_scalar(&array, 0);
+    let expected = MapScalar::new(dt.clone(), Some(values(&["a", "b"], &[Some(1), None])));
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+
+    let scalar = new_scalar(&array, 1);
+    assert_eq!(scalar.as_ref(), &MapScalar::new(dt, None) as &dyn Scalar);
+}
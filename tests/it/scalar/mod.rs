@@ -3,10 +3,12 @@ mod boolean;
 mod fixed_size_binary;
 mod fixed_size_list;
 mod list;
+mod map;
 mod null;
 mod primitive;
 mod struct_;
 mod utf8;
+mod utf8_view;
 
 // check that `PartialEq` can be derived
 #[derive(PartialEq)]
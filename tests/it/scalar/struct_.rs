@@ -1,8 +1,10 @@
 use std::sync::Arc;
 
 use arrow2::{
+    array::{Array, BooleanArray, Int32Array, StructArray},
+    bitmap::Bitmap,
     datatypes::{DataType, Field},
-    scalar::{BooleanScalar, Scalar, StructScalar},
+    scalar::{new_scalar, BooleanScalar, PrimitiveScalar, Scalar, StructScalar},
 };
 
 #[allow(clippy::eq_op)]
@@ -43,3 +45,46 @@ fn basics() {
 
     let _: &dyn std::any::Any = a.as_any();
 }
+
+#[test]
+fn from_array() {
+    let dt = DataType::Struct(vec![
+        Field::new("a", DataType::Boolean, true),
+        Field::new("b", DataType::Int32, true),
+    ]);
+    let array = StructArray::from_data(
+        dt.clone(),
+        vec![
+            Arc::new(BooleanArray::from([Some(true), None, Some(false)])) as Arc<dyn Array>,
+            Arc::new(Int32Array::from([Some(1), Some(2), None])),
+        ],
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let scalar = new_scalar(&array, 0);
+    let expected = StructScalar::new(
+        dt.clone(),
+        Some(vec![
+            Arc::new(BooleanScalar::from(Some(true))) as Arc<dyn Scalar>,
+            Arc::new(PrimitiveScalar::from(Some(1i32))),
+        ]),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+
+    let scalar = new_scalar(&array, 1);
+    assert_eq!(
+        scalar.as_ref(),
+        &StructScalar::new(dt.clone(), None) as &dyn Scalar
+    );
+
+    // a valid struct with a null field
+    let scalar = new_scalar(&array, 2);
+    let expected = StructScalar::new(
+        dt,
+        Some(vec![
+            Arc::new(BooleanScalar::from(Some(false))) as Arc<dyn Scalar>,
+            Arc::new(PrimitiveScalar::<i32>::from(None)),
+        ]),
+    );
+    assert_eq!(scalar.as_ref(), &expected as &dyn Scalar);
+}
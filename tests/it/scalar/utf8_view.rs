@@ -0,0 +1,45 @@
+use arrow2::{
+    array::{Array, Utf8ViewArray},
+    datatypes::DataType,
+    scalar::{new_scalar, Scalar, Utf8ViewScalar},
+};
+
+#[allow(clippy::eq_op)]
+#[test]
+fn equal() {
+    let a = Utf8ViewScalar::from(Some("a"));
+    let b = Utf8ViewScalar::from(None::<&str>);
+    assert_eq!(a, a);
+    assert_eq!(b, b);
+    assert!(a != b);
+    let b = Utf8ViewScalar::from(Some("b"));
+    assert!(a != b);
+    assert_eq!(b, b);
+}
+
+#[test]
+fn basics() {
+    let a = Utf8ViewScalar::from(Some("a"));
+
+    assert_eq!(a.value(), Some("a"));
+    assert_eq!(a.data_type(), &DataType::Utf8View);
+    assert!(a.is_valid());
+
+    let a = Utf8ViewScalar::from(None::<&str>);
+
+    assert_eq!(a.data_type(), &DataType::Utf8View);
+    assert!(!a.is_valid());
+
+    let _: &dyn std::any::Any = a.as_any();
+}
+
+#[test]
+fn from_array() {
+    let array = Utf8ViewArray::from([Some("a"), None, Some("a string longer than 12 bytes")]);
+    for (i, value) in array.iter().enumerate() {
+        let scalar = new_scalar(&array, i);
+        assert_eq!(scalar.data_type(), array.data_type());
+        let expected: Box<dyn Scalar> = Box::new(Utf8ViewScalar::new(value));
+        assert_eq!(scalar, expected);
+    }
+}
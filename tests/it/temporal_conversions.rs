@@ -139,3 +139,81 @@ fn add_interval_timezone() {
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("2020-10-29 01:00:00 WET", format!("{}", r));
 }
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn add_interval_days_across_dst() {
+    // Sat Mar 28 2020 12:00:00 WET; Europe/Lisbon changes to WEST the next night
+    let timestamp = 1585396800;
+    let timeunit = TimeUnit::Second;
+    let timezone = temporal_conversions::parse_offset_tz("Europe/Lisbon").unwrap();
+
+    // a day keeps the local time, and is thus 23 hours long
+    let r = temporal_conversions::add_interval(
+        timestamp,
+        timeunit,
+        months_days_ns::new(0, 1, 0),
+        &timezone,
+    );
+    assert_eq!(r, 1585479600);
+    let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
+    assert_eq!("2020-03-29 12:00:00 WEST", format!("{}", r));
+}
+
+#[test]
+fn add_naive_interval_month_end() {
+    // Sun Jan 31 2021 10:00:00
+    let timestamp = 1612087200000;
+    let timeunit = TimeUnit::Millisecond;
+
+    let r =
+        temporal_conversions::add_naive_interval(timestamp, timeunit, months_days_ns::new(1, 0, 0));
+    let r = temporal_conversions::timestamp_to_naive_datetime(r, timeunit);
+    assert_eq!("2021-02-28 10:00:00", format!("{}", r));
+
+    let r = temporal_conversions::add_naive_interval(
+        timestamp,
+        timeunit,
+        months_days_ns::new(-2, 0, 0),
+    );
+    let r = temporal_conversions::timestamp_to_naive_datetime(r, timeunit);
+    assert_eq!("2020-11-30 10:00:00", format!("{}", r));
+}
+
+#[test]
+fn add_interval_overflow() {
+    // 2262-01-01T00:00:00, close to the last nanosecond timestamp (2262-04-11)
+    let timestamp = 9_214_646_400_000_000_000;
+    let timeunit = TimeUnit::Nanosecond;
+
+    let interval = months_days_ns::new(3, 0, 0);
+    let r = temporal_conversions::checked_add_naive_interval(timestamp, timeunit, interval);
+    assert_eq!(r, Some(9_222_422_400_000_000_000));
+
+    let interval = months_days_ns::new(4, 0, 0);
+    assert_eq!(
+        temporal_conversions::checked_add_naive_interval(timestamp, timeunit, interval),
+        None
+    );
+    let timezone = temporal_conversions::parse_offset("+01:00").unwrap();
+    assert_eq!(
+        temporal_conversions::checked_add_interval(timestamp, timeunit, interval, &timezone),
+        None
+    );
+
+    // out of the range of dates
+    let interval = months_days_ns::new(i32::MAX, 0, 0);
+    assert_eq!(
+        temporal_conversions::checked_add_naive_interval(0, TimeUnit::Second, interval),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn add_interval_overflow_panics() {
+    // 2262-01-01T00:00:00, less than 4 months before the last nanosecond timestamp
+    let timestamp = 9_214_646_400_000_000_000;
+    let interval = months_days_ns::new(4, 0, 0);
+    temporal_conversions::add_naive_interval(timestamp, TimeUnit::Nanosecond, interval);
+}